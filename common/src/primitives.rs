@@ -36,7 +36,7 @@ use frame_support::traits::ConstU32;
 use frame_support::{ensure, BoundedVec, RuntimeDebug};
 use hex_literal::hex;
 use sp_core::H256;
-use sp_runtime::traits::Get;
+use sp_runtime::traits::{Get, One, Saturating, UniqueSaturatedInto, Zero};
 use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 use static_assertions::_core::cmp::Ordering;
@@ -299,6 +299,25 @@ impl<AssetId> AssetId32<AssetId> {
 
         Self::from_bytes(bytes)
     }
+
+    /// Construct asset id for the synthetic asset derived from `reference_symbol` and `salt`,
+    /// e.g. the price transformation applied to the reference symbol price.
+    pub fn from_synthetic_reference_symbol_with_salt<Symbol, Salt>(
+        reference_symbol: &Symbol,
+        salt: &Salt,
+    ) -> Self
+    where
+        Symbol: Encode,
+        Salt: Encode,
+    {
+        let mut bytes = [0u8; 32];
+        let symbol_bytes = (reference_symbol, salt).encode();
+        let symbol_hash = sp_io::hashing::blake2_128(&symbol_bytes);
+        bytes[0] = 3;
+        bytes[2..18].copy_from_slice(&symbol_hash);
+
+        Self::from_bytes(bytes)
+    }
 }
 
 impl<AssetId> From<H256> for AssetId32<AssetId> {
@@ -1074,6 +1093,30 @@ mod tests {
         // should not panic
         serde_json::to_value(&BalanceWrapper(balance)).unwrap();
     }
+
+    #[test]
+    fn should_limit_outflow_in_rolling_window() {
+        let limit = OutflowLimit {
+            max_amount: 100,
+            window: 10u32,
+            release_delay: 5,
+        };
+        let mut window = OutflowWindow::default();
+        assert!(window.try_consume(&limit, 60, 1));
+        assert!(window.try_consume(&limit, 40, 9));
+        assert!(!window.try_consume(&limit, 1, 9));
+        // Half of the previous window is still covered by the rolling window
+        assert_eq!(window.used(limit.window, 15), 50);
+        assert!(!window.try_consume(&limit, 51, 15));
+        assert!(window.try_consume(&limit, 50, 15));
+        // Both windows are outdated
+        assert_eq!(window.used(limit.window, 40), 0);
+        assert_eq!(window.start, 40);
+        // Forced outflow is counted even if it exceeds the limit
+        window.consume(limit.window, 150, 41);
+        assert_eq!(window.used(limit.window, 41), 150);
+        assert!(!window.try_consume(&limit, 1, 45));
+    }
 }
 
 #[derive(
@@ -1098,6 +1141,7 @@ impl PriceVariant {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Oracle {
     BandChainFeed,
+    PriceFeed,
 }
 
 /// Information about received oracle symbol (price and last update time)
@@ -1168,3 +1212,89 @@ impl<N: Get<u32>> Ord for BoundedString<N> {
         self.0.cmp(&other.0)
     }
 }
+
+/// Limit of an asset amount which is allowed to leave the network through a bridge
+/// during a rolling window of `window` blocks.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OutflowLimit<BlockNumber> {
+    /// Max amount of the asset which is allowed to leave during the window
+    pub max_amount: Balance,
+    /// Length of the window in blocks
+    pub window: BlockNumber,
+    /// Number of blocks an outflow exceeding the limit is held before it can be released
+    pub release_delay: BlockNumber,
+}
+
+impl<BlockNumber: Zero> OutflowLimit<BlockNumber> {
+    pub fn is_valid(&self) -> bool {
+        !self.window.is_zero()
+    }
+}
+
+/// Outflow accounting for the rolling window. The amount sent during the previous window is
+/// weighted by the part of the previous window which is still covered by the rolling window.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq, Default)]
+pub struct OutflowWindow<BlockNumber> {
+    /// Start of the current window
+    pub start: BlockNumber,
+    /// Amount sent during the current window
+    pub current: Balance,
+    /// Amount sent during the previous window
+    pub previous: Balance,
+}
+
+impl<BlockNumber> OutflowWindow<BlockNumber>
+where
+    BlockNumber: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+{
+    fn advance(&mut self, window: BlockNumber, now: BlockNumber) {
+        let elapsed_windows = now.saturating_sub(self.start) / window;
+        if elapsed_windows.is_zero() {
+            return;
+        }
+        self.previous = if elapsed_windows == BlockNumber::one() {
+            self.current
+        } else {
+            0
+        };
+        self.current = 0;
+        self.start = self
+            .start
+            .saturating_add(elapsed_windows.saturating_mul(window));
+    }
+
+    /// Amount sent during the rolling window which ends at `now`.
+    pub fn used(&mut self, window: BlockNumber, now: BlockNumber) -> Balance {
+        self.advance(window, now);
+        let window_u128: u128 = window.unique_saturated_into();
+        let passed: u128 = now.saturating_sub(self.start).unique_saturated_into();
+        let previous_weighted = self
+            .previous
+            .saturating_mul(window_u128.saturating_sub(passed))
+            .checked_div(window_u128)
+            .unwrap_or_default();
+        self.current.saturating_add(previous_weighted)
+    }
+
+    /// Adds `amount` to the window if it fits into the `limit`. Returns `false` otherwise.
+    pub fn try_consume(
+        &mut self,
+        limit: &OutflowLimit<BlockNumber>,
+        amount: Balance,
+        now: BlockNumber,
+    ) -> bool {
+        if self.used(limit.window, now).saturating_add(amount) > limit.max_amount {
+            return false;
+        }
+        self.consume(limit.window, amount, now);
+        true
+    }
+
+    /// Adds `amount` to the window regardless of the limit. Used for the outflows which were
+    /// already allowed, e.g. released from the queue.
+    pub fn consume(&mut self, window: BlockNumber, amount: Balance, now: BlockNumber) {
+        self.advance(window, now);
+        self.current = self.current.saturating_add(amount);
+    }
+}
@@ -31,7 +31,7 @@
 use crate::prelude::{ManagementMode, QuoteAmount, SwapAmount, SwapOutcome};
 use crate::{
     Fixed, LiquiditySourceFilter, LiquiditySourceId, LiquiditySourceType, Oracle, PriceVariant,
-    PswapRemintInfo, RewardReason,
+    PswapRemintInfo, Rate, RewardReason,
 };
 use frame_support::dispatch::DispatchResult;
 use frame_support::pallet_prelude::MaybeSerializeDeserialize;
@@ -219,7 +219,7 @@ pub trait LiquiditySource<TargetId, AccountId, AssetId, Amount, Error> {
 /// *Hook*-like trait for oracles to capture newly relayed symbols.
 ///
 /// A struct implementing this trait can be specified in oracle pallet *Config*
-/// so that it will be called every time new symbols were relayed.
+/// so that it will be called every time new symbols or rates were relayed.
 pub trait OnNewSymbolsRelayed<Symbol> {
     /// Upload newly relayed symbols to oracle proxy
     /// - `symbols`: which symbols to upload
@@ -227,6 +227,13 @@ pub trait OnNewSymbolsRelayed<Symbol> {
         oracle_variant: Oracle,
         symbols: BTreeSet<Symbol>,
     ) -> Result<(), DispatchError>;
+
+    /// Upload relayed rates to oracle proxy
+    /// - `rates`: symbols with their new rates
+    fn on_rates_relayed(oracle_variant: Oracle, rates: Vec<(Symbol, Rate)>);
+
+    /// Get weight of `on_rates_relayed` called with `rates` symbols
+    fn on_rates_relayed_weight(rates: u32) -> Weight;
 }
 
 impl<Symbol> OnNewSymbolsRelayed<Symbol> for () {
@@ -236,6 +243,12 @@ impl<Symbol> OnNewSymbolsRelayed<Symbol> for () {
     ) -> Result<(), DispatchError> {
         Ok(())
     }
+
+    fn on_rates_relayed(_oracle_variant: Oracle, _rates: Vec<(Symbol, Rate)>) {}
+
+    fn on_rates_relayed_weight(_rates: u32) -> Weight {
+        Weight::zero()
+    }
 }
 
 /// `DataFeed` trait indicates that particular object could be used for querying oracle data.
@@ -719,6 +732,18 @@ pub trait PoolXykPallet<AccountId, AssetId> {
     ) -> Result<(), DispatchError> {
         Err(DispatchError::CannotLookup)
     }
+
+    fn deposit_liquidity(
+        _source: AccountId,
+        _base_asset_id: AssetId,
+        _target_asset_id: AssetId,
+        _base_desired: Balance,
+        _target_desired: Balance,
+        _base_min: Balance,
+        _target_min: Balance,
+    ) -> Result<(), DispatchError> {
+        Err(DispatchError::CannotLookup)
+    }
 }
 
 pub trait DemeterFarmingPallet<AccountId, AssetId> {
@@ -801,6 +826,59 @@ impl<AssetId> OnPoolReservesChanged<AssetId> for () {
     }
 }
 
+/// Hook which is called when a swap fee is charged from the account.
+pub trait OnSwapFeeCharged<AccountId, AssetId> {
+    /// `fee` of `fee_asset` has already been transferred to `fee_account`,
+    /// `volume` is the amount of `fee_asset` traded by `who` in the swap.
+    fn on_swap_fee_charged(
+        who: &AccountId,
+        fee_account: &AccountId,
+        fee_asset: &AssetId,
+        fee: Balance,
+        volume: Balance,
+    ) -> DispatchResult;
+
+    /// Upper bound of the weight consumed by `on_swap_fee_charged`.
+    fn on_swap_fee_charged_weight() -> Weight;
+}
+
+impl<AccountId, AssetId> OnSwapFeeCharged<AccountId, AssetId> for () {
+    fn on_swap_fee_charged(
+        _who: &AccountId,
+        _fee_account: &AccountId,
+        _fee_asset: &AssetId,
+        _fee: Balance,
+        _volume: Balance,
+    ) -> DispatchResult {
+        Ok(())
+    }
+
+    fn on_swap_fee_charged_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Check of outgoing bridge transfers against pause switches and outflow limits.
+pub trait OutflowLimiter<NetworkId, AssetId, Balance> {
+    /// Ensures that `amount` of `asset_id` is allowed to leave to `network_id` now and counts
+    /// it against the outflow limit.
+    fn ensure_outflow_allowed(
+        network_id: NetworkId,
+        asset_id: &AssetId,
+        amount: &Balance,
+    ) -> DispatchResult;
+}
+
+impl<NetworkId, AssetId, Balance> OutflowLimiter<NetworkId, AssetId, Balance> for () {
+    fn ensure_outflow_allowed(
+        _network_id: NetworkId,
+        _asset_id: &AssetId,
+        _amount: &Balance,
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
+
 /// General trait for passing on the amount of burned VAL.
 pub trait OnValBurned {
     /// Report amount and fractions of burned pswap at the moment of invokation.
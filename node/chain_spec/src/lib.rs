@@ -889,6 +889,9 @@ fn testnet_genesis(
         framenode_runtime::GetXSTPoolPermissionedTechAccountId::get();
     let xst_pool_permissioned_account_id =
         framenode_runtime::GetXSTPoolPermissionedAccountId::get();
+    let xst_cdp_collateral_tech_account_id =
+        framenode_runtime::GetXSTCdpCollateralTechAccountId::get();
+    let xst_cdp_collateral_account_id = framenode_runtime::GetXSTCdpCollateralAccountId::get();
 
     let liquidity_proxy_tech_account_id = framenode_runtime::GetLiquidityProxyTechAccountId::get();
     let liquidity_proxy_account_id = framenode_runtime::GetLiquidityProxyAccountId::get();
@@ -970,6 +973,10 @@ fn testnet_genesis(
             xst_pool_permissioned_account_id.clone(),
             xst_pool_permissioned_tech_account_id.clone(),
         ),
+        (
+            xst_cdp_collateral_account_id.clone(),
+            xst_cdp_collateral_tech_account_id.clone(),
+        ),
         (
             iroha_migration_account_id.clone(),
             iroha_migration_tech_account_id.clone(),
@@ -1742,6 +1749,9 @@ fn mainnet_genesis(
         framenode_runtime::GetXSTPoolPermissionedTechAccountId::get();
     let xst_pool_permissioned_account_id =
         framenode_runtime::GetXSTPoolPermissionedAccountId::get();
+    let xst_cdp_collateral_tech_account_id =
+        framenode_runtime::GetXSTCdpCollateralTechAccountId::get();
+    let xst_cdp_collateral_account_id = framenode_runtime::GetXSTCdpCollateralAccountId::get();
 
     let market_maker_rewards_tech_account_id =
         framenode_runtime::GetMarketMakerRewardsTechAccountId::get();
@@ -1823,6 +1833,10 @@ fn mainnet_genesis(
             xst_pool_permissioned_account_id.clone(),
             xst_pool_permissioned_tech_account_id.clone(),
         ),
+        (
+            xst_cdp_collateral_account_id.clone(),
+            xst_cdp_collateral_tech_account_id.clone(),
+        ),
         (
             iroha_migration_account_id.clone(),
             iroha_migration_tech_account_id.clone(),
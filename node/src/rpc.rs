@@ -184,6 +184,7 @@ where
         framenode_runtime::BalancePrecision,
     >,
     C::Api: iroha_migration_rpc::IrohaMigrationRuntimeAPI<Block>,
+    C::Api: multicollateral_bonding_curve_pool_rpc::MBCPoolRuntimeApi<Block, AssetId, Balance>,
     C::Api: pswap_distribution_rpc::PswapDistributionRuntimeAPI<Block, AccountId, Balance>,
     C::Api: rewards_rpc::RewardsRuntimeAPI<Block, sp_core::H160, Balance>,
     C::Api: vested_rewards_rpc::VestedRewardsRuntimeApi<
@@ -193,6 +194,16 @@ where
         Balance,
         common::CrowdloanTag,
     >,
+    C::Api: vesting_rpc::VestingRuntimeApi<Block, AccountId, AssetId, Balance>,
+    C::Api: referrals_rpc::ReferralsRuntimeApi<Block, AccountId, Balance>,
+    C::Api: ceres_liquidity_locker_rpc::CeresLiquidityLockerRuntimeApi<
+        Block,
+        AccountId,
+        AssetId,
+        Balance,
+    >,
+    C::Api: ceres_staking_rpc::CeresStakingRuntimeApi<Block, AccountId, Balance>,
+    C::Api: demeter_vaults_rpc::DemeterVaultsRuntimeApi<Block, AccountId, Balance>,
     C::Api: farming_rpc::FarmingRuntimeApi<Block, AssetId>,
     C::Api: BlockBuilder<Block>,
     C::Api: farming_rpc::FarmingRuntimeApi<Block, AssetId>,
@@ -200,6 +211,9 @@ where
 {
     use assets_rpc::{AssetsAPIServer, AssetsClient};
     use beefy_gadget_rpc::{Beefy, BeefyApiServer};
+    use ceres_liquidity_locker_rpc::{CeresLiquidityLockerApiServer, CeresLiquidityLockerClient};
+    use ceres_staking_rpc::{CeresStakingApiServer, CeresStakingClient};
+    use demeter_vaults_rpc::{DemeterVaultsApiServer, DemeterVaultsClient};
     use dex_api_rpc::{DEXAPIServer, DEX};
     use dex_manager_rpc::{DEXManager, DEXManagerAPIServer};
     use eth_bridge_rpc::{EthBridgeApiServer, EthBridgeRpc};
@@ -207,13 +221,16 @@ where
     use iroha_migration_rpc::{IrohaMigrationAPIServer, IrohaMigrationClient};
     use liquidity_proxy_rpc::{LiquidityProxyAPIServer, LiquidityProxyClient};
     use mmr_rpc::{Mmr, MmrApiServer};
+    use multicollateral_bonding_curve_pool_rpc::{MBCPoolApiServer, MBCPoolClient};
     use oracle_proxy_rpc::{OracleProxyApiServer, OracleProxyClient};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use pswap_distribution_rpc::{PswapDistributionAPIServer, PswapDistributionClient};
+    use referrals_rpc::{ReferralsApiServer, ReferralsClient};
     use rewards_rpc::{RewardsAPIServer, RewardsClient};
     use substrate_frame_rpc_system::{System, SystemApiServer};
     use trading_pair_rpc::{TradingPairAPIServer, TradingPairClient};
     use vested_rewards_rpc::{VestedRewardsApiServer, VestedRewardsClient};
+    use vesting_rpc::{VestingApiServer, VestingClient};
 
     let mut io = RpcModule::new(());
     let FullDeps {
@@ -243,9 +260,15 @@ where
     io.merge(OracleProxyClient::new(client.clone()).into_rpc())?;
     io.merge(EthBridgeRpc::new(client.clone()).into_rpc())?;
     io.merge(IrohaMigrationClient::new(client.clone()).into_rpc())?;
+    io.merge(MBCPoolClient::new(client.clone()).into_rpc())?;
     io.merge(PswapDistributionClient::new(client.clone()).into_rpc())?;
     io.merge(RewardsClient::new(client.clone()).into_rpc())?;
     io.merge(VestedRewardsClient::new(client.clone()).into_rpc())?;
+    io.merge(VestingClient::new(client.clone()).into_rpc())?;
+    io.merge(ReferralsClient::new(client.clone()).into_rpc())?;
+    io.merge(CeresLiquidityLockerClient::new(client.clone()).into_rpc())?;
+    io.merge(CeresStakingClient::new(client.clone()).into_rpc())?;
+    io.merge(DemeterVaultsClient::new(client.clone()).into_rpc())?;
     io.merge(FarmingClient::new(client.clone()).into_rpc())?;
     Ok(io)
 }
@@ -44,40 +44,52 @@ fn relayer<T: Config>() -> T::AccountId {
     T::AccountId::decode(&mut &bytes[..]).expect("Failed to decode relayer ID")
 }
 
-fn symbol<T: Config>(sym: &str) -> T::Symbol {
-    let bytes = sym.encode();
-    T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+fn symbols<T: Config>(n: u32) -> Vec<T::Symbol> {
+    (0..n)
+        .map(|i| {
+            let bytes = vec![b'S', b'A' + (i / 26) as u8, b'A' + (i % 26) as u8].encode();
+            T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+        })
+        .collect()
 }
 
 benchmarks! {
     relay {
+        let n in 1 .. T::MaxRelaySymbols::get();
         let relayer = relayer::<T>();
-        let euro = symbol::<T>("EURO");
+        let symbols = symbols::<T>(n);
+        let rates: Vec<_> = symbols.iter().cloned().map(|symbol| (symbol, 2)).collect();
         Band::<T>::add_relayers(RawOrigin::Root.into(), vec![relayer.clone()])?;
-    }: _(RawOrigin::Signed(relayer), vec![(euro.clone(), 2)].try_into().unwrap(), 100, 1)
+    }: _(RawOrigin::Signed(relayer), rates.try_into().unwrap(), 100, 1)
     verify {
-        assert_eq!(Band::<T>::rates(euro), Some(BandRate {
-            value: Band::<T>::raw_rate_into_balance(2).expect("failed to convert value to Balance"),
-            last_updated: 100,
-            request_id: 1,
-            dynamic_fee: fixed!(0),
-            last_updated_block: 1u32.into(),
-        }));
+        for symbol in symbols {
+            assert_eq!(Band::<T>::rates(symbol), Some(BandRate {
+                value: Band::<T>::raw_rate_into_balance(2).expect("failed to convert value to Balance"),
+                last_updated: 100,
+                request_id: 1,
+                dynamic_fee: fixed!(0),
+                last_updated_block: 1u32.into(),
+            }));
+        }
     }
 
     force_relay {
+        let n in 1 .. T::MaxRelaySymbols::get();
         let relayer = relayer::<T>();
-        let euro = symbol::<T>("EURO");
+        let symbols = symbols::<T>(n);
+        let rates: Vec<_> = symbols.iter().cloned().map(|symbol| (symbol, 2)).collect();
         Band::<T>::add_relayers(RawOrigin::Root.into(), vec![relayer.clone()])?;
-    }: _(RawOrigin::Signed(relayer), vec![(euro.clone(), 2)].try_into().unwrap(), 100, 1)
+    }: _(RawOrigin::Signed(relayer), rates.try_into().unwrap(), 100, 1)
     verify {
-        assert_eq!(Band::<T>::rates(euro), Some(BandRate {
-            value: Band::<T>::raw_rate_into_balance(2).expect("failed to convert value to Balance"),
-            last_updated: 100,
-            request_id: 1,
-            dynamic_fee: fixed!(0),
-            last_updated_block: 1u32.into(),
-        }));
+        for symbol in symbols {
+            assert_eq!(Band::<T>::rates(symbol), Some(BandRate {
+                value: Band::<T>::raw_rate_into_balance(2).expect("failed to convert value to Balance"),
+                last_updated: 100,
+                request_id: 1,
+                dynamic_fee: fixed!(0),
+                last_updated_block: 1u32.into(),
+            }));
+        }
     }
 
     add_relayers {
@@ -100,5 +112,18 @@ benchmarks! {
     }: _(RawOrigin::Root, parameters)
     verify {}
 
+    set_relay_quorum {
+    }: _(RawOrigin::Root, 3)
+    verify {
+        assert_eq!(Band::<T>::relay_quorum(), 3);
+    }
+
+    set_max_rate_deviation {
+        let max_deviation = Some(Percent::from_percent(10));
+    }: _(RawOrigin::Root, max_deviation)
+    verify {
+        assert_eq!(Band::<T>::max_rate_deviation(), max_deviation);
+    }
+
     impl_benchmark_test_suite!(Band, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -36,6 +36,8 @@ use common::prelude::FixedWrapper;
 use common::{fixed, fixed_wrapper, Balance, DataFeed, Fixed, OnNewSymbolsRelayed, Oracle, Rate};
 use fallible_iterator::FallibleIterator;
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::traits::Saturating;
+use frame_support::sp_runtime::{PerThing, Percent};
 use frame_support::traits::Time;
 use frame_system::pallet_prelude::*;
 use sp_std::collections::btree_set::BTreeSet;
@@ -210,6 +212,10 @@ pub mod pallet {
         /// Maximum number of symbols that can be relayed within a single call.
         #[pallet::constant]
         type MaxRelaySymbols: Get<u32>;
+        /// Number of consecutive rounds with the median deviating from the previous rate
+        /// after which the median is accepted.
+        #[pallet::constant]
+        type MaxDeviatingRounds: Get<u32>;
         /// Time used for checking if rate expired
         type Time: Time;
         /// Hook which is being executed when some symbol must be disabled
@@ -238,6 +244,43 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Rates submitted by the relayers in the current round of the symbol
+    /// along with the block number of the submission
+    #[pallet::storage]
+    #[pallet::getter(fn relay_submissions)]
+    pub type RelaySubmissions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Symbol,
+        Blake2_128Concat,
+        T::AccountId,
+        (u64, T::BlockNumber),
+        OptionQuery,
+    >;
+
+    /// Number of consecutive rounds of the symbol with the median deviating from the previous rate
+    #[pallet::storage]
+    #[pallet::getter(fn deviating_rounds)]
+    pub type DeviatingRounds<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, u32, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultRelayQuorum<T: Config<I>, I: 'static>() -> u32 {
+        1
+    }
+
+    /// Number of relayer submissions required to update the rate of the symbol
+    #[pallet::storage]
+    #[pallet::getter(fn relay_quorum)]
+    pub type RelayQuorum<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, u32, ValueQuery, DefaultRelayQuorum<T, I>>;
+
+    /// Maximum deviation of the submitted rate from the median and of the median from the previous rate
+    #[pallet::storage]
+    #[pallet::getter(fn max_rate_deviation)]
+    pub type MaxRateDeviation<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, Percent, OptionQuery>;
+
     #[pallet::type_value]
     pub fn DefaultDynamicFeeParameters<T: Config<I>, I: 'static>() -> FeeCalculationParameters {
         FeeCalculationParameters {
@@ -261,6 +304,19 @@ pub mod pallet {
         RelayersAdded(Vec<T::AccountId>),
         /// Relayer accounts were removed from trusted list. [relayers]
         RelayersRemoved(Vec<T::AccountId>),
+        /// Rates were submitted by the relayer and are waiting for the quorum. [relayer, symbols]
+        RatesSubmitted(T::AccountId, Vec<T::Symbol>),
+        /// Submitted rate deviates from the median of the round. [relayer, symbol, rate, median]
+        OutlierRelayerDetected(T::AccountId, T::Symbol, Balance, Balance),
+        /// Median of the round deviates from the previous rate and is discarded. [symbol, previous rate, median]
+        RateDeviationExceeded(T::Symbol, Balance, Balance),
+        /// Median has deviated from the previous rate for too many consecutive rounds and is accepted.
+        /// [symbol, previous rate, median]
+        DeviatingRateAccepted(T::Symbol, Balance, Balance),
+        /// Relay quorum was changed. [quorum]
+        RelayQuorumSet(u32),
+        /// Maximum rate deviation was changed. [max deviation]
+        MaxRateDeviationSet(Option<Percent>),
     }
 
     #[pallet::error]
@@ -281,6 +337,8 @@ pub mod pallet {
         DynamicFeeCalculationError,
         /// Dynamic fee parameters are invalid,
         InvalidDynamicFeeParameters,
+        /// Relay quorum must be at least 1.
+        InvalidRelayQuorum,
     }
 
     #[pallet::hooks]
@@ -309,6 +367,12 @@ pub mod pallet {
         /// - The caller is a relayer;
         /// - The `resolve_time` for a particular symbol is not lower than previous saved value, ignores this rate if so;
         ///
+        /// The rate of the symbol is updated to the median of the submissions once
+        /// [`RelayQuorum`] trusted relayers have submitted it in the current round.
+        /// If [`MaxRateDeviation`] is set, submissions deviating from the median are reported
+        /// as outliers and the median deviating from the previous rate is discarded, unless it
+        /// has deviated for `MaxDeviatingRounds` consecutive rounds.
+        ///
         /// If `rates` contains duplicated symbols, then the last rate will be stored.
         ///
         /// - `origin`: the relayer account on whose behalf the transaction is being executed,
@@ -316,14 +380,15 @@ pub mod pallet {
         /// - `resolve_time`: symbols which rates are provided,
         /// - `request_id`: id of the request sent to the *BandChain* to retrieve this data.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config<I>>::WeightInfo::relay())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::relay(rates.len() as u32).saturating_add(T::OnNewSymbolsRelayedHook::on_rates_relayed_weight(rates.len() as u32)))]
         pub fn relay(
             origin: OriginFor<T>,
             rates: BoundedVec<(T::Symbol, u64), T::MaxRelaySymbols>,
             resolve_time: u64,
             request_id: u64,
         ) -> DispatchResultWithPostInfo {
-            Self::ensure_relayer(origin)?;
+            let relayer = Self::ensure_relayer(origin)?;
+            let rates = Self::aggregate_rates(&relayer, rates)?;
 
             let symbol_rates = Self::update_rates(
                 rates,
@@ -358,7 +423,7 @@ pub mod pallet {
         /// - `resolve_time`: symbols which rates are provided,
         /// - `request_id`: id of the request sent to the *BandChain* to retrieve this data.
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as Config<I>>::WeightInfo::force_relay())]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::force_relay(rates.len() as u32).saturating_add(T::OnNewSymbolsRelayedHook::on_rates_relayed_weight(rates.len() as u32)))]
         pub fn force_relay(
             origin: OriginFor<T>,
             rates: BoundedVec<(T::Symbol, u64), T::MaxRelaySymbols>,
@@ -488,11 +553,41 @@ pub mod pallet {
             DynamicFeeParameters::<T, I>::put(fee_parameters);
             Ok(().into())
         }
+
+        /// Set the number of relayer submissions required to update the rate of the symbol.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `quorum`: number of submissions, must be at least 1.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_relay_quorum())]
+        pub fn set_relay_quorum(origin: OriginFor<T>, quorum: u32) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(quorum > 0, Error::<T, I>::InvalidRelayQuorum);
+            RelayQuorum::<T, I>::put(quorum);
+            Self::deposit_event(Event::RelayQuorumSet(quorum));
+            Ok(().into())
+        }
+
+        /// Set the maximum deviation of the submitted rates, deviation checks are disabled if not set.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `max_deviation`: maximum deviation from the median and from the previous rate.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config<I>>::WeightInfo::set_max_rate_deviation())]
+        pub fn set_max_rate_deviation(
+            origin: OriginFor<T>,
+            max_deviation: Option<Percent>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            MaxRateDeviation::<T, I>::set(max_deviation);
+            Self::deposit_event(Event::MaxRateDeviationSet(max_deviation));
+            Ok(().into())
+        }
     }
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
-    fn ensure_relayer(origin: OriginFor<T>) -> DispatchResult {
+    fn ensure_relayer(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
         let account_id = ensure_signed(origin)?;
 
         Self::trusted_relayers()
@@ -505,7 +600,110 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
                     None
                 }
             })
-            .ok_or_else(|| Error::<T, I>::UnauthorizedRelayer.into())
+            .ok_or_else(|| Error::<T, I>::UnauthorizedRelayer)?;
+
+        Ok(account_id)
+    }
+
+    /// Record `rates` submitted by `relayer` in the current rounds of the symbols.
+    ///
+    /// Returns the medians of the rounds which have reached the quorum and passed the deviation check.
+    /// Submissions of the removed relayers and the submissions older than the rate stale period are ignored.
+    fn aggregate_rates(
+        relayer: &T::AccountId,
+        rates: BoundedVec<(T::Symbol, u64), T::MaxRelaySymbols>,
+    ) -> Result<BoundedVec<(T::Symbol, u64), T::MaxRelaySymbols>, DispatchError> {
+        let quorum = Self::relay_quorum() as usize;
+        let max_deviation = Self::max_rate_deviation();
+        let relayers = Self::trusted_relayers().unwrap_or_default();
+        let now = frame_system::Pallet::<T>::block_number();
+        let stale_period = T::GetBandRateStaleBlockPeriod::get();
+
+        let mut aggregated_rates = Vec::new();
+        let mut pending_symbols = Vec::new();
+        for (symbol, rate) in rates {
+            // Check the rate before it is stored in the round
+            Self::raw_rate_into_balance(rate)?;
+
+            let mut submissions: Vec<(T::AccountId, u64)> =
+                RelaySubmissions::<T, I>::iter_prefix(&symbol)
+                    .filter(|(account, (_, block))| {
+                        account != relayer
+                            && relayers.contains(account)
+                            && now.saturating_sub(*block) < stale_period
+                    })
+                    .map(|(account, (value, _))| (account, value))
+                    .collect();
+            submissions.push((relayer.clone(), rate));
+
+            if submissions.len() < quorum {
+                RelaySubmissions::<T, I>::insert(&symbol, relayer, (rate, now));
+                pending_symbols.push(symbol);
+                continue;
+            }
+
+            let _ = RelaySubmissions::<T, I>::clear_prefix(&symbol, u32::MAX, None);
+            let median = Self::median(submissions.iter().map(|(_, value)| *value).collect());
+
+            if let Some(max_deviation) = max_deviation {
+                let median_balance = Self::raw_rate_into_balance(median)?;
+                for (account, value) in submissions {
+                    if value.abs_diff(median) > max_deviation.mul_floor(median) {
+                        Self::deposit_event(Event::OutlierRelayerDetected(
+                            account,
+                            symbol.clone(),
+                            Self::raw_rate_into_balance(value)?,
+                            median_balance,
+                        ));
+                    }
+                }
+
+                if let Some(previous_rate) = Self::rates(&symbol) {
+                    if median_balance.abs_diff(previous_rate.value)
+                        > max_deviation.mul_floor(previous_rate.value)
+                    {
+                        // Market may have actually moved, so the rate is accepted if it keeps
+                        // deviating for several rounds in a row
+                        let deviating_rounds = Self::deviating_rounds(&symbol).saturating_add(1);
+                        if deviating_rounds < T::MaxDeviatingRounds::get() {
+                            DeviatingRounds::<T, I>::insert(&symbol, deviating_rounds);
+                            Self::deposit_event(Event::RateDeviationExceeded(
+                                symbol,
+                                previous_rate.value,
+                                median_balance,
+                            ));
+                            continue;
+                        }
+                        Self::deposit_event(Event::DeviatingRateAccepted(
+                            symbol.clone(),
+                            previous_rate.value,
+                            median_balance,
+                        ));
+                    }
+                }
+            }
+            DeviatingRounds::<T, I>::remove(&symbol);
+
+            aggregated_rates.push((symbol, median));
+        }
+
+        if !pending_symbols.is_empty() {
+            Self::deposit_event(Event::RatesSubmitted(relayer.clone(), pending_symbols));
+        }
+
+        Ok(BoundedVec::truncate_from(aggregated_rates))
+    }
+
+    /// Median of non-empty `values`, the mean of the two middle values is taken for even length.
+    fn median(mut values: Vec<u64>) -> u64 {
+        values.sort_unstable();
+        let middle = values.len() / 2;
+        if values.len() % 2 == 0 {
+            let (lower, upper) = (values[middle - 1], values[middle]);
+            lower + (upper - lower) / 2
+        } else {
+            values[middle]
+        }
     }
 
     /// Update rates in the storage with the new ones.
@@ -556,6 +754,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
         )?;
 
         T::OnNewSymbolsRelayedHook::on_new_symbols_relayed(Oracle::BandChainFeed, new_symbols)?;
+        T::OnNewSymbolsRelayedHook::on_rates_relayed(
+            Oracle::BandChainFeed,
+            converted_rates
+                .iter()
+                .map(|(symbol, value)| {
+                    let rate = Rate {
+                        value: *value,
+                        last_updated: resolve_time,
+                        dynamic_fee: fixed!(0),
+                    };
+                    (symbol.clone(), rate)
+                })
+                .collect(),
+        );
 
         Ok(converted_rates)
     }
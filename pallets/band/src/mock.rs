@@ -105,6 +105,7 @@ impl Config for Runtime {
     type OnSymbolDisabledHook = ();
     type GetBandRateStaleBlockPeriod = GetRateStaleBlockPeriod;
     type MaxRelaySymbols = frame_support::traits::ConstU32<100>;
+    type MaxDeviatingRounds = frame_support::traits::ConstU32<3>;
 }
 
 impl oracle_proxy::Config for Runtime {
@@ -112,6 +113,8 @@ impl oracle_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type BandChainOracle = crate::Pallet<Runtime>;
+    type PriceFeedOracle = ();
+    type MaxRateHistory = frame_support::traits::ConstU32<100>;
 }
 
 // Build genesis storage according to the mock runtime.
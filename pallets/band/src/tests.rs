@@ -31,13 +31,16 @@
 use codec::alloc::collections::HashSet;
 use common::{fixed, DataFeed, Rate};
 use common::{prelude::FixedWrapper, Balance, Fixed};
-use frame_support::traits::Hooks;
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::traits::{Get, Hooks};
 use frame_support::{assert_noop, error::BadOrigin};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_core::TryCollect;
 use sp_std::collections::btree_set::BTreeSet;
 
-use crate::{mock::*, BandRate, Error, FeeCalculationParameters, SymbolCheckBlock};
+use sp_runtime::Percent;
+
+use crate::{mock::*, BandRate, Error, Event, FeeCalculationParameters, SymbolCheckBlock};
 
 pub fn band_rate_into_balance(rate: u64) -> Balance {
     let fixed = Fixed::from_bits(rate as i128 * super::RATE_MULTIPLIER);
@@ -651,3 +654,289 @@ fn should_calculate_dynamic_fee() {
         assert_eq!(rate_d.dynamic_fee, fixed!(1));
     })
 }
+
+#[test]
+fn set_relay_quorum_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Band::relay_quorum(), 1);
+
+        Band::set_relay_quorum(RuntimeOrigin::root(), 3).expect("Failed to set relay quorum");
+        assert_eq!(Band::relay_quorum(), 3);
+
+        assert_noop!(
+            Band::set_relay_quorum(RuntimeOrigin::root(), 0),
+            Error::<Runtime>::InvalidRelayQuorum
+        );
+        assert_noop!(
+            Band::set_relay_quorum(RuntimeOrigin::signed(1), 2),
+            BadOrigin
+        );
+        assert_noop!(
+            Band::set_max_rate_deviation(RuntimeOrigin::signed(1), Some(Percent::from_percent(10))),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn relay_should_store_median_once_quorum_is_reached() {
+    new_test_ext().execute_with(|| {
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3, 4])
+            .expect("Failed to add relayers");
+        Band::set_relay_quorum(RuntimeOrigin::root(), 3).expect("Failed to set relay quorum");
+
+        for (relayer, rate) in [(1, 10), (2, 30)] {
+            Band::relay(
+                RuntimeOrigin::signed(relayer),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                100,
+                relayer,
+            )
+            .expect("Failed to relay rates");
+            assert_eq!(Band::rates("USD"), None);
+            assert_eq!(Band::relay_submissions("USD", relayer), Some((rate, 0)));
+        }
+
+        Band::relay(
+            RuntimeOrigin::signed(3),
+            vec![("USD".to_owned(), 20)].try_into().unwrap(),
+            101,
+            3,
+        )
+        .expect("Failed to relay rates");
+
+        assert_eq!(
+            Band::rates("USD"),
+            Some(BandRate {
+                value: band_rate_into_balance(20),
+                last_updated: 101,
+                request_id: 3,
+                dynamic_fee: fixed!(0),
+                last_updated_block: 0u64,
+            })
+        );
+        assert_eq!(Band::relay_submissions("USD", 1), None);
+        assert_eq!(Band::relay_submissions("USD", 2), None);
+
+        // Even number of submissions
+        Band::set_relay_quorum(RuntimeOrigin::root(), 2).expect("Failed to set relay quorum");
+        for (relayer, rate) in [(4, 10), (1, 21)] {
+            Band::relay(
+                RuntimeOrigin::signed(relayer),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                102,
+                relayer,
+            )
+            .expect("Failed to relay rates");
+        }
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(15)
+        );
+    });
+}
+
+#[test]
+fn relay_should_ignore_removed_relayers_and_stale_submissions() {
+    new_test_ext().execute_with(|| {
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3]).expect("Failed to add relayers");
+        Band::set_relay_quorum(RuntimeOrigin::root(), 2).expect("Failed to set relay quorum");
+
+        Band::relay(
+            RuntimeOrigin::signed(1),
+            vec![("USD".to_owned(), 10)].try_into().unwrap(),
+            100,
+            0,
+        )
+        .expect("Failed to relay rates");
+        Band::remove_relayers(RuntimeOrigin::root(), vec![1]).expect("Failed to remove relayers");
+
+        Band::relay(
+            RuntimeOrigin::signed(2),
+            vec![("USD".to_owned(), 20)].try_into().unwrap(),
+            100,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(Band::rates("USD"), None);
+
+        System::set_block_number(GetRateStaleBlockPeriod::get() + 1);
+        Band::relay(
+            RuntimeOrigin::signed(3),
+            vec![("USD".to_owned(), 30)].try_into().unwrap(),
+            100,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(Band::rates("USD"), None);
+        System::assert_last_event(Event::RatesSubmitted(3, vec!["USD".to_owned()]).into());
+
+        Band::relay(
+            RuntimeOrigin::signed(2),
+            vec![("USD".to_owned(), 40)].try_into().unwrap(),
+            100,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(35)
+        );
+    });
+}
+
+#[test]
+fn relay_should_report_outlier_relayers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Band::add_relayers(RuntimeOrigin::root(), vec![1, 2, 3]).expect("Failed to add relayers");
+        Band::set_relay_quorum(RuntimeOrigin::root(), 3).expect("Failed to set relay quorum");
+        Band::set_max_rate_deviation(RuntimeOrigin::root(), Some(Percent::from_percent(10)))
+            .expect("Failed to set max rate deviation");
+
+        for (relayer, rate) in [(1, 100), (2, 150), (3, 102)] {
+            Band::relay(
+                RuntimeOrigin::signed(relayer),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                100,
+                0,
+            )
+            .expect("Failed to relay rates");
+        }
+
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(102)
+        );
+        System::assert_has_event(
+            Event::OutlierRelayerDetected(
+                2,
+                "USD".to_owned(),
+                band_rate_into_balance(150),
+                band_rate_into_balance(102),
+            )
+            .into(),
+        );
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Band(Event::OutlierRelayerDetected(1, ..))
+        )));
+    });
+}
+
+#[test]
+fn relay_should_discard_rate_deviating_from_previous_rate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Band::add_relayers(RuntimeOrigin::root(), vec![1]).expect("Failed to add relayers");
+        Band::set_max_rate_deviation(RuntimeOrigin::root(), Some(Percent::from_percent(10)))
+            .expect("Failed to set max rate deviation");
+
+        for (rate, resolve_time) in [(100, 100), (120, 101)] {
+            Band::relay(
+                RuntimeOrigin::signed(1),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                resolve_time,
+                0,
+            )
+            .expect("Failed to relay rates");
+        }
+
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(100)
+        );
+        System::assert_has_event(
+            Event::RateDeviationExceeded(
+                "USD".to_owned(),
+                band_rate_into_balance(100),
+                band_rate_into_balance(120),
+            )
+            .into(),
+        );
+
+        Band::relay(
+            RuntimeOrigin::signed(1),
+            vec![("USD".to_owned(), 105)].try_into().unwrap(),
+            102,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(105)
+        );
+    });
+}
+
+#[test]
+fn relay_should_accept_rate_deviating_for_several_rounds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Band::add_relayers(RuntimeOrigin::root(), vec![1]).expect("Failed to add relayers");
+        Band::set_max_rate_deviation(RuntimeOrigin::root(), Some(Percent::from_percent(10)))
+            .expect("Failed to set max rate deviation");
+
+        for (rate, resolve_time) in [(100, 100), (120, 101), (121, 102)] {
+            Band::relay(
+                RuntimeOrigin::signed(1),
+                vec![("USD".to_owned(), rate)].try_into().unwrap(),
+                resolve_time,
+                0,
+            )
+            .expect("Failed to relay rates");
+        }
+
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(100)
+        );
+        assert_eq!(Band::deviating_rounds("USD"), 2);
+
+        // Third deviating round in a row is accepted
+        Band::relay(
+            RuntimeOrigin::signed(1),
+            vec![("USD".to_owned(), 122)].try_into().unwrap(),
+            103,
+            0,
+        )
+        .expect("Failed to relay rates");
+        assert_eq!(
+            Band::rates("USD").unwrap().value,
+            band_rate_into_balance(122)
+        );
+        assert_eq!(Band::deviating_rounds("USD"), 0);
+        System::assert_has_event(
+            Event::DeviatingRateAccepted(
+                "USD".to_owned(),
+                band_rate_into_balance(100),
+                band_rate_into_balance(122),
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn relay_weight_should_scale_with_symbols() {
+    new_test_ext().execute_with(|| {
+        let relay_call = |symbols: u32| {
+            let rates: Vec<_> = (0..symbols).map(|i| (format!("S{}", i), 1)).collect();
+            crate::Call::<Runtime>::relay {
+                rates: rates.try_into().unwrap(),
+                resolve_time: 0,
+                request_id: 0,
+            }
+            .get_dispatch_info()
+            .weight
+        };
+        let history_weight = |symbols: u32| {
+            <OracleProxy as common::OnNewSymbolsRelayed<String>>::on_rates_relayed_weight(symbols)
+        };
+
+        assert!(history_weight(10).all_gt(history_weight(1)));
+        assert!(relay_call(1).all_gte(history_weight(1)));
+        assert!(relay_call(10).all_gte(history_weight(10)));
+        assert!(relay_call(10).all_gt(relay_call(1)));
+    });
+}
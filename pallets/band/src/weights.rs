@@ -35,6 +35,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `MacBook-Pro-qwerty.local`, CPU: `<UNKNOWN>`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+//!
+//! Per-symbol components of `relay` and `force_relay` are hand estimates derived from the
+//! single symbol run, they have to be replaced with the output of the updated benchmarks.
 
 // Executed Command:
 // target/debug/framenode
@@ -60,11 +63,13 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for band.
 pub trait WeightInfo {
-	fn relay() -> Weight;
-	fn force_relay() -> Weight;
+	fn relay(n: u32, ) -> Weight;
+	fn force_relay(n: u32, ) -> Weight;
 	fn add_relayers() -> Weight;
 	fn remove_relayers() -> Weight;
 	fn set_dynamic_fee_parameters() -> Weight;
+	fn set_relay_quorum() -> Weight;
+	fn set_max_rate_deviation() -> Weight;
 }
 
 /// Weights for band using the Substrate node and recommended hardware.
@@ -72,18 +77,25 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelayQuorum (r:1 w:0)
+	/// Proof Skipped: Band RelayQuorum (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band MaxRateDeviation (r:1 w:0)
+	/// Proof Skipped: Band MaxRateDeviation (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelaySubmissions (r:1 w:0)
+	/// Proof Skipped: Band RelaySubmissions (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Band SymbolRates (r:1 w:1)
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band DeviatingRounds (r:1 w:1)
+	/// Proof Skipped: Band DeviatingRounds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
 	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
-	fn relay() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `95`
-		//  Estimated: `3255`
-		// Minimum execution time: 195_000_000 picoseconds.
-		Weight::from_parts(198_000_000, 3255)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// The range of component `n` is `[1, 100]`.
+	fn relay(n: u32, ) -> Weight {
+		Weight::from_parts(155_000_000, 1485)
+			.saturating_add(Weight::from_parts(48_000_000, 6250).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -91,14 +103,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
 	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
-	fn force_relay() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `95`
-		//  Estimated: `3255`
-		// Minimum execution time: 196_000_000 picoseconds.
-		Weight::from_parts(198_000_000, 3255)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// The range of component `n` is `[1, 100]`.
+	fn force_relay(n: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 495)
+			.saturating_add(Weight::from_parts(40_000_000, 2760).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:1)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -132,24 +143,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(41_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Band RelayQuorum (r:0 w:1)
+	/// Proof Skipped: Band RelayQuorum (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_relay_quorum() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_724_000 picoseconds.
+		Weight::from_parts(10_112_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Band MaxRateDeviation (r:0 w:1)
+	/// Proof Skipped: Band MaxRateDeviation (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_max_rate_deviation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_631_000 picoseconds.
+		Weight::from_parts(9_985_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelayQuorum (r:1 w:0)
+	/// Proof Skipped: Band RelayQuorum (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band MaxRateDeviation (r:1 w:0)
+	/// Proof Skipped: Band MaxRateDeviation (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Band RelaySubmissions (r:1 w:0)
+	/// Proof Skipped: Band RelaySubmissions (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Band SymbolRates (r:1 w:1)
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band DeviatingRounds (r:1 w:1)
+	/// Proof Skipped: Band DeviatingRounds (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
 	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
-	fn relay() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `95`
-		//  Estimated: `3255`
-		// Minimum execution time: 195_000_000 picoseconds.
-		Weight::from_parts(198_000_000, 3255)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// The range of component `n` is `[1, 100]`.
+	fn relay(n: u32, ) -> Weight {
+		Weight::from_parts(155_000_000, 1485)
+			.saturating_add(Weight::from_parts(48_000_000, 6250).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:0)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -157,14 +195,13 @@ impl WeightInfo for () {
 	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
 	/// Storage: OracleProxy SymbolProviders (r:0 w:1)
 	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
-	fn force_relay() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `95`
-		//  Estimated: `3255`
-		// Minimum execution time: 196_000_000 picoseconds.
-		Weight::from_parts(198_000_000, 3255)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// The range of component `n` is `[1, 100]`.
+	fn force_relay(n: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 495)
+			.saturating_add(Weight::from_parts(40_000_000, 2760).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Band TrustedRelayers (r:1 w:1)
 	/// Proof Skipped: Band TrustedRelayers (max_values: Some(1), max_size: None, mode: Measured)
@@ -198,4 +235,24 @@ impl WeightInfo for () {
 		Weight::from_parts(41_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Band RelayQuorum (r:0 w:1)
+	/// Proof Skipped: Band RelayQuorum (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_relay_quorum() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_724_000 picoseconds.
+		Weight::from_parts(10_112_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Band MaxRateDeviation (r:0 w:1)
+	/// Proof Skipped: Band MaxRateDeviation (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_max_rate_deviation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_631_000 picoseconds.
+		Weight::from_parts(9_985_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
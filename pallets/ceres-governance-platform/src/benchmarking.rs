@@ -5,8 +5,9 @@
 use super::*;
 
 use codec::Decode;
-use common::{balance, CERES_ASSET_ID};
+use common::{balance, AssetInfoProvider, CERES_ASSET_ID};
 use frame_benchmarking::benchmarks;
+use frame_support::weights::Weight;
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use sp_std::prelude::*;
@@ -127,6 +128,67 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::Withdrawn(caller, number_of_votes).into());
     }
 
+    create_proposal {
+        let caller = alice::<T>();
+        let poll_id = Vec::from([1, 2, 3, 4]);
+        let poll_start_timestamp = Timestamp::<T>::get();
+        let poll_end_timestamp = poll_start_timestamp + T::MIN_DURATION_OF_PROPOSAL;
+        let call: <T as Config>::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+        frame_system::Pallet::<T>::inc_providers(&caller);
+
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(CERES_ASSET_ID.clone().into()).unwrap();
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            T::ProposalDeposit::get()
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), poll_id.clone(), poll_start_timestamp, poll_end_timestamp, Box::new(call))
+    verify {
+        assert_last_event::<T>(Event::<T>::ProposalCreated(caller, poll_id).into());
+    }
+
+    execute_proposal {
+        let caller = alice::<T>();
+        let poll_id = Vec::from([1, 2, 3, 4]);
+        // Voting with more than the current supply meets any quorum below 50%
+        let number_of_votes = Assets::<T>::total_issuance(&CERES_ASSET_ID.into()).unwrap() + balance!(1);
+        let poll_start_timestamp = Timestamp::<T>::get();
+        let poll_end_timestamp = poll_start_timestamp + T::MIN_DURATION_OF_PROPOSAL;
+        let call: <T as Config>::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+        frame_system::Pallet::<T>::inc_providers(&caller);
+
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(CERES_ASSET_ID.clone().into()).unwrap();
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            number_of_votes + T::ProposalDeposit::get()
+        ).unwrap();
+
+        CeresGovernancePlatform::<T>::create_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            poll_start_timestamp,
+            poll_end_timestamp,
+            Box::new(call)
+        ).unwrap();
+
+        CeresGovernancePlatform::<T>::vote(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            1,
+            number_of_votes
+        ).unwrap();
+
+        pallet_timestamp::Now::<T>::put(poll_end_timestamp + T::ProposalTimelock::get() + 1u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), poll_id.clone(), Weight::MAX)
+    verify {
+        assert!(Proposals::<T>::get(&poll_id).is_none());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::default().build(),
@@ -13,8 +13,10 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use common::Balance;
+use frame_support::traits::EnsureOrigin;
+use frame_support::RuntimeDebug;
 pub use weights::WeightInfo;
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -39,6 +41,46 @@ pub struct PollInfo<Moment> {
     pub poll_end_timestamp: Moment,
 }
 
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ProposalInfo<AccountId, Proposal> {
+    /// Call dispatched when the proposal is approved
+    pub call: Proposal,
+    /// Account which created the proposal
+    pub proposer: AccountId,
+    /// CERES reserved from the proposer until the proposal is executed
+    pub deposit: Balance,
+    /// Votes for the proposal (option 1)
+    pub ayes: Balance,
+    /// Votes against the proposal (option 2)
+    pub nays: Balance,
+}
+
+/// Origin of calls dispatched by approved proposals.
+#[derive(
+    PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub enum RawOrigin {
+    /// Call is dispatched by an approved proposal
+    Proposal,
+}
+
+/// Ensures that origin is an approved proposal of this pallet.
+pub struct EnsureProposal;
+
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> EnsureOrigin<O> for EnsureProposal {
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|RawOrigin::Proposal| ())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::Proposal))
+    }
+}
+
 /// Storage version.
 #[derive(Encode, Decode, Eq, PartialEq, scale_info::TypeInfo)]
 pub enum StorageVersion {
@@ -52,15 +94,23 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::{migrations, PollInfo, StorageVersion, VotingInfo, WeightInfo};
+    use crate::{
+        migrations, PollInfo, ProposalInfo, RawOrigin, StorageVersion, VotingInfo, WeightInfo,
+    };
     use common::prelude::Balance;
+    use common::AssetInfoProvider;
+    use frame_support::dispatch::{
+        DispatchResult, Dispatchable, GetDispatchInfo, PostDispatchInfo,
+    };
     use frame_support::pallet_prelude::*;
     use frame_support::sp_runtime::traits::AccountIdConversion;
+    use frame_support::traits::Contains;
     use frame_support::transactional;
     use frame_support::PalletId;
     use frame_system::ensure_signed;
     use frame_system::pallet_prelude::*;
     use pallet_timestamp as timestamp;
+    use sp_runtime::Perbill;
     use sp_std::prelude::*;
 
     const PALLET_ID: PalletId = PalletId(*b"ceresgov");
@@ -75,6 +125,35 @@ pub mod pallet {
         /// Ceres asset id
         type CeresAssetId: Get<Self::AssetId>;
 
+        /// The outer origin type
+        type RuntimeOrigin: From<RawOrigin>;
+
+        /// The outer call dispatched by approved proposals
+        type Proposal: Parameter
+            + Dispatchable<
+                RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+                PostInfo = PostDispatchInfo,
+            > + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// Calls which can be dispatched by proposals
+        type ProposalFilter: Contains<<Self as Config>::Proposal>;
+
+        /// Delay between the end of the poll and the execution of the proposal in milliseconds
+        type ProposalTimelock: Get<Self::Moment>;
+
+        /// Minimum share of CERES total issuance which must vote for the proposal to be valid
+        type ProposalQuorum: Get<Perbill>;
+
+        /// CERES reserved from the proposer until the proposal is executed
+        type ProposalDeposit: Get<Balance>;
+
+        /// Minimum share of votes for the proposal to be approved
+        type ProposalThreshold: Get<Perbill>;
+
+        /// Minimum duration of the proposal poll in milliseconds
+        const MIN_DURATION_OF_PROPOSAL: Self::Moment;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -99,6 +178,17 @@ pub mod pallet {
     pub type PollData<T: Config> =
         StorageMap<_, Identity, Vec<u8>, PollInfo<T::Moment>, ValueQuery>;
 
+    /// Proposals attached to polls
+    #[pallet::storage]
+    #[pallet::getter(fn proposals)]
+    pub type Proposals<T: Config> = StorageMap<
+        _,
+        Identity,
+        Vec<u8>,
+        ProposalInfo<AccountIdOf<T>, <T as Config>::Proposal>,
+        OptionQuery,
+    >;
+
     #[pallet::type_value]
     pub fn DefaultForPalletStorageVersion<T: Config>() -> StorageVersion {
         StorageVersion::V1
@@ -110,6 +200,9 @@ pub mod pallet {
     pub type PalletStorageVersion<T: Config> =
         StorageValue<_, StorageVersion, ValueQuery, DefaultForPalletStorageVersion<T>>;
 
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -119,6 +212,12 @@ pub mod pallet {
         Created(AccountIdOf<T>, u32, T::Moment, T::Moment),
         /// Withdrawn [who, balance]
         Withdrawn(AccountIdOf<T>, Balance),
+        /// Proposal created [who, poll]
+        ProposalCreated(AccountIdOf<T>, Vec<u8>),
+        /// Proposal executed [poll, result]
+        ProposalExecuted(Vec<u8>, DispatchResult),
+        /// Proposal rejected [poll]
+        ProposalRejected(Vec<u8>),
     }
 
     #[pallet::error]
@@ -147,6 +246,18 @@ pub mod pallet {
         FundsAlreadyWithdrawn,
         /// Poll id already exists
         PollIdAlreadyExists,
+        /// Call is not allowed for proposals
+        CallNotAllowed,
+        /// Proposal does not exist
+        ProposalDoesNotExist,
+        /// Proposal is timelocked
+        ProposalIsTimelocked,
+        /// Proposal weight is bigger than provided
+        WrongProposalWeight,
+        /// Invalid minimum duration of proposal
+        InvalidMinimumDurationOfProposal,
+        /// Not enough funds for proposal deposit
+        NotEnoughFundsForDeposit,
     }
 
     #[pallet::call]
@@ -206,6 +317,15 @@ pub mod pallet {
             .map_err(|_assets_err| Error::<T>::NotEnoughFunds)?;
 
             // Update storage
+            <Proposals<T>>::mutate(&poll_id, |proposal| {
+                if let Some(proposal) = proposal {
+                    if voting_option == 1 {
+                        proposal.ayes += number_of_votes;
+                    } else {
+                        proposal.nays += number_of_votes;
+                    }
+                }
+            });
             <Voting<T>>::insert(&poll_id, &user, voting_info);
 
             //Emit event
@@ -232,41 +352,13 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
 
-            let current_timestamp = Timestamp::<T>::get();
-
-            let poll_info = <PollData<T>>::get(&poll_id);
-            ensure!(
-                poll_info.number_of_options == 0,
-                Error::<T>::PollIdAlreadyExists
-            );
-
-            ensure!(number_of_options >= 2, Error::<T>::InvalidNumberOfOption);
-
-            ensure!(
-                poll_start_timestamp >= current_timestamp,
-                Error::<T>::InvalidStartTimestamp
-            );
-
-            ensure!(
-                poll_end_timestamp > poll_start_timestamp,
-                Error::<T>::InvalidEndTimestamp
-            );
-
-            let poll_info = PollInfo {
-                number_of_options,
-                poll_end_timestamp,
-                poll_start_timestamp,
-            };
-
-            <PollData<T>>::insert(&poll_id, poll_info);
-
-            //Emit event
-            Self::deposit_event(Event::<T>::Created(
+            Self::create(
                 user,
+                &poll_id,
                 number_of_options,
                 poll_start_timestamp,
                 poll_end_timestamp,
-            ));
+            )?;
 
             // Return a successful DispatchResult
             Ok(().into())
@@ -310,6 +402,117 @@ pub mod pallet {
             // Return a successful DispatchResult
             Ok(().into())
         }
+
+        /// Create poll with two options (1 - for, 2 - against) which dispatches the call
+        /// when approved
+        #[transactional]
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_proposal())]
+        pub fn create_proposal(
+            origin: OriginFor<T>,
+            poll_id: Vec<u8>,
+            poll_start_timestamp: T::Moment,
+            poll_end_timestamp: T::Moment,
+            call: Box<<T as Config>::Proposal>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(
+                T::ProposalFilter::contains(&call),
+                Error::<T>::CallNotAllowed
+            );
+
+            ensure!(
+                poll_end_timestamp >= poll_start_timestamp + T::MIN_DURATION_OF_PROPOSAL,
+                Error::<T>::InvalidMinimumDurationOfProposal
+            );
+
+            Self::create(
+                user.clone(),
+                &poll_id,
+                2,
+                poll_start_timestamp,
+                poll_end_timestamp,
+            )?;
+
+            let deposit = T::ProposalDeposit::get();
+            Assets::<T>::reserve(&T::CeresAssetId::get(), &user, deposit)
+                .map_err(|_| Error::<T>::NotEnoughFundsForDeposit)?;
+
+            <Proposals<T>>::insert(
+                &poll_id,
+                ProposalInfo {
+                    call: *call,
+                    proposer: user.clone(),
+                    deposit,
+                    ayes: 0,
+                    nays: 0,
+                },
+            );
+
+            //Emit event
+            Self::deposit_event(Event::<T>::ProposalCreated(user, poll_id));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Dispatch the call of the proposal if quorum and threshold are met, otherwise reject it
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::execute_proposal().saturating_add(*max_weight))]
+        pub fn execute_proposal(
+            origin: OriginFor<T>,
+            poll_id: Vec<u8>,
+            max_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let proposal = <Proposals<T>>::get(&poll_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+            let poll_info = PollData::<T>::get(&poll_id);
+            let current_timestamp = Timestamp::<T>::get();
+
+            ensure!(
+                current_timestamp > poll_info.poll_end_timestamp + T::ProposalTimelock::get(),
+                Error::<T>::ProposalIsTimelocked
+            );
+
+            let call_weight = proposal.call.get_dispatch_info().weight;
+            ensure!(
+                call_weight.all_lte(max_weight),
+                Error::<T>::WrongProposalWeight
+            );
+
+            <Proposals<T>>::remove(&poll_id);
+
+            Assets::<T>::unreserve(
+                &T::CeresAssetId::get(),
+                &proposal.proposer,
+                proposal.deposit,
+            )?;
+
+            if !Self::is_approved(proposal.ayes, proposal.nays) {
+                //Emit event
+                Self::deposit_event(Event::<T>::ProposalRejected(poll_id));
+                return Ok(Some(<T as Config>::WeightInfo::execute_proposal()).into());
+            }
+
+            let result = proposal.call.dispatch(RawOrigin::Proposal.into());
+            let actual_call_weight = frame_support::dispatch::extract_actual_weight(
+                &result,
+                &proposal.call.get_dispatch_info(),
+            );
+
+            //Emit event
+            Self::deposit_event(Event::<T>::ProposalExecuted(
+                poll_id,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+
+            Ok(Some(
+                <T as Config>::WeightInfo::execute_proposal().saturating_add(actual_call_weight),
+            )
+            .into())
+        }
     }
 
     #[pallet::hooks]
@@ -330,5 +533,61 @@ pub mod pallet {
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
         }
+
+        fn create(
+            user: AccountIdOf<T>,
+            poll_id: &Vec<u8>,
+            number_of_options: u32,
+            poll_start_timestamp: T::Moment,
+            poll_end_timestamp: T::Moment,
+        ) -> DispatchResult {
+            let current_timestamp = Timestamp::<T>::get();
+
+            let poll_info = <PollData<T>>::get(poll_id);
+            ensure!(
+                poll_info.number_of_options == 0,
+                Error::<T>::PollIdAlreadyExists
+            );
+
+            ensure!(number_of_options >= 2, Error::<T>::InvalidNumberOfOption);
+
+            ensure!(
+                poll_start_timestamp >= current_timestamp,
+                Error::<T>::InvalidStartTimestamp
+            );
+
+            ensure!(
+                poll_end_timestamp > poll_start_timestamp,
+                Error::<T>::InvalidEndTimestamp
+            );
+
+            let poll_info = PollInfo {
+                number_of_options,
+                poll_end_timestamp,
+                poll_start_timestamp,
+            };
+
+            <PollData<T>>::insert(poll_id, poll_info);
+
+            //Emit event
+            Self::deposit_event(Event::<T>::Created(
+                user,
+                number_of_options,
+                poll_start_timestamp,
+                poll_end_timestamp,
+            ));
+
+            Ok(())
+        }
+
+        /// Check if proposal votes meet quorum and threshold
+        pub fn is_approved(ayes: Balance, nays: Balance) -> bool {
+            let total = ayes.saturating_add(nays);
+            let total_issuance =
+                Assets::<T>::total_issuance(&T::CeresAssetId::get()).unwrap_or_default();
+            ayes > 0
+                && total >= T::ProposalQuorum::get() * total_issuance
+                && Perbill::from_rational(ayes, total) >= T::ProposalThreshold::get()
+        }
     }
 }
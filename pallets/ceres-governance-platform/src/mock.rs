@@ -41,7 +41,7 @@ construct_runtime! {
         PoolXYK: pool_xyk::{Pallet, Call, Storage, Event<T>},
         PswapDistribution: pswap_distribution::{Pallet, Call, Config<T>, Storage, Event<T>},
         CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>},
-        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Event<T>},
+        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Origin, Event<T>},
         DemeterFarmingPlatform: demeter_farming_platform::{Pallet, Call, Storage, Event<T>},
     }
 }
@@ -98,11 +98,23 @@ impl frame_system::Config for Runtime {
 
 parameter_types! {
     pub const CeresAssetId: AssetId = CERES_ASSET_ID;
+    pub const ProposalTimelock: u64 = 86_400_000;
+    pub ProposalQuorum: Perbill = Perbill::from_percent(10);
+    pub const ProposalDeposit: Balance = balance!(10);
+    pub ProposalThreshold: Perbill = Perbill::from_percent(60);
 }
 
 impl crate::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type CeresAssetId = CeresAssetId;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type ProposalFilter = Everything;
+    type ProposalTimelock = ProposalTimelock;
+    type ProposalQuorum = ProposalQuorum;
+    type ProposalDeposit = ProposalDeposit;
+    type ProposalThreshold = ProposalThreshold;
+    const MIN_DURATION_OF_PROPOSAL: Self::Moment = 14_400_000;
     type WeightInfo = ();
 }
 
@@ -155,6 +167,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = ceres_governance_platform::EnsureProposal;
     type WeightInfo = ();
 }
 
@@ -171,6 +184,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
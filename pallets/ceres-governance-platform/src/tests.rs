@@ -5,6 +5,7 @@ mod tests {
     use frame_support::pallet_prelude::StorageMap;
     use frame_support::storage::types::ValueQuery;
     use frame_support::traits::Hooks;
+    use frame_support::weights::Weight;
     use frame_support::PalletId;
     use frame_support::{assert_err, assert_ok, Identity};
     use sp_runtime::traits::AccountIdConversion;
@@ -475,4 +476,337 @@ mod tests {
             assert_eq!(poll_b.poll_end_timestamp, 3179666710000);
         });
     }
+
+    fn add_demeter_pool() {
+        let authority = demeter_farming_platform::AuthorityAccount::<Runtime>::get();
+        assert_ok!(DemeterFarmingPlatform::register_token(
+            RuntimeOrigin::signed(authority),
+            CERES_ASSET_ID,
+            balance!(1),
+            balance!(0.6),
+            balance!(0.2),
+            balance!(0.2),
+            ALICE
+        ));
+        assert_ok!(DemeterFarmingPlatform::add_pool(
+            RuntimeOrigin::signed(authority),
+            CERES_ASSET_ID,
+            CERES_ASSET_ID,
+            CERES_ASSET_ID,
+            false,
+            1,
+            balance!(0),
+            true
+        ));
+    }
+
+    fn change_multiplier_call(new_multiplier: u32) -> Box<RuntimeCall> {
+        Box::new(RuntimeCall::DemeterFarmingPlatform(
+            demeter_farming_platform::Call::change_pool_multiplier {
+                base_asset: CERES_ASSET_ID,
+                pool_asset: CERES_ASSET_ID,
+                reward_asset: CERES_ASSET_ID,
+                is_farm: false,
+                new_multiplier,
+            },
+        ))
+    }
+
+    fn pool_multiplier() -> u32 {
+        demeter_farming_platform::Pools::<Runtime>::get(&CERES_ASSET_ID, &CERES_ASSET_ID)[0]
+            .multiplier
+    }
+
+    #[test]
+    fn create_proposal_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let poll_id = Vec::from([1, 2, 3, 4]);
+            let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let poll_end_timestamp =
+                poll_start_timestamp + <Runtime as pallet::Config>::MIN_DURATION_OF_PROPOSAL;
+
+            assert_err!(
+                CeresGovernancePlatform::create_proposal(
+                    RuntimeOrigin::signed(ALICE),
+                    poll_id.clone(),
+                    poll_start_timestamp,
+                    poll_end_timestamp - 1,
+                    change_multiplier_call(2)
+                ),
+                Error::<Runtime>::InvalidMinimumDurationOfProposal
+            );
+
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(2)
+            ));
+            assert_eq!(
+                Assets::total_balance(&CERES_ASSET_ID, &ALICE).unwrap()
+                    - Assets::free_balance(&CERES_ASSET_ID, &ALICE).unwrap(),
+                ProposalDeposit::get()
+            );
+
+            let poll_info = pallet::PollData::<Runtime>::get(&poll_id);
+            assert_eq!(poll_info.number_of_options, 2);
+            assert_eq!(poll_info.poll_end_timestamp, poll_end_timestamp);
+
+            let proposal = pallet::Proposals::<Runtime>::get(&poll_id).unwrap();
+            assert_eq!(proposal.call, *change_multiplier_call(2));
+            assert_eq!(proposal.proposer, ALICE);
+            assert_eq!(proposal.deposit, ProposalDeposit::get());
+            assert_eq!(proposal.ayes, 0);
+            assert_eq!(proposal.nays, 0);
+
+            assert_err!(
+                CeresGovernancePlatform::create_proposal(
+                    RuntimeOrigin::signed(ALICE),
+                    poll_id,
+                    poll_start_timestamp,
+                    poll_end_timestamp,
+                    change_multiplier_call(3)
+                ),
+                Error::<Runtime>::PollIdAlreadyExists
+            );
+        });
+    }
+
+    #[test]
+    fn execute_proposal_is_timelocked() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let poll_id = Vec::from([1, 2, 3, 4]);
+            let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let poll_end_timestamp =
+                poll_start_timestamp + <Runtime as pallet::Config>::MIN_DURATION_OF_PROPOSAL;
+
+            assert_err!(
+                CeresGovernancePlatform::execute_proposal(
+                    RuntimeOrigin::signed(BOB),
+                    poll_id.clone(),
+                    Weight::MAX
+                ),
+                Error::<Runtime>::ProposalDoesNotExist
+            );
+
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(2)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+                poll_end_timestamp + ProposalTimelock::get(),
+            );
+
+            assert_err!(
+                CeresGovernancePlatform::execute_proposal(
+                    RuntimeOrigin::signed(BOB),
+                    poll_id,
+                    Weight::MAX
+                ),
+                Error::<Runtime>::ProposalIsTimelocked
+            );
+        });
+    }
+
+    #[test]
+    fn execute_proposal_wrong_proposal_weight() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let poll_id = Vec::from([1, 2, 3, 4]);
+            let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let poll_end_timestamp =
+                poll_start_timestamp + <Runtime as pallet::Config>::MIN_DURATION_OF_PROPOSAL;
+
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(2)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+                poll_end_timestamp + ProposalTimelock::get() + 1,
+            );
+
+            assert_err!(
+                CeresGovernancePlatform::execute_proposal(
+                    RuntimeOrigin::signed(BOB),
+                    poll_id,
+                    Weight::zero()
+                ),
+                Error::<Runtime>::WrongProposalWeight
+            );
+        });
+    }
+
+    #[test]
+    fn execute_proposal_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let poll_id = Vec::from([1, 2, 3, 4]);
+            let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let poll_end_timestamp =
+                poll_start_timestamp + <Runtime as pallet::Config>::MIN_DURATION_OF_PROPOSAL;
+
+            add_demeter_pool();
+
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(2)
+            ));
+
+            assert_ok!(CeresGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id.clone(),
+                1,
+                balance!(300)
+            ));
+            assert_ok!(CeresGovernancePlatform::vote(
+                RuntimeOrigin::signed(BOB),
+                poll_id.clone(),
+                2,
+                balance!(100)
+            ));
+
+            let proposal = pallet::Proposals::<Runtime>::get(&poll_id).unwrap();
+            assert_eq!(proposal.ayes, balance!(300));
+            assert_eq!(proposal.nays, balance!(100));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+                poll_end_timestamp + ProposalTimelock::get() + 1,
+            );
+
+            assert_ok!(CeresGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                poll_id.clone(),
+                Weight::MAX
+            ));
+
+            assert_eq!(pool_multiplier(), 2);
+            assert!(pallet::Proposals::<Runtime>::get(&poll_id).is_none());
+            assert_eq!(
+                Assets::total_balance(&CERES_ASSET_ID, &ALICE).unwrap(),
+                Assets::free_balance(&CERES_ASSET_ID, &ALICE).unwrap()
+            );
+
+            assert_err!(
+                CeresGovernancePlatform::execute_proposal(
+                    RuntimeOrigin::signed(BOB),
+                    poll_id,
+                    Weight::MAX
+                ),
+                Error::<Runtime>::ProposalDoesNotExist
+            );
+        });
+    }
+
+    #[test]
+    fn execute_proposal_rejected() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let poll_id_a = Vec::from([1, 2, 3, 4]);
+            let poll_id_b = Vec::from([1, 2, 3, 5]);
+            let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let poll_end_timestamp =
+                poll_start_timestamp + <Runtime as pallet::Config>::MIN_DURATION_OF_PROPOSAL;
+
+            add_demeter_pool();
+
+            // Quorum is not met
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id_a.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(2)
+            ));
+            assert_ok!(CeresGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id_a.clone(),
+                1,
+                balance!(50)
+            ));
+
+            // Threshold is not met
+            assert_ok!(CeresGovernancePlatform::create_proposal(
+                RuntimeOrigin::signed(ALICE),
+                poll_id_b.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                change_multiplier_call(3)
+            ));
+            assert_ok!(CeresGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id_b.clone(),
+                1,
+                balance!(250)
+            ));
+            assert_ok!(CeresGovernancePlatform::vote(
+                RuntimeOrigin::signed(BOB),
+                poll_id_b.clone(),
+                2,
+                balance!(250)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+                poll_end_timestamp + ProposalTimelock::get() + 1,
+            );
+
+            assert_ok!(CeresGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                poll_id_a.clone(),
+                Weight::MAX
+            ));
+            assert_ok!(CeresGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                poll_id_b.clone(),
+                Weight::MAX
+            ));
+
+            assert_eq!(pool_multiplier(), 1);
+            assert!(pallet::Proposals::<Runtime>::get(&poll_id_a).is_none());
+            assert!(pallet::Proposals::<Runtime>::get(&poll_id_b).is_none());
+        });
+    }
+
+    #[test]
+    fn proposal_origin_is_not_accepted_by_other_calls() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            add_demeter_pool();
+
+            assert_err!(
+                DemeterFarmingPlatform::change_total_tokens(
+                    crate::RawOrigin::Proposal.into(),
+                    CERES_ASSET_ID,
+                    CERES_ASSET_ID,
+                    CERES_ASSET_ID,
+                    false,
+                    balance!(1)
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            assert_ok!(DemeterFarmingPlatform::change_pool_multiplier(
+                crate::RawOrigin::Proposal.into(),
+                CERES_ASSET_ID,
+                CERES_ASSET_ID,
+                CERES_ASSET_ID,
+                false,
+                5
+            ));
+            assert_eq!(pool_multiplier(), 5);
+        });
+    }
 }
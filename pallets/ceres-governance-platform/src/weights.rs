@@ -62,6 +62,8 @@ pub trait WeightInfo {
 	fn vote() -> Weight;
 	fn create_poll() -> Weight;
 	fn withdraw() -> Weight;
+	fn create_proposal() -> Weight;
+	fn execute_proposal() -> Weight;
 }
 
 /// Weights for ceres_governance_platform using the Substrate node and recommended hardware.
@@ -118,6 +120,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: CeresGovernancePlatform PollData (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform PollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresGovernancePlatform Proposals (r:0 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Proposals (max_values: None, max_size: None, mode: Measured)
+	fn create_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `4332`
+		// Minimum execution time: 36_718_000 picoseconds.
+		Weight::from_parts(37_524_000, 4332)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: CeresGovernancePlatform Proposals (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Proposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform PollData (r:1 w:0)
+	/// Proof Skipped: CeresGovernancePlatform PollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn execute_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6015`
+		// Minimum execution time: 28_904_000 picoseconds.
+		Weight::from_parts(29_611_000, 6015)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -173,4 +205,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: CeresGovernancePlatform PollData (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform PollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresGovernancePlatform Proposals (r:0 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Proposals (max_values: None, max_size: None, mode: Measured)
+	fn create_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `368`
+		//  Estimated: `4332`
+		// Minimum execution time: 36_718_000 picoseconds.
+		Weight::from_parts(37_524_000, 4332)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: CeresGovernancePlatform Proposals (r:1 w:1)
+	/// Proof Skipped: CeresGovernancePlatform Proposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresGovernancePlatform PollData (r:1 w:0)
+	/// Proof Skipped: CeresGovernancePlatform PollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn execute_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6015`
+		// Minimum execution time: 28_904_000 picoseconds.
+		Weight::from_parts(29_611_000, 6015)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
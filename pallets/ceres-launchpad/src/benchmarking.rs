@@ -80,7 +80,8 @@ benchmarks! {
         balance!(0.2),
         balance!(0.2),
         current_timestamp + 3u32.into(),
-        balance!(0.2)
+        balance!(0.2),
+        SaleType::FixedPrice
     )
     verify {
         assert_last_event::<T>(Event::<T>::ILOCreated(caller.clone(), CERES_ASSET_ID.into()).into());
@@ -138,7 +139,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.2),
             current_timestamp + 3u32.into(),
-            balance!(0.2)
+            balance!(0.2),
+            SaleType::FixedPrice
         ).unwrap();
 
         CeresLaunchpad::<T>::add_whitelisted_contributor(
@@ -204,7 +206,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.2),
             current_timestamp + 3u32.into(),
-            balance!(0.2)
+            balance!(0.2),
+            SaleType::FixedPrice
         ).unwrap();
 
         pallet_timestamp::Now::<T>::put(current_timestamp + 7u32.into());
@@ -275,7 +278,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.2),
             current_timestamp + 3u32.into(),
-            balance!(0.2)
+            balance!(0.2),
+            SaleType::FixedPrice
         ).unwrap();
 
 
@@ -354,7 +358,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.2),
             current_timestamp + 3u32.into(),
-            balance!(0.2)
+            balance!(0.2),
+            SaleType::FixedPrice
         ).unwrap();
 
         pallet_timestamp::Now::<T>::put(current_timestamp + 7u32.into());
@@ -436,7 +441,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.1),
             current_timestamp + 30u32.into(),
-            balance!(0.18)
+            balance!(0.18),
+            SaleType::FixedPrice
         ).unwrap();
 
         pallet_timestamp::Now::<T>::put(current_timestamp + 7u32.into());
@@ -540,7 +546,8 @@ benchmarks! {
             balance!(0.2),
             balance!(0.2),
             current_timestamp + 3u32.into(),
-            balance!(0.2)
+            balance!(0.2),
+            SaleType::FixedPrice
         ).unwrap();
 
 
@@ -611,6 +618,205 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::RemovedWhitelistedIloOrganizer(ilo_organizer).into());
     }
 
+    cancel_ilo {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        let current_timestamp = Timestamp::<T>::get();
+
+        let asset_id = T::AssetId::from(CERES_ASSET_ID);
+        let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner.clone()).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        CeresLaunchpad::<T>::add_whitelisted_ilo_organizer(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        CeresLaunchpad::<T>::create_ilo(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(500),
+            balance!(900),
+            true,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5u32.into(),
+            current_timestamp + 10u32.into(),
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            SaleType::FixedPrice
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), CERES_ASSET_ID.into())
+    verify {
+        assert_last_event::<T>(Event::<T>::ILOCancelled(caller, CERES_ASSET_ID.into()).into());
+    }
+
+    claim_refund {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        let current_timestamp = Timestamp::<T>::get();
+
+        let asset_id = T::AssetId::from(CERES_ASSET_ID);
+        let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner.clone()).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner.clone()).into(),
+            XOR.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        CeresLaunchpad::<T>::add_whitelisted_ilo_organizer(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        CeresLaunchpad::<T>::create_ilo(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(500),
+            balance!(900),
+            true,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5u32.into(),
+            current_timestamp + 10u32.into(),
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            SaleType::FixedPrice
+        ).unwrap();
+
+        pallet_timestamp::Now::<T>::put(current_timestamp + 7u32.into());
+
+        CeresLaunchpad::<T>::add_whitelisted_contributor(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        // Contribute less than soft cap
+        let funds_to_contribute = balance!(500);
+        CeresLaunchpad::<T>::contribute(
+            RawOrigin::Signed(caller.clone()).into(),
+            CERES_ASSET_ID.into(),
+            funds_to_contribute
+        ).unwrap();
+
+        pallet_timestamp::Now::<T>::put(current_timestamp + 11u32.into());
+
+        CeresLaunchpad::<T>::finish_ilo(
+            RawOrigin::Signed(caller.clone()).into(),
+            CERES_ASSET_ID.into()
+        ).unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), CERES_ASSET_ID.into())
+    verify {
+        assert_last_event::<T>(Event::<T>::Refunded(caller, CERES_ASSET_ID.into(), funds_to_contribute).into());
+    }
+
+    bid {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        let current_timestamp = Timestamp::<T>::get();
+        let funds_to_contribute = balance!(800);
+        let price = balance!(0.12);
+
+        let asset_id = T::AssetId::from(CERES_ASSET_ID);
+        let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner.clone()).into(),
+            CERES_ASSET_ID.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner.clone()).into(),
+            XOR.into(),
+            caller.clone(),
+            balance!(20000)
+        ).unwrap();
+
+        CeresLaunchpad::<T>::add_whitelisted_ilo_organizer(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        // Create ILO
+        CeresLaunchpad::<T>::create_ilo(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(7693),
+            balance!(3000),
+            balance!(0.13),
+            balance!(600),
+            balance!(1000),
+            balance!(500),
+            balance!(900),
+            true,
+            balance!(0.75),
+            balance!(0.25),
+            31,
+            current_timestamp + 5u32.into(),
+            current_timestamp + 10u32.into(),
+            balance!(1000),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            balance!(0.2),
+            current_timestamp + 3u32.into(),
+            balance!(0.2),
+            SaleType::BatchAuction { min_price: balance!(0.1) }
+        ).unwrap();
+
+        CeresLaunchpad::<T>::add_whitelisted_contributor(
+            RawOrigin::Signed(pallet::AuthorityAccount::<T>::get()).into(),
+            caller.clone()
+        ).unwrap();
+
+        pallet_timestamp::Now::<T>::put(current_timestamp + 7u32.into());
+    }: _(RawOrigin::Signed(caller.clone()), CERES_ASSET_ID.into(), funds_to_contribute, price)
+    verify {
+        assert_last_event::<T>(Event::<T>::BidPlaced(caller, CERES_ASSET_ID.into(), funds_to_contribute, price).into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::benchmarking().build(),
@@ -2,6 +2,7 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
+pub mod migrations;
 pub mod weights;
 
 #[cfg(test)]
@@ -36,12 +37,63 @@ pub struct ILOInfo<Balance, AccountId, Moment, AssetId> {
     team_vesting: TeamVesting<Balance, Moment>,
     sold_tokens: Balance,
     funds_raised: Balance,
-    succeeded: bool,
-    failed: bool,
+    status: ILOStatus,
     lp_tokens: Balance,
     claimed_lp_tokens: bool,
     finish_timestamp: Moment,
     base_asset: AssetId,
+    sale_type: SaleType<Balance>,
+}
+
+/// ILO lifecycle status
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum ILOStatus {
+    /// ILO is created and accepts contributions between start and end timestamp
+    Created,
+    /// ILO reached soft cap and liquidity is provided
+    Succeeded,
+    /// ILO missed soft cap, contributors can reclaim their funds
+    Refunding,
+    /// ILO is cancelled by the organizer before start
+    Cancelled,
+}
+
+impl Default for ILOStatus {
+    fn default() -> Self {
+        ILOStatus::Created
+    }
+}
+
+/// Sale format of ILO
+///
+/// `ilo_price` of ILO is the lowest price of Dutch auction and the highest bid price of batch
+/// auction, so `tokens_for_ilo` always covers hard cap.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum SaleType<Balance> {
+    /// Tokens are sold at `ilo_price`
+    FixedPrice,
+    /// Price descends linearly from `start_price` to `ilo_price` between start and end timestamp
+    DutchAuction { start_price: Balance },
+    /// Contributors bid funds and price, uniform clearing price is computed at finish
+    BatchAuction { min_price: Balance },
+}
+
+impl<Balance> Default for SaleType<Balance> {
+    fn default() -> Self {
+        SaleType::FixedPrice
+    }
+}
+
+/// Result of batch auction clearing
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct BatchAuctionResult<Balance> {
+    /// Price paid by all winning bids
+    clearing_price: Balance,
+    /// Filled part of bids placed exactly at clearing price
+    marginal_fill: Balance,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -71,12 +123,26 @@ pub struct ContributionInfo<Balance> {
     number_of_claims: u32,
 }
 
+/// Storage version.
+#[derive(Encode, Decode, Eq, PartialEq, scale_info::TypeInfo)]
+pub enum StorageVersion {
+    /// Initial version
+    V1,
+    /// After replacing succeeded and failed flags with ILO status
+    V2,
+    /// After adding sale type to ILO info
+    V3,
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use crate::{ContributionInfo, ContributorsVesting, ILOInfo};
+    use crate::{
+        migrations, BatchAuctionResult, ContributionInfo, ContributorsVesting, ILOInfo, ILOStatus,
+        SaleType, StorageVersion,
+    };
     use common::fixnum::ops::RoundMode;
     use common::prelude::{Balance, FixedWrapper, XOR};
     use common::{balance, AssetInfoProvider, DEXId, PoolXykPallet, PSWAP, XSTUSD};
@@ -94,6 +160,9 @@ pub mod pallet {
 
     const PALLET_ID: PalletId = PalletId(*b"crslaunc");
 
+    /// Maximum number of bids in a single batch auction, bids are iterated when it is cleared
+    pub const MAX_BATCH_AUCTION_BIDS: u32 = 500;
+
     // TODO: #395 use AssetInfoProvider instead of assets pallet
     #[pallet::config]
     pub trait Config:
@@ -110,6 +179,9 @@ pub mod pallet {
         /// One day represented in milliseconds
         const MILLISECONDS_PER_DAY: Self::Moment;
 
+        /// Origin which can change fees in addition to the authority account
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -192,6 +264,17 @@ pub mod pallet {
     pub type AuthorityAccount<T: Config> =
         StorageValue<_, AccountIdOf<T>, ValueQuery, DefaultForAuthorityAccount<T>>;
 
+    #[pallet::type_value]
+    pub fn DefaultForPalletStorageVersion<T: Config>() -> StorageVersion {
+        StorageVersion::V1
+    }
+
+    /// Pallet storage version
+    #[pallet::storage]
+    #[pallet::getter(fn pallet_storage_version)]
+    pub type PalletStorageVersion<T: Config> =
+        StorageValue<_, StorageVersion, ValueQuery, DefaultForPalletStorageVersion<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn ilos)]
     pub type ILOs<T: Config> = StorageMap<
@@ -214,6 +297,24 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Bid prices of batch auction contributors
+    #[pallet::storage]
+    #[pallet::getter(fn batch_auction_bids)]
+    pub type BatchAuctionBids<T: Config> =
+        StorageDoubleMap<_, Identity, AssetIdOf<T>, Identity, AccountIdOf<T>, Balance, OptionQuery>;
+
+    /// Number of bids placed in batch auctions
+    #[pallet::storage]
+    #[pallet::getter(fn batch_auction_bid_count)]
+    pub type BatchAuctionBidCount<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, u32, ValueQuery>;
+
+    /// Clearing results of finished batch auctions
+    #[pallet::storage]
+    #[pallet::getter(fn batch_auction_results)]
+    pub type BatchAuctionResults<T: Config> =
+        StorageMap<_, Identity, AssetIdOf<T>, BatchAuctionResult<Balance>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn whitelisted_contributors)]
     pub type WhitelistedContributors<T: Config> = StorageValue<_, Vec<AccountIdOf<T>>, ValueQuery>;
@@ -249,6 +350,16 @@ pub mod pallet {
         RemovedWhitelistedContributor(AccountIdOf<T>),
         /// ILO organizer removed [who]
         RemovedWhitelistedIloOrganizer(AccountIdOf<T>),
+        /// ILO cancelled [who, what]
+        ILOCancelled(AccountIdOf<T>, AssetIdOf<T>),
+        /// ILO entered refunding state [what]
+        ILORefunding(AssetIdOf<T>),
+        /// Contribution refunded [who, what, balance]
+        Refunded(AccountIdOf<T>, AssetIdOf<T>, Balance),
+        /// Bid placed in batch auction [who, what, balance, price]
+        BidPlaced(AccountIdOf<T>, AssetIdOf<T>, Balance, Balance),
+        /// Batch auction cleared [what, clearing price]
+        BatchAuctionCleared(AssetIdOf<T>, Balance),
     }
 
     #[pallet::error]
@@ -337,6 +448,22 @@ pub mod pallet {
         InvalidFeePercent,
         /// Asset in which funds are being raised is not supported
         BaseAssetNotSupported,
+        /// ILO is cancelled
+        ILOIsCancelled,
+        /// ILO already started
+        ILOAlreadyStarted,
+        /// ILO is not in refunding state
+        ILOIsNotRefunding,
+        /// Invalid Dutch auction start price or batch auction min price
+        InvalidAuctionPrice,
+        /// Action is not supported for sale type of ILO
+        WrongSaleType,
+        /// Bid price must be between batch auction min price and ILO price
+        InvalidBidPrice,
+        /// Bid already placed
+        BidAlreadyPlaced,
+        /// Maximum number of bids in batch auction is reached
+        TooManyBids,
     }
 
     #[pallet::call]
@@ -368,6 +495,7 @@ pub mod pallet {
             first_release_percent: Balance,
             vesting_period: T::Moment,
             vesting_percent: Balance,
+            sale_type: SaleType<Balance>,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin.clone())?;
 
@@ -379,14 +507,10 @@ pub mod pallet {
                 return Err(Error::<T>::BaseAssetNotSupported.into());
             }
 
+            // Check if ILO for token already exists, cancelled ILO can be created again
             ensure!(
-                !<ILOs<T>>::contains_key(&asset_id),
-                Error::<T>::ILOAlreadyExists
-            );
-
-            // Check if ILO for token already exists
-            ensure!(
-                !<ILOs<T>>::contains_key(&asset_id),
+                <ILOs<T>>::get(&asset_id)
+                    .map_or(true, |ilo_info| ilo_info.status == ILOStatus::Cancelled),
                 Error::<T>::ILOAlreadyExists
             );
 
@@ -433,6 +557,7 @@ pub mod pallet {
                 vesting_period,
                 vesting_percent,
             )?;
+            Self::check_sale_type(ilo_price, &sale_type)?;
 
             ensure!(
                 CeresBurnFeeAmount::<T>::get()
@@ -485,12 +610,12 @@ pub mod pallet {
                 },
                 sold_tokens: balance!(0),
                 funds_raised: balance!(0),
-                succeeded: false,
-                failed: false,
+                status: ILOStatus::Created,
                 lp_tokens: balance!(0),
                 claimed_lp_tokens: false,
                 finish_timestamp: 0u32.into(),
                 base_asset,
+                sale_type,
             };
 
             <ILOs<T>>::insert(&asset_id, &ilo_info);
@@ -532,30 +657,19 @@ pub mod pallet {
             let mut contribution_info = <Contributions<T>>::get(&asset_id, &user);
 
             ensure!(
-                ilo_info.start_timestamp < current_timestamp,
-                Error::<T>::ILONotStarted
-            );
-            ensure!(
-                ilo_info.end_timestamp > current_timestamp,
-                Error::<T>::ILOIsFinished
-            );
-            ensure!(
-                funds_to_contribute >= ilo_info.min_contribution,
-                Error::<T>::ContributionIsLowerThenMin
-            );
-            ensure!(
-                contribution_info.funds_contributed + funds_to_contribute
-                    <= ilo_info.max_contribution,
-                Error::<T>::ContributionIsBiggerThenMax
-            );
-            ensure!(
-                ilo_info.funds_raised + funds_to_contribute <= ilo_info.hard_cap,
-                Error::<T>::HardCapIsHit
+                !matches!(ilo_info.sale_type, SaleType::BatchAuction { .. }),
+                Error::<T>::WrongSaleType
             );
+            Self::check_contribution(
+                &ilo_info,
+                &contribution_info,
+                funds_to_contribute,
+                current_timestamp,
+            )?;
 
             // Calculate amount of bought tokens
             let tokens_bought = (FixedWrapper::from(funds_to_contribute)
-                / FixedWrapper::from(ilo_info.ilo_price))
+                / FixedWrapper::from(Self::current_price(&ilo_info, current_timestamp)))
             .try_into_balance()
             .unwrap_or(0);
 
@@ -601,6 +715,10 @@ pub mod pallet {
             // Get contribution info
             let contribution_info = <Contributions<T>>::get(&asset_id, &user);
 
+            ensure!(
+                ilo_info.status == ILOStatus::Created,
+                Error::<T>::ILOIsFinished
+            );
             ensure!(
                 ilo_info.start_timestamp < current_timestamp,
                 Error::<T>::ILONotStarted
@@ -643,6 +761,11 @@ pub mod pallet {
             // Update map
             <ILOs<T>>::insert(&asset_id, &ilo_info);
             <Contributions<T>>::remove(&asset_id, &user);
+            if <BatchAuctionBids<T>>::take(&asset_id, &user).is_some() {
+                <BatchAuctionBidCount<T>>::mutate(&asset_id, |count| {
+                    *count = count.saturating_sub(1)
+                });
+            }
 
             // Emit event
             Self::deposit_event(Event::<T>::EmergencyWithdrawn(
@@ -654,10 +777,10 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Finish ILO
+        /// Finish ILO, batch auction is cleared by reading all of its bids
         #[transactional]
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as Config>::WeightInfo::finish_ilo())]
+        #[pallet::weight(<T as Config>::WeightInfo::finish_ilo().saturating_add(T::DbWeight::get().reads(2 * MAX_BATCH_AUCTION_BIDS as u64)))]
         pub fn finish_ilo(
             origin: OriginFor<T>,
             asset_id: AssetIdOf<T>,
@@ -671,6 +794,13 @@ pub mod pallet {
                 return Err(Error::<T>::Unauthorized.into());
             }
 
+            match ilo_info.status {
+                ILOStatus::Refunding => return Err(Error::<T>::ILOIsFailed.into()),
+                ILOStatus::Succeeded => return Err(Error::<T>::ILOIsSucceeded.into()),
+                ILOStatus::Cancelled => return Err(Error::<T>::ILOIsCancelled.into()),
+                ILOStatus::Created => {}
+            }
+
             // Get current timestamp
             let current_timestamp = Timestamp::<T>::get();
             ensure!(
@@ -678,34 +808,25 @@ pub mod pallet {
                     || ilo_info.funds_raised == ilo_info.hard_cap,
                 Error::<T>::ILOIsNotFinished
             );
-            ensure!(!ilo_info.failed, Error::<T>::ILOIsFailed);
-            ensure!(!ilo_info.succeeded, Error::<T>::ILOIsSucceeded);
 
-            let pallet_account = Self::account_id();
-            if ilo_info.funds_raised < ilo_info.soft_cap {
-                // Failed ILO
-                ilo_info.failed = true;
-                let total_tokens = ilo_info.tokens_for_liquidity + ilo_info.tokens_for_ilo;
-                if !ilo_info.refund_type {
-                    Assets::<T>::burn(
-                        RawOrigin::Signed(pallet_account).into(),
-                        asset_id.into(),
-                        total_tokens,
-                    )?;
-                } else {
-                    Assets::<T>::transfer_from(
-                        &asset_id.into(),
-                        &pallet_account,
-                        &ilo_info.ilo_organizer,
-                        total_tokens,
-                    )?;
-                }
+            if let SaleType::BatchAuction { min_price } = ilo_info.sale_type {
+                let result = Self::clear_batch_auction(&asset_id, &mut ilo_info, min_price);
+                let clearing_price = result.clearing_price;
+                <BatchAuctionResults<T>>::insert(&asset_id, result);
+
+                Self::deposit_event(Event::BatchAuctionCleared(asset_id, clearing_price));
+            }
 
+            if ilo_info.funds_raised < ilo_info.soft_cap {
+                // Failed ILO, contributors can reclaim their funds
+                Self::enter_refunding(&asset_id, &mut ilo_info)?;
                 <ILOs<T>>::insert(&asset_id, &ilo_info);
 
                 return Ok(().into());
             }
 
+            let pallet_account = Self::account_id();
+
             // Transfer fee to authority account
             let funds_raised_fee = (FixedWrapper::from(ilo_info.funds_raised)
                 * FixedWrapper::from(FeePercentOnRaisedFunds::<T>::get()))
@@ -784,7 +905,7 @@ pub mod pallet {
             Assets::<T>::burn(
                 RawOrigin::Signed(pallet_account.clone()).into(),
                 asset_id.into(),
-                ilo_info.tokens_for_ilo - ilo_info.sold_tokens,
+                ilo_info.tokens_for_ilo.saturating_sub(ilo_info.sold_tokens),
             )?;
 
             // Lock liquidity
@@ -807,7 +928,7 @@ pub mod pallet {
             ilo_info.lp_tokens =
                 PoolXYK::<T>::balance_of_pool_provider(pool_account, pallet_account).unwrap_or(0);
 
-            ilo_info.succeeded = true;
+            ilo_info.status = ILOStatus::Succeeded;
             ilo_info.finish_timestamp = current_timestamp;
             <ILOs<T>>::insert(&asset_id, &ilo_info);
 
@@ -923,8 +1044,10 @@ pub mod pallet {
             // Get ILO info
             let ilo_info = <ILOs<T>>::get(&asset_id).ok_or(Error::<T>::ILODoesNotExist)?;
 
-            if !ilo_info.failed && !ilo_info.succeeded {
-                return Err(Error::<T>::ILOIsNotFinished.into());
+            match ilo_info.status {
+                ILOStatus::Created => return Err(Error::<T>::ILOIsNotFinished.into()),
+                ILOStatus::Cancelled => return Err(Error::<T>::ILOIsCancelled.into()),
+                ILOStatus::Succeeded | ILOStatus::Refunding => {}
             }
 
             // Get contribution info
@@ -937,16 +1060,32 @@ pub mod pallet {
             let pallet_account = Self::account_id();
 
             // ILO failed
-            if ilo_info.failed {
+            if ilo_info.status == ILOStatus::Refunding {
                 // Claim unused funds
-                Assets::<T>::transfer_from(
-                    &ilo_info.base_asset.into(),
-                    &pallet_account,
-                    &user,
-                    contribution_info.funds_contributed,
-                )?;
-                contribution_info.claiming_finished = true;
+                Self::refund_contribution(&ilo_info, &user, &mut contribution_info)?;
             } else {
+                // Settle batch auction bid
+                if matches!(ilo_info.sale_type, SaleType::BatchAuction { .. })
+                    && contribution_info.tokens_bought == balance!(0)
+                {
+                    Self::settle_batch_auction_bid(
+                        &asset_id,
+                        &ilo_info,
+                        &user,
+                        &mut contribution_info,
+                    )?;
+
+                    if contribution_info.tokens_bought == balance!(0) {
+                        contribution_info.claiming_finished = true;
+                        <Contributions<T>>::insert(&asset_id, &user, contribution_info);
+
+                        // Emit an event
+                        Self::deposit_event(Event::Claimed(user.clone(), asset_id));
+
+                        return Ok(().into());
+                    }
+                }
+
                 // First claim
                 if contribution_info.tokens_claimed == balance!(0) {
                     let tokens_to_claim = (FixedWrapper::from(contribution_info.tokens_bought)
@@ -1034,11 +1173,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             fee_percent: Balance,
         ) -> DispatchResultWithPostInfo {
-            let user = ensure_signed(origin)?;
-
-            if user != AuthorityAccount::<T>::get() {
-                return Err(Error::<T>::Unauthorized.into());
-            }
+            Self::ensure_authority(origin)?;
 
             if fee_percent > balance!(1) {
                 return Err(Error::<T>::InvalidFeePercent.into());
@@ -1059,11 +1194,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             ceres_fee: Balance,
         ) -> DispatchResultWithPostInfo {
-            let user = ensure_signed(origin)?;
-
-            if user != AuthorityAccount::<T>::get() {
-                return Err(Error::<T>::Unauthorized.into());
-            }
+            Self::ensure_authority(origin)?;
 
             CeresBurnFeeAmount::<T>::put(ceres_fee);
 
@@ -1080,11 +1211,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             ceres_fee: Balance,
         ) -> DispatchResultWithPostInfo {
-            let user = ensure_signed(origin)?;
-
-            if user != AuthorityAccount::<T>::get() {
-                return Err(Error::<T>::Unauthorized.into());
-            }
+            Self::ensure_authority(origin)?;
 
             CeresForContributionInILO::<T>::put(ceres_fee);
 
@@ -1217,6 +1344,177 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Cancel ILO before it starts
+        #[transactional]
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_ilo())]
+        pub fn cancel_ilo(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            // Get ILO info
+            let mut ilo_info = <ILOs<T>>::get(&asset_id).ok_or(Error::<T>::ILODoesNotExist)?;
+
+            if user != ilo_info.ilo_organizer {
+                return Err(Error::<T>::Unauthorized.into());
+            }
+
+            match ilo_info.status {
+                ILOStatus::Refunding => return Err(Error::<T>::ILOIsFailed.into()),
+                ILOStatus::Succeeded => return Err(Error::<T>::ILOIsSucceeded.into()),
+                ILOStatus::Cancelled => return Err(Error::<T>::ILOIsCancelled.into()),
+                ILOStatus::Created => {}
+            }
+
+            let current_timestamp = Timestamp::<T>::get();
+            ensure!(
+                current_timestamp <= ilo_info.start_timestamp,
+                Error::<T>::ILOAlreadyStarted
+            );
+
+            // Return tokens to ILO organizer
+            let total_tokens = ilo_info.tokens_for_liquidity + ilo_info.tokens_for_ilo;
+            Assets::<T>::transfer_from(
+                &asset_id.into(),
+                &Self::account_id(),
+                &ilo_info.ilo_organizer,
+                total_tokens,
+            )?;
+
+            ilo_info.status = ILOStatus::Cancelled;
+            <ILOs<T>>::insert(&asset_id, &ilo_info);
+
+            // Emit an event
+            Self::deposit_event(Event::ILOCancelled(user, asset_id));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Claim full contribution from ILO in refunding state
+        #[transactional]
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_refund())]
+        pub fn claim_refund(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            // Get ILO info
+            let ilo_info = <ILOs<T>>::get(&asset_id).ok_or(Error::<T>::ILODoesNotExist)?;
+
+            ensure!(
+                matches!(ilo_info.status, ILOStatus::Refunding | ILOStatus::Cancelled),
+                Error::<T>::ILOIsNotRefunding
+            );
+
+            // Get contribution info
+            let mut contribution_info = <Contributions<T>>::get(&asset_id, &user);
+            ensure!(
+                !contribution_info.claiming_finished,
+                Error::<T>::FundsAlreadyClaimed
+            );
+            ensure!(
+                contribution_info.funds_contributed > 0,
+                Error::<T>::NothingToClaim
+            );
+
+            Self::refund_contribution(&ilo_info, &user, &mut contribution_info)?;
+            let funds_contributed = contribution_info.funds_contributed;
+            <Contributions<T>>::insert(&asset_id, &user, contribution_info);
+
+            // Emit an event
+            Self::deposit_event(Event::Refunded(user, asset_id, funds_contributed));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Place bid in batch auction
+        #[transactional]
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::bid())]
+        pub fn bid(
+            origin: OriginFor<T>,
+            asset_id: AssetIdOf<T>,
+            funds_to_contribute: Balance,
+            price: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            if !WhitelistedContributors::<T>::get().contains(&user) {
+                return Err(Error::<T>::AccountIsNotWhitelisted.into());
+            }
+
+            let current_timestamp = Timestamp::<T>::get();
+
+            ensure!(
+                CeresForContributionInILO::<T>::get()
+                    <= Assets::<T>::free_balance(&CeresAssetIdOf::<T>::get().into(), &user)
+                        .unwrap_or(0),
+                Error::<T>::NotEnoughCeres
+            );
+
+            // Get ILO info
+            let mut ilo_info = <ILOs<T>>::get(&asset_id).ok_or(Error::<T>::ILODoesNotExist)?;
+
+            let min_price = match ilo_info.sale_type {
+                SaleType::BatchAuction { min_price } => min_price,
+                _ => return Err(Error::<T>::WrongSaleType.into()),
+            };
+            ensure!(
+                price >= min_price && price <= ilo_info.ilo_price,
+                Error::<T>::InvalidBidPrice
+            );
+            ensure!(
+                !<BatchAuctionBids<T>>::contains_key(&asset_id, &user),
+                Error::<T>::BidAlreadyPlaced
+            );
+            let bid_count = <BatchAuctionBidCount<T>>::get(&asset_id);
+            ensure!(bid_count < MAX_BATCH_AUCTION_BIDS, Error::<T>::TooManyBids);
+
+            // Get contribution info
+            let mut contribution_info = <Contributions<T>>::get(&asset_id, &user);
+
+            Self::check_contribution(
+                &ilo_info,
+                &contribution_info,
+                funds_to_contribute,
+                current_timestamp,
+            )?;
+
+            ilo_info.funds_raised += funds_to_contribute;
+            contribution_info.funds_contributed += funds_to_contribute;
+
+            // Transfer base_asset to pallet
+            Assets::<T>::transfer_from(
+                &ilo_info.base_asset.into(),
+                &user,
+                &Self::account_id(),
+                funds_to_contribute,
+            )?;
+
+            // Update storage
+            <ILOs<T>>::insert(&asset_id, &ilo_info);
+            <Contributions<T>>::insert(&asset_id, &user, contribution_info);
+            <BatchAuctionBids<T>>::insert(&asset_id, &user, price);
+            <BatchAuctionBidCount<T>>::insert(&asset_id, bid_count + 1);
+
+            // Emit an event
+            Self::deposit_event(Event::<T>::BidPlaced(
+                user,
+                asset_id,
+                funds_to_contribute,
+                price,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
@@ -1227,38 +1525,28 @@ pub mod pallet {
             if (now % T::BLOCKS_PER_ONE_DAY).is_zero() {
                 let current_timestamp = Timestamp::<T>::get();
                 let days_to_finish_ilo = 14u32;
-                let pallet_account = Self::account_id();
 
                 let ilos = ILOs::<T>::iter().collect::<Vec<_>>();
                 for (ilo_asset, mut ilo_info) in ilos {
                     if current_timestamp > ilo_info.end_timestamp
-                        && !ilo_info.failed
-                        && !ilo_info.succeeded
+                        && ilo_info.status == ILOStatus::Created
                     {
                         let finish_timestamp = ilo_info.end_timestamp
                             + (T::MILLISECONDS_PER_DAY.saturating_mul(days_to_finish_ilo.into()))
                                 .into();
                         if current_timestamp >= finish_timestamp {
-                            ilo_info.failed = true;
-
-                            let total_tokens =
-                                ilo_info.tokens_for_liquidity + ilo_info.tokens_for_ilo;
-                            if !ilo_info.refund_type {
-                                let _ = Assets::<T>::burn(
-                                    RawOrigin::Signed(pallet_account.clone()).into(),
-                                    ilo_asset.into(),
-                                    total_tokens,
-                                );
-                            } else {
-                                let _ = Assets::<T>::transfer_from(
-                                    &ilo_asset.into(),
-                                    &pallet_account,
-                                    &ilo_info.ilo_organizer,
-                                    total_tokens,
+                            let result = common::with_transaction(|| {
+                                Self::enter_refunding(&ilo_asset, &mut ilo_info)?;
+                                <ILOs<T>>::insert(&ilo_asset, &ilo_info);
+                                Ok::<_, DispatchError>(())
+                            });
+                            if let Err(err) = result {
+                                frame_support::log::error!(
+                                    "Failed to put ILO for {:?} into refunding state: {:?}",
+                                    ilo_asset,
+                                    err
                                 );
                             }
-
-                            <ILOs<T>>::insert(&ilo_asset, ilo_info);
                             counter += 1;
                         }
                     }
@@ -1269,14 +1557,318 @@ pub mod pallet {
                 .reads(counter)
                 .saturating_add(T::DbWeight::get().writes(counter))
         }
+
+        fn on_runtime_upgrade() -> Weight {
+            match Self::pallet_storage_version() {
+                StorageVersion::V1 => {
+                    let weight = migrations::migrate::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    weight
+                }
+                StorageVersion::V2 => {
+                    let weight = migrations::migrate_sale_type::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V3);
+                    weight
+                }
+                StorageVersion::V3 => Weight::zero(),
+            }
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Check if origin is the authority account or the governance origin
+        fn ensure_authority(origin: OriginFor<T>) -> DispatchResult {
+            match T::GovernanceOrigin::try_origin(origin) {
+                Ok(_) => Ok(()),
+                Err(origin) => {
+                    let user = ensure_signed(origin)?;
+                    ensure!(
+                        user == AuthorityAccount::<T>::get(),
+                        Error::<T>::Unauthorized
+                    );
+                    Ok(())
+                }
+            }
+        }
+
         /// The account ID of pallet
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
         }
 
+        /// Put ILO in refunding state and burn or return ILO organizer tokens
+        fn enter_refunding(
+            asset_id: &AssetIdOf<T>,
+            ilo_info: &mut ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+        ) -> DispatchResult {
+            ilo_info.status = ILOStatus::Refunding;
+
+            let pallet_account = Self::account_id();
+            let total_tokens = ilo_info.tokens_for_liquidity + ilo_info.tokens_for_ilo;
+            if !ilo_info.refund_type {
+                Assets::<T>::burn(
+                    RawOrigin::Signed(pallet_account).into(),
+                    *asset_id,
+                    total_tokens,
+                )?;
+            } else {
+                Assets::<T>::transfer_from(
+                    asset_id,
+                    &pallet_account,
+                    &ilo_info.ilo_organizer,
+                    total_tokens,
+                )?;
+            }
+
+            Self::deposit_event(Event::ILORefunding(*asset_id));
+
+            Ok(())
+        }
+
+        /// Check sale type parameters
+        fn check_sale_type(
+            ilo_price: Balance,
+            sale_type: &SaleType<Balance>,
+        ) -> Result<(), DispatchError> {
+            match *sale_type {
+                SaleType::FixedPrice => {}
+                SaleType::DutchAuction { start_price } => {
+                    if start_price <= ilo_price {
+                        return Err(Error::<T>::InvalidAuctionPrice.into());
+                    }
+                }
+                SaleType::BatchAuction { min_price } => {
+                    if min_price == balance!(0) || min_price > ilo_price {
+                        return Err(Error::<T>::InvalidAuctionPrice.into());
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Check if contribution can be made in ILO
+        fn check_contribution(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            contribution_info: &ContributionInfo<Balance>,
+            funds_to_contribute: Balance,
+            current_timestamp: T::Moment,
+        ) -> Result<(), DispatchError> {
+            ensure!(
+                ilo_info.status != ILOStatus::Cancelled,
+                Error::<T>::ILOIsCancelled
+            );
+            ensure!(
+                ilo_info.start_timestamp < current_timestamp,
+                Error::<T>::ILONotStarted
+            );
+            ensure!(
+                ilo_info.end_timestamp > current_timestamp,
+                Error::<T>::ILOIsFinished
+            );
+            ensure!(
+                funds_to_contribute >= ilo_info.min_contribution,
+                Error::<T>::ContributionIsLowerThenMin
+            );
+            ensure!(
+                contribution_info.funds_contributed + funds_to_contribute
+                    <= ilo_info.max_contribution,
+                Error::<T>::ContributionIsBiggerThenMax
+            );
+            ensure!(
+                ilo_info.funds_raised + funds_to_contribute <= ilo_info.hard_cap,
+                Error::<T>::HardCapIsHit
+            );
+
+            Ok(())
+        }
+
+        /// Current token price of fixed price sale or Dutch auction
+        fn current_price(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            current_timestamp: T::Moment,
+        ) -> Balance {
+            match ilo_info.sale_type {
+                SaleType::DutchAuction { start_price } => {
+                    let duration: u64 = ilo_info
+                        .end_timestamp
+                        .saturating_sub(ilo_info.start_timestamp)
+                        .unique_saturated_into();
+                    let elapsed: u64 = current_timestamp
+                        .saturating_sub(ilo_info.start_timestamp)
+                        .unique_saturated_into();
+                    if duration == 0 || elapsed >= duration {
+                        return ilo_info.ilo_price;
+                    }
+
+                    let price_drop = (FixedWrapper::from(start_price - ilo_info.ilo_price)
+                        * FixedWrapper::from(balance!(elapsed))
+                        / FixedWrapper::from(balance!(duration)))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                    start_price.saturating_sub(price_drop)
+                }
+                SaleType::FixedPrice | SaleType::BatchAuction { .. } => ilo_info.ilo_price,
+            }
+        }
+
+        /// Calculate uniform clearing price of batch auction and update sold tokens and raised funds
+        fn clear_batch_auction(
+            asset_id: &AssetIdOf<T>,
+            ilo_info: &mut ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            min_price: Balance,
+        ) -> BatchAuctionResult<Balance> {
+            let mut bids = <BatchAuctionBids<T>>::iter_prefix(asset_id)
+                .map(|(contributor, price)| {
+                    (
+                        price,
+                        <Contributions<T>>::get(asset_id, &contributor).funds_contributed,
+                    )
+                })
+                .collect::<Vec<_>>();
+            bids.sort_by(|a, b| b.0.cmp(&a.0));
+
+            let supply = ilo_info.tokens_for_ilo;
+            let mut funds_above = balance!(0);
+            let mut result = None;
+            let mut i = 0;
+            while i < bids.len() {
+                let price = bids[i].0;
+                let mut level_funds = balance!(0);
+                while i < bids.len() && bids[i].0 == price {
+                    level_funds += bids[i].1;
+                    i += 1;
+                }
+
+                // Supply is sold out to higher bids at price above this level
+                let price_above_level = (FixedWrapper::from(funds_above)
+                    / FixedWrapper::from(supply))
+                .try_into_balance()
+                .unwrap_or(0);
+                if price_above_level > price {
+                    result = Some(BatchAuctionResult {
+                        clearing_price: price_above_level,
+                        marginal_fill: balance!(0),
+                    });
+                    break;
+                }
+
+                // Supply is sold out at this price level, bids at this level are partially filled
+                let tokens_above = (FixedWrapper::from(funds_above) / FixedWrapper::from(price))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                let level_tokens = (FixedWrapper::from(level_funds) / FixedWrapper::from(price))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                if tokens_above + level_tokens >= supply {
+                    let marginal_fill = (FixedWrapper::from(supply.saturating_sub(tokens_above))
+                        / FixedWrapper::from(level_tokens))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                    result = Some(BatchAuctionResult {
+                        clearing_price: price,
+                        marginal_fill,
+                    });
+                    break;
+                }
+
+                funds_above += level_funds;
+            }
+
+            // Supply is not sold out at any bid price
+            let result = result.unwrap_or_else(|| {
+                let clearing_price = (FixedWrapper::from(funds_above) / FixedWrapper::from(supply))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                BatchAuctionResult {
+                    clearing_price: clearing_price.max(min_price),
+                    marginal_fill: balance!(1),
+                }
+            });
+
+            let mut sold_tokens = balance!(0);
+            let mut funds_raised = balance!(0);
+            for (price, funds) in bids {
+                let (tokens_bought, funds_spent) = Self::batch_auction_fill(price, funds, &result);
+                sold_tokens += tokens_bought;
+                funds_raised += funds_spent;
+            }
+            ilo_info.sold_tokens = sold_tokens.min(supply);
+            ilo_info.funds_raised = funds_raised;
+
+            result
+        }
+
+        /// Calculate tokens bought and funds spent by batch auction bid
+        fn batch_auction_fill(
+            price: Balance,
+            funds: Balance,
+            result: &BatchAuctionResult<Balance>,
+        ) -> (Balance, Balance) {
+            if price < result.clearing_price {
+                return (balance!(0), balance!(0));
+            }
+
+            let mut tokens_bought =
+                FixedWrapper::from(funds) / FixedWrapper::from(result.clearing_price);
+            if price == result.clearing_price {
+                tokens_bought = tokens_bought * FixedWrapper::from(result.marginal_fill);
+            }
+            let tokens_bought = tokens_bought.try_into_balance().unwrap_or(0);
+            let funds_spent = (FixedWrapper::from(tokens_bought)
+                * FixedWrapper::from(result.clearing_price))
+            .try_into_balance()
+            .unwrap_or(0);
+
+            (tokens_bought, funds_spent.min(funds))
+        }
+
+        /// Set bought tokens of batch auction bid and return unused funds
+        fn settle_batch_auction_bid(
+            asset_id: &AssetIdOf<T>,
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            contributor: &AccountIdOf<T>,
+            contribution_info: &mut ContributionInfo<Balance>,
+        ) -> DispatchResult {
+            let result =
+                <BatchAuctionResults<T>>::get(asset_id).ok_or(Error::<T>::ILOIsNotFinished)?;
+            let price = <BatchAuctionBids<T>>::get(asset_id, contributor).unwrap_or(0);
+
+            let (tokens_bought, funds_spent) =
+                Self::batch_auction_fill(price, contribution_info.funds_contributed, &result);
+            let unused_funds = contribution_info.funds_contributed - funds_spent;
+            if unused_funds > 0 {
+                Assets::<T>::transfer_from(
+                    &ilo_info.base_asset,
+                    &Self::account_id(),
+                    contributor,
+                    unused_funds,
+                )?;
+            }
+
+            contribution_info.funds_contributed = funds_spent;
+            contribution_info.tokens_bought = tokens_bought;
+
+            Ok(())
+        }
+
+        /// Return full contribution to contributor without penalty
+        fn refund_contribution(
+            ilo_info: &ILOInfo<Balance, AccountIdOf<T>, T::Moment, AssetIdOf<T>>,
+            contributor: &AccountIdOf<T>,
+            contribution_info: &mut ContributionInfo<Balance>,
+        ) -> DispatchResult {
+            Assets::<T>::transfer_from(
+                &ilo_info.base_asset,
+                &Self::account_id(),
+                contributor,
+                contribution_info.funds_contributed,
+            )?;
+            contribution_info.claiming_finished = true;
+
+            Ok(())
+        }
+
         /// Check parameters
         fn check_parameters(
             tokens_for_ilo: Balance,
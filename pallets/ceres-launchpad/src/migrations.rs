@@ -0,0 +1,173 @@
+use crate::{Config, ContributorsVesting, ILOInfo, ILOStatus, ILOs, SaleType, TeamVesting};
+use codec::{Decode, Encode};
+use common::Balance;
+use frame_support::log;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+#[derive(Encode, Decode)]
+pub struct ILOInfoV1<Balance, AccountId, Moment, AssetId> {
+    ilo_organizer: AccountId,
+    tokens_for_ilo: Balance,
+    tokens_for_liquidity: Balance,
+    ilo_price: Balance,
+    soft_cap: Balance,
+    hard_cap: Balance,
+    min_contribution: Balance,
+    max_contribution: Balance,
+    refund_type: bool,
+    liquidity_percent: Balance,
+    listing_price: Balance,
+    lockup_days: u32,
+    start_timestamp: Moment,
+    end_timestamp: Moment,
+    contributors_vesting: ContributorsVesting<Balance, Moment>,
+    team_vesting: TeamVesting<Balance, Moment>,
+    sold_tokens: Balance,
+    funds_raised: Balance,
+    succeeded: bool,
+    failed: bool,
+    lp_tokens: Balance,
+    claimed_lp_tokens: bool,
+    finish_timestamp: Moment,
+    base_asset: AssetId,
+}
+
+#[derive(Encode, Decode)]
+pub struct ILOInfoV2<Balance, AccountId, Moment, AssetId> {
+    ilo_organizer: AccountId,
+    tokens_for_ilo: Balance,
+    tokens_for_liquidity: Balance,
+    ilo_price: Balance,
+    soft_cap: Balance,
+    hard_cap: Balance,
+    min_contribution: Balance,
+    max_contribution: Balance,
+    refund_type: bool,
+    liquidity_percent: Balance,
+    listing_price: Balance,
+    lockup_days: u32,
+    start_timestamp: Moment,
+    end_timestamp: Moment,
+    contributors_vesting: ContributorsVesting<Balance, Moment>,
+    team_vesting: TeamVesting<Balance, Moment>,
+    sold_tokens: Balance,
+    funds_raised: Balance,
+    status: ILOStatus,
+    lp_tokens: Balance,
+    claimed_lp_tokens: bool,
+    finish_timestamp: Moment,
+    base_asset: AssetId,
+}
+
+pub fn migrate<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    migrate_ilo_status::<T>()
+}
+
+pub fn migrate_sale_type<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    ILOs::<T>::translate_values::<
+        ILOInfoV2<
+            Balance,
+            <T as frame_system::Config>::AccountId,
+            T::Moment,
+            <T as assets::Config>::AssetId,
+        >,
+        _,
+    >(|v| {
+        weight += 1;
+        Some(ILOInfo {
+            ilo_organizer: v.ilo_organizer,
+            tokens_for_ilo: v.tokens_for_ilo,
+            tokens_for_liquidity: v.tokens_for_liquidity,
+            ilo_price: v.ilo_price,
+            soft_cap: v.soft_cap,
+            hard_cap: v.hard_cap,
+            min_contribution: v.min_contribution,
+            max_contribution: v.max_contribution,
+            refund_type: v.refund_type,
+            liquidity_percent: v.liquidity_percent,
+            listing_price: v.listing_price,
+            lockup_days: v.lockup_days,
+            start_timestamp: v.start_timestamp,
+            end_timestamp: v.end_timestamp,
+            contributors_vesting: v.contributors_vesting,
+            team_vesting: v.team_vesting,
+            sold_tokens: v.sold_tokens,
+            funds_raised: v.funds_raised,
+            status: v.status,
+            lp_tokens: v.lp_tokens,
+            claimed_lp_tokens: v.claimed_lp_tokens,
+            finish_timestamp: v.finish_timestamp,
+            base_asset: v.base_asset,
+            sale_type: SaleType::FixedPrice,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "ILOInfo migrated to new version with sale_type field"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
+
+pub fn migrate_ilo_status<T: Config>() -> Weight {
+    let mut weight: u64 = 0;
+
+    ILOs::<T>::translate_values::<
+        ILOInfoV1<
+            Balance,
+            <T as frame_system::Config>::AccountId,
+            T::Moment,
+            <T as assets::Config>::AssetId,
+        >,
+        _,
+    >(|v| {
+        weight += 1;
+        let status = if v.succeeded {
+            ILOStatus::Succeeded
+        } else if v.failed {
+            ILOStatus::Refunding
+        } else {
+            ILOStatus::Created
+        };
+
+        Some(ILOInfo {
+            ilo_organizer: v.ilo_organizer,
+            tokens_for_ilo: v.tokens_for_ilo,
+            tokens_for_liquidity: v.tokens_for_liquidity,
+            ilo_price: v.ilo_price,
+            soft_cap: v.soft_cap,
+            hard_cap: v.hard_cap,
+            min_contribution: v.min_contribution,
+            max_contribution: v.max_contribution,
+            refund_type: v.refund_type,
+            liquidity_percent: v.liquidity_percent,
+            listing_price: v.listing_price,
+            lockup_days: v.lockup_days,
+            start_timestamp: v.start_timestamp,
+            end_timestamp: v.end_timestamp,
+            contributors_vesting: v.contributors_vesting,
+            team_vesting: v.team_vesting,
+            sold_tokens: v.sold_tokens,
+            funds_raised: v.funds_raised,
+            status,
+            lp_tokens: v.lp_tokens,
+            claimed_lp_tokens: v.claimed_lp_tokens,
+            finish_timestamp: v.finish_timestamp,
+            base_asset: v.base_asset,
+            sale_type: SaleType::FixedPrice,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "ILOInfo migrated to new version with status and sale_type fields"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
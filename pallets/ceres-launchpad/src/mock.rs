@@ -114,6 +114,7 @@ impl frame_system::Config for Runtime {
 
 impl crate::Config for Runtime {
     const MILLISECONDS_PER_DAY: Self::Moment = 86_400_000;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
 }
@@ -168,6 +169,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -184,6 +186,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -1,6 +1,8 @@
 mod tests {
     use crate::mock::*;
-    use crate::{pallet, Error, FeePercentOnRaisedFunds, Pallet as CeresLaunchpadPallet};
+    use crate::{
+        pallet, Error, FeePercentOnRaisedFunds, ILOStatus, Pallet as CeresLaunchpadPallet, SaleType,
+    };
     use common::fixnum::ops::CheckedAdd;
     use common::prelude::FixedWrapper;
     use common::{
@@ -183,7 +185,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::ParameterCantBeZero
             );
@@ -219,7 +222,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::ParameterCantBeZero
             );
@@ -255,7 +259,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidSoftCap
             );
@@ -291,7 +296,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidMinimumContribution
             );
@@ -327,7 +333,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidMaximumContribution
             );
@@ -363,7 +370,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidLiquidityPercent
             );
@@ -399,7 +407,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidLockupDays
             );
@@ -435,7 +444,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidStartTimestamp
             );
@@ -471,7 +481,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidEndTimestamp
             );
@@ -507,7 +518,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidPrice
             );
@@ -543,7 +555,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidNumberOfTokensForILO
             );
@@ -579,7 +592,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.1),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidNumberOfTokensForLiquidity
             );
@@ -616,6 +630,7 @@ mod tests {
                     balance!(0.2),
                     current_timestamp + 3,
                     balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidTeamFirstReleasePercent
             );
@@ -652,6 +667,7 @@ mod tests {
                     balance!(0.2),
                     current_timestamp + 3,
                     balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidTeamVestingPercent
             );
@@ -688,6 +704,7 @@ mod tests {
                     balance!(0.2),
                     current_timestamp + 3,
                     balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidTeamVestingPercent
             );
@@ -724,6 +741,7 @@ mod tests {
                     balance!(0.2),
                     current_timestamp + 3,
                     balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidTeamVestingPercent
             );
@@ -760,6 +778,7 @@ mod tests {
                     balance!(0.2),
                     current_timestamp + 3,
                     balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidTeamVestingPeriod
             );
@@ -795,7 +814,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0),
                     current_timestamp + 3,
-                    balance!(20)
+                    balance!(20),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidFirstReleasePercent
             );
@@ -831,7 +851,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0)
+                    balance!(0),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidVestingPercent
             );
@@ -867,7 +888,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.9)
+                    balance!(0.9),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidVestingPercent
             );
@@ -903,7 +925,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.3)
+                    balance!(0.3),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidVestingPercent
             );
@@ -939,7 +962,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     0u32.into(),
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::InvalidVestingPeriod
             );
@@ -975,7 +999,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::NotEnoughCeres
             );
@@ -1011,7 +1036,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::NotEnoughTokens
             );
@@ -1048,7 +1074,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::AccountIsNotWhitelisted
             );
@@ -1085,7 +1112,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_eq!(
@@ -1135,7 +1163,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_err!(
@@ -1162,7 +1191,8 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::ILOAlreadyExists
             );
@@ -1211,7 +1241,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1262,7 +1293,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
             assert_err!(
                 CeresLaunchpadPallet::<Runtime>::contribute(
@@ -1303,7 +1335,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -1348,7 +1381,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1392,7 +1426,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1442,7 +1477,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1492,7 +1528,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1537,7 +1574,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1600,7 +1638,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1675,7 +1714,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_err!(
@@ -1716,7 +1756,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -1759,7 +1800,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1802,7 +1844,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1883,7 +1926,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -1977,7 +2021,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_err!(
@@ -2018,7 +2063,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -2061,7 +2107,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -2085,7 +2132,7 @@ mod tests {
             );
 
             let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
-            assert_eq!(ilo_info.failed, true);
+            assert_eq!(ilo_info.status, ILOStatus::Refunding);
         });
     }
 
@@ -2117,7 +2164,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -2141,7 +2189,7 @@ mod tests {
             );
 
             let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
-            assert_eq!(ilo_info.failed, true);
+            assert_eq!(ilo_info.status, ILOStatus::Refunding);
         });
     }
 
@@ -2173,7 +2221,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
@@ -2221,7 +2270,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2327,7 +2377,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2380,7 +2431,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2495,7 +2547,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2623,7 +2676,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_err!(
@@ -2664,7 +2718,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2729,7 +2784,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2792,7 +2848,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2864,7 +2921,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 50,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -2929,7 +2987,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.1),
                 30u32.into(),
-                balance!(0.18)
+                balance!(0.18),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3118,7 +3177,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             assert_err!(
@@ -3159,7 +3219,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3222,7 +3283,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3307,7 +3369,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3392,7 +3455,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3473,7 +3537,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3548,7 +3613,8 @@ mod tests {
                 balance!(0.2),
                 balance!(0.2),
                 current_timestamp + 3,
-                balance!(0.2)
+                balance!(0.2),
+                SaleType::FixedPrice
             ));
 
             pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
@@ -3580,7 +3646,7 @@ mod tests {
                 balance!(15990)
             );
 
-            assert_eq!(ilo_info.failed, true);
+            assert_eq!(ilo_info.status, ILOStatus::Refunding);
         });
     }
 
@@ -3767,10 +3833,1093 @@ mod tests {
                     balance!(0.2),
                     balance!(0.2),
                     current_timestamp + 3,
-                    balance!(0.2)
+                    balance!(0.2),
+                    SaleType::FixedPrice
                 ),
                 Error::<Runtime>::AccountIsNotWhitelisted
             );
         });
     }
+
+    #[test]
+    fn cancel_ilo_ilo_does_not_exist() {
+        preset_initial(|| {
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILODoesNotExist
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_ilo_unauthorized() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::Unauthorized
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_ilo_ilo_already_started() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOAlreadyStarted
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_ilo_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                false,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+
+            let pallet_account = PalletId(*b"crslaunc").into_account_truncating();
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &pallet_account)
+                    .expect("Failed to query free balance."),
+                balance!(0)
+            );
+
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &ALICE)
+                    .expect("Failed to query free balance."),
+                balance!(15990)
+            );
+
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.status, ILOStatus::Cancelled);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOIsCancelled
+            );
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::contribute(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(0.21)
+                ),
+                Error::<Runtime>::ILOIsCancelled
+            );
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOIsCancelled
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_ilo_finished_ilo() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                false,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+
+            pallet::ILOs::<Runtime>::mutate(&CERES_ASSET_ID, |ilo_info| {
+                ilo_info.as_mut().unwrap().status = ILOStatus::Refunding;
+            });
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOIsFailed
+            );
+
+            pallet::ILOs::<Runtime>::mutate(&CERES_ASSET_ID, |ilo_info| {
+                ilo_info.as_mut().unwrap().status = ILOStatus::Succeeded;
+            });
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOIsSucceeded
+            );
+        });
+    }
+
+    #[test]
+    fn create_ilo_after_cancel_ilo_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::cancel_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.status, ILOStatus::Created);
+        });
+    }
+
+    #[test]
+    fn finish_ilo_soft_cap_not_reached_refunding_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                false,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(0.21)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.status, ILOStatus::Refunding);
+            assert_eq!(ilo_info.funds_raised, balance!(0.21));
+        });
+    }
+
+    #[test]
+    fn claim_refund_ilo_does_not_exist() {
+        preset_initial(|| {
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::claim_refund(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILODoesNotExist
+            );
+        });
+    }
+
+    #[test]
+    fn claim_refund_ilo_is_not_refunding() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(0.21)
+            ));
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::claim_refund(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::ILOIsNotRefunding
+            );
+        });
+    }
+
+    #[test]
+    fn claim_refund_nothing_to_claim() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                false,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(0.21)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::claim_refund(
+                    RuntimeOrigin::signed(DAN),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::NothingToClaim
+            );
+        });
+    }
+
+    #[test]
+    fn claim_refund_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(0.25),
+                false,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(0.21)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+            assert_eq!(
+                Assets::free_balance(&XOR, &CHARLES).expect("Failed to query free balance."),
+                balance!(1999.79)
+            );
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::claim_refund(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into()
+            ));
+
+            assert_eq!(
+                Assets::free_balance(&XOR, &CHARLES).expect("Failed to query free balance."),
+                balance!(2000)
+            );
+
+            let contribution_info =
+                pallet::Contributions::<Runtime>::get(&CERES_ASSET_ID, &CHARLES);
+            assert_eq!(contribution_info.claiming_finished, true);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::claim_refund(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into()
+                ),
+                Error::<Runtime>::FundsAlreadyClaimed
+            );
+        });
+    }
+
+    #[test]
+    fn create_ilo_invalid_dutch_auction_start_price() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::create_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset.into(),
+                    CERES_ASSET_ID.into(),
+                    balance!(7693),
+                    balance!(3000),
+                    balance!(0.13),
+                    balance!(600),
+                    balance!(1000),
+                    balance!(0.2),
+                    balance!(1500),
+                    true,
+                    balance!(0.75),
+                    balance!(0.25),
+                    31,
+                    current_timestamp + 5,
+                    current_timestamp + 10,
+                    balance!(1000),
+                    balance!(0.2),
+                    current_timestamp + 3,
+                    balance!(0.2),
+                    balance!(0.2),
+                    current_timestamp + 3,
+                    balance!(0.2),
+                    SaleType::DutchAuction {
+                        start_price: balance!(0.13)
+                    }
+                ),
+                Error::<Runtime>::InvalidAuctionPrice
+            );
+        });
+    }
+
+    #[test]
+    fn create_ilo_invalid_batch_auction_min_price() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::create_ilo(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset.into(),
+                    CERES_ASSET_ID.into(),
+                    balance!(7693),
+                    balance!(3000),
+                    balance!(0.13),
+                    balance!(600),
+                    balance!(1000),
+                    balance!(0.2),
+                    balance!(1500),
+                    true,
+                    balance!(0.75),
+                    balance!(0.25),
+                    31,
+                    current_timestamp + 5,
+                    current_timestamp + 10,
+                    balance!(1000),
+                    balance!(0.2),
+                    current_timestamp + 3,
+                    balance!(0.2),
+                    balance!(0.2),
+                    current_timestamp + 3,
+                    balance!(0.2),
+                    SaleType::BatchAuction {
+                        min_price: balance!(0.2)
+                    }
+                ),
+                Error::<Runtime>::InvalidAuctionPrice
+            );
+        });
+    }
+
+    #[test]
+    fn contribute_dutch_auction_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::DutchAuction {
+                    start_price: balance!(0.26)
+                }
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 7);
+
+            // Price dropped from 0.26 to 0.208 after 2 of 5 milliseconds
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::contribute(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(520)
+            ));
+
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.sold_tokens, balance!(2500));
+            assert_eq!(ilo_info.funds_raised, balance!(520));
+
+            let contribution_info =
+                pallet::Contributions::<Runtime>::get(&CERES_ASSET_ID, &CHARLES);
+            assert_eq!(contribution_info.tokens_bought, balance!(2500));
+        });
+    }
+
+    #[test]
+    fn contribute_wrong_sale_type() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::BatchAuction {
+                    min_price: balance!(0.1)
+                }
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::contribute(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100)
+                ),
+                Error::<Runtime>::WrongSaleType
+            );
+        });
+    }
+
+    #[test]
+    fn bid_wrong_sale_type() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::FixedPrice
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::bid(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100),
+                    balance!(0.13)
+                ),
+                Error::<Runtime>::WrongSaleType
+            );
+        });
+    }
+
+    #[test]
+    fn bid_invalid_bid_price() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::BatchAuction {
+                    min_price: balance!(0.1)
+                }
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::bid(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100),
+                    balance!(0.09)
+                ),
+                Error::<Runtime>::InvalidBidPrice
+            );
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::bid(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100),
+                    balance!(0.14)
+                ),
+                Error::<Runtime>::InvalidBidPrice
+            );
+        });
+    }
+
+    #[test]
+    fn bid_bid_already_placed() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::BatchAuction {
+                    min_price: balance!(0.1)
+                }
+            ));
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::bid(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(100),
+                balance!(0.12)
+            ));
+
+            assert_eq!(
+                pallet::BatchAuctionBids::<Runtime>::get(&CERES_ASSET_ID, &CHARLES),
+                Some(balance!(0.12))
+            );
+            assert_eq!(
+                pallet::BatchAuctionBidCount::<Runtime>::get(&CERES_ASSET_ID),
+                1
+            );
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.funds_raised, balance!(100));
+
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::bid(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100),
+                    balance!(0.12)
+                ),
+                Error::<Runtime>::BidAlreadyPlaced
+            );
+
+            pallet::BatchAuctionBids::<Runtime>::remove(&CERES_ASSET_ID, &CHARLES);
+            pallet::BatchAuctionBidCount::<Runtime>::insert(
+                &CERES_ASSET_ID,
+                pallet::MAX_BATCH_AUCTION_BIDS,
+            );
+            assert_err!(
+                CeresLaunchpadPallet::<Runtime>::bid(
+                    RuntimeOrigin::signed(CHARLES),
+                    CERES_ASSET_ID.into(),
+                    balance!(100),
+                    balance!(0.12)
+                ),
+                Error::<Runtime>::TooManyBids
+            );
+        });
+    }
+
+    #[test]
+    fn finish_ilo_batch_auction_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::BatchAuction {
+                    min_price: balance!(0.1)
+                }
+            ));
+
+            let xor: AssetId = XOR.into();
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &xor,
+                &ALICE,
+                &DAN,
+                balance!(1000)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::bid(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(600),
+                balance!(0.13)
+            ));
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::bid(
+                RuntimeOrigin::signed(DAN),
+                CERES_ASSET_ID.into(),
+                balance!(400),
+                balance!(0.1)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+            let result = pallet::BatchAuctionResults::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(result.clearing_price, balance!(0.1));
+            assert_eq!(result.marginal_fill, balance!(0.42325));
+
+            let ilo_info = pallet::ILOs::<Runtime>::get(&CERES_ASSET_ID).unwrap();
+            assert_eq!(ilo_info.status, ILOStatus::Succeeded);
+            assert_eq!(ilo_info.sold_tokens, balance!(7693));
+            assert_eq!(ilo_info.funds_raised, balance!(769.3));
+        });
+    }
+
+    #[test]
+    fn claim_batch_auction_ok() {
+        preset_initial(|| {
+            let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+            let base_asset = XOR;
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::create_ilo(
+                RuntimeOrigin::signed(ALICE),
+                base_asset.into(),
+                CERES_ASSET_ID.into(),
+                balance!(7693),
+                balance!(3000),
+                balance!(0.13),
+                balance!(600),
+                balance!(1000),
+                balance!(0.2),
+                balance!(1500),
+                true,
+                balance!(0.75),
+                balance!(0.25),
+                31,
+                current_timestamp + 5,
+                current_timestamp + 10,
+                balance!(1000),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                balance!(0.2),
+                current_timestamp + 3,
+                balance!(0.2),
+                SaleType::BatchAuction {
+                    min_price: balance!(0.1)
+                }
+            ));
+
+            let xor: AssetId = XOR.into();
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &xor,
+                &ALICE,
+                &DAN,
+                balance!(1000)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 6);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::bid(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+                balance!(600),
+                balance!(0.13)
+            ));
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::bid(
+                RuntimeOrigin::signed(DAN),
+                CERES_ASSET_ID.into(),
+                balance!(400),
+                balance!(0.1)
+            ));
+
+            pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 11);
+
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::finish_ilo(
+                RuntimeOrigin::signed(ALICE),
+                CERES_ASSET_ID.into()
+            ));
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+                RuntimeOrigin::signed(CHARLES),
+                CERES_ASSET_ID.into(),
+            ));
+
+            let contribution_info =
+                pallet::Contributions::<Runtime>::get(&CERES_ASSET_ID, &CHARLES);
+            assert_eq!(contribution_info.tokens_bought, balance!(6000));
+            assert_eq!(contribution_info.funds_contributed, balance!(600));
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &CHARLES)
+                    .expect("Failed to query free balance."),
+                balance!(6200)
+            );
+            assert_eq!(
+                Assets::free_balance(&XOR, &CHARLES).expect("Failed to query free balance."),
+                balance!(1400)
+            );
+
+            // Bid at clearing price is partially filled and unused funds are returned
+            assert_ok!(CeresLaunchpadPallet::<Runtime>::claim(
+                RuntimeOrigin::signed(DAN),
+                CERES_ASSET_ID.into(),
+            ));
+
+            let contribution_info = pallet::Contributions::<Runtime>::get(&CERES_ASSET_ID, &DAN);
+            assert_eq!(contribution_info.tokens_bought, balance!(1693));
+            assert_eq!(contribution_info.funds_contributed, balance!(169.3));
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &DAN).expect("Failed to query free balance."),
+                balance!(11338.6)
+            );
+            assert_eq!(
+                Assets::free_balance(&XOR, &DAN).expect("Failed to query free balance."),
+                balance!(830.7)
+            );
+        });
+    }
 }
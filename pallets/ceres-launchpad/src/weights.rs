@@ -72,6 +72,9 @@ pub trait WeightInfo {
 	fn remove_whitelisted_contributor() -> Weight;
 	fn add_whitelisted_ilo_organizer() -> Weight;
 	fn remove_whitelisted_ilo_organizer() -> Weight;
+	fn cancel_ilo() -> Weight;
+	fn claim_refund() -> Weight;
+	fn bid() -> Weight;
 }
 
 /// Weights for ceres_launchpad using the Substrate node and recommended hardware.
@@ -342,6 +345,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_ilo() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1559`
+		//  Estimated: `15975`
+		// Minimum execution time: 72_318_000 picoseconds.
+		Weight::from_parts(73_590_000, 15975)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad Contributions (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad Contributions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1653`
+		//  Estimated: `16081`
+		// Minimum execution time: 82_906_000 picoseconds.
+		Weight::from_parts(84_417_000, 16081)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: CeresLaunchpad WhitelistedContributors (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad WhitelistedContributors (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad CeresForContributionInILO (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad CeresForContributionInILO (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad ILOs (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad BatchAuctionBids (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad BatchAuctionBids (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad Contributions (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad Contributions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1712`
+		//  Estimated: `23508`
+		// Minimum execution time: 121_052_000 picoseconds.
+		Weight::from_parts(122_961_000, 23508)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -611,4 +673,63 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_ilo() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1559`
+		//  Estimated: `15975`
+		// Minimum execution time: 72_318_000 picoseconds.
+		Weight::from_parts(73_590_000, 15975)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: CeresLaunchpad ILOs (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad Contributions (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad Contributions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1653`
+		//  Estimated: `16081`
+		// Minimum execution time: 82_906_000 picoseconds.
+		Weight::from_parts(84_417_000, 16081)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: CeresLaunchpad WhitelistedContributors (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad WhitelistedContributors (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad CeresForContributionInILO (r:1 w:0)
+	/// Proof Skipped: CeresLaunchpad CeresForContributionInILO (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: CeresLaunchpad ILOs (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad ILOs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad BatchAuctionBids (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad BatchAuctionBids (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLaunchpad Contributions (r:1 w:1)
+	/// Proof Skipped: CeresLaunchpad Contributions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1712`
+		//  Estimated: `23508`
+		// Minimum execution time: 121_052_000 picoseconds.
+		Weight::from_parts(122_961_000, 23508)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
\ No newline at end of file
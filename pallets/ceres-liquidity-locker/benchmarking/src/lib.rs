@@ -173,6 +173,64 @@ benchmarks! {
     verify {
         assert_eq!(ceres_liquidity_locker::FeesOptionTwoCeresAmount::<T>::get(), balance!(69));
     }
+
+    lock_liquidity_linear {
+        setup_benchmark::<T>()?;
+        let caller = alice::<T>();
+        let release_start_timestamp = Timestamp::<T>::get() + 5u32.into();
+        let timestamp = Timestamp::<T>::get() + 10u32.into();
+        let lp_percentage = balance!(0.5);
+        let ceres_asset_id: T::AssetId = common::AssetId32::from_bytes(hex!(
+            "008bcfd2387d3fc453333557eecb0efe59fcba128769b2feefdd306e98e66440"
+        )).into();
+    }: {
+        let _ = ceres_liquidity_locker::Pallet::<T>::lock_liquidity_linear(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            ceres_asset_id,
+            release_start_timestamp,
+            timestamp,
+            lp_percentage,
+            false
+        );
+    }
+    verify {
+        let lockups_alice = ceres_liquidity_locker::LockerData::<T>::get(caller.clone());
+        assert_eq!(lockups_alice.len(), 1);
+        assert_eq!(lockups_alice.get(0).unwrap().release_start_timestamp, release_start_timestamp);
+        assert_eq!(lockups_alice.get(0).unwrap().unlocking_timestamp, timestamp);
+    }
+
+    extend_lock {
+        setup_benchmark::<T>()?;
+        let caller = alice::<T>();
+        let timestamp = Timestamp::<T>::get() + 5u32.into();
+        let new_timestamp = Timestamp::<T>::get() + 10u32.into();
+        let ceres_asset_id: T::AssetId = common::AssetId32::from_bytes(hex!(
+            "008bcfd2387d3fc453333557eecb0efe59fcba128769b2feefdd306e98e66440"
+        )).into();
+
+        ceres_liquidity_locker::Pallet::<T>::lock_liquidity(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            ceres_asset_id,
+            timestamp,
+            balance!(0.5),
+            false
+        )?;
+    }: {
+        let _ = ceres_liquidity_locker::Pallet::<T>::extend_lock(
+            RawOrigin::Signed(caller.clone()).into(),
+            XOR.into(),
+            ceres_asset_id,
+            timestamp,
+            new_timestamp
+        );
+    }
+    verify {
+        let lockups_alice = ceres_liquidity_locker::LockerData::<T>::get(caller.clone());
+        assert_eq!(lockups_alice.get(0).unwrap().unlocking_timestamp, new_timestamp);
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -0,0 +1,101 @@
+use codec::Codec;
+
+use jsonrpsee::{
+    core::{Error as RpcError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use ceres_liquidity_locker_runtime_api::{
+    CeresLiquidityLockerApi as CeresLiquidityLockerRuntimeApi, LockedLiquidityInfo,
+};
+
+#[rpc(server, client)]
+pub trait CeresLiquidityLockerApi<BlockHash, AccountId, AssetId, LockedLiquidityInfo> {
+    #[method(name = "ceresLiquidityLocker_lockedLiquidity")]
+    fn locked_liquidity(
+        &self,
+        asset_a: AssetId,
+        asset_b: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<LockedLiquidityInfo>;
+
+    #[method(name = "ceresLiquidityLocker_lockedLiquidityOfAccount")]
+    fn locked_liquidity_of_account(
+        &self,
+        account_id: AccountId,
+        asset_a: AssetId,
+        asset_b: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<LockedLiquidityInfo>;
+}
+
+pub struct CeresLiquidityLockerClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CeresLiquidityLockerClient<C, B> {
+    /// Construct default `CeresLiquidityLockerClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, Balance>
+    CeresLiquidityLockerApiServer<
+        <Block as BlockT>::Hash,
+        AccountId,
+        AssetId,
+        LockedLiquidityInfo<Balance>,
+    > for CeresLiquidityLockerClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CeresLiquidityLockerRuntimeApi<Block, AccountId, AssetId, Balance>,
+    AccountId: Codec,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn locked_liquidity(
+        &self,
+        asset_a: AssetId,
+        asset_b: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<LockedLiquidityInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.locked_liquidity(&at, asset_a, asset_b)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn locked_liquidity_of_account(
+        &self,
+        account_id: AccountId,
+        asset_a: AssetId,
+        asset_b: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<LockedLiquidityInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.locked_liquidity_of_account(&at, account_id, asset_a, asset_b)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::string_serialization;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct LockedLiquidityInfo<Balance> {
+    /// Pool tokens which are currently locked
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub locked_pool_tokens: Balance,
+    /// Total issuance of pool tokens
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub total_pool_tokens: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait CeresLiquidityLockerApi<AccountId, AssetId, Balance> where
+        AccountId: Codec,
+        AssetId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+    {
+        /// Locked pool tokens of pool and total issuance of pool tokens
+        fn locked_liquidity(asset_a: AssetId, asset_b: AssetId) -> LockedLiquidityInfo<Balance>;
+
+        /// Locked pool tokens of user in pool and total issuance of pool tokens
+        fn locked_liquidity_of_account(account_id: AccountId, asset_a: AssetId, asset_b: AssetId) -> LockedLiquidityInfo<Balance>;
+    }
+}
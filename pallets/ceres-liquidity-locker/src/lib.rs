@@ -15,7 +15,7 @@ use codec::{Decode, Encode};
 use frame_support::weights::Weight;
 pub use weights::WeightInfo;
 
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Default, PartialEq, Eq, Clone, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct LockInfo<Balance, Moment, AssetId> {
     /// Amount of locked pool tokens
@@ -26,6 +26,8 @@ pub struct LockInfo<Balance, Moment, AssetId> {
     pub asset_a: AssetId,
     /// Target asset of locked liquidity
     pub asset_b: AssetId,
+    /// The timestamp from which the tokens are released linearly until unlocking timestamp
+    pub release_start_timestamp: Moment,
 }
 
 /// Storage version.
@@ -35,6 +37,8 @@ pub enum StorageVersion {
     V1,
     /// After migrating to timestamp calculation
     V2,
+    /// After adding linear release of locked liquidity
+    V3,
 }
 
 pub use pallet::*;
@@ -45,7 +49,7 @@ pub mod pallet {
     use common::prelude::{Balance, FixedWrapper};
     use common::{balance, DemeterFarmingPallet, PoolXykPallet};
     use frame_support::pallet_prelude::*;
-    use frame_support::sp_runtime::traits::Zero;
+    use frame_support::sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
     use frame_system::ensure_signed;
     use frame_system::pallet_prelude::*;
     use hex_literal::hex;
@@ -157,6 +161,8 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Funds Locked [who, amount, timestamp]
         Locked(AccountIdOf<T>, Balance, T::Moment),
+        /// Lock Extended [who, asset_a, asset_b, unlocking_timestamp]
+        LockExtended(AccountIdOf<T>, AssetIdOf<T>, AssetIdOf<T>, T::Moment),
     }
 
     #[pallet::error]
@@ -171,6 +177,10 @@ pub mod pallet {
         Unauthorized,
         /// Unlocking date cannot be in past
         InvalidUnlockingTimestamp,
+        /// Release start date must be in future and before unlocking date
+        InvalidReleaseStartTimestamp,
+        /// Lock info does not exist
+        LockInfoDoesNotExist,
     }
 
     #[pallet::call]
@@ -187,115 +197,15 @@ pub mod pallet {
             option: bool,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
-            ensure!(
-                percentage_of_pool_tokens <= balance!(1),
-                Error::<T>::InvalidPercentage
-            );
-
-            // Get current timestamp
-            let current_timestamp = Timestamp::<T>::get();
-            ensure!(
-                unlocking_timestamp > current_timestamp,
-                Error::<T>::InvalidUnlockingTimestamp
-            );
-
-            let mut lock_info = LockInfo {
-                pool_tokens: 0,
+            Self::lock(
+                user,
                 asset_a,
                 asset_b,
                 unlocking_timestamp,
-            };
-
-            // Get pool account
-            let pool_account: AccountIdOf<T> = T::XYKPool::properties_of_pool(asset_a, asset_b)
-                .ok_or(Error::<T>::PoolDoesNotExist)?
-                .0;
-
-            // Calculate number of pool tokens to be locked
-            let mut pool_tokens =
-                T::XYKPool::balance_of_pool_provider(pool_account.clone(), user.clone())
-                    .unwrap_or(0);
-            if pool_tokens == 0 {
-                return Err(Error::<T>::InsufficientLiquidityToLock.into());
-            }
-
-            lock_info.pool_tokens = (FixedWrapper::from(pool_tokens)
-                * FixedWrapper::from(percentage_of_pool_tokens))
-            .try_into_balance()
-            .unwrap_or(lock_info.pool_tokens);
-
-            // Check if user has enough liquidity to lock
-            let lockups = <LockerData<T>>::get(&user);
-            let mut locked_pool_tokens = 0;
-
-            for locks in lockups.iter() {
-                if locks.asset_a == asset_a && locks.asset_b == asset_b {
-                    if current_timestamp < locks.unlocking_timestamp {
-                        locked_pool_tokens = locked_pool_tokens + locks.pool_tokens;
-                    }
-                }
-            }
-
-            let unlocked_pool_tokens = pool_tokens - locked_pool_tokens;
-            ensure!(
-                lock_info.pool_tokens <= unlocked_pool_tokens,
-                Error::<T>::InsufficientLiquidityToLock
-            );
-
-            // Pay Locker fees
-            if option {
-                // Transfer 1% of LP tokens
-                Self::pay_fee_in_lp_tokens(
-                    pool_account.clone(),
-                    asset_a,
-                    asset_b,
-                    user.clone(),
-                    lock_info.pool_tokens,
-                    FixedWrapper::from(0.01),
-                    option,
-                )?;
-            } else {
-                // Transfer CERES fee amount
-                Assets::<T>::transfer_from(
-                    &T::CeresAssetId::get().into(),
-                    &user,
-                    &FeesOptionTwoAccount::<T>::get(),
-                    FeesOptionTwoCeresAmount::<T>::get(),
-                )?;
-                // Transfer 0.5% of LP tokens
-                Self::pay_fee_in_lp_tokens(
-                    pool_account.clone(),
-                    asset_a,
-                    asset_b,
-                    user.clone(),
-                    lock_info.pool_tokens,
-                    FixedWrapper::from(0.005),
-                    option,
-                )?;
-            }
-
-            pool_tokens = T::XYKPool::balance_of_pool_provider(pool_account.clone(), user.clone())
-                .unwrap_or(0);
-            T::DemeterFarmingPlatform::update_pool_tokens(
-                user.clone(),
-                pool_tokens,
-                asset_a,
-                asset_b,
-            )?;
-
-            // Put updated address info into storage
-            // Get lock info of extrinsic caller
-            <LockerData<T>>::append(&user, lock_info);
-
-            // Emit an event
-            Self::deposit_event(Event::Locked(
-                user,
+                unlocking_timestamp,
                 percentage_of_pool_tokens,
-                current_timestamp,
-            ));
-
-            // Return a successful DispatchResult
-            Ok(().into())
+                option,
+            )
         }
 
         /// Change CERES fee
@@ -314,6 +224,83 @@ pub mod pallet {
             FeesOptionTwoCeresAmount::<T>::put(ceres_fee);
             Ok(().into())
         }
+
+        /// Lock liquidity which is released linearly between release start and unlocking timestamp
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::lock_liquidity_linear())]
+        pub fn lock_liquidity_linear(
+            origin: OriginFor<T>,
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+            release_start_timestamp: T::Moment,
+            unlocking_timestamp: T::Moment,
+            percentage_of_pool_tokens: Balance,
+            option: bool,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+            Self::lock(
+                user,
+                asset_a,
+                asset_b,
+                release_start_timestamp,
+                unlocking_timestamp,
+                percentage_of_pool_tokens,
+                option,
+            )
+        }
+
+        /// Extend lock
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::extend_lock())]
+        pub fn extend_lock(
+            origin: OriginFor<T>,
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+            unlocking_timestamp: T::Moment,
+            new_unlocking_timestamp: T::Moment,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+            ensure!(
+                new_unlocking_timestamp > unlocking_timestamp,
+                Error::<T>::InvalidUnlockingTimestamp
+            );
+
+            // Get current timestamp
+            let current_timestamp = Timestamp::<T>::get();
+            let mut lockups = <LockerData<T>>::get(&user);
+            let lock = lockups
+                .iter_mut()
+                .find(|lock| {
+                    lock.asset_a == asset_a
+                        && lock.asset_b == asset_b
+                        && lock.unlocking_timestamp == unlocking_timestamp
+                        && current_timestamp < lock.unlocking_timestamp
+                })
+                .ok_or(Error::<T>::LockInfoDoesNotExist)?;
+
+            if lock.release_start_timestamp == lock.unlocking_timestamp {
+                // Cliff lock stays cliff lock
+                lock.release_start_timestamp = new_unlocking_timestamp;
+            } else if lock.release_start_timestamp < current_timestamp {
+                // Already released tokens stay released, the rest is released over the new period
+                lock.pool_tokens = Self::locked_pool_tokens(lock, current_timestamp);
+                lock.release_start_timestamp = current_timestamp;
+            }
+            lock.unlocking_timestamp = new_unlocking_timestamp;
+
+            <LockerData<T>>::insert(&user, lockups);
+
+            // Emit an event
+            Self::deposit_event(Event::LockExtended(
+                user,
+                asset_a,
+                asset_b,
+                new_unlocking_timestamp,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
@@ -350,7 +337,11 @@ pub mod pallet {
         fn on_runtime_upgrade() -> Weight {
             if Self::pallet_storage_version() == StorageVersion::V1 {
                 let weight = migrations::migrate::<T>();
-                PalletStorageVersion::<T>::put(StorageVersion::V2);
+                PalletStorageVersion::<T>::put(StorageVersion::V3);
+                weight
+            } else if Self::pallet_storage_version() == StorageVersion::V2 {
+                let weight = migrations::migrate_release_start::<T>();
+                PalletStorageVersion::<T>::put(StorageVersion::V3);
                 weight
             } else {
                 Weight::zero()
@@ -389,9 +380,8 @@ pub mod pallet {
             let mut locked_pool_tokens = 0;
             for locks in lockups.iter() {
                 if locks.asset_a == asset_a && locks.asset_b == asset_b {
-                    if current_timestamp < locks.unlocking_timestamp {
-                        locked_pool_tokens = locked_pool_tokens + locks.pool_tokens;
-                    }
+                    locked_pool_tokens =
+                        locked_pool_tokens + Self::locked_pool_tokens(locks, current_timestamp);
                 }
             }
             let unlocked_pool_tokens = pool_tokens.checked_sub(locked_pool_tokens).unwrap_or(0);
@@ -433,5 +423,217 @@ pub mod pallet {
             );
             return result;
         }
+
+        /// Lock liquidity of user
+        fn lock(
+            user: AccountIdOf<T>,
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+            release_start_timestamp: T::Moment,
+            unlocking_timestamp: T::Moment,
+            percentage_of_pool_tokens: Balance,
+            option: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                percentage_of_pool_tokens <= balance!(1),
+                Error::<T>::InvalidPercentage
+            );
+
+            // Get current timestamp
+            let current_timestamp = Timestamp::<T>::get();
+            ensure!(
+                unlocking_timestamp > current_timestamp,
+                Error::<T>::InvalidUnlockingTimestamp
+            );
+            ensure!(
+                release_start_timestamp > current_timestamp
+                    && release_start_timestamp <= unlocking_timestamp,
+                Error::<T>::InvalidReleaseStartTimestamp
+            );
+
+            let mut lock_info = LockInfo {
+                pool_tokens: 0,
+                asset_a,
+                asset_b,
+                unlocking_timestamp,
+                release_start_timestamp,
+            };
+
+            // Get pool account
+            let pool_account: AccountIdOf<T> = T::XYKPool::properties_of_pool(asset_a, asset_b)
+                .ok_or(Error::<T>::PoolDoesNotExist)?
+                .0;
+
+            // Calculate number of pool tokens to be locked
+            let mut pool_tokens =
+                T::XYKPool::balance_of_pool_provider(pool_account.clone(), user.clone())
+                    .unwrap_or(0);
+            if pool_tokens == 0 {
+                return Err(Error::<T>::InsufficientLiquidityToLock.into());
+            }
+
+            lock_info.pool_tokens = (FixedWrapper::from(pool_tokens)
+                * FixedWrapper::from(percentage_of_pool_tokens))
+            .try_into_balance()
+            .unwrap_or(lock_info.pool_tokens);
+
+            // Check if user has enough liquidity to lock
+            let lockups = <LockerData<T>>::get(&user);
+            let mut locked_pool_tokens = 0;
+
+            for locks in lockups.iter() {
+                if locks.asset_a == asset_a && locks.asset_b == asset_b {
+                    locked_pool_tokens =
+                        locked_pool_tokens + Self::locked_pool_tokens(locks, current_timestamp);
+                }
+            }
+
+            let unlocked_pool_tokens = pool_tokens.saturating_sub(locked_pool_tokens);
+            ensure!(
+                lock_info.pool_tokens <= unlocked_pool_tokens,
+                Error::<T>::InsufficientLiquidityToLock
+            );
+
+            // Pay Locker fees
+            if option {
+                // Transfer 1% of LP tokens
+                Self::pay_fee_in_lp_tokens(
+                    pool_account.clone(),
+                    asset_a,
+                    asset_b,
+                    user.clone(),
+                    lock_info.pool_tokens,
+                    FixedWrapper::from(0.01),
+                    option,
+                )?;
+            } else {
+                // Transfer CERES fee amount
+                Assets::<T>::transfer_from(
+                    &T::CeresAssetId::get().into(),
+                    &user,
+                    &FeesOptionTwoAccount::<T>::get(),
+                    FeesOptionTwoCeresAmount::<T>::get(),
+                )?;
+                // Transfer 0.5% of LP tokens
+                Self::pay_fee_in_lp_tokens(
+                    pool_account.clone(),
+                    asset_a,
+                    asset_b,
+                    user.clone(),
+                    lock_info.pool_tokens,
+                    FixedWrapper::from(0.005),
+                    option,
+                )?;
+            }
+
+            pool_tokens = T::XYKPool::balance_of_pool_provider(pool_account.clone(), user.clone())
+                .unwrap_or(0);
+            T::DemeterFarmingPlatform::update_pool_tokens(
+                user.clone(),
+                pool_tokens,
+                asset_a,
+                asset_b,
+            )?;
+
+            // Put updated address info into storage
+            // Get lock info of extrinsic caller
+            <LockerData<T>>::append(&user, lock_info);
+
+            // Emit an event
+            Self::deposit_event(Event::Locked(
+                user,
+                percentage_of_pool_tokens,
+                current_timestamp,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Calculate number of pool tokens which are still locked at given timestamp
+        pub fn locked_pool_tokens(
+            lock: &LockInfo<Balance, T::Moment, AssetIdOf<T>>,
+            timestamp: T::Moment,
+        ) -> Balance {
+            if timestamp >= lock.unlocking_timestamp {
+                return 0;
+            }
+            if timestamp <= lock.release_start_timestamp {
+                return lock.pool_tokens;
+            }
+
+            let release_period: u64 = lock
+                .unlocking_timestamp
+                .saturating_sub(lock.release_start_timestamp)
+                .unique_saturated_into();
+            let remaining_period: u64 = lock
+                .unlocking_timestamp
+                .saturating_sub(timestamp)
+                .unique_saturated_into();
+
+            (FixedWrapper::from(lock.pool_tokens) * FixedWrapper::from(balance!(remaining_period))
+                / FixedWrapper::from(balance!(release_period)))
+            .try_into_balance()
+            .unwrap_or(0)
+        }
+
+        /// Get number of locked pool tokens and total number of pool tokens of pool
+        pub fn locked_liquidity(
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+        ) -> (Balance, Balance) {
+            let current_timestamp = Timestamp::<T>::get();
+            let mut locked_pool_tokens: Balance = 0;
+            for (_, lockups) in <LockerData<T>>::iter() {
+                locked_pool_tokens = locked_pool_tokens.saturating_add(
+                    Self::locked_pool_tokens_in_pool(&lockups, asset_a, asset_b, current_timestamp),
+                );
+            }
+
+            let total_pool_tokens = Self::total_pool_tokens(asset_a, asset_b);
+
+            (locked_pool_tokens, total_pool_tokens)
+        }
+
+        /// Get number of locked pool tokens of user and total number of pool tokens of pool
+        pub fn locked_liquidity_of_account(
+            user: &AccountIdOf<T>,
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+        ) -> (Balance, Balance) {
+            let locked_pool_tokens = Self::locked_pool_tokens_in_pool(
+                &<LockerData<T>>::get(user),
+                asset_a,
+                asset_b,
+                Timestamp::<T>::get(),
+            );
+
+            let total_pool_tokens = Self::total_pool_tokens(asset_a, asset_b);
+
+            (locked_pool_tokens, total_pool_tokens)
+        }
+
+        /// Sum of pool tokens of lockups which are still locked in pool
+        fn locked_pool_tokens_in_pool(
+            lockups: &[LockInfo<Balance, T::Moment, AssetIdOf<T>>],
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+            current_timestamp: T::Moment,
+        ) -> Balance {
+            lockups
+                .iter()
+                .filter(|lock| lock.asset_a == asset_a && lock.asset_b == asset_b)
+                .fold(0, |locked_pool_tokens: Balance, lock| {
+                    locked_pool_tokens
+                        .saturating_add(Self::locked_pool_tokens(lock, current_timestamp))
+                })
+        }
+
+        /// Total issuance of pool tokens of pool
+        fn total_pool_tokens(asset_a: AssetIdOf<T>, asset_b: AssetIdOf<T>) -> Balance {
+            T::XYKPool::properties_of_pool(asset_a, asset_b)
+                .and_then(|(pool_account, _)| T::XYKPool::total_issuance(&pool_account).ok())
+                .unwrap_or(0)
+        }
     }
 }
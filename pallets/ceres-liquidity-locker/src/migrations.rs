@@ -1,14 +1,52 @@
 use crate::{AssetIdOf, Config, LockInfo, LockerData, Timestamp, Weight};
+use codec::{Decode, Encode};
 use common::{convert_block_number_to_timestamp, Balance};
 use frame_support::log;
 use frame_support::traits::Get;
 use sp_std::vec::Vec;
 
+#[derive(Encode, Decode)]
+pub struct LockInfoV2<Balance, Moment, AssetId> {
+    pool_tokens: Balance,
+    unlocking_timestamp: Moment,
+    asset_a: AssetId,
+    asset_b: AssetId,
+}
+
 pub fn migrate<T: Config>() -> Weight {
     sp_runtime::runtime_logger::RuntimeLogger::init();
     migrate_locker_data::<T>()
 }
 
+pub fn migrate_release_start<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    LockerData::<T>::translate_values::<Vec<LockInfoV2<Balance, T::Moment, AssetIdOf<T>>>, _>(
+        |v| {
+            weight += 1;
+            Some(
+                v.into_iter()
+                    .map(|lock| LockInfo {
+                        pool_tokens: lock.pool_tokens,
+                        unlocking_timestamp: lock.unlocking_timestamp,
+                        asset_a: lock.asset_a,
+                        asset_b: lock.asset_b,
+                        release_start_timestamp: lock.unlocking_timestamp,
+                    })
+                    .collect::<Vec<LockInfo<Balance, T::Moment, AssetIdOf<T>>>>(),
+            )
+        },
+    );
+
+    log::info!(
+        target: "runtime",
+        "LockInfo migrated to new version with release_start_timestamp field"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
+
 pub fn migrate_locker_data<T: Config>() -> Weight {
     let mut weight: u64 = 0;
 
@@ -33,6 +71,7 @@ pub fn migrate_locker_data<T: Config>() -> Weight {
                         unlocking_timestamp,
                         asset_a,
                         asset_b,
+                        release_start_timestamp: unlocking_timestamp,
                     }
                 })
                 .collect::<Vec<LockInfo<Balance, T::Moment, AssetIdOf<T>>>>(),
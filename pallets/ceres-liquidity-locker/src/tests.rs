@@ -7,7 +7,7 @@ use common::{
 };
 use frame_support::{assert_err, assert_ok, Identity};
 
-use crate::{AccountIdOf, AssetIdOf};
+use crate::{AccountIdOf, AssetIdOf, StorageVersion};
 use frame_support::pallet_prelude::StorageMap;
 use frame_support::storage::types::ValueQuery;
 use frame_support::traits::Hooks;
@@ -710,7 +710,7 @@ fn liquidity_locker_storage_migration_works() {
             assert_eq!(lockup.unlocking_timestamp, 9988000);
         }
 
-        // Storage version should be V2 so no changes made
+        // Storage version should be V3 so no changes made
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(11000000);
         run_to_block(10);
 
@@ -732,3 +732,531 @@ fn liquidity_locker_storage_migration_works() {
         }
     });
 }
+
+#[test]
+fn liquidity_locker_release_start_storage_migration_works() {
+    preset_initial(|_dex_id| {
+        generate_storage_instance!(CeresLiquidityLocker, LockerData);
+        type OldLockerData = StorageMap<
+            LockerDataOldInstance,
+            Identity,
+            AccountIdOf<Runtime>,
+            Vec<(Balance, u64, AssetIdOf<Runtime>, AssetIdOf<Runtime>)>,
+            ValueQuery,
+        >;
+
+        let base_asset: AssetId = XOR.into();
+        let target_asset: AssetId = CERES_ASSET_ID.into();
+
+        OldLockerData::insert(
+            ALICE(),
+            vec![
+                (balance!(5), 10690000u64, base_asset, target_asset),
+                (balance!(6), 3179666650000u64, base_asset, target_asset),
+            ],
+        );
+        OldLockerData::insert(
+            BOB(),
+            vec![(balance!(7), 9988000u64, base_asset, target_asset)],
+        );
+
+        ceres_liquidity_locker::PalletStorageVersion::<Runtime>::put(StorageVersion::V2);
+
+        // Storage migration
+        ceres_liquidity_locker::Pallet::<Runtime>::on_runtime_upgrade();
+
+        let lockups_alice = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE());
+        assert_eq!(lockups_alice.len(), 2);
+        assert_eq!(lockups_alice[0].pool_tokens, balance!(5));
+        assert_eq!(lockups_alice[0].unlocking_timestamp, 10690000);
+        assert_eq!(lockups_alice[0].release_start_timestamp, 10690000);
+        assert_eq!(lockups_alice[1].pool_tokens, balance!(6));
+        assert_eq!(lockups_alice[1].release_start_timestamp, 3179666650000);
+
+        let lockups_bob = ceres_liquidity_locker::LockerData::<Runtime>::get(BOB());
+        assert_eq!(lockups_bob.len(), 1);
+        assert_eq!(lockups_bob[0].asset_a, base_asset);
+        assert_eq!(lockups_bob[0].asset_b, target_asset);
+        assert_eq!(lockups_bob[0].release_start_timestamp, 9988000);
+
+        assert!(
+            ceres_liquidity_locker::PalletStorageVersion::<Runtime>::get() == StorageVersion::V3
+        );
+    });
+}
+
+#[test]
+fn lock_liquidity_linear_invalid_release_start_timestamp() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_err!(
+            ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity_linear(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp,
+                current_timestamp + 300,
+                balance!(0.5),
+                true
+            ),
+            ceres_liquidity_locker::Error::<Runtime>::InvalidReleaseStartTimestamp
+        );
+
+        assert_err!(
+            ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity_linear(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 400,
+                current_timestamp + 300,
+                balance!(0.5),
+                true
+            ),
+            ceres_liquidity_locker::Error::<Runtime>::InvalidReleaseStartTimestamp
+        );
+    });
+}
+
+#[test]
+fn lock_liquidity_linear_ok() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360),
+            balance!(144),
+            balance!(360),
+            balance!(144),
+        ));
+
+        // Lock 100% of LP tokens, released linearly from +100 to +300
+        assert_ok!(
+            ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity_linear(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 100,
+                current_timestamp + 300,
+                balance!(1),
+                true
+            )
+        );
+
+        let lockups_alice = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE());
+        assert_eq!(lockups_alice.len(), 1);
+        let lock = lockups_alice.get(0).unwrap();
+        assert_eq!(lock.release_start_timestamp, current_timestamp + 100);
+        assert_eq!(lock.unlocking_timestamp, current_timestamp + 300);
+
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_pool_tokens(
+                lock,
+                current_timestamp + 50
+            ),
+            lock.pool_tokens
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_pool_tokens(
+                lock,
+                current_timestamp + 200
+            ),
+            lock.pool_tokens / 2
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_pool_tokens(
+                lock,
+                current_timestamp + 300
+            ),
+            0
+        );
+
+        // Whole liquidity is locked before release start
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::check_if_has_enough_unlocked_liquidity(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                balance!(1)
+            ),
+            false
+        );
+
+        // Half of locked liquidity is released in the middle of release period
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 200);
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::check_if_has_enough_unlocked_liquidity(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                lock.pool_tokens / 4
+            ),
+            true
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::check_if_has_enough_unlocked_liquidity(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                lock.pool_tokens * 3 / 4
+            ),
+            false
+        );
+    });
+}
+
+#[test]
+fn extend_lock_lock_info_does_not_exist() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 100,
+            balance!(0.5),
+            true
+        ));
+
+        assert_err!(
+            ceres_liquidity_locker::Pallet::<Runtime>::extend_lock(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 50,
+                current_timestamp + 200,
+            ),
+            ceres_liquidity_locker::Error::<Runtime>::LockInfoDoesNotExist
+        );
+
+        // Expired lock cannot be extended
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 100);
+        assert_err!(
+            ceres_liquidity_locker::Pallet::<Runtime>::extend_lock(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 100,
+                current_timestamp + 200,
+            ),
+            ceres_liquidity_locker::Error::<Runtime>::LockInfoDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn extend_lock_invalid_unlocking_timestamp() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 100,
+            balance!(0.5),
+            true
+        ));
+
+        assert_err!(
+            ceres_liquidity_locker::Pallet::<Runtime>::extend_lock(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 100,
+                current_timestamp + 100,
+            ),
+            ceres_liquidity_locker::Error::<Runtime>::InvalidUnlockingTimestamp
+        );
+    });
+}
+
+#[test]
+fn extend_lock_ok() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 100,
+            balance!(0.5),
+            true
+        ));
+        let pool_tokens =
+            ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE())[0].pool_tokens;
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::extend_lock(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 100,
+            current_timestamp + 500,
+        ));
+
+        let lockups_alice = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE());
+        assert_eq!(lockups_alice.len(), 1);
+        assert_eq!(lockups_alice[0].pool_tokens, pool_tokens);
+        assert_eq!(
+            lockups_alice[0].unlocking_timestamp,
+            current_timestamp + 500
+        );
+        assert_eq!(
+            lockups_alice[0].release_start_timestamp,
+            current_timestamp + 500
+        );
+
+        // Liquidity stays locked after the old unlocking timestamp
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 200);
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::check_if_has_enough_unlocked_liquidity(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                pool_tokens * 2
+            ),
+            false
+        );
+    });
+}
+
+#[test]
+fn extend_lock_during_release_ok() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_ok!(
+            ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity_linear(
+                RuntimeOrigin::signed(ALICE()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 100,
+                current_timestamp + 300,
+                balance!(0.5),
+                true
+            )
+        );
+        let lock = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE())[0].clone();
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 200);
+        let locked_pool_tokens = ceres_liquidity_locker::Pallet::<Runtime>::locked_pool_tokens(
+            &lock,
+            current_timestamp + 200,
+        );
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::extend_lock(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 300,
+            current_timestamp + 600,
+        ));
+
+        // Released tokens stay released and the rest is released until the new unlocking timestamp
+        let lockups_alice = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE());
+        assert_eq!(lockups_alice[0].pool_tokens, locked_pool_tokens);
+        assert_eq!(
+            lockups_alice[0].release_start_timestamp,
+            current_timestamp + 200
+        );
+        assert_eq!(
+            lockups_alice[0].unlocking_timestamp,
+            current_timestamp + 600
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_pool_tokens(
+                &lockups_alice[0],
+                current_timestamp + 400
+            ),
+            locked_pool_tokens / 2
+        );
+    });
+}
+
+#[test]
+fn locked_liquidity_ok() {
+    preset_initial(|dex_id| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+        assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+            RuntimeOrigin::signed(BOB()),
+            dex_id,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            balance!(360000),
+            balance!(144000),
+            balance!(360000),
+            balance!(144000),
+        ));
+
+        assert_ok!(ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity(
+            RuntimeOrigin::signed(ALICE()),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            current_timestamp + 100,
+            balance!(0.5),
+            true
+        ));
+        assert_ok!(
+            ceres_liquidity_locker::Pallet::<Runtime>::lock_liquidity_linear(
+                RuntimeOrigin::signed(BOB()),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                current_timestamp + 100,
+                current_timestamp + 300,
+                balance!(0.3),
+                true
+            )
+        );
+
+        let alice_lock = ceres_liquidity_locker::LockerData::<Runtime>::get(ALICE())[0].clone();
+        let bob_lock = ceres_liquidity_locker::LockerData::<Runtime>::get(BOB())[0].clone();
+
+        let pool_account: AccountId =
+            <Runtime as ceres_liquidity_locker::Config>::XYKPool::properties(
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+            )
+            .expect("Pool does not exist")
+            .0;
+        let total_pool_tokens = pool_xyk::TotalIssuances::<Runtime>::get(&pool_account).unwrap();
+
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity(
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (
+                alice_lock.pool_tokens + bob_lock.pool_tokens,
+                total_pool_tokens
+            )
+        );
+
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity_of_account(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (alice_lock.pool_tokens, total_pool_tokens)
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity_of_account(
+                &BOB(),
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (bob_lock.pool_tokens, total_pool_tokens)
+        );
+
+        // Alice lock is expired and half of Bob lock is released
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 200);
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity(
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (bob_lock.pool_tokens / 2, total_pool_tokens)
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity_of_account(
+                &ALICE(),
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (0, total_pool_tokens)
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity_of_account(
+                &BOB(),
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ),
+            (bob_lock.pool_tokens / 2, total_pool_tokens)
+        );
+
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity_of_account(
+                &ALICE(),
+                XOR.into(),
+                DOT.into()
+            ),
+            (0, 0)
+        );
+        assert_eq!(
+            ceres_liquidity_locker::Pallet::<Runtime>::locked_liquidity(XOR.into(), DOT.into()),
+            (0, 0)
+        );
+    });
+}
@@ -61,6 +61,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn lock_liquidity() -> Weight;
 	fn change_ceres_fee() -> Weight;
+	fn lock_liquidity_linear() -> Weight;
+	fn extend_lock() -> Weight;
 }
 
 /// Weights for ceres_liquidity_locker using the Substrate node and recommended hardware.
@@ -108,6 +110,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Properties (r:1 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK PoolProviders (r:2 w:2)
+	/// Proof Skipped: PoolXYK PoolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker LockerData (r:1 w:1)
+	/// Proof Skipped: CeresLiquidityLocker LockerData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker FeesOptionTwoAccount (r:1 w:0)
+	/// Proof Skipped: CeresLiquidityLocker FeesOptionTwoAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker FeesOptionTwoCeresAmount (r:1 w:0)
+	/// Proof Skipped: CeresLiquidityLocker FeesOptionTwoCeresAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoolXYK AccountPools (r:1 w:1)
+	/// Proof Skipped: PoolXYK AccountPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	fn lock_liquidity_linear() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1611`
+		//  Estimated: `38048`
+		// Minimum execution time: 148_212_000 picoseconds.
+		Weight::from_parts(150_034_000, 38048)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLiquidityLocker LockerData (r:1 w:1)
+	/// Proof Skipped: CeresLiquidityLocker LockerData (max_values: None, max_size: None, mode: Measured)
+	fn extend_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3390`
+		// Minimum execution time: 19_841_000 picoseconds.
+		Weight::from_parts(20_527_000, 3390)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -154,4 +198,46 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Properties (r:1 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK PoolProviders (r:2 w:2)
+	/// Proof Skipped: PoolXYK PoolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker LockerData (r:1 w:1)
+	/// Proof Skipped: CeresLiquidityLocker LockerData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker FeesOptionTwoAccount (r:1 w:0)
+	/// Proof Skipped: CeresLiquidityLocker FeesOptionTwoAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CeresLiquidityLocker FeesOptionTwoCeresAmount (r:1 w:0)
+	/// Proof Skipped: CeresLiquidityLocker FeesOptionTwoCeresAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoolXYK AccountPools (r:1 w:1)
+	/// Proof Skipped: PoolXYK AccountPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	fn lock_liquidity_linear() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1611`
+		//  Estimated: `38048`
+		// Minimum execution time: 148_212_000 picoseconds.
+		Weight::from_parts(150_034_000, 38048)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: CeresLiquidityLocker LockerData (r:1 w:1)
+	/// Proof Skipped: CeresLiquidityLocker LockerData (max_values: None, max_size: None, mode: Measured)
+	fn extend_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3390`
+		// Minimum execution time: 19_841_000 picoseconds.
+		Weight::from_parts(20_527_000, 3390)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
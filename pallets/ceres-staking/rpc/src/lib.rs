@@ -0,0 +1,63 @@
+use codec::Codec;
+
+use jsonrpsee::{
+    core::{Error as RpcError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use ceres_staking_runtime_api::{BalanceInfo, CeresStakingApi as CeresStakingRuntimeApi};
+
+#[rpc(server, client)]
+pub trait CeresStakingApi<BlockHash, AccountId, BalanceInfo> {
+    #[method(name = "ceresStaking_pendingRewards")]
+    fn pending_rewards(&self, account_id: AccountId, at: Option<BlockHash>) -> Result<BalanceInfo>;
+}
+
+pub struct CeresStakingClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CeresStakingClient<C, B> {
+    /// Construct default `CeresStakingClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance>
+    CeresStakingApiServer<<Block as BlockT>::Hash, AccountId, BalanceInfo<Balance>>
+    for CeresStakingClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CeresStakingRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn pending_rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<BalanceInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.pending_rewards(&at, account_id)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::string_serialization;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct BalanceInfo<Balance> {
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub balance: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait CeresStakingApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+    {
+        /// Rewards of staker including the ones which are not settled yet
+        fn pending_rewards(account_id: AccountId) -> BalanceInfo<Balance>;
+    }
+}
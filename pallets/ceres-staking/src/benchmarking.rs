@@ -82,6 +82,20 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::RewardsChanged(rewards).into());
     }
 
+    set_reward_schedule {
+        let caller = AuthorityAccount::<T>::get();
+        let schedule: Vec<RewardPeriod<T::BlockNumber>> = (0..T::MaxRewardPeriods::get())
+            .map(|i| RewardPeriod {
+                start_block: T::BlockNumber::from(i),
+                rewards_per_day: balance!(1),
+            })
+            .collect();
+        let periods = schedule.len() as u32;
+    }: _(RawOrigin::Signed(caller.clone()), schedule)
+    verify {
+        assert_last_event::<T>(Event::<T>::RewardScheduleChanged(periods).into());
+    }
+
     impl_benchmark_test_suite!(
         CeresStaking,
         crate::mock::ExtBuilder::default().build(),
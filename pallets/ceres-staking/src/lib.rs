@@ -2,6 +2,7 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
+pub mod migrations;
 pub mod weights;
 
 mod benchmarking;
@@ -24,6 +25,27 @@ pub struct StakingInfo {
     deposited: Balance,
     /// Current rewards in CERES
     rewards: Balance,
+    /// Rewards already accounted for at the last settlement
+    reward_debt: Balance,
+}
+
+/// Part of the reward schedule which starts at `start_block`
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RewardPeriod<BlockNumber> {
+    /// Block from which the period is active
+    pub start_block: BlockNumber,
+    /// Number of CERES distributed per day during the period
+    pub rewards_per_day: Balance,
+}
+
+/// Storage version.
+#[derive(Encode, Decode, Eq, PartialEq, scale_info::TypeInfo)]
+pub enum StorageVersion {
+    /// Initial version
+    V1,
+    /// After adding reward index
+    V2,
 }
 
 pub use pallet::*;
@@ -39,6 +61,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use hex_literal::hex;
     use sp_runtime::traits::{AccountIdConversion, Zero};
+    use sp_std::vec::Vec;
 
     const PALLET_ID: PalletId = PalletId(*b"cerstake");
 
@@ -62,6 +85,9 @@ pub mod pallet {
         /// Maximum Ceres in staking pool
         type MaximumCeresInStakingPool: Get<Balance>;
 
+        /// Maximum number of periods in reward schedule
+        type MaxRewardPeriods: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -80,6 +106,8 @@ pub mod pallet {
         Withdrawn(AccountIdOf<T>, Balance, Balance),
         /// Rewards changed [balance]
         RewardsChanged(Balance),
+        /// Reward schedule changed [number of periods]
+        RewardScheduleChanged(u32),
     }
 
     #[pallet::error]
@@ -88,6 +116,10 @@ pub mod pallet {
         StakingPoolIsFull,
         /// Unauthorized
         Unauthorized,
+        /// Reward schedule periods must be ordered by start block
+        InvalidRewardSchedule,
+        /// Reward schedule has too many periods
+        TooManyRewardPeriods,
     }
 
     #[pallet::type_value]
@@ -122,6 +154,28 @@ pub mod pallet {
     pub(super) type RewardsRemaining<T: Config> =
         StorageValue<_, Balance, ValueQuery, RewardsRemainingDefault>;
 
+    /// Rewards accumulated per deposited CERES since the beginning of staking
+    #[pallet::storage]
+    #[pallet::getter(fn reward_index)]
+    pub(super) type RewardIndex<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+    /// Reward periods ordered by start block. `CeresPerDay` is used if no period is active
+    #[pallet::storage]
+    #[pallet::getter(fn reward_schedule)]
+    pub(super) type RewardSchedule<T: Config> =
+        StorageValue<_, Vec<RewardPeriod<T::BlockNumber>>, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultForPalletStorageVersion<T: Config>() -> StorageVersion {
+        StorageVersion::V1
+    }
+
+    /// Pallet storage version
+    #[pallet::storage]
+    #[pallet::getter(fn pallet_storage_version)]
+    pub type PalletStorageVersion<T: Config> =
+        StorageValue<_, StorageVersion, ValueQuery, DefaultForPalletStorageVersion<T>>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -151,9 +205,13 @@ pub mod pallet {
 
             // Get staking info of extrinsic caller
             let mut staking_info = <Stakers<T>>::get(&source);
+            let reward_index = RewardIndex::<T>::get();
 
             // Set staking info
+            Self::settle_rewards(&mut staking_info, reward_index);
             staking_info.deposited = staking_info.deposited + amount;
+            staking_info.reward_debt =
+                Self::accumulated_rewards(staking_info.deposited, reward_index);
 
             // Put updated staking info into storage
             <Stakers<T>>::insert(&source, staking_info);
@@ -173,7 +231,8 @@ pub mod pallet {
             let source = ensure_signed(origin)?;
 
             // Get staking info of extrinsic caller
-            let staking_info = <Stakers<T>>::get(&source);
+            let mut staking_info = <Stakers<T>>::get(&source);
+            Self::settle_rewards(&mut staking_info, RewardIndex::<T>::get());
             let withdrawing_amount = staking_info.deposited + staking_info.rewards;
 
             // Withdraw CERES
@@ -222,6 +281,39 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Change reward schedule
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_schedule())]
+        pub fn set_reward_schedule(
+            origin: OriginFor<T>,
+            schedule: Vec<RewardPeriod<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            if user != AuthorityAccount::<T>::get() {
+                return Err(Error::<T>::Unauthorized.into());
+            }
+
+            ensure!(
+                schedule.len() <= T::MaxRewardPeriods::get() as usize,
+                Error::<T>::TooManyRewardPeriods
+            );
+            ensure!(
+                schedule
+                    .windows(2)
+                    .all(|periods| periods[0].start_block < periods[1].start_block),
+                Error::<T>::InvalidRewardSchedule
+            );
+
+            let periods = schedule.len() as u32;
+            RewardSchedule::<T>::put(schedule);
+
+            // Emit an event
+            Self::deposit_event(Event::RewardScheduleChanged(periods));
+
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
@@ -231,27 +323,20 @@ pub mod pallet {
 
             if (now % T::BLOCKS_PER_ONE_DAY).is_zero() {
                 let rewards_remaining = RewardsRemaining::<T>::get();
-                let ceres_per_day = T::CeresPerDay::get();
-
-                if rewards_remaining >= ceres_per_day {
-                    let total_deposited = FixedWrapper::from(TotalDeposited::<T>::get());
-                    let ceres_per_day_fixed = FixedWrapper::from(ceres_per_day);
-
-                    for staker in <Stakers<T>>::iter() {
-                        let share_in_pool =
-                            FixedWrapper::from(staker.1.deposited) / total_deposited.clone();
-                        let reward = share_in_pool * ceres_per_day_fixed.clone();
-
-                        let mut staking_info = <Stakers<T>>::get(&staker.0);
-                        staking_info.rewards = (FixedWrapper::from(staking_info.rewards) + reward)
-                            .try_into_balance()
-                            .unwrap_or(staking_info.rewards);
-
-                        <Stakers<T>>::insert(&staker.0, staking_info);
-                        counter += 1;
-                    }
-
+                let ceres_per_day = Self::rewards_per_day(now);
+                let total_deposited = TotalDeposited::<T>::get();
+
+                if rewards_remaining >= ceres_per_day && total_deposited != 0 {
+                    let reward_per_ceres = (FixedWrapper::from(ceres_per_day)
+                        / FixedWrapper::from(total_deposited))
+                    .try_into_balance()
+                    .unwrap_or(0);
+
+                    RewardIndex::<T>::mutate(|index| {
+                        *index = index.saturating_add(reward_per_ceres)
+                    });
                     RewardsRemaining::<T>::put(rewards_remaining - ceres_per_day);
+                    counter += 2;
                 }
             }
 
@@ -259,6 +344,16 @@ pub mod pallet {
                 .reads(4)
                 .saturating_add(T::DbWeight::get().writes(counter))
         }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Self::pallet_storage_version() == StorageVersion::V1 {
+                let weight = migrations::migrate::<T>();
+                PalletStorageVersion::<T>::put(StorageVersion::V2);
+                weight
+            } else {
+                Weight::zero()
+            }
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -266,5 +361,38 @@ pub mod pallet {
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
         }
+
+        /// Number of CERES distributed per day at block `now`
+        pub fn rewards_per_day(now: T::BlockNumber) -> Balance {
+            RewardSchedule::<T>::get()
+                .into_iter()
+                .rev()
+                .find(|period| period.start_block <= now)
+                .map(|period| period.rewards_per_day)
+                .unwrap_or_else(T::CeresPerDay::get)
+        }
+
+        /// Rewards earned by `deposited` CERES at given reward index
+        fn accumulated_rewards(deposited: Balance, reward_index: Balance) -> Balance {
+            (FixedWrapper::from(deposited) * FixedWrapper::from(reward_index))
+                .try_into_balance()
+                .unwrap_or(0)
+        }
+
+        /// Move rewards accumulated since the last settlement into staker rewards
+        fn settle_rewards(staking_info: &mut StakingInfo, reward_index: Balance) {
+            let accumulated = Self::accumulated_rewards(staking_info.deposited, reward_index);
+            staking_info.rewards = staking_info
+                .rewards
+                .saturating_add(accumulated.saturating_sub(staking_info.reward_debt));
+            staking_info.reward_debt = accumulated;
+        }
+
+        /// Rewards of staker including the ones which are not settled yet
+        pub fn pending_rewards(who: &AccountIdOf<T>) -> Balance {
+            let mut staking_info = <Stakers<T>>::get(who);
+            Self::settle_rewards(&mut staking_info, RewardIndex::<T>::get());
+            staking_info.rewards
+        }
     }
 }
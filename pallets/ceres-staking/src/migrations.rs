@@ -0,0 +1,32 @@
+use crate::{Config, Stakers, StakingInfo, Weight};
+use common::Balance;
+use frame_support::log;
+use frame_support::traits::Get;
+
+pub fn migrate<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    migrate_stakers::<T>()
+}
+
+/// Rewards credited before the migration stay in `rewards`, so reward debt starts from zero
+/// together with the reward index.
+pub fn migrate_stakers<T: Config>() -> Weight {
+    let mut weight: u64 = 0;
+
+    Stakers::<T>::translate_values::<(Balance, Balance), _>(|(deposited, rewards)| {
+        weight += 1;
+
+        Some(StakingInfo {
+            deposited,
+            rewards,
+            reward_debt: 0,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "StakingInfo migrated to new version with reward_debt field"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
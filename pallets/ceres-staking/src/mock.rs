@@ -89,6 +89,7 @@ parameter_types! {
     pub const CeresPerDay: Balance = balance!(6.66666666667);
     pub const CeresAssetId: AssetId = CERES_ASSET_ID;
     pub const MaximumCeresInStakingPool: Balance = balance!(7200);
+    pub const MaxRewardPeriods: u32 = 16;
 }
 
 impl crate::Config for Runtime {
@@ -97,6 +98,7 @@ impl crate::Config for Runtime {
     type CeresPerDay = CeresPerDay;
     type CeresAssetId = CeresAssetId;
     type MaximumCeresInStakingPool = MaximumCeresInStakingPool;
+    type MaxRewardPeriods = MaxRewardPeriods;
     type WeightInfo = ();
 }
 
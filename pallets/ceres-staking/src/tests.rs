@@ -1,9 +1,11 @@
 mod tests {
     use crate::mock::*;
-    use crate::{pallet, Error};
+    use crate::{pallet, Error, RewardPeriod, StakingInfo, StorageVersion};
     use common::prelude::FixedWrapper;
-    use common::{balance, AssetInfoProvider, CERES_ASSET_ID};
-    use frame_support::{assert_err, assert_ok, PalletId};
+    use common::{balance, generate_storage_instance, AssetInfoProvider, Balance, CERES_ASSET_ID};
+    use frame_support::pallet_prelude::{StorageMap, ValueQuery};
+    use frame_support::traits::{Get, Hooks};
+    use frame_support::{assert_err, assert_ok, Identity, PalletId};
     use sp_runtime::traits::AccountIdConversion;
 
     #[test]
@@ -164,16 +166,16 @@ mod tests {
                 true
             );
             // Check Alice's staking rewards
-            let staking_info_alice = pallet::Stakers::<Runtime>::get(&ALICE);
             assert_eq!(
-                (FixedWrapper::from(staking_info_alice.rewards) - FixedWrapper::from(6.0606060606))
+                (FixedWrapper::from(CeresStaking::pending_rewards(&ALICE))
+                    - FixedWrapper::from(6.0606060606))
                     < diff,
                 true
             );
             // Check Bob's staking rewards
-            let staking_info_bob = pallet::Stakers::<Runtime>::get(&BOB);
             assert_eq!(
-                (FixedWrapper::from(staking_info_bob.rewards) - FixedWrapper::from(0.606060606))
+                (FixedWrapper::from(CeresStaking::pending_rewards(&BOB))
+                    - FixedWrapper::from(0.606060606))
                     < diff,
                 true
             );
@@ -212,4 +214,186 @@ mod tests {
             assert_eq!(pallet::RewardsRemaining::<Runtime>::get(), balance!(100));
         });
     }
+
+    #[test]
+    fn should_settle_rewards_lazily() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_ok!(CeresStaking::set_reward_schedule(
+                RuntimeOrigin::signed(pallet::AuthorityAccount::<Runtime>::get()),
+                vec![RewardPeriod {
+                    start_block: 0,
+                    rewards_per_day: balance!(10),
+                }]
+            ));
+
+            assert_ok!(CeresStaking::deposit(
+                RuntimeOrigin::signed(ALICE),
+                balance!(400)
+            ));
+            run_to_block(BLOCKS_PER_DAY);
+
+            // Stakers are not touched by distribution
+            assert_eq!(pallet::RewardIndex::<Runtime>::get(), balance!(0.025));
+            assert_eq!(pallet::Stakers::<Runtime>::get(&ALICE).rewards, balance!(0));
+            assert_eq!(CeresStaking::pending_rewards(&ALICE), balance!(10));
+
+            // Bob does not receive rewards distributed before his deposit
+            assert_ok!(CeresStaking::deposit(
+                RuntimeOrigin::signed(BOB),
+                balance!(100)
+            ));
+            assert_eq!(CeresStaking::pending_rewards(&BOB), balance!(0));
+
+            run_to_block(2 * BLOCKS_PER_DAY);
+
+            assert_eq!(pallet::RewardsRemaining::<Runtime>::get(), balance!(580));
+            assert_eq!(CeresStaking::pending_rewards(&ALICE), balance!(18));
+            assert_eq!(CeresStaking::pending_rewards(&BOB), balance!(2));
+
+            // Rewards are settled on deposit
+            assert_ok!(CeresStaking::deposit(
+                RuntimeOrigin::signed(ALICE),
+                balance!(100)
+            ));
+            assert_eq!(
+                pallet::Stakers::<Runtime>::get(&ALICE).rewards,
+                balance!(18)
+            );
+            assert_eq!(CeresStaking::pending_rewards(&ALICE), balance!(18));
+
+            assert_ok!(CeresStaking::withdraw(RuntimeOrigin::signed(BOB)));
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &BOB).expect("Failed to query free balance."),
+                balance!(102)
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_schedule_unauthorized() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_err!(
+                CeresStaking::set_reward_schedule(RuntimeOrigin::signed(ALICE), vec![]),
+                Error::<Runtime>::Unauthorized
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_schedule_invalid_reward_schedule() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_err!(
+                CeresStaking::set_reward_schedule(
+                    RuntimeOrigin::signed(pallet::AuthorityAccount::<Runtime>::get()),
+                    vec![
+                        RewardPeriod {
+                            start_block: 20,
+                            rewards_per_day: balance!(10),
+                        },
+                        RewardPeriod {
+                            start_block: 20,
+                            rewards_per_day: balance!(20),
+                        }
+                    ]
+                ),
+                Error::<Runtime>::InvalidRewardSchedule
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_schedule_too_many_reward_periods() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let schedule = (0..=MaxRewardPeriods::get() as u64)
+                .map(|start_block| RewardPeriod {
+                    start_block,
+                    rewards_per_day: balance!(1),
+                })
+                .collect();
+            assert_err!(
+                CeresStaking::set_reward_schedule(
+                    RuntimeOrigin::signed(pallet::AuthorityAccount::<Runtime>::get()),
+                    schedule
+                ),
+                Error::<Runtime>::TooManyRewardPeriods
+            );
+        });
+    }
+
+    #[test]
+    fn set_reward_schedule_ok() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            assert_ok!(CeresStaking::set_reward_schedule(
+                RuntimeOrigin::signed(pallet::AuthorityAccount::<Runtime>::get()),
+                vec![
+                    RewardPeriod {
+                        start_block: 0,
+                        rewards_per_day: balance!(10),
+                    },
+                    RewardPeriod {
+                        start_block: 20_000,
+                        rewards_per_day: balance!(20),
+                    }
+                ]
+            ));
+            assert_eq!(CeresStaking::rewards_per_day(19_999), balance!(10));
+            assert_eq!(CeresStaking::rewards_per_day(20_000), balance!(20));
+
+            assert_ok!(CeresStaking::deposit(
+                RuntimeOrigin::signed(ALICE),
+                balance!(500)
+            ));
+            run_to_block(2 * BLOCKS_PER_DAY);
+
+            assert_eq!(CeresStaking::pending_rewards(&ALICE), balance!(30));
+            assert_eq!(pallet::RewardsRemaining::<Runtime>::get(), balance!(570));
+
+            // Empty schedule falls back to CeresPerDay
+            assert_ok!(CeresStaking::set_reward_schedule(
+                RuntimeOrigin::signed(pallet::AuthorityAccount::<Runtime>::get()),
+                vec![]
+            ));
+            assert_eq!(CeresStaking::rewards_per_day(20_000), CeresPerDay::get());
+        });
+    }
+
+    #[test]
+    fn ceres_staking_storage_migration_works() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            generate_storage_instance!(CeresStaking, Stakers);
+            type OldStakers =
+                StorageMap<StakersOldInstance, Identity, AccountId, (Balance, Balance), ValueQuery>;
+
+            OldStakers::insert(ALICE, (balance!(100), balance!(5)));
+            OldStakers::insert(BOB, (balance!(50), balance!(1)));
+
+            // Storage migration
+            CeresStaking::on_runtime_upgrade();
+
+            assert_eq!(
+                pallet::Stakers::<Runtime>::get(&ALICE),
+                StakingInfo {
+                    deposited: balance!(100),
+                    rewards: balance!(5),
+                    reward_debt: 0,
+                }
+            );
+            assert_eq!(
+                pallet::Stakers::<Runtime>::get(&BOB),
+                StakingInfo {
+                    deposited: balance!(50),
+                    rewards: balance!(1),
+                    reward_debt: 0,
+                }
+            );
+            assert!(pallet::PalletStorageVersion::<Runtime>::get() == StorageVersion::V2);
+            assert_eq!(CeresStaking::pending_rewards(&ALICE), balance!(5));
+        });
+    }
 }
@@ -62,6 +62,7 @@ pub trait WeightInfo {
 	fn deposit() -> Weight;
 	fn withdraw() -> Weight;
 	fn change_rewards_remaining() -> Weight;
+	fn set_reward_schedule() -> Weight;
 }
 
 /// Weights for ceres_staking using the Substrate node and recommended hardware.
@@ -114,6 +115,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresStaking AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: CeresStaking AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CeresStaking RewardSchedule (r:0 w:1)
+	/// Proof Skipped: CeresStaking RewardSchedule (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_reward_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `647`
+		// Minimum execution time: 20_311_000 picoseconds.
+		Weight::from_parts(20_762_000, 647)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -165,4 +179,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: CeresStaking AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: CeresStaking AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CeresStaking RewardSchedule (r:0 w:1)
+	/// Proof Skipped: CeresStaking RewardSchedule (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_reward_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `647`
+		// Minimum execution time: 20_311_000 picoseconds.
+		Weight::from_parts(20_762_000, 647)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
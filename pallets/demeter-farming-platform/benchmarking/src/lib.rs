@@ -9,7 +9,7 @@ use common::{
     balance, AssetName, AssetSymbol, Balance, CERES_ASSET_ID, DEFAULT_BALANCE_PRECISION, XOR,
     XSTUSD,
 };
-use demeter_farming_platform::{AccountIdOf, AuthorityAccount, UserInfos};
+use demeter_farming_platform::{AccountIdOf, AuthorityAccount, LockPeriod, UserInfos};
 use frame_benchmarking::benchmarks;
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
@@ -99,6 +99,67 @@ fn setup_benchmark_assets_only<T: Config>() -> Result<(), &'static str> {
     Ok(())
 }
 
+fn setup_staking_deposit<T: Config>(
+    caller: &T::AccountId,
+    pooled_tokens: Balance,
+) -> Result<(), &'static str> {
+    let authority = AuthorityAccount::<T>::get();
+    let reward_asset = CERES_ASSET_ID;
+    let pallet_account: AccountIdOf<T> = PalletId(*b"deofarms").into_account_truncating();
+    frame_system::Pallet::<T>::inc_providers(caller);
+
+    setup_benchmark_assets_only::<T>()?;
+
+    let _ = Assets::<T>::mint(
+        RawOrigin::Signed(caller.clone()).into(),
+        reward_asset.into(),
+        caller.clone(),
+        balance!(20000),
+    );
+
+    let _ = Assets::<T>::mint(
+        RawOrigin::Signed(caller.clone()).into(),
+        reward_asset.into(),
+        pallet_account.clone(),
+        balance!(20000),
+    );
+
+    // Register token
+    let _ = DemeterFarmingPlatform::<T>::register_token(
+        RawOrigin::Signed(authority.clone()).into(),
+        reward_asset.into(),
+        balance!(1),
+        balance!(0.6),
+        balance!(0.2),
+        balance!(0.2),
+        caller.clone(),
+    );
+
+    // Add pool
+    let _ = DemeterFarmingPlatform::<T>::add_pool(
+        RawOrigin::Signed(authority.clone()).into(),
+        reward_asset.into(),
+        reward_asset.into(),
+        reward_asset.into(),
+        false,
+        2,
+        balance!(0),
+        true,
+    );
+
+    // Deposit
+    let _ = DemeterFarmingPlatform::<T>::deposit(
+        RawOrigin::Signed(caller.clone()).into(),
+        reward_asset.into(),
+        reward_asset.into(),
+        reward_asset.into(),
+        false,
+        pooled_tokens,
+    );
+
+    Ok(())
+}
+
 fn run_to_block<T: Config>(n: u32) {
     while frame_system::Pallet::<T>::block_number() < n.into() {
         frame_system::Pallet::<T>::on_finalize(frame_system::Pallet::<T>::block_number().into());
@@ -297,7 +358,7 @@ benchmarks! {
         let user_infos = UserInfos::<T>::get(&caller);
         let mut rewards = balance!(0);
         for user_info in user_infos {
-            rewards = user_info.rewards;
+            rewards = DemeterFarmingPlatform::<T>::user_rewards(&user_info);
         }
 
     }: {
@@ -661,6 +722,89 @@ benchmarks! {
         assert_last_event::<T>(demeter_farming_platform::Event::<T>::TokenInfoChanged(caller, CERES_ASSET_ID.into()).into());
     }
 
+    lock_deposit {
+        let caller = alice::<T>();
+        let reward_asset = CERES_ASSET_ID;
+        let pooled_tokens = balance!(30);
+
+        setup_staking_deposit::<T>(&caller, pooled_tokens)?;
+        let lock_end = frame_system::Pallet::<T>::block_number() + T::BLOCKS_PER_ONE_DAY * 365u32.into();
+    }: {
+        let _ = DemeterFarmingPlatform::<T>::lock_deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            false,
+            pooled_tokens,
+            LockPeriod::OneYear,
+        );
+    }
+    verify {
+        assert_last_event::<T>(demeter_farming_platform::Event::<T>::DepositLocked(caller, reward_asset.into(), reward_asset.into(), reward_asset.into(), false, pooled_tokens, lock_end).into());
+    }
+
+    withdraw_locked {
+        let caller = alice::<T>();
+        let reward_asset = CERES_ASSET_ID;
+        let pooled_tokens = balance!(30);
+
+        setup_staking_deposit::<T>(&caller, pooled_tokens)?;
+        let _ = DemeterFarmingPlatform::<T>::lock_deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            false,
+            pooled_tokens,
+            LockPeriod::OneYear,
+        );
+    }: {
+        let _ = DemeterFarmingPlatform::<T>::withdraw_locked(
+            RawOrigin::Signed(caller.clone()).into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            pooled_tokens,
+            false,
+        );
+    }
+    verify {
+        assert_last_event::<T>(demeter_farming_platform::Event::<T>::LockedWithdrawn(caller, pooled_tokens, balance!(3), reward_asset.into(), reward_asset.into(), reward_asset.into(), false).into());
+    }
+
+    unlock_deposit {
+        let caller = alice::<T>();
+        let reward_asset = CERES_ASSET_ID;
+        let pooled_tokens = balance!(30);
+
+        setup_staking_deposit::<T>(&caller, pooled_tokens)?;
+        let _ = DemeterFarmingPlatform::<T>::lock_deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            false,
+            pooled_tokens,
+            LockPeriod::OneMonth,
+        );
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::BLOCKS_PER_ONE_DAY * 30u32.into(),
+        );
+    }: {
+        let _ = DemeterFarmingPlatform::<T>::unlock_deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            reward_asset.into(),
+            reward_asset.into(),
+            reward_asset.into(),
+            false,
+        );
+    }
+    verify {
+        assert_last_event::<T>(demeter_farming_platform::Event::<T>::DepositUnlocked(caller, reward_asset.into(), reward_asset.into(), reward_asset.into(), false).into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::default().build(),
@@ -13,6 +13,7 @@ mod tests;
 
 use codec::{Decode, Encode};
 use common::{Balance, DemeterFarmingPallet};
+use sp_runtime::Percent;
 pub use weights::WeightInfo;
 
 /// Storage version.
@@ -22,6 +23,45 @@ pub enum StorageVersion {
     V1,
     /// After adding base_asset field
     V2,
+    /// After adding reward per share accumulator
+    V3,
+    /// After adding lock boosts
+    V4,
+}
+
+/// Duration for which deposited tokens can be locked in exchange for a reward boost
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum LockPeriod {
+    /// Locked for 30 days, 10% boost
+    OneMonth,
+    /// Locked for 90 days, 30% boost
+    ThreeMonths,
+    /// Locked for 180 days, 60% boost
+    SixMonths,
+    /// Locked for 365 days, 100% boost
+    OneYear,
+}
+
+impl LockPeriod {
+    /// Number of days tokens are locked for
+    pub fn days(&self) -> u32 {
+        match self {
+            LockPeriod::OneMonth => 30,
+            LockPeriod::ThreeMonths => 90,
+            LockPeriod::SixMonths => 180,
+            LockPeriod::OneYear => 365,
+        }
+    }
+
+    /// Additional reward shares granted for locked tokens
+    pub fn boost(&self) -> Percent {
+        match self {
+            LockPeriod::OneMonth => Percent::from_percent(10),
+            LockPeriod::ThreeMonths => Percent::from_percent(30),
+            LockPeriod::SixMonths => Percent::from_percent(60),
+            LockPeriod::OneYear => Percent::from_percent(100),
+        }
+    }
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -36,6 +76,10 @@ pub struct PoolData<AssetId> {
     pub rewards_to_be_distributed: Balance,
     pub is_removed: bool,
     pub base_asset: AssetId,
+    /// Rewards accumulated per pooled token, scaled as fixed point
+    pub acc_reward_per_share: Balance,
+    /// Reward shares granted by locks on top of pooled tokens
+    pub total_boosted_tokens: Balance,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -52,21 +96,32 @@ pub struct TokenInfo<AccountId> {
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub struct UserInfo<AssetId> {
+pub struct UserInfo<AssetId, BlockNumber> {
     pub base_asset: AssetId,
     pub pool_asset: AssetId,
     pub reward_asset: AssetId,
     pub is_farm: bool,
     pub pooled_tokens: Balance,
     pub rewards: Balance,
+    /// Rewards already accounted for at the last settlement
+    pub reward_debt: Balance,
+    /// Part of pooled tokens which can't be withdrawn without a penalty before `lock_end`
+    pub locked_tokens: Balance,
+    /// Reward shares granted for locked tokens on top of pooled tokens
+    pub boosted_tokens: Balance,
+    /// Block at which the lock expires
+    pub lock_end: BlockNumber,
 }
 
 use frame_support::dispatch::DispatchError;
+use frame_support::RuntimeDebug;
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::{migrations, PoolData, StorageVersion, TokenInfo, UserInfo, WeightInfo};
+    use crate::{
+        migrations, LockPeriod, PoolData, StorageVersion, TokenInfo, UserInfo, WeightInfo,
+    };
     use common::prelude::{AssetInfoProvider, Balance, FixedWrapper};
     use common::{balance, PoolXykPallet};
     use frame_support::pallet_prelude::*;
@@ -80,6 +135,9 @@ pub mod pallet {
 
     const PALLET_ID: PalletId = PalletId(*b"deofarms");
 
+    /// Maximum number of lock expirations processed before a single reward distribution
+    pub const MAX_LOCK_EXPIRATIONS: u32 = 100;
+
     // TODO: #395 use AssetInfoProvider instead of assets pallet
     #[pallet::config]
     pub trait Config:
@@ -94,6 +152,9 @@ pub mod pallet {
         /// One hour represented in block number
         const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self>;
 
+        /// Origin which can change pool parameters in addition to the authority account
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -101,6 +162,15 @@ pub mod pallet {
     type Assets<T> = assets::Pallet<T>;
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     pub type AssetIdOf<T> = <T as assets::Config>::AssetId;
+    pub type UserInfoOf<T> = UserInfo<AssetIdOf<T>, BlockNumberFor<T>>;
+    /// User, base asset, pool asset, reward asset and is_farm of a lock
+    pub type LockKeyOf<T> = (
+        AccountIdOf<T>,
+        AssetIdOf<T>,
+        AssetIdOf<T>,
+        AssetIdOf<T>,
+        bool,
+    );
 
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
@@ -115,7 +185,7 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn user_info)]
     pub type UserInfos<T: Config> =
-        StorageMap<_, Identity, AccountIdOf<T>, Vec<UserInfo<AssetIdOf<T>>>, ValueQuery>;
+        StorageMap<_, Identity, AccountIdOf<T>, Vec<UserInfoOf<T>>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn pools)]
@@ -129,6 +199,25 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Locks which expire before the reward distribution at the block. Every lock of a user in
+    /// a pool has a single entry, which is moved when the lock is extended.
+    #[pallet::storage]
+    #[pallet::getter(fn lock_expirations)]
+    pub type LockExpirations<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        LockKeyOf<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Reward distribution block starting from which lock expirations are not processed yet
+    #[pallet::storage]
+    #[pallet::getter(fn next_lock_expiration_block)]
+    pub type NextLockExpirationBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::type_value]
     pub fn DefaultForPalletStorageVersion<T: Config>() -> StorageVersion {
         StorageVersion::V1
@@ -163,6 +252,17 @@ pub mod pallet {
     pub type FeeAccount<T: Config> =
         StorageValue<_, AccountIdOf<T>, ValueQuery, DefaultFeeAccount<T>>;
 
+    #[pallet::type_value]
+    pub fn DefaultEarlyWithdrawPenalty<T: Config>() -> Balance {
+        balance!(0.1)
+    }
+
+    /// Part of locked tokens withdrawn before the lock expires which is sent to fee account
+    #[pallet::storage]
+    #[pallet::getter(fn early_withdraw_penalty)]
+    pub type EarlyWithdrawPenalty<T: Config> =
+        StorageValue<_, Balance, ValueQuery, DefaultEarlyWithdrawPenalty<T>>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -249,6 +349,34 @@ pub mod pallet {
             bool,
             Balance,
         ),
+        /// Deposit locked [who, base_asset, pool_asset, reward_asset, is_farm, amount, lock_end]
+        DepositLocked(
+            AccountIdOf<T>,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            bool,
+            Balance,
+            BlockNumberFor<T>,
+        ),
+        /// Locked deposit withdrawn before the lock expired [who, amount, penalty, base_asset, pool_asset, reward_asset, is_farm]
+        LockedWithdrawn(
+            AccountIdOf<T>,
+            Balance,
+            Balance,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            bool,
+        ),
+        /// Expired lock released [who, base_asset, pool_asset, reward_asset, is_farm]
+        DepositUnlocked(
+            AccountIdOf<T>,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            bool,
+        ),
     }
 
     #[pallet::error]
@@ -279,6 +407,16 @@ pub mod pallet {
         PoolDoesNotHaveRewards,
         /// Unauthorized
         Unauthorized,
+        /// Pooled tokens are locked
+        DepositIsLocked,
+        /// Lock can't end before the current lock
+        InvalidLockPeriod,
+        /// Amount must be greater than zero
+        InvalidAmount,
+        /// Deposit is not locked
+        DepositIsNotLocked,
+        /// Lock is not expired
+        LockIsNotExpired,
     }
 
     #[pallet::call]
@@ -365,8 +503,8 @@ pub mod pallet {
                 .ok_or(Error::<T>::RewardTokenIsNotRegistered)?;
 
             // Check if pool already exists
-            let pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
-            for pool_info in pool_infos {
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            for pool_info in pool_infos.iter() {
                 if !pool_info.is_removed
                     && pool_info.is_farm == is_farm
                     && pool_info.base_asset == base_asset
@@ -375,26 +513,45 @@ pub mod pallet {
                 }
             }
 
-            let pool_info = PoolData {
-                multiplier,
-                deposit_fee,
-                is_core,
-                is_farm,
-                total_tokens_in_pool: 0,
-                rewards: 0,
-                rewards_to_be_distributed: 0,
-                is_removed: false,
-                base_asset,
-            };
-
             if is_farm {
                 token_info.farms_total_multiplier += multiplier;
             } else {
                 token_info.staking_total_multiplier += multiplier;
             }
 
+            // Removed pool is restored with its accumulated reward per share and totals, so
+            // reward debts of its users stay valid. The accumulator doesn't grow while the pool
+            // is removed, so there is nothing to settle.
+            let removed_pool = pool_infos.iter_mut().find(|pool_info| {
+                pool_info.is_farm == is_farm && pool_info.base_asset == base_asset
+            });
+            match removed_pool {
+                Some(pool_info) => {
+                    pool_info.multiplier = multiplier;
+                    pool_info.deposit_fee = deposit_fee;
+                    pool_info.is_core = is_core;
+                    pool_info.is_removed = false;
+                    <Pools<T>>::insert(&pool_asset, &reward_asset, pool_infos);
+                }
+                None => {
+                    let pool_info = PoolData {
+                        multiplier,
+                        deposit_fee,
+                        is_core,
+                        is_farm,
+                        total_tokens_in_pool: 0,
+                        rewards: 0,
+                        rewards_to_be_distributed: 0,
+                        is_removed: false,
+                        base_asset,
+                        acc_reward_per_share: 0,
+                        total_boosted_tokens: 0,
+                    };
+                    <Pools<T>>::append(&pool_asset, &reward_asset, pool_info);
+                }
+            }
+
             <TokenInfos<T>>::insert(&reward_asset, token_info);
-            <Pools<T>>::append(&pool_asset, &reward_asset, pool_info);
 
             // Emit an event
             Self::deposit_event(Event::PoolAdded(
@@ -447,6 +604,10 @@ pub mod pallet {
                 is_farm,
                 pooled_tokens: 0,
                 rewards: 0,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: Zero::zero(),
             };
             exist = false;
             let mut user_infos = <UserInfos<T>>::get(&user);
@@ -458,6 +619,7 @@ pub mod pallet {
                 {
                     user_info.pooled_tokens = u_info.pooled_tokens;
                     user_info.rewards = u_info.rewards;
+                    user_info.reward_debt = u_info.reward_debt;
                     exist = true;
                 }
             }
@@ -525,14 +687,21 @@ pub mod pallet {
                     {
                         if u_info.pooled_tokens > lp_tokens {
                             let pool_tokens_diff = u_info.pooled_tokens - lp_tokens;
-                            u_info.pooled_tokens = lp_tokens;
                             let mut pool_data = <Pools<T>>::get(&pool_asset, &u_info.reward_asset);
+                            let acc_reward_per_share =
+                                Self::acc_reward_per_share(&pool_data, &base_asset, is_farm);
+                            Self::settle_rewards(u_info, acc_reward_per_share);
+                            u_info.pooled_tokens = lp_tokens;
+                            let removed_boost = Self::clamp_lock(u_info);
+                            Self::reset_reward_debt(u_info, acc_reward_per_share);
                             for p_info in pool_data.iter_mut() {
                                 if !p_info.is_removed
                                     && p_info.is_farm == is_farm
                                     && p_info.base_asset == base_asset
                                 {
                                     p_info.total_tokens_in_pool -= pool_tokens_diff;
+                                    p_info.total_boosted_tokens =
+                                        p_info.total_boosted_tokens.saturating_sub(removed_boost);
                                 }
                             }
                             <Pools<T>>::insert(&pool_asset, &u_info.reward_asset, pool_data);
@@ -542,6 +711,7 @@ pub mod pallet {
             }
 
             // Update user info
+            let acc_reward_per_share = pool_info.acc_reward_per_share;
             if exist {
                 for u_info in user_infos.iter_mut() {
                     if u_info.pool_asset == pool_asset
@@ -549,11 +719,14 @@ pub mod pallet {
                         && u_info.is_farm == is_farm
                         && u_info.base_asset == base_asset
                     {
+                        Self::settle_rewards(u_info, acc_reward_per_share);
                         u_info.pooled_tokens += pooled_tokens;
+                        Self::reset_reward_debt(u_info, acc_reward_per_share);
                     }
                 }
             } else {
                 user_info.pooled_tokens += pooled_tokens;
+                Self::reset_reward_debt(&mut user_info, acc_reward_per_share);
                 user_infos.push(user_info);
             }
             <UserInfos<T>>::insert(&user, user_infos);
@@ -610,6 +783,8 @@ pub mod pallet {
                 }
             }
             ensure!(exist, Error::<T>::PoolDoesNotExist);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
 
             // Get user info
             let mut user_infos = <UserInfos<T>>::get(&user);
@@ -621,6 +796,7 @@ pub mod pallet {
                     && user_info.is_farm == is_farm
                     && user_info.base_asset == base_asset
                 {
+                    Self::settle_rewards(user_info, acc_reward_per_share);
                     ensure!(user_info.rewards != 0, Error::<T>::ZeroRewards);
                     ensure!(
                         pool_info_rewards >= user_info.rewards,
@@ -679,6 +855,13 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
 
+            // Get pool info
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut removed_boost = balance!(0);
+
             // Get user info
             let mut user_infos = <UserInfos<T>>::get(&user);
 
@@ -692,6 +875,15 @@ pub mod pallet {
                         pooled_tokens <= user_info.pooled_tokens,
                         Error::<T>::InsufficientFunds
                     );
+                    Self::settle_rewards(user_info, acc_reward_per_share);
+                    if user_info.locked_tokens != 0 && now >= user_info.lock_end {
+                        let locked_tokens = user_info.locked_tokens;
+                        removed_boost = Self::release_lock(user_info, locked_tokens);
+                    }
+                    ensure!(
+                        pooled_tokens <= user_info.pooled_tokens - user_info.locked_tokens,
+                        Error::<T>::DepositIsLocked
+                    );
 
                     if is_farm == false {
                         Assets::<T>::transfer_from(
@@ -702,14 +894,15 @@ pub mod pallet {
                         )?;
                     }
                     user_info.pooled_tokens -= pooled_tokens;
+                    Self::reset_reward_debt(user_info, acc_reward_per_share);
                 }
             }
 
-            // Get pool info
-            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
             for pool_info in pool_infos.iter_mut() {
                 if pool_info.is_farm == is_farm && pool_info.base_asset == base_asset {
                     pool_info.total_tokens_in_pool -= pooled_tokens;
+                    pool_info.total_boosted_tokens =
+                        pool_info.total_boosted_tokens.saturating_sub(removed_boost);
                 }
             }
 
@@ -781,11 +974,7 @@ pub mod pallet {
             is_farm: bool,
             new_multiplier: u32,
         ) -> DispatchResultWithPostInfo {
-            let user = ensure_signed(origin)?;
-
-            if user != AuthorityAccount::<T>::get() {
-                return Err(Error::<T>::Unauthorized.into());
-            }
+            let user = Self::ensure_authority(origin)?;
 
             // Get pool info and check if pool exists
             let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
@@ -896,6 +1085,11 @@ pub mod pallet {
             }
 
             // Get pool info
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+            let mut removed_boost = balance!(0);
+
             let mut user_infos = <UserInfos<T>>::get(&changed_user);
             for u_info in user_infos.iter_mut() {
                 if u_info.pool_asset == pool_asset
@@ -903,8 +1097,24 @@ pub mod pallet {
                     && u_info.is_farm == is_farm
                     && u_info.base_asset == base_asset
                 {
+                    Self::settle_rewards(u_info, acc_reward_per_share);
                     u_info.pooled_tokens = pool_tokens;
+                    removed_boost = Self::clamp_lock(u_info);
+                    Self::reset_reward_debt(u_info, acc_reward_per_share);
+                }
+            }
+
+            if removed_boost != 0 {
+                for p_info in pool_infos.iter_mut() {
+                    if !p_info.is_removed
+                        && p_info.is_farm == is_farm
+                        && p_info.base_asset == base_asset
+                    {
+                        p_info.total_boosted_tokens =
+                            p_info.total_boosted_tokens.saturating_sub(removed_boost);
+                    }
                 }
+                <Pools<T>>::insert(&pool_asset, &reward_asset, pool_infos);
             }
 
             <UserInfos<T>>::insert(&changed_user, &user_infos);
@@ -934,11 +1144,7 @@ pub mod pallet {
             is_farm: bool,
             deposit_fee: Balance,
         ) -> DispatchResultWithPostInfo {
-            let user = ensure_signed(origin)?;
-
-            if user != AuthorityAccount::<T>::get() {
-                return Err(Error::<T>::Unauthorized.into());
-            }
+            let user = Self::ensure_authority(origin)?;
 
             // Check if deposit fee is valid
             ensure!(deposit_fee <= balance!(1), Error::<T>::InvalidDepositFee);
@@ -1018,6 +1224,305 @@ pub mod pallet {
             // Return a successful DispatchResult
             Ok(().into())
         }
+
+        /// Lock part of pooled tokens until the end of `period` in exchange for a reward boost.
+        /// Locking again extends the lock and the boost of the new period applies to all
+        /// locked tokens, so the lock can't end earlier than the current one.
+        #[transactional]
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::lock_deposit())]
+        pub fn lock_deposit(
+            origin: OriginFor<T>,
+            base_asset: AssetIdOf<T>,
+            pool_asset: AssetIdOf<T>,
+            reward_asset: AssetIdOf<T>,
+            is_farm: bool,
+            amount: Balance,
+            period: LockPeriod,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(amount != balance!(0), Error::<T>::InvalidAmount);
+
+            // Get pool info and check if pool exists
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            ensure!(
+                pool_infos.iter().any(|p_info| !p_info.is_removed
+                    && p_info.is_farm == is_farm
+                    && p_info.base_asset == base_asset),
+                Error::<T>::PoolDoesNotExist
+            );
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let lock_end = now + T::BLOCKS_PER_ONE_DAY * period.days().into();
+            let mut added_boost = balance!(0);
+            let mut removed_boost = balance!(0);
+            let mut previous_lock_end = None;
+            let mut exist = false;
+
+            // Get user info
+            let mut user_infos = <UserInfos<T>>::get(&user);
+            for user_info in user_infos.iter_mut() {
+                if user_info.pool_asset == pool_asset
+                    && user_info.reward_asset == reward_asset
+                    && user_info.is_farm == is_farm
+                    && user_info.base_asset == base_asset
+                {
+                    exist = true;
+                    Self::settle_rewards(user_info, acc_reward_per_share);
+                    if user_info.locked_tokens != 0 {
+                        previous_lock_end = Some(user_info.lock_end);
+                    }
+                    if user_info.locked_tokens != 0 && now >= user_info.lock_end {
+                        let locked_tokens = user_info.locked_tokens;
+                        removed_boost = Self::release_lock(user_info, locked_tokens);
+                    }
+
+                    ensure!(
+                        lock_end >= user_info.lock_end,
+                        Error::<T>::InvalidLockPeriod
+                    );
+                    ensure!(
+                        amount <= user_info.pooled_tokens - user_info.locked_tokens,
+                        Error::<T>::InsufficientFunds
+                    );
+
+                    user_info.locked_tokens += amount;
+                    removed_boost += user_info.boosted_tokens;
+                    added_boost = period.boost() * user_info.locked_tokens;
+                    user_info.boosted_tokens = added_boost;
+                    user_info.lock_end = lock_end;
+                    Self::reset_reward_debt(user_info, acc_reward_per_share);
+                }
+            }
+            ensure!(exist, Error::<T>::InsufficientFunds);
+
+            for p_info in pool_infos.iter_mut() {
+                if !p_info.is_removed
+                    && p_info.is_farm == is_farm
+                    && p_info.base_asset == base_asset
+                {
+                    p_info.total_boosted_tokens = p_info
+                        .total_boosted_tokens
+                        .saturating_sub(removed_boost)
+                        .saturating_add(added_boost);
+                }
+            }
+
+            let lock_key = (
+                user.clone(),
+                base_asset.clone(),
+                pool_asset.clone(),
+                reward_asset.clone(),
+                is_farm,
+            );
+            if let Some(previous_lock_end) = previous_lock_end {
+                <LockExpirations<T>>::remove(Self::expiration_block(previous_lock_end), &lock_key);
+            }
+            <LockExpirations<T>>::insert(Self::expiration_block(lock_end), lock_key, ());
+
+            // Update storage
+            <UserInfos<T>>::insert(&user, user_infos);
+            <Pools<T>>::insert(&pool_asset, &reward_asset, pool_infos);
+
+            // Emit an event
+            Self::deposit_event(Event::<T>::DepositLocked(
+                user,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                amount,
+                lock_end,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Withdraw pooled tokens including locked ones before the lock expires.
+        /// Penalty is charged on the locked part and sent to the fee account.
+        #[transactional]
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_locked())]
+        pub fn withdraw_locked(
+            origin: OriginFor<T>,
+            base_asset: AssetIdOf<T>,
+            pool_asset: AssetIdOf<T>,
+            reward_asset: AssetIdOf<T>,
+            pooled_tokens: Balance,
+            is_farm: bool,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            // Get pool info
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut removed_boost = balance!(0);
+            let mut penalty = balance!(0);
+            let mut exist = false;
+
+            // Get user info
+            let mut user_infos = <UserInfos<T>>::get(&user);
+            for user_info in user_infos.iter_mut() {
+                if user_info.pool_asset == pool_asset
+                    && user_info.reward_asset == reward_asset
+                    && user_info.is_farm == is_farm
+                    && user_info.base_asset == base_asset
+                {
+                    exist = true;
+                    ensure!(
+                        user_info.locked_tokens != 0 && now < user_info.lock_end,
+                        Error::<T>::DepositIsNotLocked
+                    );
+                    ensure!(
+                        pooled_tokens <= user_info.pooled_tokens,
+                        Error::<T>::InsufficientFunds
+                    );
+                    Self::settle_rewards(user_info, acc_reward_per_share);
+
+                    // Unlocked tokens are withdrawn first
+                    let locked_part = pooled_tokens
+                        .saturating_sub(user_info.pooled_tokens - user_info.locked_tokens);
+                    penalty = (FixedWrapper::from(locked_part)
+                        * FixedWrapper::from(EarlyWithdrawPenalty::<T>::get()))
+                    .try_into_balance()
+                    .unwrap_or(0);
+                    removed_boost = Self::release_lock(user_info, locked_part);
+
+                    if is_farm {
+                        if penalty != balance!(0) {
+                            let pool_account =
+                                T::XYKPool::properties_of_pool(base_asset, pool_asset.clone())
+                                    .ok_or(Error::<T>::PoolDoesNotExist)?
+                                    .0;
+                            T::XYKPool::transfer_lp_tokens(
+                                pool_account,
+                                base_asset,
+                                pool_asset,
+                                user.clone(),
+                                FeeAccount::<T>::get(),
+                                penalty,
+                            )?;
+                        }
+                    } else {
+                        Assets::<T>::transfer_from(
+                            &pool_asset,
+                            &Self::account_id(),
+                            &user,
+                            pooled_tokens - penalty,
+                        )?;
+                        if penalty != balance!(0) {
+                            Assets::<T>::transfer_from(
+                                &pool_asset,
+                                &Self::account_id(),
+                                &FeeAccount::<T>::get(),
+                                penalty,
+                            )?;
+                        }
+                    }
+                    user_info.pooled_tokens -= pooled_tokens;
+                    Self::reset_reward_debt(user_info, acc_reward_per_share);
+                }
+            }
+            ensure!(exist, Error::<T>::DepositIsNotLocked);
+
+            for pool_info in pool_infos.iter_mut() {
+                if pool_info.is_farm == is_farm && pool_info.base_asset == base_asset {
+                    pool_info.total_tokens_in_pool -= pooled_tokens;
+                    pool_info.total_boosted_tokens =
+                        pool_info.total_boosted_tokens.saturating_sub(removed_boost);
+                }
+            }
+
+            // Update storage
+            <UserInfos<T>>::insert(&user, user_infos);
+            <Pools<T>>::insert(&pool_asset, &reward_asset, &pool_infos);
+
+            // Emit an event
+            Self::deposit_event(Event::<T>::LockedWithdrawn(
+                user,
+                pooled_tokens,
+                penalty,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Release expired lock of any user, so the boost stops earning rewards
+        #[transactional]
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::unlock_deposit())]
+        pub fn unlock_deposit(
+            origin: OriginFor<T>,
+            locked_user: AccountIdOf<T>,
+            base_asset: AssetIdOf<T>,
+            pool_asset: AssetIdOf<T>,
+            reward_asset: AssetIdOf<T>,
+            is_farm: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            // Get pool info
+            let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut removed_boost = balance!(0);
+            let mut exist = false;
+
+            // Get user info
+            let mut user_infos = <UserInfos<T>>::get(&locked_user);
+            for user_info in user_infos.iter_mut() {
+                if user_info.pool_asset == pool_asset
+                    && user_info.reward_asset == reward_asset
+                    && user_info.is_farm == is_farm
+                    && user_info.base_asset == base_asset
+                {
+                    exist = true;
+                    ensure!(user_info.locked_tokens != 0, Error::<T>::DepositIsNotLocked);
+                    ensure!(now >= user_info.lock_end, Error::<T>::LockIsNotExpired);
+
+                    Self::settle_rewards(user_info, acc_reward_per_share);
+                    let locked_tokens = user_info.locked_tokens;
+                    removed_boost = Self::release_lock(user_info, locked_tokens);
+                    Self::reset_reward_debt(user_info, acc_reward_per_share);
+                }
+            }
+            ensure!(exist, Error::<T>::DepositIsNotLocked);
+
+            for pool_info in pool_infos.iter_mut() {
+                if pool_info.is_farm == is_farm && pool_info.base_asset == base_asset {
+                    pool_info.total_boosted_tokens =
+                        pool_info.total_boosted_tokens.saturating_sub(removed_boost);
+                }
+            }
+
+            // Update storage
+            <UserInfos<T>>::insert(&locked_user, user_infos);
+            <Pools<T>>::insert(&pool_asset, &reward_asset, &pool_infos);
+
+            // Emit an event
+            Self::deposit_event(Event::<T>::DepositUnlocked(
+                locked_user,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
@@ -1026,7 +1531,8 @@ pub mod pallet {
             let mut counter = Weight::zero();
 
             if (now % T::BLOCKS_PER_HOUR_AND_A_HALF).is_zero() {
-                counter = Self::distribute_rewards_to_users();
+                counter =
+                    Self::expire_locks(now).saturating_add(Self::distribute_rewards_to_users());
             }
             if (now % T::BLOCKS_PER_ONE_DAY).is_zero() {
                 counter = Self::distribute_rewards_to_pools();
@@ -1036,17 +1542,43 @@ pub mod pallet {
         }
 
         fn on_runtime_upgrade() -> Weight {
-            if Self::pallet_storage_version() == StorageVersion::V1 {
-                let weight = migrations::migrate::<T>();
-                PalletStorageVersion::<T>::put(StorageVersion::V2);
-                weight
-            } else {
-                Weight::zero()
+            match Self::pallet_storage_version() {
+                StorageVersion::V1 => {
+                    let weight = migrations::migrate::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V2 => {
+                    let weight = migrations::migrate_to_reward_per_share::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V3 => {
+                    let weight = migrations::migrate_to_lock_boosts::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V4 => Weight::zero(),
             }
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Check if origin is the authority account or the governance origin
+        fn ensure_authority(origin: OriginFor<T>) -> Result<AccountIdOf<T>, DispatchError> {
+            match T::GovernanceOrigin::try_origin(origin) {
+                Ok(_) => Ok(AuthorityAccount::<T>::get()),
+                Err(origin) => {
+                    let user = ensure_signed(origin)?;
+                    ensure!(
+                        user == AuthorityAccount::<T>::get(),
+                        Error::<T>::Unauthorized
+                    );
+                    Ok(user)
+                }
+            }
+        }
+
         /// The account ID of pallet
         fn account_id() -> T::AccountId {
             PALLET_ID.into_account_truncating()
@@ -1148,6 +1680,8 @@ pub mod pallet {
                 .saturating_add(T::DbWeight::get().writes(counter))
         }
 
+        /// Distribute rewards to users by increasing the accumulated reward per share of each
+        /// pool. Users' rewards are settled lazily when their position changes.
         fn distribute_rewards_to_users() -> Weight {
             let mut counter: u64 = 0;
             let per_hour_and_half = balance!(0.0625);
@@ -1162,27 +1696,17 @@ pub mod pallet {
                             .try_into_balance()
                             .unwrap_or(zero);
 
-                        for (user, mut user_infos) in UserInfos::<T>::iter() {
-                            let mut changed = false;
-                            for user_info in user_infos.iter_mut() {
-                                if user_info.pool_asset == pool_asset
-                                    && user_info.reward_asset == reward_asset
-                                    && user_info.is_farm == pool_info.is_farm
-                                    && user_info.base_asset == pool_info.base_asset
-                                {
-                                    let amount_per_user = (FixedWrapper::from(amount_per_hour)
-                                        * (FixedWrapper::from(user_info.pooled_tokens)
-                                            / FixedWrapper::from(pool_info.total_tokens_in_pool)))
-                                    .try_into_balance()
-                                    .unwrap_or(zero);
-                                    user_info.rewards += amount_per_user;
-                                    changed = true;
-                                }
-                            }
-                            if changed {
-                                <UserInfos<T>>::insert(user, user_infos);
-                                counter += 1;
-                            }
+                        let total_shares = pool_info
+                            .total_tokens_in_pool
+                            .saturating_add(pool_info.total_boosted_tokens);
+                        if total_shares != zero {
+                            let reward_per_share = (FixedWrapper::from(amount_per_hour)
+                                / FixedWrapper::from(total_shares))
+                            .try_into_balance()
+                            .unwrap_or(zero);
+                            pool_info.acc_reward_per_share = pool_info
+                                .acc_reward_per_share
+                                .saturating_add(reward_per_share);
                         }
 
                         pool_info.rewards += amount_per_hour;
@@ -1197,6 +1721,191 @@ pub mod pallet {
                 .saturating_add(T::DbWeight::get().writes(counter))
         }
 
+        /// First reward distribution block at or after `lock_end`
+        fn expiration_block(lock_end: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            let remainder = lock_end % T::BLOCKS_PER_HOUR_AND_A_HALF;
+            if remainder.is_zero() {
+                lock_end
+            } else {
+                lock_end - remainder + T::BLOCKS_PER_HOUR_AND_A_HALF
+            }
+        }
+
+        /// Release locks which expired since the previous distribution before rewards are
+        /// distributed, so boosts don't earn rewards after the lock end. At most
+        /// `MAX_LOCK_EXPIRATIONS` locks are released at once, the rest are released before the
+        /// following distributions. Locks which were released in the meantime are skipped.
+        fn expire_locks(now: BlockNumberFor<T>) -> Weight {
+            let mut counter: u64 = 0;
+            let mut block = <NextLockExpirationBlock<T>>::get();
+            if block.is_zero() {
+                block = now;
+            }
+
+            let mut expired_locks = Vec::new();
+            while block <= now {
+                let remaining = (MAX_LOCK_EXPIRATIONS as usize).saturating_sub(expired_locks.len());
+                expired_locks.extend(
+                    <LockExpirations<T>>::iter_key_prefix(block)
+                        .take(remaining)
+                        .map(|lock_key| (block, lock_key)),
+                );
+                if expired_locks.len() >= MAX_LOCK_EXPIRATIONS as usize {
+                    break;
+                }
+                block += T::BLOCKS_PER_HOUR_AND_A_HALF;
+            }
+            <NextLockExpirationBlock<T>>::put(block);
+
+            for (block, (user, base_asset, pool_asset, reward_asset, is_farm)) in expired_locks {
+                <LockExpirations<T>>::remove(
+                    block,
+                    (
+                        user.clone(),
+                        base_asset.clone(),
+                        pool_asset.clone(),
+                        reward_asset.clone(),
+                        is_farm,
+                    ),
+                );
+                let mut pool_infos = <Pools<T>>::get(&pool_asset, &reward_asset);
+                let acc_reward_per_share =
+                    Self::acc_reward_per_share(&pool_infos, &base_asset, is_farm);
+                let mut removed_boost = balance!(0);
+
+                let mut user_infos = <UserInfos<T>>::get(&user);
+                for user_info in user_infos.iter_mut() {
+                    if user_info.pool_asset == pool_asset
+                        && user_info.reward_asset == reward_asset
+                        && user_info.is_farm == is_farm
+                        && user_info.base_asset == base_asset
+                        && user_info.locked_tokens != 0
+                        && now >= user_info.lock_end
+                    {
+                        Self::settle_rewards(user_info, acc_reward_per_share);
+                        let locked_tokens = user_info.locked_tokens;
+                        removed_boost = Self::release_lock(user_info, locked_tokens);
+                        Self::reset_reward_debt(user_info, acc_reward_per_share);
+                    }
+                }
+                counter += 1;
+
+                if removed_boost != 0 {
+                    for pool_info in pool_infos.iter_mut() {
+                        if pool_info.is_farm == is_farm && pool_info.base_asset == base_asset {
+                            pool_info.total_boosted_tokens =
+                                pool_info.total_boosted_tokens.saturating_sub(removed_boost);
+                        }
+                    }
+                    <UserInfos<T>>::insert(&user, user_infos);
+                    <Pools<T>>::insert(&pool_asset, &reward_asset, pool_infos);
+                }
+            }
+
+            T::DbWeight::get()
+                .reads(counter * 3 + 2)
+                .saturating_add(T::DbWeight::get().writes(counter * 3 + 1))
+        }
+
+        /// Accumulated reward per share of the pool matching `base_asset` and `is_farm`.
+        /// Active pool is preferred over removed ones.
+        pub(crate) fn acc_reward_per_share(
+            pool_infos: &[PoolData<AssetIdOf<T>>],
+            base_asset: &AssetIdOf<T>,
+            is_farm: bool,
+        ) -> Balance {
+            let mut acc_reward_per_share = balance!(0);
+            for pool_info in pool_infos {
+                if pool_info.is_farm == is_farm && &pool_info.base_asset == base_asset {
+                    acc_reward_per_share = pool_info.acc_reward_per_share;
+                    if !pool_info.is_removed {
+                        break;
+                    }
+                }
+            }
+            acc_reward_per_share
+        }
+
+        /// Rewards earned by `pooled_tokens` at given accumulated reward per share
+        fn accumulated_rewards(pooled_tokens: Balance, acc_reward_per_share: Balance) -> Balance {
+            (FixedWrapper::from(pooled_tokens) * FixedWrapper::from(acc_reward_per_share))
+                .try_into_balance()
+                .unwrap_or(0)
+        }
+
+        /// Reward shares of user, boosted tokens are counted on top of pooled tokens
+        fn reward_shares(user_info: &UserInfoOf<T>) -> Balance {
+            user_info
+                .pooled_tokens
+                .saturating_add(user_info.boosted_tokens)
+        }
+
+        /// Move pending rewards into user rewards
+        pub(crate) fn settle_rewards(user_info: &mut UserInfoOf<T>, acc_reward_per_share: Balance) {
+            let accumulated =
+                Self::accumulated_rewards(Self::reward_shares(user_info), acc_reward_per_share);
+            user_info.rewards = user_info
+                .rewards
+                .saturating_add(accumulated.saturating_sub(user_info.reward_debt));
+            user_info.reward_debt = accumulated;
+        }
+
+        /// Reset reward debt after pooled tokens of user are changed
+        pub(crate) fn reset_reward_debt(
+            user_info: &mut UserInfoOf<T>,
+            acc_reward_per_share: Balance,
+        ) {
+            user_info.reward_debt =
+                Self::accumulated_rewards(Self::reward_shares(user_info), acc_reward_per_share);
+        }
+
+        /// Unlock `amount` of locked tokens and remove the proportional part of the boost.
+        /// Returns removed boost.
+        pub(crate) fn release_lock(user_info: &mut UserInfoOf<T>, amount: Balance) -> Balance {
+            let amount = amount.min(user_info.locked_tokens);
+            if amount == 0 {
+                return 0;
+            }
+
+            let removed_boost = if amount == user_info.locked_tokens {
+                user_info.boosted_tokens
+            } else {
+                (FixedWrapper::from(user_info.boosted_tokens) * FixedWrapper::from(amount)
+                    / FixedWrapper::from(user_info.locked_tokens))
+                .try_into_balance()
+                .unwrap_or(0)
+                .min(user_info.boosted_tokens)
+            };
+
+            user_info.locked_tokens -= amount;
+            user_info.boosted_tokens -= removed_boost;
+            if user_info.locked_tokens == 0 {
+                user_info.lock_end = Zero::zero();
+            }
+            removed_boost
+        }
+
+        /// Keep locked tokens within pooled tokens after pooled tokens are reduced outside of
+        /// withdrawal. Returns removed boost.
+        pub(crate) fn clamp_lock(user_info: &mut UserInfoOf<T>) -> Balance {
+            let excess = user_info
+                .locked_tokens
+                .saturating_sub(user_info.pooled_tokens);
+            Self::release_lock(user_info, excess)
+        }
+
+        /// Rewards of user including pending ones which are not settled yet
+        pub fn user_rewards(user_info: &UserInfoOf<T>) -> Balance {
+            let pool_infos = <Pools<T>>::get(&user_info.pool_asset, &user_info.reward_asset);
+            let acc_reward_per_share =
+                Self::acc_reward_per_share(&pool_infos, &user_info.base_asset, user_info.is_farm);
+            let accumulated =
+                Self::accumulated_rewards(Self::reward_shares(user_info), acc_reward_per_share);
+            user_info
+                .rewards
+                .saturating_add(accumulated.saturating_sub(user_info.reward_debt))
+        }
+
         /// Check if user has enough free liquidity for withdrawing
         pub fn check_if_has_enough_liquidity_out_of_farming(
             user: &AccountIdOf<T>,
@@ -1253,11 +1962,18 @@ impl<T: Config> DemeterFarmingPallet<T::AccountId, T::AssetId> for Pallet<T> {
             {
                 if u_info.pooled_tokens > pool_tokens {
                     let pool_tokens_diff = u_info.pooled_tokens - pool_tokens;
-                    u_info.pooled_tokens = pool_tokens;
                     let mut pool_data = <Pools<T>>::get(&pool_asset, &u_info.reward_asset);
+                    let acc_reward_per_share =
+                        Self::acc_reward_per_share(&pool_data, &base_asset, true);
+                    Self::settle_rewards(u_info, acc_reward_per_share);
+                    u_info.pooled_tokens = pool_tokens;
+                    let removed_boost = Self::clamp_lock(u_info);
+                    Self::reset_reward_debt(u_info, acc_reward_per_share);
                     for p_info in pool_data.iter_mut() {
                         if !p_info.is_removed && p_info.is_farm && p_info.base_asset == base_asset {
                             p_info.total_tokens_in_pool -= pool_tokens_diff;
+                            p_info.total_boosted_tokens =
+                                p_info.total_boosted_tokens.saturating_sub(removed_boost);
                         }
                     }
                     <Pools<T>>::insert(&pool_asset, &u_info.reward_asset, pool_data);
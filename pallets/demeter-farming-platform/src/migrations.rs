@@ -1,9 +1,10 @@
-use crate::{AssetIdOf, Config, PoolData, Pools, UserInfo, UserInfos};
+use crate::{AssetIdOf, Config, PoolData, Pools, UserInfo, UserInfoOf, UserInfos};
 use codec::{Decode, Encode};
 use common::{Balance, XOR};
 use frame_support::dispatch::Weight;
 use frame_support::log;
 use frame_support::traits::Get;
+use sp_runtime::traits::Zero;
 use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -27,6 +28,54 @@ struct OldUserInfo<AssetId> {
     pub rewards: Balance,
 }
 
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+struct PoolDataV2<AssetId> {
+    pub multiplier: u32,
+    pub deposit_fee: Balance,
+    pub is_core: bool,
+    pub is_farm: bool,
+    pub total_tokens_in_pool: Balance,
+    pub rewards: Balance,
+    pub rewards_to_be_distributed: Balance,
+    pub is_removed: bool,
+    pub base_asset: AssetId,
+}
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+struct UserInfoV2<AssetId> {
+    pub base_asset: AssetId,
+    pub pool_asset: AssetId,
+    pub reward_asset: AssetId,
+    pub is_farm: bool,
+    pub pooled_tokens: Balance,
+    pub rewards: Balance,
+}
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+struct PoolDataV3<AssetId> {
+    pub multiplier: u32,
+    pub deposit_fee: Balance,
+    pub is_core: bool,
+    pub is_farm: bool,
+    pub total_tokens_in_pool: Balance,
+    pub rewards: Balance,
+    pub rewards_to_be_distributed: Balance,
+    pub is_removed: bool,
+    pub base_asset: AssetId,
+    pub acc_reward_per_share: Balance,
+}
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+struct UserInfoV3<AssetId> {
+    pub base_asset: AssetId,
+    pub pool_asset: AssetId,
+    pub reward_asset: AssetId,
+    pub is_farm: bool,
+    pub pooled_tokens: Balance,
+    pub rewards: Balance,
+    pub reward_debt: Balance,
+}
+
 pub fn migrate<T: Config>() -> Weight {
     sp_runtime::runtime_logger::RuntimeLogger::init();
     migrate_pool_and_user_data::<T>()
@@ -56,6 +105,8 @@ pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
                         rewards_to_be_distributed: old_pool_data.rewards_to_be_distributed,
                         is_removed: old_pool_data.is_removed,
                         base_asset: base_asset.into(),
+                        acc_reward_per_share: 0,
+                        total_boosted_tokens: 0,
                     }
                 })
                 .collect::<Vec<PoolData<AssetIdOf<T>>>>(),
@@ -80,9 +131,13 @@ pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
                         is_farm: old_user_info.is_farm,
                         pooled_tokens: old_user_info.pooled_tokens,
                         rewards: old_user_info.rewards,
+                        reward_debt: 0,
+                        locked_tokens: 0,
+                        boosted_tokens: 0,
+                        lock_end: Zero::zero(),
                     }
                 })
-                .collect::<Vec<UserInfo<AssetIdOf<T>>>>(),
+                .collect::<Vec<UserInfoOf<T>>>(),
         )
     });
 
@@ -93,3 +148,125 @@ pub fn migrate_pool_and_user_data<T: Config>() -> Weight {
 
     T::DbWeight::get().reads_writes(weight, weight)
 }
+
+/// Adds reward per share accumulator to pools and reward debt to users.
+/// Already distributed rewards stay in `UserInfo::rewards`, so starting both new fields
+/// from zero keeps balances of users unchanged.
+pub fn migrate_to_reward_per_share<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    Pools::<T>::translate::<Vec<PoolDataV2<AssetIdOf<T>>>, _>(|_, _, v| {
+        Some(
+            v.into_iter()
+                .map(|old_pool_data: PoolDataV2<AssetIdOf<T>>| {
+                    weight += 1;
+
+                    PoolData {
+                        multiplier: old_pool_data.multiplier,
+                        deposit_fee: old_pool_data.deposit_fee,
+                        is_core: old_pool_data.is_core,
+                        is_farm: old_pool_data.is_farm,
+                        total_tokens_in_pool: old_pool_data.total_tokens_in_pool,
+                        rewards: old_pool_data.rewards,
+                        rewards_to_be_distributed: old_pool_data.rewards_to_be_distributed,
+                        is_removed: old_pool_data.is_removed,
+                        base_asset: old_pool_data.base_asset,
+                        acc_reward_per_share: 0,
+                        total_boosted_tokens: 0,
+                    }
+                })
+                .collect::<Vec<PoolData<AssetIdOf<T>>>>(),
+        )
+    });
+
+    UserInfos::<T>::translate::<Vec<UserInfoV2<AssetIdOf<T>>>, _>(|_, v| {
+        Some(
+            v.into_iter()
+                .map(|old_user_info: UserInfoV2<AssetIdOf<T>>| {
+                    weight += 1;
+
+                    UserInfo {
+                        base_asset: old_user_info.base_asset,
+                        pool_asset: old_user_info.pool_asset,
+                        reward_asset: old_user_info.reward_asset,
+                        is_farm: old_user_info.is_farm,
+                        pooled_tokens: old_user_info.pooled_tokens,
+                        rewards: old_user_info.rewards,
+                        reward_debt: 0,
+                        locked_tokens: 0,
+                        boosted_tokens: 0,
+                        lock_end: Zero::zero(),
+                    }
+                })
+                .collect::<Vec<UserInfoOf<T>>>(),
+        )
+    });
+
+    log::info!(
+        target: "runtime",
+        "PoolData and UserInfo migrated to new version with reward per share accumulator"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
+
+/// Adds lock boost fields to pools and users. There are no locks before the migration.
+pub fn migrate_to_lock_boosts<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    Pools::<T>::translate::<Vec<PoolDataV3<AssetIdOf<T>>>, _>(|_, _, v| {
+        Some(
+            v.into_iter()
+                .map(|old_pool_data: PoolDataV3<AssetIdOf<T>>| {
+                    weight += 1;
+
+                    PoolData {
+                        multiplier: old_pool_data.multiplier,
+                        deposit_fee: old_pool_data.deposit_fee,
+                        is_core: old_pool_data.is_core,
+                        is_farm: old_pool_data.is_farm,
+                        total_tokens_in_pool: old_pool_data.total_tokens_in_pool,
+                        rewards: old_pool_data.rewards,
+                        rewards_to_be_distributed: old_pool_data.rewards_to_be_distributed,
+                        is_removed: old_pool_data.is_removed,
+                        base_asset: old_pool_data.base_asset,
+                        acc_reward_per_share: old_pool_data.acc_reward_per_share,
+                        total_boosted_tokens: 0,
+                    }
+                })
+                .collect::<Vec<PoolData<AssetIdOf<T>>>>(),
+        )
+    });
+
+    UserInfos::<T>::translate::<Vec<UserInfoV3<AssetIdOf<T>>>, _>(|_, v| {
+        Some(
+            v.into_iter()
+                .map(|old_user_info: UserInfoV3<AssetIdOf<T>>| {
+                    weight += 1;
+
+                    UserInfo {
+                        base_asset: old_user_info.base_asset,
+                        pool_asset: old_user_info.pool_asset,
+                        reward_asset: old_user_info.reward_asset,
+                        is_farm: old_user_info.is_farm,
+                        pooled_tokens: old_user_info.pooled_tokens,
+                        rewards: old_user_info.rewards,
+                        reward_debt: old_user_info.reward_debt,
+                        locked_tokens: 0,
+                        boosted_tokens: 0,
+                        lock_end: Zero::zero(),
+                    }
+                })
+                .collect::<Vec<UserInfoOf<T>>>(),
+        )
+    });
+
+    log::info!(
+        target: "runtime",
+        "PoolData and UserInfo migrated to new version with lock boost fields"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
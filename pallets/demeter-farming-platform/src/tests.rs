@@ -8,7 +8,7 @@ mod tests {
         TradingPairSourceManager, CERES_ASSET_ID, DEFAULT_BALANCE_PRECISION, DEMETER_ASSET_ID, XOR,
         XSTUSD,
     };
-    use demeter_farming_platform::{PoolData, TokenInfo, UserInfo};
+    use demeter_farming_platform::{LockPeriod, PoolData, TokenInfo};
     use frame_support::pallet_prelude::{StorageDoubleMap, StorageMap};
     use frame_support::storage::types::ValueQuery;
     use frame_support::traits::Hooks;
@@ -16,6 +16,8 @@ mod tests {
     use hex_literal::hex;
     use sp_runtime::traits::AccountIdConversion;
 
+    type UserInfo = demeter_farming_platform::UserInfo<AssetId, BlockNumber>;
+
     fn preset_initial<Fun>(tests: Fun)
     where
         Fun: Fn(),
@@ -569,6 +571,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -650,6 +654,70 @@ mod tests {
         });
     }
 
+    #[test]
+    fn add_removed_pool_keeps_accumulator() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = true;
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::register_token(
+                RuntimeOrigin::signed(demeter_farming_platform::AuthorityAccount::<Runtime>::get()),
+                reward_asset,
+                balance!(1),
+                balance!(0.6),
+                balance!(0.2),
+                balance!(0.2),
+                BOB
+            ));
+
+            let pool_info = PoolData {
+                multiplier: 1,
+                deposit_fee: balance!(0),
+                is_core: true,
+                is_farm,
+                total_tokens_in_pool: balance!(10),
+                rewards: balance!(5),
+                rewards_to_be_distributed: 0,
+                is_removed: true,
+                base_asset: XOR,
+                acc_reward_per_share: balance!(0.5),
+                total_boosted_tokens: 0,
+            };
+            demeter_farming_platform::Pools::<Runtime>::append(
+                &pool_asset,
+                &reward_asset,
+                &pool_info,
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::add_pool(
+                RuntimeOrigin::signed(demeter_farming_platform::AuthorityAccount::<Runtime>::get()),
+                pool_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                2,
+                balance!(0.4),
+                false,
+            ));
+
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos.len(), 1);
+            assert_eq!(
+                pool_infos[0],
+                PoolData {
+                    multiplier: 2,
+                    deposit_fee: balance!(0.4),
+                    is_core: false,
+                    is_removed: false,
+                    ..pool_info
+                }
+            );
+        });
+    }
+
     #[test]
     fn add_pool_xstusd_ok() {
         let mut ext = ExtBuilder::default().build();
@@ -1326,6 +1394,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1363,6 +1435,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1378,6 +1452,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1415,6 +1493,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1430,6 +1510,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1464,6 +1548,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1479,6 +1565,10 @@ mod tests {
                 is_farm,
                 pooled_tokens: balance!(1000),
                 rewards: balance!(100),
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1535,6 +1625,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1550,6 +1642,10 @@ mod tests {
                 is_farm,
                 pooled_tokens: balance!(1000),
                 rewards: balance!(100),
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1606,6 +1702,10 @@ mod tests {
                 is_farm,
                 pooled_tokens: 20,
                 rewards: 1,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1643,6 +1743,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1658,6 +1760,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards: 1,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -1871,6 +1977,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -1916,6 +2024,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2034,6 +2144,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2102,6 +2214,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2170,6 +2284,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2274,6 +2390,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2319,6 +2437,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2373,6 +2493,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2600,6 +2722,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2653,6 +2777,8 @@ mod tests {
                 rewards_to_be_distributed: 0,
                 is_removed: false,
                 base_asset: XSTUSD,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
             };
 
             demeter_farming_platform::Pools::<Runtime>::append(
@@ -2705,6 +2831,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -2750,6 +2880,10 @@ mod tests {
                 is_farm,
                 pooled_tokens,
                 rewards,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
             };
 
             demeter_farming_platform::UserInfos::<Runtime>::append(ALICE, user_info);
@@ -2978,7 +3112,10 @@ mod tests {
                     && user_info.is_farm
                     && user_info.base_asset == xor
                 {
-                    assert_eq!(user_info.rewards, balance!(540));
+                    assert_eq!(
+                        demeter_farming_platform::Pallet::<Runtime>::user_rewards(user_info),
+                        balance!(540)
+                    );
                 }
             }
             let user_info_bob = demeter_farming_platform::UserInfos::<Runtime>::get(&BOB);
@@ -2988,12 +3125,18 @@ mod tests {
                     && user_info.is_farm
                     && user_info.base_asset == xor
                 {
-                    assert_eq!(user_info.rewards, balance!(540));
+                    assert_eq!(
+                        demeter_farming_platform::Pallet::<Runtime>::user_rewards(user_info),
+                        balance!(540)
+                    );
                 } else if user_info.pool_asset == ceres
                     && user_info.reward_asset == deo
                     && !user_info.is_farm
                 {
-                    assert_eq!(user_info.rewards, balance!(648));
+                    assert_eq!(
+                        demeter_farming_platform::Pallet::<Runtime>::user_rewards(user_info),
+                        balance!(648)
+                    );
                 }
             }
 
@@ -3021,7 +3164,10 @@ mod tests {
                     && user_info.is_farm
                     && user_info.base_asset == xor
                 {
-                    assert_eq!(user_info.rewards, balance!(9));
+                    assert_eq!(
+                        demeter_farming_platform::Pallet::<Runtime>::user_rewards(user_info),
+                        balance!(9)
+                    );
                 }
             }
             for user_info in &user_info_bob {
@@ -3029,7 +3175,10 @@ mod tests {
                     && user_info.reward_asset == util
                     && !user_info.is_farm
                 {
-                    assert_eq!(user_info.rewards, balance!(7.2));
+                    assert_eq!(
+                        demeter_farming_platform::Pallet::<Runtime>::user_rewards(user_info),
+                        balance!(7.2)
+                    );
                 }
             }
 
@@ -3061,6 +3210,121 @@ mod tests {
         });
     }
 
+    #[test]
+    fn on_initialize_settles_user_rewards_lazily() {
+        preset_initial(|| {
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = true;
+
+            let pool_info = PoolData {
+                multiplier: 1,
+                deposit_fee: balance!(0),
+                is_core: true,
+                is_farm,
+                total_tokens_in_pool: balance!(1000),
+                rewards: 0,
+                rewards_to_be_distributed: balance!(160),
+                is_removed: false,
+                base_asset: XOR,
+                acc_reward_per_share: 0,
+                total_boosted_tokens: 0,
+            };
+
+            demeter_farming_platform::Pools::<Runtime>::append(
+                &pool_asset,
+                &reward_asset,
+                &pool_info,
+            );
+
+            for (user, pooled_tokens) in [(ALICE, balance!(750)), (BOB, balance!(250))] {
+                let user_info = UserInfo {
+                    base_asset: XOR,
+                    pool_asset,
+                    reward_asset,
+                    is_farm,
+                    pooled_tokens,
+                    rewards: 0,
+                    reward_debt: 0,
+                    locked_tokens: 0,
+                    boosted_tokens: 0,
+                    lock_end: 0,
+                };
+                demeter_farming_platform::UserInfos::<Runtime>::append(user, user_info);
+            }
+
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+
+            // Users are not touched by distribution
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos_alice[0].rewards, balance!(0));
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_alice[0]),
+                balance!(7.5)
+            );
+            let user_infos_bob = demeter_farming_platform::UserInfos::<Runtime>::get(BOB);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_bob[0]),
+                balance!(2.5)
+            );
+
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].acc_reward_per_share, balance!(0.01));
+            assert_eq!(pool_infos[0].rewards, balance!(10));
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                pool_asset,
+                pool_asset,
+                reward_asset,
+                balance!(500),
+                is_farm,
+            ));
+
+            // Pending rewards are settled on withdraw
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos_alice[0].rewards, balance!(7.5));
+            assert_eq!(user_infos_alice[0].reward_debt, balance!(2.5));
+
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(1800);
+
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_alice[0]),
+                balance!(12.5)
+            );
+            let user_infos_bob = demeter_farming_platform::UserInfos::<Runtime>::get(BOB);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_bob[0]),
+                balance!(7.5)
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::get_rewards(
+                RuntimeOrigin::signed(ALICE),
+                pool_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+            ));
+
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos_alice[0].rewards, balance!(0));
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_alice[0]),
+                balance!(0)
+            );
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].rewards, balance!(7.5));
+            assert_eq!(
+                Assets::free_balance(&CERES_ASSET_ID, &ALICE)
+                    .expect("Failed to query free balance."),
+                balance!(3012.5)
+            );
+        });
+    }
+
     #[test]
     fn check_if_has_enough_liquidity_out_of_farming_true() {
         preset_initial(|| {
@@ -3985,4 +4249,661 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn demeter_farming_platform_reward_per_share_migration_works() {
+        preset_initial(|| {
+            generate_storage_instance!(DemeterFarmingPlatform, Pools);
+            type OldPools = StorageDoubleMap<
+                PoolsOldInstance,
+                Identity,
+                AssetIdOf<Runtime>,
+                Identity,
+                AssetIdOf<Runtime>,
+                Vec<(
+                    u32,
+                    Balance,
+                    bool,
+                    bool,
+                    Balance,
+                    Balance,
+                    Balance,
+                    bool,
+                    AssetIdOf<Runtime>,
+                )>,
+                ValueQuery,
+            >;
+
+            generate_storage_instance!(DemeterFarmingPlatform, UserInfos);
+            type OldUserInfos = StorageMap<
+                UserInfosOldInstance,
+                Identity,
+                AccountIdOf<Runtime>,
+                Vec<(
+                    AssetIdOf<Runtime>,
+                    AssetIdOf<Runtime>,
+                    AssetIdOf<Runtime>,
+                    bool,
+                    Balance,
+                    Balance,
+                )>,
+                ValueQuery,
+            >;
+
+            let asset_xor: AssetId = XOR.into();
+            let asset_ceres: AssetId = CERES_ASSET_ID.into();
+
+            demeter_farming_platform::PalletStorageVersion::<Runtime>::put(
+                demeter_farming_platform::StorageVersion::V2,
+            );
+
+            OldPools::insert(
+                asset_ceres,
+                asset_ceres,
+                vec![(
+                    4u32,
+                    balance!(0.03),
+                    false,
+                    true,
+                    balance!(130),
+                    balance!(25),
+                    balance!(8),
+                    false,
+                    asset_xor,
+                )],
+            );
+            OldUserInfos::insert(
+                ALICE,
+                vec![(
+                    asset_xor,
+                    asset_ceres,
+                    asset_ceres,
+                    true,
+                    balance!(5),
+                    balance!(10),
+                )],
+            );
+
+            // Storage migration
+            demeter_farming_platform::Pallet::<Runtime>::on_runtime_upgrade();
+
+            assert!(
+                demeter_farming_platform::PalletStorageVersion::<Runtime>::get()
+                    == demeter_farming_platform::StorageVersion::V4
+            );
+
+            let pools = demeter_farming_platform::Pools::<Runtime>::get(asset_ceres, asset_ceres);
+            assert_eq!(
+                pools,
+                vec![PoolData {
+                    multiplier: 4,
+                    deposit_fee: balance!(0.03),
+                    is_core: false,
+                    is_farm: true,
+                    total_tokens_in_pool: balance!(130),
+                    rewards: balance!(25),
+                    rewards_to_be_distributed: balance!(8),
+                    is_removed: false,
+                    base_asset: asset_xor,
+                    acc_reward_per_share: 0,
+                    total_boosted_tokens: 0,
+                }]
+            );
+
+            let users = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(
+                users,
+                vec![UserInfo {
+                    base_asset: asset_xor,
+                    pool_asset: asset_ceres,
+                    reward_asset: asset_ceres,
+                    is_farm: true,
+                    pooled_tokens: balance!(5),
+                    rewards: balance!(10),
+                    reward_debt: 0,
+                    locked_tokens: 0,
+                    boosted_tokens: 0,
+                    lock_end: 0,
+                }]
+            );
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&users[0]),
+                balance!(10)
+            );
+        });
+    }
+
+    fn insert_staking_position(
+        base_asset: AssetId,
+        pool_asset: AssetId,
+        reward_asset: AssetId,
+        rewards_to_be_distributed: Balance,
+        positions: &[(AccountId, Balance)],
+    ) {
+        let pool_info = PoolData {
+            multiplier: 1,
+            deposit_fee: balance!(0),
+            is_core: true,
+            is_farm: false,
+            total_tokens_in_pool: positions.iter().map(|(_, tokens)| tokens).sum(),
+            rewards: 0,
+            rewards_to_be_distributed,
+            is_removed: false,
+            base_asset,
+            acc_reward_per_share: 0,
+            total_boosted_tokens: 0,
+        };
+        demeter_farming_platform::Pools::<Runtime>::append(&pool_asset, &reward_asset, &pool_info);
+
+        for (user, pooled_tokens) in positions {
+            let user_info = UserInfo {
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm: false,
+                pooled_tokens: *pooled_tokens,
+                rewards: 0,
+                reward_debt: 0,
+                locked_tokens: 0,
+                boosted_tokens: 0,
+                lock_end: 0,
+            };
+            demeter_farming_platform::UserInfos::<Runtime>::append(user, user_info);
+        }
+    }
+
+    #[test]
+    fn lock_deposit_boosts_rewards() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                balance!(240),
+                &[(ALICE, balance!(500)), (BOB, balance!(500))],
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(500),
+                LockPeriod::OneYear,
+            ));
+
+            let lock_end = frame_system::Pallet::<Runtime>::block_number()
+                + 365 * <Runtime as ceres_liquidity_locker::Config>::BLOCKS_PER_ONE_DAY;
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos[0].locked_tokens, balance!(500));
+            assert_eq!(user_infos[0].boosted_tokens, balance!(500));
+            assert_eq!(user_infos[0].lock_end, lock_end);
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].total_boosted_tokens, balance!(500));
+
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+
+            // 15 tokens are distributed over 1500 reward shares
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_alice[0]),
+                balance!(10)
+            );
+            let user_infos_bob = demeter_farming_platform::UserInfos::<Runtime>::get(BOB);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_bob[0]),
+                balance!(5)
+            );
+
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::withdraw(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    balance!(100),
+                    is_farm,
+                ),
+                demeter_farming_platform::Error::<Runtime>::DepositIsLocked
+            );
+
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                    RuntimeOrigin::signed(BOB),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    is_farm,
+                    balance!(600),
+                    LockPeriod::OneMonth,
+                ),
+                demeter_farming_platform::Error::<Runtime>::InsufficientFunds
+            );
+        });
+    }
+
+    #[test]
+    fn lock_deposit_invalid_lock_period() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                0,
+                &[(ALICE, balance!(500))],
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(100),
+                LockPeriod::SixMonths,
+            ));
+
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    is_farm,
+                    balance!(100),
+                    LockPeriod::OneMonth,
+                ),
+                demeter_farming_platform::Error::<Runtime>::InvalidLockPeriod
+            );
+
+            // Extending the lock applies the new boost to all locked tokens
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(100),
+                LockPeriod::OneYear,
+            ));
+
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos[0].locked_tokens, balance!(200));
+            assert_eq!(user_infos[0].boosted_tokens, balance!(200));
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].total_boosted_tokens, balance!(200));
+        });
+    }
+
+    #[test]
+    fn withdraw_locked_penalty_ok() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            let fee_account = demeter_farming_platform::FeeAccount::<Runtime>::get();
+            let fee_account_balance =
+                Assets::free_balance(&XOR, &fee_account).expect("Failed to query free balance.");
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                0,
+                &[(ALICE, balance!(30))],
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(20),
+                LockPeriod::ThreeMonths,
+            ));
+
+            // 10 unlocked tokens are withdrawn first, penalty is charged on 15 locked tokens
+            assert_ok!(
+                demeter_farming_platform::Pallet::<Runtime>::withdraw_locked(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    balance!(25),
+                    is_farm,
+                )
+            );
+
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos[0].pooled_tokens, balance!(5));
+            assert_eq!(user_infos[0].locked_tokens, balance!(5));
+            assert_eq!(user_infos[0].boosted_tokens, balance!(1.5));
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].total_tokens_in_pool, balance!(5));
+            assert_eq!(pool_infos[0].total_boosted_tokens, balance!(1.5));
+
+            assert_eq!(
+                Assets::free_balance(&XOR, &ALICE).expect("Failed to query free balance."),
+                balance!(2023.5)
+            );
+            assert_eq!(
+                Assets::free_balance(&XOR, &fee_account).expect("Failed to query free balance."),
+                fee_account_balance + balance!(1.5)
+            );
+        });
+    }
+
+    #[test]
+    fn unlock_deposit_ok() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                0,
+                &[(ALICE, balance!(30))],
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(30),
+                LockPeriod::OneMonth,
+            ));
+
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::unlock_deposit(
+                    RuntimeOrigin::signed(BOB),
+                    ALICE,
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    is_farm,
+                ),
+                demeter_farming_platform::Error::<Runtime>::LockIsNotExpired
+            );
+
+            let lock_end = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE)[0].lock_end;
+            frame_system::Pallet::<Runtime>::set_block_number(lock_end);
+
+            assert_err!(
+                demeter_farming_platform::Pallet::<Runtime>::withdraw_locked(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    balance!(30),
+                    is_farm,
+                ),
+                demeter_farming_platform::Error::<Runtime>::DepositIsNotLocked
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::unlock_deposit(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+            ));
+
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos[0].locked_tokens, balance!(0));
+            assert_eq!(user_infos[0].boosted_tokens, balance!(0));
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].total_boosted_tokens, balance!(0));
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                balance!(30),
+                is_farm,
+            ));
+        });
+    }
+
+    #[test]
+    fn expired_lock_stops_boosting_rewards() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                balance!(240),
+                &[(ALICE, balance!(500)), (BOB, balance!(500))],
+            );
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                RuntimeOrigin::signed(ALICE),
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                balance!(500),
+                LockPeriod::OneMonth,
+            ));
+
+            let lock_end = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE)[0].lock_end;
+            let expiration_block = (lock_end + 899) / 900 * 900;
+            assert_eq!(
+                demeter_farming_platform::LockExpirations::<Runtime>::iter_prefix(expiration_block)
+                    .count(),
+                1
+            );
+
+            frame_system::Pallet::<Runtime>::set_block_number(expiration_block);
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(expiration_block);
+
+            // Boost is released before 15 tokens are distributed over 1000 reward shares
+            let user_infos_alice = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(user_infos_alice[0].locked_tokens, balance!(0));
+            assert_eq!(user_infos_alice[0].boosted_tokens, balance!(0));
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_alice[0]),
+                balance!(7.5)
+            );
+            let user_infos_bob = demeter_farming_platform::UserInfos::<Runtime>::get(BOB);
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&user_infos_bob[0]),
+                balance!(7.5)
+            );
+            let pool_infos =
+                demeter_farming_platform::Pools::<Runtime>::get(&pool_asset, &reward_asset);
+            assert_eq!(pool_infos[0].total_boosted_tokens, balance!(0));
+            assert!(
+                demeter_farming_platform::LockExpirations::<Runtime>::iter_prefix(expiration_block)
+                    .next()
+                    .is_none()
+            );
+            assert_eq!(
+                demeter_farming_platform::NextLockExpirationBlock::<Runtime>::get(),
+                expiration_block + 900
+            );
+        });
+    }
+
+    #[test]
+    fn extended_lock_has_single_expiration() {
+        preset_initial(|| {
+            let base_asset = XSTUSD;
+            let pool_asset = XOR;
+            let reward_asset = CERES_ASSET_ID;
+            let is_farm = false;
+            insert_staking_position(
+                base_asset,
+                pool_asset,
+                reward_asset,
+                balance!(240),
+                &[(ALICE, balance!(500)), (BOB, balance!(500))],
+            );
+
+            for period in [
+                LockPeriod::OneMonth,
+                LockPeriod::OneMonth,
+                LockPeriod::ThreeMonths,
+            ] {
+                assert_ok!(demeter_farming_platform::Pallet::<Runtime>::lock_deposit(
+                    RuntimeOrigin::signed(ALICE),
+                    base_asset,
+                    pool_asset,
+                    reward_asset,
+                    is_farm,
+                    balance!(100),
+                    period,
+                ));
+            }
+
+            let lock_end = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE)[0].lock_end;
+            let expiration_block = (lock_end + 899) / 900 * 900;
+            let expirations: Vec<_> =
+                demeter_farming_platform::LockExpirations::<Runtime>::iter_keys().collect();
+            assert_eq!(
+                expirations,
+                vec![(
+                    expiration_block,
+                    (ALICE, base_asset, pool_asset, reward_asset, is_farm)
+                )]
+            );
+        });
+    }
+
+    #[test]
+    fn demeter_farming_platform_lock_boosts_migration_works() {
+        preset_initial(|| {
+            generate_storage_instance!(DemeterFarmingPlatform, Pools);
+            type OldPools = StorageDoubleMap<
+                PoolsOldInstance,
+                Identity,
+                AssetIdOf<Runtime>,
+                Identity,
+                AssetIdOf<Runtime>,
+                Vec<(
+                    u32,
+                    Balance,
+                    bool,
+                    bool,
+                    Balance,
+                    Balance,
+                    Balance,
+                    bool,
+                    AssetIdOf<Runtime>,
+                    Balance,
+                )>,
+                ValueQuery,
+            >;
+
+            generate_storage_instance!(DemeterFarmingPlatform, UserInfos);
+            type OldUserInfos = StorageMap<
+                UserInfosOldInstance,
+                Identity,
+                AccountIdOf<Runtime>,
+                Vec<(
+                    AssetIdOf<Runtime>,
+                    AssetIdOf<Runtime>,
+                    AssetIdOf<Runtime>,
+                    bool,
+                    Balance,
+                    Balance,
+                    Balance,
+                )>,
+                ValueQuery,
+            >;
+
+            let asset_xor: AssetId = XOR.into();
+            let asset_ceres: AssetId = CERES_ASSET_ID.into();
+
+            demeter_farming_platform::PalletStorageVersion::<Runtime>::put(
+                demeter_farming_platform::StorageVersion::V3,
+            );
+
+            OldPools::insert(
+                asset_ceres,
+                asset_ceres,
+                vec![(
+                    4u32,
+                    balance!(0.03),
+                    false,
+                    true,
+                    balance!(130),
+                    balance!(25),
+                    balance!(8),
+                    false,
+                    asset_xor,
+                    balance!(0.1),
+                )],
+            );
+            OldUserInfos::insert(
+                ALICE,
+                vec![(
+                    asset_xor,
+                    asset_ceres,
+                    asset_ceres,
+                    true,
+                    balance!(5),
+                    balance!(10),
+                    balance!(0.2),
+                )],
+            );
+
+            // Storage migration
+            demeter_farming_platform::Pallet::<Runtime>::on_runtime_upgrade();
+
+            assert!(
+                demeter_farming_platform::PalletStorageVersion::<Runtime>::get()
+                    == demeter_farming_platform::StorageVersion::V4
+            );
+
+            let pools = demeter_farming_platform::Pools::<Runtime>::get(asset_ceres, asset_ceres);
+            assert_eq!(pools[0].acc_reward_per_share, balance!(0.1));
+            assert_eq!(pools[0].total_boosted_tokens, 0);
+
+            let users = demeter_farming_platform::UserInfos::<Runtime>::get(ALICE);
+            assert_eq!(
+                users,
+                vec![UserInfo {
+                    base_asset: asset_xor,
+                    pool_asset: asset_ceres,
+                    reward_asset: asset_ceres,
+                    is_farm: true,
+                    pooled_tokens: balance!(5),
+                    rewards: balance!(10),
+                    reward_debt: balance!(0.2),
+                    locked_tokens: 0,
+                    boosted_tokens: 0,
+                    lock_end: 0,
+                }]
+            );
+            assert_eq!(
+                demeter_farming_platform::Pallet::<Runtime>::user_rewards(&users[0]),
+                balance!(10.3)
+            );
+        });
+    }
 }
@@ -70,6 +70,9 @@ pub trait WeightInfo {
 	fn change_total_tokens() -> Weight;
 	fn change_info() -> Weight;
 	fn change_token_info() -> Weight;
+	fn lock_deposit() -> Weight;
+	fn withdraw_locked() -> Weight;
+	fn unlock_deposit() -> Weight;
 }
 
 /// Weights for demeter_farming_platform using the Substrate node and recommended hardware.
@@ -236,6 +239,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform LockExpirations (r:0 w:2)
+	/// Proof Skipped: DemeterFarmingPlatform LockExpirations (max_values: None, max_size: None, mode: Measured)
+	fn lock_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `8312`
+		// Minimum execution time: 38_742_000 picoseconds.
+		Weight::from_parts(39_915_000, 8312)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform EarlyWithdrawPenalty (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform EarlyWithdrawPenalty (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_locked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `24715`
+		// Minimum execution time: 104_318_000 picoseconds.
+		Weight::from_parts(106_207_000, 24715)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	fn unlock_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `8312`
+		// Minimum execution time: 35_104_000 picoseconds.
+		Weight::from_parts(36_283_000, 8312)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -401,4 +459,59 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform LockExpirations (r:0 w:2)
+	/// Proof Skipped: DemeterFarmingPlatform LockExpirations (max_values: None, max_size: None, mode: Measured)
+	fn lock_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `8312`
+		// Minimum execution time: 38_742_000 picoseconds.
+		Weight::from_parts(39_915_000, 8312)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform EarlyWithdrawPenalty (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform EarlyWithdrawPenalty (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_locked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `24715`
+		// Minimum execution time: 104_318_000 picoseconds.
+		Weight::from_parts(106_207_000, 24715)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	fn unlock_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `8312`
+		// Minimum execution time: 35_104_000 picoseconds.
+		Weight::from_parts(36_283_000, 8312)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use codec::Codec;
+
+use jsonrpsee::{
+    core::{Error as RpcError, RpcResult as Result},
+    proc_macros::rpc,
+    types::error::CallError,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use demeter_vaults_runtime_api::{DemeterVaultsApi as DemeterVaultsRuntimeApi, ShareInfo};
+
+#[rpc(server, client)]
+pub trait DemeterVaultsApi<BlockHash, AccountId, ShareInfo> {
+    #[method(name = "demeterVaults_shareInfo")]
+    fn share_info(
+        &self,
+        vault_id: u32,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<ShareInfo>;
+}
+
+pub struct DemeterVaultsClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> DemeterVaultsClient<C, B> {
+    /// Construct default `DemeterVaultsClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance>
+    DemeterVaultsApiServer<<Block as BlockT>::Hash, AccountId, ShareInfo<Balance>>
+    for DemeterVaultsClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: DemeterVaultsRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn share_info(
+        &self,
+        vault_id: u32,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ShareInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.share_info(&at, vault_id, account_id)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+}
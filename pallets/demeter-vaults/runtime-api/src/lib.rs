@@ -0,0 +1,59 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::string_serialization;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct ShareInfo<Balance> {
+    /// Shares of the account
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub shares: Balance,
+    /// Shares issued by the vault
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub total_shares: Balance,
+    /// Pooled tokens which can be withdrawn for the account shares
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub balance: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait DemeterVaultsApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+    {
+        /// Shares of account in vault and their value in pooled tokens
+        fn share_info(vault_id: u32, account_id: AccountId) -> ShareInfo<Balance>;
+    }
+}
@@ -0,0 +1,225 @@
+//! Demeter vaults module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use codec::Decode;
+use common::{balance, AssetId32, PredefinedAssetId, CERES_ASSET_ID, XOR};
+use demeter_farming_platform::PoolData;
+use frame_benchmarking::benchmarks;
+use frame_support::PalletId;
+use frame_system::{EventRecord, RawOrigin};
+use hex_literal::hex;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::prelude::*;
+
+use crate::Pallet as DemeterVaults;
+use assets::Pallet as Assets;
+
+// Support Functions
+fn alice<T: Config>() -> T::AccountId {
+    let bytes = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+    T::AccountId::decode(&mut &bytes[..]).unwrap()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+fn mint_ceres<T: Config>(account: &T::AccountId, amount: Balance)
+where
+    T::AssetId: From<AssetId32<PredefinedAssetId>>,
+{
+    let asset_id = T::AssetId::from(CERES_ASSET_ID);
+    let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+    Assets::<T>::mint(
+        RawOrigin::Signed(asset_owner).into(),
+        asset_id,
+        account.clone(),
+        amount,
+    )
+    .unwrap();
+}
+
+fn setup_vault<T: Config>()
+where
+    T::AssetId: From<AssetId32<PredefinedAssetId>>,
+{
+    let pool_info = PoolData {
+        multiplier: 1,
+        deposit_fee: balance!(0),
+        is_core: true,
+        is_farm: false,
+        total_tokens_in_pool: 0,
+        rewards: 0,
+        rewards_to_be_distributed: balance!(240),
+        is_removed: false,
+        base_asset: XOR.into(),
+        acc_reward_per_share: 0,
+        total_boosted_tokens: 0,
+    };
+    demeter_farming_platform::Pools::<T>::append(
+        &T::AssetId::from(CERES_ASSET_ID),
+        &T::AssetId::from(CERES_ASSET_ID),
+        &pool_info,
+    );
+
+    DemeterVaults::<T>::create_vault(
+        RawOrigin::Signed(AuthorityAccount::<T>::get()).into(),
+        common::DEXId::Polkaswap.into(),
+        XOR.into(),
+        CERES_ASSET_ID.into(),
+        CERES_ASSET_ID.into(),
+        false,
+        balance!(0.1),
+    )
+    .unwrap();
+}
+
+benchmarks! {
+    where_clause {
+        where T::AssetId: From<AssetId32<PredefinedAssetId>>
+    }
+
+    create_vault {
+        let caller = AuthorityAccount::<T>::get();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        let pool_info = PoolData {
+            multiplier: 1,
+            deposit_fee: balance!(0),
+            is_core: true,
+            is_farm: false,
+            total_tokens_in_pool: 0,
+            rewards: 0,
+            rewards_to_be_distributed: balance!(240),
+            is_removed: false,
+            base_asset: XOR.into(),
+            acc_reward_per_share: 0,
+            total_boosted_tokens: 0,
+        };
+        demeter_farming_platform::Pools::<T>::append(
+            &T::AssetId::from(CERES_ASSET_ID),
+            &T::AssetId::from(CERES_ASSET_ID),
+            &pool_info,
+        );
+    }: {
+        DemeterVaults::<T>::create_vault(
+            RawOrigin::Signed(caller.clone()).into(),
+            common::DEXId::Polkaswap.into(),
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            CERES_ASSET_ID.into(),
+            false,
+            balance!(0.1)
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::VaultCreated(0, XOR.into(), CERES_ASSET_ID.into(), CERES_ASSET_ID.into(), false).into());
+    }
+
+    deposit {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        mint_ceres::<T>(&caller, balance!(1000));
+        setup_vault::<T>();
+    }: {
+        DemeterVaults::<T>::deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            balance!(100)
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::Deposited(caller, 0, balance!(100), balance!(100)).into());
+    }
+
+    withdraw {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        mint_ceres::<T>(&caller, balance!(1000));
+        setup_vault::<T>();
+
+        DemeterVaults::<T>::deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            balance!(100)
+        ).unwrap();
+    }: {
+        DemeterVaults::<T>::withdraw(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            balance!(50)
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::Withdrawn(caller, 0, balance!(50), balance!(50)).into());
+    }
+
+    compound {
+        let caller = alice::<T>();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        mint_ceres::<T>(&caller, balance!(1000));
+        let demeter_account: T::AccountId = PalletId(*b"deofarms").into_account_truncating();
+        mint_ceres::<T>(&demeter_account, balance!(1000));
+        setup_vault::<T>();
+
+        DemeterVaults::<T>::deposit(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            balance!(100)
+        ).unwrap();
+
+        // Accumulate 10 reward tokens for the vault
+        demeter_farming_platform::Pools::<T>::mutate(
+            &T::AssetId::from(CERES_ASSET_ID),
+            &T::AssetId::from(CERES_ASSET_ID),
+            |pool_infos| {
+                pool_infos[0].rewards = balance!(10);
+                pool_infos[0].acc_reward_per_share = balance!(0.1);
+            },
+        );
+    }: {
+        DemeterVaults::<T>::compound(
+            RawOrigin::Signed(caller.clone()).into(),
+            0
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::Compounded(0, balance!(10), balance!(1), balance!(9)).into());
+    }
+
+    change_performance_fee {
+        let caller = AuthorityAccount::<T>::get();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        setup_vault::<T>();
+    }: {
+        DemeterVaults::<T>::change_performance_fee(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            balance!(0.2)
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::PerformanceFeeChanged(caller, 0, balance!(0.2)).into());
+    }
+
+    change_vault_status {
+        let caller = AuthorityAccount::<T>::get();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        setup_vault::<T>();
+    }: {
+        DemeterVaults::<T>::change_vault_status(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            false
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::VaultStatusChanged(caller, 0, false).into());
+    }
+}
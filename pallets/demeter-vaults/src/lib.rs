@@ -0,0 +1,765 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// TODO #167: fix clippy warnings
+#![allow(clippy::all)]
+
+pub mod weights;
+
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+use common::Balance;
+pub use weights::WeightInfo;
+
+/// Vault which auto-compounds rewards of a single demeter pool
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct VaultInfo<AssetId, DEXId> {
+    /// DEX used to swap harvested rewards
+    pub dex_id: DEXId,
+    pub base_asset: AssetId,
+    pub pool_asset: AssetId,
+    pub reward_asset: AssetId,
+    pub is_farm: bool,
+    /// Shares issued to depositors
+    pub total_shares: Balance,
+    /// Part of harvested rewards which is sent to fee account
+    pub performance_fee: Balance,
+    pub is_active: bool,
+    /// Base asset left over from adding liquidity, owned by the vault
+    pub base_asset_leftover: Balance,
+    /// Pool asset left over from adding liquidity, owned by the vault
+    pub pool_asset_leftover: Balance,
+}
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::{VaultInfo, WeightInfo};
+    use common::prelude::{AssetInfoProvider, Balance, FixedWrapper, SwapAmount};
+    use common::{
+        balance, LiquidityProxyTrait, LiquiditySourceFilter, PoolXykPallet, PriceToolsPallet,
+        PriceVariant,
+    };
+    use frame_support::pallet_prelude::*;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
+    use frame_support::transactional;
+    use frame_support::PalletId;
+    use frame_system::pallet_prelude::*;
+    use frame_system::RawOrigin;
+    use hex_literal::hex;
+    use sp_runtime::traits::{AccountIdConversion, Zero};
+    use sp_runtime::Perbill;
+    use sp_std::prelude::*;
+
+    const PALLET_ID: PalletId = PalletId(*b"deovault");
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + demeter_farming_platform::Config {
+        /// Because this pallet emits events, it depends on the runtime's definition of an event.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Liquidity proxy used to swap harvested rewards
+        type LiquidityProxy: LiquidityProxyTrait<Self::DEXId, Self::AccountId, Self::AssetId>;
+
+        /// Average prices used to bound the output of reward swaps
+        type PriceTools: PriceToolsPallet<Self::AssetId>;
+
+        /// Maximum deviation of swap output from the average price
+        const MAX_SLIPPAGE: Perbill;
+
+        /// Number of blocks between automatic compounding of all vaults
+        const BLOCKS_PER_COMPOUND: BlockNumberFor<Self>;
+
+        /// Maximum number of vaults compounded automatically in a single block
+        const MAX_VAULTS_PER_BLOCK: u32;
+
+        /// Origin which can manage vaults in addition to the authority account
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    type Assets<T> = assets::Pallet<T>;
+    type DemeterFarmingPlatform<T> = demeter_farming_platform::Pallet<T>;
+    pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub type AssetIdOf<T> = <T as assets::Config>::AssetId;
+    pub type DEXIdOf<T> = <T as common::Config>::DEXId;
+    pub type VaultInfoOf<T> = VaultInfo<AssetIdOf<T>, DEXIdOf<T>>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub (super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::type_value]
+    pub fn DefaultForAuthorityAccount<T: Config>() -> AccountIdOf<T> {
+        let bytes = hex!("fc096e24663f4dd1e2d48092c73213354c067c0c715ec68e7fcad185da626801");
+        AccountIdOf::<T>::decode(&mut &bytes[..]).unwrap()
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn authority_account)]
+    pub type AuthorityAccount<T: Config> =
+        StorageValue<_, AccountIdOf<T>, ValueQuery, DefaultForAuthorityAccount<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultFeeAccount<T: Config>() -> AccountIdOf<T> {
+        let bytes = hex!("fc096e24663f4dd1e2d48092c73213354c067c0c715ec68e7fcad185da626801");
+        AccountIdOf::<T>::decode(&mut &bytes[..]).unwrap()
+    }
+
+    /// Account for performance fees
+    #[pallet::storage]
+    #[pallet::getter(fn fee_account)]
+    pub type FeeAccount<T: Config> =
+        StorageValue<_, AccountIdOf<T>, ValueQuery, DefaultFeeAccount<T>>;
+
+    /// Number of created vaults
+    #[pallet::storage]
+    #[pallet::getter(fn vault_count)]
+    pub type VaultCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn vaults)]
+    pub type Vaults<T: Config> = StorageMap<_, Identity, u32, VaultInfoOf<T>, OptionQuery>;
+
+    /// Next vault to be compounded automatically, `None` when all vaults are compounded
+    #[pallet::storage]
+    #[pallet::getter(fn compound_cursor)]
+    pub type CompoundCursor<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Vault shares of each depositor
+    #[pallet::storage]
+    #[pallet::getter(fn shares)]
+    pub type Shares<T: Config> =
+        StorageDoubleMap<_, Identity, u32, Identity, AccountIdOf<T>, Balance, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub (super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Vault created [vault_id, base_asset, pool_asset, reward_asset, is_farm]
+        VaultCreated(u32, AssetIdOf<T>, AssetIdOf<T>, AssetIdOf<T>, bool),
+        /// Deposited to vault [who, vault_id, amount, shares]
+        Deposited(AccountIdOf<T>, u32, Balance, Balance),
+        /// Withdrawn from vault [who, vault_id, amount, shares]
+        Withdrawn(AccountIdOf<T>, u32, Balance, Balance),
+        /// Rewards compounded [vault_id, rewards, fee, deposited]
+        Compounded(u32, Balance, Balance, Balance),
+        /// Performance fee changed [who, vault_id, performance_fee]
+        PerformanceFeeChanged(AccountIdOf<T>, u32, Balance),
+        /// Vault status changed [who, vault_id, is_active]
+        VaultStatusChanged(AccountIdOf<T>, u32, bool),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Unauthorized
+        Unauthorized,
+        /// Pool does not exist
+        PoolDoesNotExist,
+        /// Vault already exists
+        VaultAlreadyExists,
+        /// Vault does not exist
+        VaultDoesNotExist,
+        /// Vault is not active
+        VaultIsNotActive,
+        /// Invalid performance fee
+        InvalidPerformanceFee,
+        /// Invalid amount
+        InvalidAmount,
+        /// Insufficient funds
+        InsufficientFunds,
+        /// Insufficient LP tokens
+        InsufficientLPTokens,
+        /// Not enough liquidity out of farming or locks
+        NotEnoughFreeLiquidity,
+        /// Insufficient shares
+        InsufficientShares,
+        /// Deposit is too small to mint shares
+        ZeroShares,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create vault for demeter pool
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault())]
+        pub fn create_vault(
+            origin: OriginFor<T>,
+            dex_id: DEXIdOf<T>,
+            base_asset: AssetIdOf<T>,
+            pool_asset: AssetIdOf<T>,
+            reward_asset: AssetIdOf<T>,
+            is_farm: bool,
+            performance_fee: Balance,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_authority(origin)?;
+
+            ensure!(
+                performance_fee <= balance!(1),
+                Error::<T>::InvalidPerformanceFee
+            );
+
+            // Check if pool exists
+            let pool_infos = demeter_farming_platform::Pools::<T>::get(&pool_asset, &reward_asset);
+            ensure!(
+                pool_infos.iter().any(|p_info| !p_info.is_removed
+                    && p_info.is_farm == is_farm
+                    && p_info.base_asset == base_asset),
+                Error::<T>::PoolDoesNotExist
+            );
+
+            // Check if vault already exists
+            ensure!(
+                !Vaults::<T>::iter_values().any(|vault| vault.base_asset == base_asset
+                    && vault.pool_asset == pool_asset
+                    && vault.reward_asset == reward_asset
+                    && vault.is_farm == is_farm),
+                Error::<T>::VaultAlreadyExists
+            );
+
+            let vault_id = VaultCount::<T>::get();
+            let vault_info = VaultInfo {
+                dex_id,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+                total_shares: 0,
+                performance_fee,
+                is_active: true,
+                base_asset_leftover: 0,
+                pool_asset_leftover: 0,
+            };
+            Vaults::<T>::insert(vault_id, vault_info);
+            VaultCount::<T>::put(vault_id + 1);
+
+            // Emit an event
+            Self::deposit_event(Event::VaultCreated(
+                vault_id,
+                base_asset,
+                pool_asset,
+                reward_asset,
+                is_farm,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Deposit pool tokens to vault in exchange for shares
+        #[transactional]
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit())]
+        pub fn deposit(
+            origin: OriginFor<T>,
+            vault_id: u32,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(amount > 0, Error::<T>::InvalidAmount);
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultDoesNotExist)?;
+            ensure!(vault.is_active, Error::<T>::VaultIsNotActive);
+            let vault_account = Self::vault_account_id(vault_id);
+
+            // Transfer pooled tokens to vault account
+            if vault.is_farm {
+                let pool_account = Self::pool_account(&vault)?;
+                let lp_tokens =
+                    T::XYKPool::balance_of_pool_provider(pool_account.clone(), user.clone())
+                        .unwrap_or(0);
+                ensure!(amount <= lp_tokens, Error::<T>::InsufficientLPTokens);
+                ensure!(
+                    ceres_liquidity_locker::Pallet::<T>::check_if_has_enough_unlocked_liquidity(
+                        &user,
+                        vault.base_asset,
+                        vault.pool_asset,
+                        amount,
+                    ) && DemeterFarmingPlatform::<T>::check_if_has_enough_liquidity_out_of_farming(
+                        &user,
+                        vault.base_asset,
+                        vault.pool_asset,
+                        amount,
+                    ),
+                    Error::<T>::NotEnoughFreeLiquidity
+                );
+
+                T::XYKPool::transfer_lp_tokens(
+                    pool_account,
+                    vault.base_asset,
+                    vault.pool_asset,
+                    user.clone(),
+                    vault_account.clone(),
+                    amount,
+                )?;
+            } else {
+                ensure!(
+                    amount <= Assets::<T>::free_balance(&vault.pool_asset, &user).unwrap_or(0),
+                    Error::<T>::InsufficientFunds
+                );
+                Assets::<T>::transfer_from(&vault.pool_asset, &user, &vault_account, amount)?;
+            }
+
+            // Deposit to demeter pool, deposit fee is taken from the deposited amount
+            let tokens_before = Self::vault_tokens(&vault, &vault_account);
+            Self::deposit_to_pool(&vault, &vault_account, amount)?;
+            let deposited = Self::vault_tokens(&vault, &vault_account) - tokens_before;
+
+            // Mint shares
+            let shares = if vault.total_shares == 0 || tokens_before == 0 {
+                deposited
+            } else {
+                (FixedWrapper::from(deposited) * FixedWrapper::from(vault.total_shares)
+                    / FixedWrapper::from(tokens_before))
+                .try_into_balance()
+                .unwrap_or(0)
+            };
+            ensure!(shares > 0, Error::<T>::ZeroShares);
+
+            Shares::<T>::mutate(vault_id, &user, |user_shares| *user_shares += shares);
+            vault.total_shares += shares;
+            Vaults::<T>::insert(vault_id, vault);
+
+            // Emit an event
+            Self::deposit_event(Event::Deposited(user, vault_id, deposited, shares));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Burn shares and withdraw pool tokens from vault
+        #[transactional]
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+        pub fn withdraw(
+            origin: OriginFor<T>,
+            vault_id: u32,
+            shares: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(shares > 0, Error::<T>::InvalidAmount);
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultDoesNotExist)?;
+            let user_shares = Shares::<T>::get(vault_id, &user);
+            ensure!(shares <= user_shares, Error::<T>::InsufficientShares);
+            let vault_account = Self::vault_account_id(vault_id);
+
+            let amount = Self::shares_value(&vault, &vault_account, shares);
+            if amount > 0 {
+                DemeterFarmingPlatform::<T>::withdraw(
+                    RawOrigin::Signed(vault_account.clone()).into(),
+                    vault.base_asset,
+                    vault.pool_asset,
+                    vault.reward_asset,
+                    amount,
+                    vault.is_farm,
+                )
+                .map_err(|e| e.error)?;
+
+                // Transfer pooled tokens to user
+                if vault.is_farm {
+                    T::XYKPool::transfer_lp_tokens(
+                        Self::pool_account(&vault)?,
+                        vault.base_asset,
+                        vault.pool_asset,
+                        vault_account,
+                        user.clone(),
+                        amount,
+                    )?;
+                } else {
+                    Assets::<T>::transfer_from(&vault.pool_asset, &vault_account, &user, amount)?;
+                }
+            }
+
+            // Burn shares
+            if user_shares == shares {
+                Shares::<T>::remove(vault_id, &user);
+            } else {
+                Shares::<T>::insert(vault_id, &user, user_shares - shares);
+            }
+            vault.total_shares -= shares;
+            Vaults::<T>::insert(vault_id, vault);
+
+            // Emit an event
+            Self::deposit_event(Event::Withdrawn(user, vault_id, amount, shares));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Harvest vault rewards and deposit them back to the pool
+        #[transactional]
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::compound())]
+        pub fn compound(origin: OriginFor<T>, vault_id: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            Self::compound_vault(vault_id)?;
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Change performance fee of vault
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_performance_fee())]
+        pub fn change_performance_fee(
+            origin: OriginFor<T>,
+            vault_id: u32,
+            performance_fee: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let user = Self::ensure_authority(origin)?;
+
+            ensure!(
+                performance_fee <= balance!(1),
+                Error::<T>::InvalidPerformanceFee
+            );
+
+            Vaults::<T>::try_mutate(vault_id, |vault| -> DispatchResult {
+                let vault = vault.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+                vault.performance_fee = performance_fee;
+                Ok(())
+            })?;
+
+            // Emit an event
+            Self::deposit_event(Event::PerformanceFeeChanged(
+                user,
+                vault_id,
+                performance_fee,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Activate or deactivate vault, withdrawals are allowed from inactive vaults
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_vault_status())]
+        pub fn change_vault_status(
+            origin: OriginFor<T>,
+            vault_id: u32,
+            is_active: bool,
+        ) -> DispatchResultWithPostInfo {
+            let user = Self::ensure_authority(origin)?;
+
+            Vaults::<T>::try_mutate(vault_id, |vault| -> DispatchResult {
+                let vault = vault.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+                vault.is_active = is_active;
+                Ok(())
+            })?;
+
+            // Emit an event
+            Self::deposit_event(Event::VaultStatusChanged(user, vault_id, is_active));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Compound all active vaults, at most `MAX_VAULTS_PER_BLOCK` vaults per block
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut counter = T::DbWeight::get().reads_writes(2, 1);
+
+            let start = if (now % T::BLOCKS_PER_COMPOUND).is_zero() {
+                0
+            } else {
+                match CompoundCursor::<T>::get() {
+                    Some(start) => start,
+                    None => return T::DbWeight::get().reads(1),
+                }
+            };
+            let end = VaultCount::<T>::get().min(start.saturating_add(T::MAX_VAULTS_PER_BLOCK));
+
+            for vault_id in start..end {
+                counter = counter.saturating_add(T::DbWeight::get().reads(1));
+                let vault = match Vaults::<T>::get(vault_id) {
+                    Some(vault) => vault,
+                    None => continue,
+                };
+                if !vault.is_active || vault.total_shares == 0 {
+                    continue;
+                }
+
+                // Vaults without rewards or with failed swaps are skipped
+                let _ = with_transaction(|| match Self::compound_vault(vault_id) {
+                    Ok(_) => TransactionOutcome::Commit(Ok(())),
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
+                });
+                counter = counter.saturating_add(<T as Config>::WeightInfo::compound());
+            }
+
+            if end < VaultCount::<T>::get() {
+                CompoundCursor::<T>::put(end);
+            } else {
+                CompoundCursor::<T>::kill();
+            }
+
+            counter
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check if origin is the authority account or the governance origin
+        fn ensure_authority(origin: OriginFor<T>) -> Result<AccountIdOf<T>, DispatchError> {
+            match <T as Config>::GovernanceOrigin::try_origin(origin) {
+                Ok(_) => Ok(AuthorityAccount::<T>::get()),
+                Err(origin) => {
+                    let user = ensure_signed(origin)?;
+                    ensure!(
+                        user == AuthorityAccount::<T>::get(),
+                        Error::<T>::Unauthorized
+                    );
+                    Ok(user)
+                }
+            }
+        }
+
+        /// The account ID of vault which holds its position in demeter pool
+        pub fn vault_account_id(vault_id: u32) -> AccountIdOf<T> {
+            PALLET_ID.into_sub_account_truncating(vault_id)
+        }
+
+        /// XYK pool account of farm vault
+        fn pool_account(vault: &VaultInfoOf<T>) -> Result<AccountIdOf<T>, DispatchError> {
+            Ok(
+                T::XYKPool::properties_of_pool(vault.base_asset, vault.pool_asset)
+                    .ok_or(Error::<T>::PoolDoesNotExist)?
+                    .0,
+            )
+        }
+
+        /// Pooled tokens of vault in demeter pool
+        pub fn vault_tokens(vault: &VaultInfoOf<T>, vault_account: &AccountIdOf<T>) -> Balance {
+            demeter_farming_platform::UserInfos::<T>::get(vault_account)
+                .iter()
+                .find(|u_info| {
+                    u_info.pool_asset == vault.pool_asset
+                        && u_info.reward_asset == vault.reward_asset
+                        && u_info.is_farm == vault.is_farm
+                        && u_info.base_asset == vault.base_asset
+                })
+                .map(|u_info| u_info.pooled_tokens)
+                .unwrap_or(0)
+        }
+
+        /// Pooled tokens which can be withdrawn for given shares
+        pub fn shares_value(
+            vault: &VaultInfoOf<T>,
+            vault_account: &AccountIdOf<T>,
+            shares: Balance,
+        ) -> Balance {
+            let tokens = Self::vault_tokens(vault, vault_account);
+            if vault.total_shares == 0 {
+                return 0;
+            }
+            if shares == vault.total_shares {
+                return tokens;
+            }
+
+            (FixedWrapper::from(shares) * FixedWrapper::from(tokens)
+                / FixedWrapper::from(vault.total_shares))
+            .try_into_balance()
+            .unwrap_or(0)
+        }
+
+        /// Shares of user, total shares of vault and pooled tokens which can be withdrawn for user shares
+        pub fn share_info(vault_id: u32, user: &AccountIdOf<T>) -> (Balance, Balance, Balance) {
+            let vault = match Vaults::<T>::get(vault_id) {
+                Some(vault) => vault,
+                None => return (0, 0, 0),
+            };
+            let shares = Shares::<T>::get(vault_id, user);
+            let value = Self::shares_value(&vault, &Self::vault_account_id(vault_id), shares);
+
+            (shares, vault.total_shares, value)
+        }
+
+        fn deposit_to_pool(
+            vault: &VaultInfoOf<T>,
+            vault_account: &AccountIdOf<T>,
+            amount: Balance,
+        ) -> DispatchResult {
+            DemeterFarmingPlatform::<T>::deposit(
+                RawOrigin::Signed(vault_account.clone()).into(),
+                vault.base_asset,
+                vault.pool_asset,
+                vault.reward_asset,
+                vault.is_farm,
+                amount,
+            )
+            .map_err(|e| e.error)?;
+
+            Ok(())
+        }
+
+        /// Claim vault rewards, take performance fee and deposit the rest back to the pool
+        pub fn compound_vault(vault_id: u32) -> DispatchResult {
+            let mut vault = Vaults::<T>::get(vault_id).ok_or(Error::<T>::VaultDoesNotExist)?;
+            ensure!(vault.is_active, Error::<T>::VaultIsNotActive);
+            let vault_account = Self::vault_account_id(vault_id);
+
+            // Claim rewards
+            let reward_balance =
+                Assets::<T>::free_balance(&vault.reward_asset, &vault_account).unwrap_or(0);
+            DemeterFarmingPlatform::<T>::get_rewards(
+                RawOrigin::Signed(vault_account.clone()).into(),
+                vault.base_asset,
+                vault.pool_asset,
+                vault.reward_asset,
+                vault.is_farm,
+            )
+            .map_err(|e| e.error)?;
+            let rewards = Assets::<T>::free_balance(&vault.reward_asset, &vault_account)
+                .unwrap_or(0)
+                .saturating_sub(reward_balance);
+
+            // Pay performance fee
+            let fee = (FixedWrapper::from(rewards) * FixedWrapper::from(vault.performance_fee))
+                .try_into_balance()
+                .unwrap_or(0);
+            if fee > 0 {
+                Assets::<T>::transfer_from(
+                    &vault.reward_asset,
+                    &vault_account,
+                    &FeeAccount::<T>::get(),
+                    fee,
+                )?;
+            }
+
+            // Convert rewards to pool tokens
+            let pooled_tokens = if vault.is_farm {
+                Self::add_liquidity(&mut vault, &vault_account, rewards - fee)?
+            } else {
+                Self::swap(
+                    &vault,
+                    &vault_account,
+                    &vault.reward_asset,
+                    &vault.pool_asset,
+                    rewards - fee,
+                )?
+            };
+
+            // Deposit pool tokens back to the pool
+            let tokens_before = Self::vault_tokens(&vault, &vault_account);
+            if pooled_tokens > 0 {
+                Self::deposit_to_pool(&vault, &vault_account, pooled_tokens)?;
+            }
+            let deposited = Self::vault_tokens(&vault, &vault_account) - tokens_before;
+            Vaults::<T>::insert(vault_id, vault);
+
+            // Emit an event
+            Self::deposit_event(Event::Compounded(vault_id, rewards, fee, deposited));
+
+            Ok(())
+        }
+
+        /// Swap vault tokens using liquidity proxy. Output can't be lower than the average price
+        /// allows, reduced by `MAX_SLIPPAGE`.
+        fn swap(
+            vault: &VaultInfoOf<T>,
+            vault_account: &AccountIdOf<T>,
+            input_asset: &AssetIdOf<T>,
+            output_asset: &AssetIdOf<T>,
+            amount: Balance,
+        ) -> Result<Balance, DispatchError> {
+            if input_asset == output_asset || amount == 0 {
+                return Ok(amount);
+            }
+
+            let price =
+                T::PriceTools::get_average_price(input_asset, output_asset, PriceVariant::Sell)?;
+            let expected_output = (FixedWrapper::from(amount) * FixedWrapper::from(price))
+                .try_into_balance()
+                .unwrap_or(0);
+            let min_output = (Perbill::one() - T::MAX_SLIPPAGE) * expected_output;
+
+            let outcome = T::LiquidityProxy::exchange(
+                vault.dex_id,
+                vault_account,
+                vault_account,
+                input_asset,
+                output_asset,
+                SwapAmount::with_desired_input(amount, min_output),
+                LiquiditySourceFilter::empty(vault.dex_id),
+            )?;
+
+            Ok(outcome.amount)
+        }
+
+        /// Swap rewards to both pool assets and add liquidity, returns minted LP tokens.
+        /// Only leftovers of previous compounds are added on top of swapped rewards, other
+        /// balances of the vault account are ignored.
+        fn add_liquidity(
+            vault: &mut VaultInfoOf<T>,
+            vault_account: &AccountIdOf<T>,
+            rewards: Balance,
+        ) -> Result<Balance, DispatchError> {
+            let half = rewards / 2;
+            let base_amount = Self::swap(
+                vault,
+                vault_account,
+                &vault.reward_asset,
+                &vault.base_asset,
+                half,
+            )?
+            .saturating_add(vault.base_asset_leftover);
+            let target_amount = Self::swap(
+                vault,
+                vault_account,
+                &vault.reward_asset,
+                &vault.pool_asset,
+                rewards - half,
+            )?
+            .saturating_add(vault.pool_asset_leftover);
+
+            vault.base_asset_leftover = base_amount;
+            vault.pool_asset_leftover = target_amount;
+            if base_amount == 0 || target_amount == 0 {
+                return Ok(0);
+            }
+
+            let base_balance =
+                Assets::<T>::free_balance(&vault.base_asset, vault_account).unwrap_or(0);
+            let target_balance =
+                Assets::<T>::free_balance(&vault.pool_asset, vault_account).unwrap_or(0);
+            let pool_account = Self::pool_account(vault)?;
+            let lp_tokens =
+                T::XYKPool::balance_of_pool_provider(pool_account.clone(), vault_account.clone())
+                    .unwrap_or(0);
+            T::XYKPool::deposit_liquidity(
+                vault_account.clone(),
+                vault.base_asset,
+                vault.pool_asset,
+                base_amount,
+                target_amount,
+                Balance::zero(),
+                Balance::zero(),
+            )?;
+
+            vault.base_asset_leftover = base_amount.saturating_sub(base_balance.saturating_sub(
+                Assets::<T>::free_balance(&vault.base_asset, vault_account).unwrap_or(0),
+            ));
+            vault.pool_asset_leftover =
+                target_amount.saturating_sub(target_balance.saturating_sub(
+                    Assets::<T>::free_balance(&vault.pool_asset, vault_account).unwrap_or(0),
+                ));
+
+            Ok(
+                T::XYKPool::balance_of_pool_provider(pool_account, vault_account.clone())
+                    .unwrap_or(0)
+                    .saturating_sub(lp_tokens),
+            )
+        }
+    }
+}
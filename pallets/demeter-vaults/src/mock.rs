@@ -0,0 +1,449 @@
+use crate as demeter_vaults;
+pub use common::mock::*;
+use common::mock::{ExistentialDeposits, GetTradingPairRestrictedFlag};
+use common::prelude::{Balance, QuoteAmount, SwapAmount, SwapOutcome};
+pub use common::TechAssetId as Tas;
+pub use common::TechPurpose::*;
+use common::{
+    balance, fixed, hash, DEXId, DEXInfo, Fixed, LiquidityProxyTrait, LiquiditySourceFilter,
+    LiquiditySourceType, PriceToolsPallet, PriceVariant, CERES_ASSET_ID, DEMETER_ASSET_ID, PSWAP,
+    VAL, XOR, XST, XSTUSD,
+};
+use currencies::BasicCurrencyAdapter;
+use frame_support::traits::{Everything, GenesisBuild, Hooks};
+use frame_support::weights::Weight;
+use frame_support::{construct_runtime, ensure, parameter_types};
+use frame_system;
+use frame_system::pallet_prelude::BlockNumberFor;
+use permissions::{Scope, MANAGE_DEX};
+use sp_core::H256;
+use sp_runtime::testing::Header;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{AccountId32, DispatchError, DispatchResult};
+use sp_runtime::{Perbill, Percent};
+
+pub type BlockNumber = u64;
+pub type AccountId = AccountId32;
+pub type Amount = i128;
+pub type AssetId = common::AssetId32<common::PredefinedAssetId>;
+pub type TechAssetId = common::TechAssetId<common::PredefinedAssetId>;
+pub type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime! {
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Assets: assets::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Tokens: tokens::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        Currencies: currencies::{Pallet, Call, Storage},
+        Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+        DexManager: dex_manager::{Pallet, Call, Config<T>, Storage},
+        TradingPair: trading_pair::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Permissions: permissions::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Technical: technical::{Pallet, Call, Config<T>, Storage, Event<T>},
+        PoolXYK: pool_xyk::{Pallet, Call, Storage, Event<T>},
+        PswapDistribution: pswap_distribution::{Pallet, Call, Config<T>, Storage, Event<T>},
+        MBCPool: multicollateral_bonding_curve_pool::{Pallet, Call, Config<T>, Storage, Event<T>},
+        VestedRewards: vested_rewards::{Pallet, Call, Storage, Event<T>},
+        CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>},
+        DemeterFarmingPlatform: demeter_farming_platform::{Pallet, Call, Storage, Event<T>},
+        DemeterVaults: demeter_vaults::{Pallet, Call, Storage, Event<T>}
+    }
+}
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const CHARLES: AccountId = AccountId32::new([3u8; 32]);
+pub const BUY_BACK_ACCOUNT: AccountId = AccountId32::new([23u8; 32]);
+pub const DEX_A_ID: DEXId = DEXId::Polkaswap;
+pub const DEX_B_ID: DEXId = DEXId::PolkaswapXSTUSD;
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 0);
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+    pub GetXykFee: Fixed = fixed!(0.003);
+    pub GetIncentiveAssetId: AssetId = common::PSWAP.into();
+    pub const GetDefaultSubscriptionFrequency: BlockNumber = 10;
+    pub const GetBurnUpdateFrequency: BlockNumber = 14400;
+    pub GetParliamentAccountId: AccountId = AccountId32::new([100u8; 32]);
+    pub GetPswapDistributionAccountId: AccountId = AccountId32::new([101u8; 32]);
+    pub GetMarketMakerRewardsAccountId: AccountId = AccountId32::new([102u8; 32]);
+    pub GetBondingCurveRewardsAccountId: AccountId = AccountId32::new([103u8; 32]);
+    pub GetFarmingRewardsAccountId: AccountId = AccountId32::new([104u8; 32]);
+    pub GetCrowdloanRewardsAccountId: AccountId = AccountId32::new([105u8; 32]);
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<65536>;
+}
+
+parameter_types! {
+    pub const DemeterAssetId: AssetId = DEMETER_ASSET_ID;
+}
+
+impl demeter_farming_platform::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type DemeterAssetId = DemeterAssetId;
+    const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub static MockAveragePrice: Balance = balance!(1);
+}
+
+impl demeter_vaults::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type LiquidityProxy = MockLiquidityProxy;
+    type PriceTools = MockPriceTools;
+    const MAX_SLIPPAGE: Perbill = Perbill::from_percent(1);
+    const BLOCKS_PER_COMPOUND: BlockNumberFor<Self> = 3600;
+    const MAX_VAULTS_PER_BLOCK: u32 = 1;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
+    type WeightInfo = ();
+}
+
+/// Returns `MockAveragePrice` for all pairs
+pub struct MockPriceTools;
+
+impl PriceToolsPallet<AssetId> for MockPriceTools {
+    fn get_average_price(
+        _input_asset_id: &AssetId,
+        _output_asset_id: &AssetId,
+        _price_variant: PriceVariant,
+    ) -> Result<Balance, DispatchError> {
+        Ok(MockAveragePrice::get())
+    }
+
+    fn register_asset(_asset_id: &AssetId) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Swaps assets 1:1 by burning input and minting output
+pub struct MockLiquidityProxy;
+
+impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockLiquidityProxy {
+    fn quote(
+        _dex_id: DEXId,
+        _input_asset_id: &AssetId,
+        _output_asset_id: &AssetId,
+        amount: QuoteAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+        _deduce_fee: bool,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        Ok(SwapOutcome::new(amount.amount(), 0))
+    }
+
+    fn exchange(
+        _dex_id: DEXId,
+        sender: &AccountId,
+        receiver: &AccountId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: SwapAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        let (amount, min_amount) = match amount {
+            SwapAmount::WithDesiredInput {
+                desired_amount_in,
+                min_amount_out,
+            } => (desired_amount_in, min_amount_out),
+            SwapAmount::WithDesiredOutput {
+                desired_amount_out, ..
+            } => (desired_amount_out, 0),
+        };
+        ensure!(amount >= min_amount, DispatchError::Other("Slippage"));
+        Assets::burn_from(input_asset_id, sender, sender, amount)?;
+        let owner = Assets::asset_owner(output_asset_id).unwrap();
+        Assets::mint_to(output_asset_id, &owner, receiver, amount)?;
+        Ok(SwapOutcome::new(amount, 0))
+    }
+}
+
+parameter_types! {
+    pub const GetBaseAssetId: AssetId = XOR;
+    pub const GetBuyBackAssetId: AssetId = XST;
+    pub GetBuyBackSupplyAssets: Vec<AssetId> = vec![VAL, PSWAP];
+    pub const GetBuyBackPercentage: u8 = 10;
+    pub const GetBuyBackAccountId: AccountId = BUY_BACK_ACCOUNT;
+    pub const GetBuyBackDexId: DEXId = DEXId::Polkaswap;
+    pub GetTBCBuyBackXSTPercent: Fixed = fixed!(0.025);
+}
+
+impl assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ExtraAccountId = [u8; 32];
+    type ExtraAssetRecordArg =
+        common::AssetIdExtraAssetRecordArg<DEXId, common::LiquiditySourceType, [u8; 32]>;
+    type AssetId = AssetId;
+    type GetBaseAssetId = GetBaseAssetId;
+    type GetBuyBackAssetId = GetBuyBackAssetId;
+    type GetBuyBackSupplyAssets = GetBuyBackSupplyAssets;
+    type GetBuyBackPercentage = GetBuyBackPercentage;
+    type GetBuyBackAccountId = GetBuyBackAccountId;
+    type GetBuyBackDexId = GetBuyBackDexId;
+    type BuyBackLiquidityProxy = ();
+    type Currency = currencies::Pallet<Runtime>;
+    type GetTotalBalance = ();
+    type WeightInfo = ();
+}
+
+impl common::Config for Runtime {
+    type DEXId = DEXId;
+    type LstId = common::LiquiditySourceType;
+}
+
+impl permissions::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+}
+
+impl dex_manager::Config for Runtime {}
+
+impl trading_pair::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type EnsureDEXManager = dex_manager::Pallet<Runtime>;
+    type DexInfoProvider = dex_manager::Pallet<Runtime>;
+    type WeightInfo = ();
+}
+
+impl pool_xyk::Config for Runtime {
+    const MIN_XOR: Balance = balance!(0.0007);
+    type RuntimeEvent = RuntimeEvent;
+    type PairSwapAction = pool_xyk::PairSwapAction<AssetId, AccountId, TechAccountId>;
+    type DepositLiquidityAction =
+        pool_xyk::DepositLiquidityAction<AssetId, AccountId, TechAccountId>;
+    type WithdrawLiquidityAction =
+        pool_xyk::WithdrawLiquidityAction<AssetId, AccountId, TechAccountId>;
+    type PolySwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type EnsureDEXManager = dex_manager::Pallet<Runtime>;
+    type GetFee = GetXykFee;
+    type OnPoolCreated = PswapDistribution;
+    type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
+    type WeightInfo = ();
+    type XSTMarketInfo = ();
+    type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
+}
+parameter_types! {
+    pub const CeresAssetId: AssetId = CERES_ASSET_ID;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl ceres_liquidity_locker::Config for Runtime {
+    const BLOCKS_PER_ONE_DAY: BlockNumberFor<Self> = 14400;
+    type RuntimeEvent = RuntimeEvent;
+    type XYKPool = PoolXYK;
+    type DemeterFarmingPlatform = DemeterFarmingPlatform;
+    type CeresAssetId = CeresAssetId;
+    type WeightInfo = ();
+}
+
+impl multicollateral_bonding_curve_pool::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type LiquidityProxy = ();
+    type EnsureDEXManager = dex_manager::Pallet<Runtime>;
+    type EnsureTradingPairExists = trading_pair::Pallet<Runtime>;
+    type PriceToolsPallet = ();
+    type VestedRewardsPallet = VestedRewards;
+    type BuyBackHandler = ();
+    type BuyBackXSTPercent = GetTBCBuyBackXSTPercent;
+    type WeightInfo = ();
+}
+
+impl vested_rewards::Config for Runtime {
+    const BLOCKS_PER_DAY: BlockNumberFor<Self> = 14400;
+    type RuntimeEvent = RuntimeEvent;
+    type GetMarketMakerRewardsAccountId = GetMarketMakerRewardsAccountId;
+    type GetBondingCurveRewardsAccountId = GetBondingCurveRewardsAccountId;
+    type GetFarmingRewardsAccountId = GetFarmingRewardsAccountId;
+    type WeightInfo = ();
+}
+
+impl pswap_distribution::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    const PSWAP_BURN_PERCENT: Percent = Percent::from_percent(3);
+    type GetIncentiveAssetId = GetIncentiveAssetId;
+    type GetXSTAssetId = GetBuyBackAssetId;
+    type LiquidityProxy = ();
+    type CompatBalance = Balance;
+    type GetDefaultSubscriptionFrequency = GetDefaultSubscriptionFrequency;
+    type GetBurnUpdateFrequency = GetBurnUpdateFrequency;
+    type GetTechnicalAccountId = GetPswapDistributionAccountId;
+    type EnsureDEXManager = ();
+    type OnPswapBurnedAggregator = ();
+    type WeightInfo = ();
+    type GetParliamentAccountId = GetParliamentAccountId;
+    type PoolXykPallet = PoolXYK;
+    type BuyBackHandler = ();
+    type DexInfoProvider = dex_manager::Pallet<Runtime>;
+}
+
+impl technical::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type TechAssetId = TechAssetId;
+    type TechAccountId = TechAccountId;
+    type Trigger = ();
+    type Condition = ();
+    type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+}
+
+impl tokens::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = AssetId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type CurrencyHooks = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type DustRemovalWhitelist = Everything;
+}
+
+impl currencies::Config for Runtime {
+    type MultiCurrency = Tokens;
+    type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+    type GetNativeCurrencyId = <Runtime as assets::Config>::GetBaseAssetId;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 0;
+    pub const TransferFee: u128 = 0;
+    pub const CreationFee: u128 = 0;
+    pub const TransactionByteFee: u128 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+}
+
+pub struct ExtBuilder {
+    initial_dex_list: Vec<(DEXId, DEXInfo<AssetId>)>,
+    endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+    initial_permission_owners: Vec<(u32, Scope, Vec<AccountId>)>,
+    initial_permissions: Vec<(AccountId, Scope, Vec<u32>)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            initial_dex_list: vec![
+                (
+                    DEX_A_ID,
+                    DEXInfo {
+                        base_asset_id: XOR.into(),
+                        synthetic_base_asset_id: XST.into(),
+                        is_public: true,
+                    },
+                ),
+                (
+                    DEX_B_ID,
+                    DEXInfo {
+                        base_asset_id: XSTUSD.into(),
+                        synthetic_base_asset_id: XST.into(),
+                        is_public: true,
+                    },
+                ),
+            ],
+            endowed_accounts: vec![
+                (ALICE, CERES_ASSET_ID.into(), balance!(1000)),
+                (BOB, CERES_ASSET_ID.into(), balance!(500)),
+            ],
+            initial_permission_owners: vec![
+                (MANAGE_DEX, Scope::Limited(hash(&DEX_A_ID)), vec![BOB]),
+                (MANAGE_DEX, Scope::Limited(hash(&DEX_B_ID)), vec![BOB]),
+            ],
+            initial_permissions: vec![
+                (ALICE, Scope::Limited(hash(&DEX_A_ID)), vec![MANAGE_DEX]),
+                (ALICE, Scope::Limited(hash(&DEX_B_ID)), vec![MANAGE_DEX]),
+            ],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = SystemConfig::default().build_storage::<Runtime>().unwrap();
+
+        dex_manager::GenesisConfig::<Runtime> {
+            dex_list: self.initial_dex_list,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        TokensConfig {
+            balances: self.endowed_accounts,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        permissions::GenesisConfig::<Runtime> {
+            initial_permission_owners: self.initial_permission_owners,
+            initial_permissions: self.initial_permissions,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        t.into()
+    }
+}
+
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        System::on_initialize(System::block_number());
+        DemeterFarmingPlatform::on_initialize(System::block_number());
+        DemeterVaults::on_initialize(System::block_number());
+    }
+}
@@ -0,0 +1,526 @@
+mod tests {
+    use crate::mock::*;
+    use crate::{Error, Event, VaultInfo};
+    use common::{
+        balance, AssetInfoProvider, AssetName, AssetSymbol, Balance, PoolXykPallet, CERES_ASSET_ID,
+        DEFAULT_BALANCE_PRECISION, XOR,
+    };
+    use demeter_farming_platform::PoolData;
+    use frame_support::traits::Hooks;
+    use frame_support::{assert_err, assert_ok, PalletId};
+    use sp_runtime::traits::AccountIdConversion;
+    use sp_runtime::DispatchError;
+
+    fn preset_initial<Fun>(tests: Fun)
+    where
+        Fun: Fn(),
+    {
+        let mut ext = ExtBuilder::default().build();
+        let xor: AssetId = XOR.into();
+        let ceres: AssetId = CERES_ASSET_ID.into();
+        let pallet_account = PalletId(*b"deofarms").into_account_truncating();
+
+        ext.execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+
+            assert_ok!(assets::Pallet::<Runtime>::register_asset_id(
+                ALICE,
+                XOR.into(),
+                AssetSymbol(b"XOR".to_vec()),
+                AssetName(b"SORA".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+                Balance::from(0u32),
+                true,
+                None,
+                None,
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::register_asset_id(
+                ALICE,
+                CERES_ASSET_ID.into(),
+                AssetSymbol(b"CERES".to_vec()),
+                AssetName(b"Ceres".to_vec()),
+                DEFAULT_BALANCE_PRECISION,
+                Balance::from(0u32),
+                true,
+                None,
+                None,
+            ));
+
+            assert_ok!(trading_pair::Pallet::<Runtime>::register(
+                RuntimeOrigin::signed(BOB),
+                DEX_A_ID,
+                XOR.into(),
+                CERES_ASSET_ID.into()
+            ));
+
+            assert_ok!(pool_xyk::Pallet::<Runtime>::initialize_pool(
+                RuntimeOrigin::signed(BOB),
+                DEX_A_ID,
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &xor,
+                &ALICE,
+                &ALICE,
+                balance!(2000)
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &ceres,
+                &ALICE,
+                &ALICE,
+                balance!(2000)
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &ceres,
+                &ALICE,
+                &BOB,
+                balance!(2000)
+            ));
+
+            assert_ok!(assets::Pallet::<Runtime>::mint_to(
+                &ceres,
+                &ALICE,
+                &pallet_account,
+                balance!(1000)
+            ));
+
+            tests();
+        });
+    }
+
+    fn insert_pool(is_farm: bool, rewards_to_be_distributed: Balance) {
+        let pool_info = PoolData {
+            multiplier: 1,
+            deposit_fee: balance!(0),
+            is_core: true,
+            is_farm,
+            total_tokens_in_pool: 0,
+            rewards: 0,
+            rewards_to_be_distributed,
+            is_removed: false,
+            base_asset: XOR.into(),
+            acc_reward_per_share: 0,
+            total_boosted_tokens: 0,
+        };
+        demeter_farming_platform::Pools::<Runtime>::append(
+            &AssetId::from(CERES_ASSET_ID),
+            &AssetId::from(CERES_ASSET_ID),
+            &pool_info,
+        );
+    }
+
+    fn create_vault(is_farm: bool) {
+        assert_ok!(DemeterVaults::create_vault(
+            RuntimeOrigin::signed(crate::AuthorityAccount::<Runtime>::get()),
+            DEX_A_ID,
+            XOR.into(),
+            CERES_ASSET_ID.into(),
+            CERES_ASSET_ID.into(),
+            is_farm,
+            balance!(0.1),
+        ));
+    }
+
+    #[test]
+    fn create_vault_unauthorized() {
+        preset_initial(|| {
+            insert_pool(false, balance!(240));
+
+            assert_err!(
+                DemeterVaults::create_vault(
+                    RuntimeOrigin::signed(ALICE),
+                    DEX_A_ID,
+                    XOR.into(),
+                    CERES_ASSET_ID.into(),
+                    CERES_ASSET_ID.into(),
+                    false,
+                    balance!(0.1),
+                ),
+                Error::<Runtime>::Unauthorized
+            );
+        });
+    }
+
+    #[test]
+    fn create_vault_checks_pool_and_fee() {
+        preset_initial(|| {
+            let authority = crate::AuthorityAccount::<Runtime>::get();
+
+            assert_err!(
+                DemeterVaults::create_vault(
+                    RuntimeOrigin::signed(authority.clone()),
+                    DEX_A_ID,
+                    XOR.into(),
+                    CERES_ASSET_ID.into(),
+                    CERES_ASSET_ID.into(),
+                    false,
+                    balance!(0.1),
+                ),
+                Error::<Runtime>::PoolDoesNotExist
+            );
+
+            insert_pool(false, balance!(240));
+
+            assert_err!(
+                DemeterVaults::create_vault(
+                    RuntimeOrigin::signed(authority.clone()),
+                    DEX_A_ID,
+                    XOR.into(),
+                    CERES_ASSET_ID.into(),
+                    CERES_ASSET_ID.into(),
+                    false,
+                    balance!(1.1),
+                ),
+                Error::<Runtime>::InvalidPerformanceFee
+            );
+
+            create_vault(false);
+            assert_eq!(
+                crate::Vaults::<Runtime>::get(0),
+                Some(VaultInfo {
+                    dex_id: DEX_A_ID,
+                    base_asset: XOR.into(),
+                    pool_asset: CERES_ASSET_ID.into(),
+                    reward_asset: CERES_ASSET_ID.into(),
+                    is_farm: false,
+                    total_shares: 0,
+                    performance_fee: balance!(0.1),
+                    is_active: true,
+                    base_asset_leftover: 0,
+                    pool_asset_leftover: 0,
+                })
+            );
+            assert_eq!(crate::VaultCount::<Runtime>::get(), 1);
+
+            assert_err!(
+                DemeterVaults::create_vault(
+                    RuntimeOrigin::signed(authority),
+                    DEX_A_ID,
+                    XOR.into(),
+                    CERES_ASSET_ID.into(),
+                    CERES_ASSET_ID.into(),
+                    false,
+                    balance!(0.2),
+                ),
+                Error::<Runtime>::VaultAlreadyExists
+            );
+        });
+    }
+
+    #[test]
+    fn staking_vault_deposit_compound_withdraw() {
+        preset_initial(|| {
+            let ceres: AssetId = CERES_ASSET_ID.into();
+            insert_pool(false, balance!(240));
+            create_vault(false);
+            let vault_account = DemeterVaults::vault_account_id(0);
+
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(100)
+            ));
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(BOB),
+                0,
+                balance!(100)
+            ));
+            assert_eq!(crate::Shares::<Runtime>::get(0, &ALICE), balance!(100));
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(&vault_account);
+            assert_eq!(user_infos[0].pooled_tokens, balance!(200));
+
+            // 15 tokens are distributed to the vault
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+
+            assert_ok!(DemeterVaults::compound(RuntimeOrigin::signed(CHARLES), 0));
+            frame_system::Pallet::<Runtime>::assert_last_event(
+                Event::<Runtime>::Compounded(0, balance!(15), balance!(1.5), balance!(13.5)).into(),
+            );
+            assert_eq!(
+                assets::Pallet::<Runtime>::free_balance(
+                    &ceres,
+                    &crate::FeeAccount::<Runtime>::get()
+                )
+                .unwrap(),
+                balance!(1.5)
+            );
+            assert_eq!(
+                DemeterVaults::share_info(0, &ALICE),
+                (balance!(100), balance!(200), balance!(106.75))
+            );
+
+            assert_err!(
+                DemeterVaults::withdraw(RuntimeOrigin::signed(ALICE), 0, balance!(101)),
+                Error::<Runtime>::InsufficientShares
+            );
+
+            let balance_before = assets::Pallet::<Runtime>::free_balance(&ceres, &ALICE).unwrap();
+            assert_ok!(DemeterVaults::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(100)
+            ));
+            assert_eq!(
+                assets::Pallet::<Runtime>::free_balance(&ceres, &ALICE).unwrap(),
+                balance_before + balance!(106.75)
+            );
+            assert_eq!(crate::Shares::<Runtime>::get(0, &ALICE), 0);
+            assert_eq!(
+                crate::Vaults::<Runtime>::get(0).unwrap().total_shares,
+                balance!(100)
+            );
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(&vault_account);
+            assert_eq!(user_infos[0].pooled_tokens, balance!(106.75));
+
+            // New deposits are minted at the current share price
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(106.75)
+            ));
+            assert_eq!(crate::Shares::<Runtime>::get(0, &ALICE), balance!(100));
+        });
+    }
+
+    #[test]
+    fn vaults_are_compounded_periodically() {
+        preset_initial(|| {
+            insert_pool(false, balance!(240));
+            create_vault(false);
+            let vault_account = DemeterVaults::vault_account_id(0);
+
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(100)
+            ));
+
+            run_to_block(3600);
+
+            // 4 distributions of 15 tokens minus performance fee
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(&vault_account);
+            assert_eq!(user_infos[0].pooled_tokens, balance!(154));
+        });
+    }
+
+    #[test]
+    fn vaults_are_compounded_in_batches() {
+        preset_initial(|| {
+            insert_pool(false, balance!(240));
+            insert_pool(true, balance!(240));
+            create_vault(false);
+            create_vault(true);
+
+            run_to_block(3600);
+            assert_eq!(crate::CompoundCursor::<Runtime>::get(), Some(1));
+
+            run_to_block(3601);
+            assert_eq!(crate::CompoundCursor::<Runtime>::get(), None);
+        });
+    }
+
+    #[test]
+    fn inactive_vault_allows_only_withdrawals() {
+        preset_initial(|| {
+            insert_pool(false, balance!(240));
+            create_vault(false);
+
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(100)
+            ));
+
+            assert_err!(
+                DemeterVaults::change_vault_status(RuntimeOrigin::signed(ALICE), 0, false),
+                Error::<Runtime>::Unauthorized
+            );
+            assert_ok!(DemeterVaults::change_vault_status(
+                RuntimeOrigin::signed(crate::AuthorityAccount::<Runtime>::get()),
+                0,
+                false
+            ));
+
+            assert_err!(
+                DemeterVaults::deposit(RuntimeOrigin::signed(ALICE), 0, balance!(100)),
+                Error::<Runtime>::VaultIsNotActive
+            );
+            assert_err!(
+                DemeterVaults::compound(RuntimeOrigin::signed(ALICE), 0),
+                Error::<Runtime>::VaultIsNotActive
+            );
+            assert_ok!(DemeterVaults::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                balance!(100)
+            ));
+        });
+    }
+
+    #[test]
+    fn farm_vault_adds_liquidity_when_compounding() {
+        preset_initial(|| {
+            let pool_account = pool_xyk::Properties::<Runtime>::get(
+                AssetId::from(XOR),
+                AssetId::from(CERES_ASSET_ID),
+            )
+            .unwrap()
+            .0;
+            assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+                RuntimeOrigin::signed(ALICE),
+                DEX_A_ID,
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+            ));
+            let lp_tokens = PoolXYK::balance_of_pool_provider(pool_account.clone(), ALICE).unwrap();
+
+            insert_pool(true, balance!(240));
+            create_vault(true);
+            let vault_account = DemeterVaults::vault_account_id(0);
+
+            assert_err!(
+                DemeterVaults::deposit(RuntimeOrigin::signed(ALICE), 0, lp_tokens + 1),
+                Error::<Runtime>::InsufficientLPTokens
+            );
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                lp_tokens / 2
+            ));
+            assert_eq!(
+                PoolXYK::balance_of_pool_provider(pool_account.clone(), vault_account.clone()),
+                Some(lp_tokens / 2)
+            );
+
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+            assert_ok!(DemeterVaults::compound(RuntimeOrigin::signed(CHARLES), 0));
+
+            let user_infos = demeter_farming_platform::UserInfos::<Runtime>::get(&vault_account);
+            let vault_lp_tokens =
+                PoolXYK::balance_of_pool_provider(pool_account.clone(), vault_account.clone())
+                    .unwrap();
+            assert!(vault_lp_tokens > lp_tokens / 2);
+            assert_eq!(user_infos[0].pooled_tokens, vault_lp_tokens);
+
+            assert_ok!(DemeterVaults::withdraw(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                lp_tokens / 2
+            ));
+            assert_eq!(
+                PoolXYK::balance_of_pool_provider(pool_account.clone(), ALICE),
+                Some(lp_tokens - lp_tokens / 2 + vault_lp_tokens)
+            );
+            assert_eq!(
+                PoolXYK::balance_of_pool_provider(pool_account, vault_account),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn farm_vault_compound_is_bounded_by_average_price() {
+        preset_initial(|| {
+            let xor: AssetId = XOR.into();
+            assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+                RuntimeOrigin::signed(ALICE),
+                DEX_A_ID,
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+            ));
+            let pool_account = pool_xyk::Properties::<Runtime>::get(
+                AssetId::from(XOR),
+                AssetId::from(CERES_ASSET_ID),
+            )
+            .unwrap()
+            .0;
+            let lp_tokens = PoolXYK::balance_of_pool_provider(pool_account, ALICE).unwrap();
+
+            insert_pool(true, balance!(240));
+            create_vault(true);
+            let vault_account = DemeterVaults::vault_account_id(0);
+            assert_ok!(DemeterVaults::deposit(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                lp_tokens
+            ));
+            demeter_farming_platform::Pallet::<Runtime>::on_initialize(900);
+
+            // Swap output is below the average price
+            MockAveragePrice::set(balance!(2));
+            assert_err!(
+                DemeterVaults::compound(RuntimeOrigin::signed(CHARLES), 0),
+                DispatchError::Other("Slippage")
+            );
+
+            // Donated tokens are not added to liquidity
+            assert_ok!(assets::Pallet::<Runtime>::transfer_from(
+                &xor,
+                &ALICE,
+                &vault_account,
+                balance!(100)
+            ));
+            MockAveragePrice::set(balance!(1));
+            assert_ok!(DemeterVaults::compound(RuntimeOrigin::signed(CHARLES), 0));
+
+            let vault = crate::Vaults::<Runtime>::get(0).unwrap();
+            assert_eq!(
+                assets::Pallet::<Runtime>::free_balance(&xor, &vault_account).unwrap(),
+                balance!(100) + vault.base_asset_leftover
+            );
+        });
+    }
+
+    #[test]
+    fn farm_vault_rejects_farmed_liquidity() {
+        preset_initial(|| {
+            assert_ok!(pool_xyk::Pallet::<Runtime>::deposit_liquidity(
+                RuntimeOrigin::signed(ALICE),
+                DEX_A_ID,
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+                balance!(1000),
+            ));
+            let pool_account = pool_xyk::Properties::<Runtime>::get(
+                AssetId::from(XOR),
+                AssetId::from(CERES_ASSET_ID),
+            )
+            .unwrap()
+            .0;
+            let lp_tokens = PoolXYK::balance_of_pool_provider(pool_account, ALICE).unwrap();
+
+            insert_pool(true, balance!(240));
+            create_vault(true);
+
+            assert_ok!(demeter_farming_platform::Pallet::<Runtime>::deposit(
+                RuntimeOrigin::signed(ALICE),
+                XOR.into(),
+                CERES_ASSET_ID.into(),
+                CERES_ASSET_ID.into(),
+                true,
+                lp_tokens,
+            ));
+
+            assert_err!(
+                DemeterVaults::deposit(RuntimeOrigin::signed(ALICE), 0, lp_tokens),
+                Error::<Runtime>::NotEnoughFreeLiquidity
+            );
+        });
+    }
+}
@@ -0,0 +1,284 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Weights for demeter_vaults
+//!
+//! These weights are hand estimates based on the storage accesses of the extrinsics and the
+//! benchmarked extrinsics of demeter farming platform, they were not produced by a benchmark
+//! run. They have to be replaced with the benchmark output before the pallet is used on a live
+//! network.
+
+// Command to replace the estimates with benchmarked weights:
+// ./target/release/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=demeter_vaults
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./file_header.txt
+// --template=./pallet-weight-template.hbs
+// --output=./pallets/demeter-vaults/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for demeter_vaults.
+pub trait WeightInfo {
+	fn create_vault() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn compound() -> Weight;
+	fn change_performance_fee() -> Weight;
+	fn change_vault_status() -> Weight;
+}
+
+/// Weights for demeter_vaults using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults VaultCount (r:1 w:1)
+	/// Proof Skipped: DemeterVaults VaultCount (max_values: Some(1), max_size: None, mode: Measured)
+	fn create_vault() -> Weight {
+		Weight::from_parts(39_104_000, 7364)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Shares (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Shares (max_values: None, max_size: None, mode: Measured)
+	fn deposit() -> Weight {
+		Weight::from_parts(173_942_000, 31952)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Shares (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Shares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw() -> Weight {
+		Weight::from_parts(130_271_000, 27418)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:0)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:10 w:10)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:4 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DemeterVaults FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DexManager DEXInfos (r:2 w:0)
+	/// Proof Skipped: DexManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:2 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Reserves (r:2 w:2)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK PoolProviders (r:2 w:1)
+	/// Proof Skipped: PoolXYK PoolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK TotalIssuances (r:1 w:1)
+	/// Proof Skipped: PoolXYK TotalIssuances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK AccountPools (r:1 w:1)
+	/// Proof Skipped: PoolXYK AccountPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TradingPair LockedLiquiditySources (r:1 w:0)
+	/// Proof Skipped: TradingPair LockedLiquiditySources (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Technical TechAccounts (r:2 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	fn compound() -> Weight {
+		Weight::from_parts(519_870_000, 112637)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	fn change_performance_fee() -> Weight {
+		Weight::from_parts(24_893_000, 3746)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	fn change_vault_status() -> Weight {
+		Weight::from_parts(24_552_000, 3746)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults VaultCount (r:1 w:1)
+	/// Proof Skipped: DemeterVaults VaultCount (max_values: Some(1), max_size: None, mode: Measured)
+	fn create_vault() -> Weight {
+		Weight::from_parts(39_104_000, 7364)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Shares (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Shares (max_values: None, max_size: None, mode: Measured)
+	fn deposit() -> Weight {
+		Weight::from_parts(173_942_000, 31952)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Shares (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Shares (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Number (r:1 w:0)
+	/// Proof: System Number (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw() -> Weight {
+		Weight::from_parts(130_271_000, 27418)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DemeterVaults Vaults (r:1 w:0)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:10 w:10)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: DemeterFarmingPlatform Pools (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform Pools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform UserInfos (r:1 w:1)
+	/// Proof Skipped: DemeterFarmingPlatform UserInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:4 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: DemeterVaults FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterFarmingPlatform FeeAccount (r:1 w:0)
+	/// Proof Skipped: DemeterFarmingPlatform FeeAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DexManager DEXInfos (r:2 w:0)
+	/// Proof Skipped: DexManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:2 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Reserves (r:2 w:2)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK PoolProviders (r:2 w:1)
+	/// Proof Skipped: PoolXYK PoolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK TotalIssuances (r:1 w:1)
+	/// Proof Skipped: PoolXYK TotalIssuances (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK AccountPools (r:1 w:1)
+	/// Proof Skipped: PoolXYK AccountPools (max_values: None, max_size: None, mode: Measured)
+	/// Storage: TradingPair LockedLiquiditySources (r:1 w:0)
+	/// Proof Skipped: TradingPair LockedLiquiditySources (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Technical TechAccounts (r:2 w:0)
+	/// Proof Skipped: Technical TechAccounts (max_values: None, max_size: None, mode: Measured)
+	fn compound() -> Weight {
+		Weight::from_parts(519_870_000, 112637)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	fn change_performance_fee() -> Weight {
+		Weight::from_parts(24_893_000, 3746)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DemeterVaults AuthorityAccount (r:1 w:0)
+	/// Proof Skipped: DemeterVaults AuthorityAccount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DemeterVaults Vaults (r:1 w:1)
+	/// Proof Skipped: DemeterVaults Vaults (max_values: None, max_size: None, mode: Measured)
+	fn change_vault_status() -> Weight {
+		Weight::from_parts(24_552_000, 3746)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
\ No newline at end of file
@@ -80,7 +80,8 @@ use bridge_types::GenericNetworkId;
 use codec::{Decode, Encode};
 use common::prelude::Balance;
 use common::{
-    AssetInfoProvider, AssetName, AssetSymbol, BalancePrecision, DEFAULT_BALANCE_PRECISION,
+    AssetInfoProvider, AssetName, AssetSymbol, BalancePrecision, OutflowLimit, OutflowWindow,
+    DEFAULT_BALANCE_PRECISION,
 };
 use core::stringify;
 use frame_support::dispatch::{DispatchError, DispatchResult};
@@ -172,6 +173,9 @@ pub const OFFCHAIN_TRANSACTION_WEIGHT_LIMIT: Weight =
     Weight::from_parts(10_000_000_000_000_000u64, 10_000_000_000_000_000u64);
 const MAX_PENDING_TX_BLOCKS_PERIOD: u32 = 100;
 const RE_HANDLE_TXS_PERIOD: u32 = 200;
+/// Number of blocks after registration of an outgoing transfer, when its author can ask for
+/// a refund (~7 days).
+pub const OUTGOING_TRANSFER_REFUND_TIMEOUT: u32 = 100_800;
 /// Minimum peers required to start bridge migration
 pub const MINIMUM_PEERS_FOR_MIGRATION: usize = 3;
 
@@ -323,6 +327,61 @@ pub enum BridgeSignatureVersion {
     V3,
 }
 
+/// Gas pricing strategy used by relayers when sending transactions to a sidechain network.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum GasStrategy {
+    /// Legacy transactions with a single `gasPrice`.
+    Legacy,
+    /// EIP-1559 transactions with `maxFeePerGas` and `maxPriorityFeePerGas`.
+    Eip1559,
+}
+
+/// Sidechain network settings. Allows to operate EVM networks other than Ethereum mainnet.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct NetworkSettings {
+    /// EVM chain id of the network. Off-chain workers check that the sidechain node they are
+    /// connected to serves this chain.
+    pub chain_id: u64,
+    /// Number of sidechain blocks which should be mined after a transaction before it's handled.
+    pub confirmations: u64,
+    /// Gas pricing strategy.
+    pub gas_strategy: GasStrategy,
+}
+
+impl NetworkSettings {
+    /// Settings used for networks without explicitly set ones.
+    pub fn ethereum() -> Self {
+        Self {
+            chain_id: 1,
+            confirmations: CONFIRMATION_INTERVAL,
+            gas_strategy: GasStrategy::Legacy,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.chain_id != 0
+            && self.confirmations != 0
+            // Re-handling of pending multisig calls relies on this.
+            && self.confirmations < MAX_PENDING_TX_BLOCKS_PERIOD as u64
+    }
+}
+
+/// Outgoing transfer exceeding the outflow limit. The transferred amount is reserved on the
+/// sender's account until the transfer is released or cancelled.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct QueuedOutflow<T: Config> {
+    pub from: T::AccountId,
+    pub to: EthAddress,
+    pub asset_id: AssetIdOf<T>,
+    pub amount: Balance,
+    pub network_id: BridgeNetworkId<T>,
+    /// Block number after which anyone can release the transfer.
+    pub release_at: T::BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -425,6 +484,8 @@ pub mod pallet {
         /// - `bridge_contract_address` - address of smart-contract deployed on a corresponding
         /// network.
         /// - `initial_peers` - a set of initial network peers.
+        /// - `signature_version` - bridge function signature version.
+        /// - `network_settings` - chain id, confirmations and gas strategy of the network.
         #[transactional]
         #[pallet::call_index(0)]
         // eth-bridge pallet will be deprecated soon, so we set const weight here
@@ -434,10 +495,15 @@ pub mod pallet {
             bridge_contract_address: EthAddress,
             initial_peers: Vec<T::AccountId>,
             signature_version: BridgeSignatureVersion,
+            network_settings: NetworkSettings,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
             let net_id = NextNetworkId::<T>::get();
             ensure!(!initial_peers.is_empty(), Error::<T>::NotEnoughPeers);
+            ensure!(
+                network_settings.is_valid(),
+                Error::<T>::InvalidNetworkSettings
+            );
             let peers_account_id = bridge_multisig::Pallet::<T>::register_multisig_inner(
                 initial_peers[0].clone(),
                 initial_peers.clone(),
@@ -446,6 +512,7 @@ pub mod pallet {
             BridgeAccount::<T>::insert(net_id, peers_account_id);
             BridgeStatuses::<T>::insert(net_id, BridgeStatus::Initialized);
             BridgeSignatureVersions::<T>::insert(net_id, signature_version);
+            Self::update_network_settings(net_id, network_settings);
             Peers::<T>::insert(net_id, initial_peers.into_iter().collect::<BTreeSet<_>>());
             NextNetworkId::<T>::set(net_id + T::NetworkId::one());
             Ok(().into())
@@ -551,20 +618,28 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             debug!("called transfer_to_sidechain");
             let from = ensure_signed(origin)?;
-            let nonce = frame_system::Pallet::<T>::account_nonce(&from);
-            let timepoint = bridge_multisig::Pallet::<T>::thischain_timepoint();
-            Self::add_request(&OffchainRequest::outgoing(OutgoingRequest::Transfer(
-                OutgoingTransfer {
-                    from: from.clone(),
-                    to,
-                    asset_id,
-                    amount,
-                    nonce,
-                    network_id,
-                    timepoint,
-                },
-            )))?;
-            frame_system::Pallet::<T>::inc_account_nonce(&from);
+            ensure!(
+                !PausedNetworks::<T>::get(network_id),
+                Error::<T>::NetworkPaused
+            );
+            if let Some(limit) = OutflowLimits::<T>::get(network_id, &asset_id) {
+                let now = frame_system::Pallet::<T>::block_number();
+                let fits = OutflowWindows::<T>::mutate(network_id, &asset_id, |window| {
+                    window.try_consume(&limit, amount, now)
+                });
+                if !fits {
+                    Self::queue_outflow(QueuedOutflow {
+                        from,
+                        to,
+                        asset_id,
+                        amount,
+                        network_id,
+                        release_at: now.saturating_add(limit.release_delay),
+                    })?;
+                    return Ok(().into());
+                }
+            }
+            Self::add_outgoing_transfer(from, to, asset_id, amount, network_id)?;
             Ok(().into())
         }
 
@@ -1023,6 +1098,205 @@ pub mod pallet {
             SidechainAssetPrecision::<T>::insert(network_id, &asset_id, precision);
             Ok(().into())
         }
+
+        /// Set sidechain network settings (chain id, confirmations and gas strategy).
+        ///
+        /// Can only be called by root.
+        #[pallet::call_index(17)]
+        // eth-bridge pallet will be deprecated soon, so we set const weight here
+        #[pallet::weight(EXTRINSIC_FIXED_WEIGHT)]
+        pub fn set_network_settings(
+            origin: OriginFor<T>,
+            network_id: BridgeNetworkId<T>,
+            network_settings: NetworkSettings,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                BridgeStatuses::<T>::contains_key(network_id),
+                Error::<T>::UnknownNetwork
+            );
+            ensure!(
+                network_settings.is_valid(),
+                Error::<T>::InvalidNetworkSettings
+            );
+            Self::update_network_settings(network_id, network_settings);
+            Self::deposit_event(Event::NetworkSettingsChanged(network_id, network_settings));
+            Ok(().into())
+        }
+
+        /// Set or remove the outflow limit of the asset for the given network.
+        ///
+        /// Transfers exceeding the limit are queued and can be released after the release delay.
+        ///
+        /// Can only be called by root.
+        #[pallet::call_index(18)]
+        // eth-bridge pallet will be deprecated soon, so we set const weight here
+        #[pallet::weight(EXTRINSIC_FIXED_WEIGHT)]
+        pub fn set_outflow_limit(
+            origin: OriginFor<T>,
+            network_id: BridgeNetworkId<T>,
+            asset_id: AssetIdOf<T>,
+            limit: Option<OutflowLimit<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                BridgeStatuses::<T>::contains_key(network_id),
+                Error::<T>::UnknownNetwork
+            );
+            match limit {
+                Some(limit) => {
+                    ensure!(limit.is_valid(), Error::<T>::InvalidOutflowLimit);
+                    OutflowLimits::<T>::insert(network_id, &asset_id, limit);
+                }
+                None => {
+                    OutflowLimits::<T>::remove(network_id, &asset_id);
+                    OutflowWindows::<T>::remove(network_id, &asset_id);
+                }
+            }
+            Self::deposit_event(Event::OutflowLimitSet(network_id, asset_id, limit));
+            Ok(().into())
+        }
+
+        /// Pause or resume outgoing transfers to the given network.
+        ///
+        /// Can only be called by root.
+        #[pallet::call_index(19)]
+        // eth-bridge pallet will be deprecated soon, so we set const weight here
+        #[pallet::weight(EXTRINSIC_FIXED_WEIGHT)]
+        pub fn set_network_paused(
+            origin: OriginFor<T>,
+            network_id: BridgeNetworkId<T>,
+            paused: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                BridgeStatuses::<T>::contains_key(network_id),
+                Error::<T>::UnknownNetwork
+            );
+            if paused {
+                PausedNetworks::<T>::insert(network_id, true);
+            } else {
+                PausedNetworks::<T>::remove(network_id);
+            }
+            Self::deposit_event(Event::NetworkPauseChanged(network_id, paused));
+            Ok(().into())
+        }
+
+        /// Release the queued outgoing transfer and register it as a bridge request.
+        ///
+        /// Can be called by anyone after the release delay has passed, or by root at any time.
+        #[transactional]
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_to_sidechain())]
+        pub fn release_queued_outflow(
+            origin: OriginFor<T>,
+            outflow_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = frame_system::ensure_signed_or_root(origin)?;
+            let outflow =
+                QueuedOutflows::<T>::get(outflow_id).ok_or(Error::<T>::QueuedOutflowNotFound)?;
+            if who.is_some() {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() >= outflow.release_at,
+                    Error::<T>::QueuedOutflowIsLocked
+                );
+            }
+            ensure!(
+                !PausedNetworks::<T>::get(outflow.network_id),
+                Error::<T>::NetworkPaused
+            );
+            // Released outflow is counted in the window it's released in, so the following
+            // transfers can't exceed the limit.
+            if let Some(limit) = OutflowLimits::<T>::get(outflow.network_id, &outflow.asset_id) {
+                let now = frame_system::Pallet::<T>::block_number();
+                OutflowWindows::<T>::mutate(outflow.network_id, &outflow.asset_id, |window| {
+                    window.consume(limit.window, outflow.amount, now)
+                });
+            }
+            QueuedOutflows::<T>::remove(outflow_id);
+            let remainder =
+                assets::Pallet::<T>::unreserve(&outflow.asset_id, &outflow.from, outflow.amount)?;
+            ensure!(remainder == 0, Error::<T>::FailedToUnreserve);
+            let tx_hash = Self::add_outgoing_transfer(
+                outflow.from,
+                outflow.to,
+                outflow.asset_id,
+                outflow.amount,
+                outflow.network_id,
+            )?;
+            Self::deposit_event(Event::QueuedOutflowReleased(outflow_id, tx_hash));
+            Ok(().into())
+        }
+
+        /// Cancel the queued outgoing transfer and return the reserved funds to the sender.
+        ///
+        /// Can be called by the sender or root.
+        #[transactional]
+        #[pallet::call_index(21)]
+        // eth-bridge pallet will be deprecated soon, so we set const weight here
+        #[pallet::weight(EXTRINSIC_FIXED_WEIGHT)]
+        pub fn cancel_queued_outflow(
+            origin: OriginFor<T>,
+            outflow_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = frame_system::ensure_signed_or_root(origin)?;
+            let outflow =
+                QueuedOutflows::<T>::get(outflow_id).ok_or(Error::<T>::QueuedOutflowNotFound)?;
+            if let Some(who) = who {
+                ensure!(who == outflow.from, Error::<T>::Forbidden);
+            }
+            QueuedOutflows::<T>::remove(outflow_id);
+            let remainder =
+                assets::Pallet::<T>::unreserve(&outflow.asset_id, &outflow.from, outflow.amount)?;
+            ensure!(remainder == 0, Error::<T>::FailedToUnreserve);
+            Self::deposit_event(Event::QueuedOutflowCancelled(outflow_id));
+            Ok(().into())
+        }
+
+        /// Ask for a refund of an outgoing transfer which wasn't executed on Sidechain.
+        ///
+        /// Can be called by the author of the transfer after `OUTGOING_TRANSFER_REFUND_TIMEOUT`
+        /// blocks since the request registration. Only pending requests without any approvals are
+        /// refunded. Peer signatures are public, so even a partially approved request can be
+        /// completed and executed on Sidechain, thus it's never refunded.
+        #[transactional]
+        #[pallet::call_index(22)]
+        // eth-bridge pallet will be deprecated soon, so we set const weight here
+        #[pallet::weight(EXTRINSIC_FIXED_WEIGHT)]
+        pub fn request_refund(
+            origin: OriginFor<T>,
+            hash: H256,
+            network_id: BridgeNetworkId<T>,
+        ) -> DispatchResultWithPostInfo {
+            debug!("called request_refund. Hash: {:?}", hash);
+            let author = ensure_signed(origin)?;
+            let request = Requests::<T>::get(network_id, hash).ok_or(Error::<T>::UnknownRequest)?;
+            let Some((OutgoingRequest::Transfer(transfer), _)) = request.as_outgoing() else {
+                fail!(Error::<T>::RefundIsNotAvailable);
+            };
+            ensure!(transfer.from == author, Error::<T>::Forbidden);
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let refund_block = RequestSubmissionHeight::<T>::get(network_id, hash)
+                .saturating_add(OUTGOING_TRANSFER_REFUND_TIMEOUT.into());
+            ensure!(
+                current_block >= refund_block,
+                Error::<T>::RequestIsNotExpired
+            );
+            ensure!(
+                RequestApprovals::<T>::get(network_id, hash).is_empty(),
+                Error::<T>::RefundIsNotAvailable
+            );
+            match RequestStatuses::<T>::get(network_id, hash) {
+                Some(RequestStatus::Pending) => {
+                    transfer.refund(hash)?;
+                    RequestStatuses::<T>::insert(network_id, hash, RequestStatus::Refunded);
+                    Self::remove_request_from_queue(network_id, &hash);
+                    Self::deposit_event(Event::RequestRefunded(hash));
+                }
+                _ => fail!(Error::<T>::RefundIsNotAvailable),
+            }
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -1044,6 +1318,25 @@ pub mod pallet {
         CancellationFailed(H256),
         /// The request registration has been failed. [Request Hash, Error]
         RegisterRequestFailed(H256, DispatchError),
+        /// Sidechain network settings have been changed. [Network Id, Settings]
+        NetworkSettingsChanged(BridgeNetworkId<T>, NetworkSettings),
+        /// Outflow limit of the asset has been set or removed. [Network Id, Asset Id, Limit]
+        OutflowLimitSet(
+            BridgeNetworkId<T>,
+            AssetIdOf<T>,
+            Option<OutflowLimit<T::BlockNumber>>,
+        ),
+        /// Outgoing transfers to the network have been paused or resumed. [Network Id, Paused]
+        NetworkPauseChanged(BridgeNetworkId<T>, bool),
+        /// Outgoing transfer exceeding the outflow limit has been queued.
+        /// [Queued Outflow Id, Sender, Asset Id, Amount, Release Block]
+        OutflowQueued(u64, T::AccountId, AssetIdOf<T>, Balance, T::BlockNumber),
+        /// Queued outgoing transfer has been released. [Queued Outflow Id, Request Hash]
+        QueuedOutflowReleased(u64, H256),
+        /// Queued outgoing transfer has been cancelled and refunded. [Queued Outflow Id]
+        QueuedOutflowCancelled(u64),
+        /// The outgoing transfer was refunded to its author. [Request Hash]
+        RequestRefunded(H256),
     }
 
     #[cfg_attr(test, derive(PartialEq, Eq))]
@@ -1221,6 +1514,22 @@ pub mod pallet {
         ReadStorageError,
         /// Bridge needs to have at least 3 peers for migration. Add new peer
         UnsafeMigration,
+        /// Invalid sidechain network settings.
+        InvalidNetworkSettings,
+        /// Sidechain node serves a chain with unexpected chain id.
+        SidechainChainIdMismatch,
+        /// Outgoing transfers to the network are paused.
+        NetworkPaused,
+        /// Invalid outflow limit.
+        InvalidOutflowLimit,
+        /// Queued outflow not found.
+        QueuedOutflowNotFound,
+        /// Queued outflow can't be released yet.
+        QueuedOutflowIsLocked,
+        /// Refund timeout of the request hasn't passed yet.
+        RequestIsNotExpired,
+        /// The request can't be refunded.
+        RefundIsNotAvailable,
     }
 
     impl<T: Config> Error<T> {
@@ -1442,6 +1751,56 @@ pub mod pallet {
         BridgeSignatureVersion::V3
     }
 
+    /// Sidechain network settings. Ethereum mainnet settings are used if not set.
+    #[pallet::storage]
+    pub(super) type SidechainNetworkSettings<T: Config> =
+        StorageMap<_, Twox64Concat, BridgeNetworkId<T>, NetworkSettings>;
+
+    /// Block number at which sidechain network settings were last changed. Used by off-chain
+    /// workers to re-verify the sidechain node.
+    #[pallet::storage]
+    pub(super) type SidechainNetworkSettingsUpdatedAt<T: Config> =
+        StorageMap<_, Twox64Concat, BridgeNetworkId<T>, T::BlockNumber, ValueQuery>;
+
+    /// Outflow limits of assets per network.
+    #[pallet::storage]
+    #[pallet::getter(fn outflow_limit)]
+    pub(super) type OutflowLimits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BridgeNetworkId<T>,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        OutflowLimit<T::BlockNumber>,
+    >;
+
+    /// Outflow accounting of limited assets per network.
+    #[pallet::storage]
+    pub(super) type OutflowWindows<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BridgeNetworkId<T>,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        OutflowWindow<T::BlockNumber>,
+        ValueQuery,
+    >;
+
+    /// Networks with paused outgoing transfers.
+    #[pallet::storage]
+    #[pallet::getter(fn is_network_paused)]
+    pub(super) type PausedNetworks<T: Config> =
+        StorageMap<_, Twox64Concat, BridgeNetworkId<T>, bool, ValueQuery>;
+
+    /// Outgoing transfers exceeding the outflow limits.
+    #[pallet::storage]
+    #[pallet::getter(fn queued_outflow)]
+    pub(super) type QueuedOutflows<T: Config> = StorageMap<_, Twox64Concat, u64, QueuedOutflow<T>>;
+
+    /// Next queued outflow id counter.
+    #[pallet::storage]
+    pub(super) type NextQueuedOutflowId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn pending_bridge_signature_version)]
     pub(super) type PendingBridgeSignatureVersions<T: Config> =
@@ -1525,6 +1884,78 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+    /// Returns settings of the sidechain network.
+    pub fn network_settings(network_id: BridgeNetworkId<T>) -> NetworkSettings {
+        SidechainNetworkSettings::<T>::get(network_id).unwrap_or_else(NetworkSettings::ethereum)
+    }
+
+    fn update_network_settings(network_id: BridgeNetworkId<T>, network_settings: NetworkSettings) {
+        SidechainNetworkSettings::<T>::insert(network_id, network_settings);
+        SidechainNetworkSettingsUpdatedAt::<T>::insert(
+            network_id,
+            frame_system::Pallet::<T>::block_number(),
+        );
+    }
+
+    /// Registers an outgoing transfer request and increments the sender's nonce.
+    fn add_outgoing_transfer(
+        from: T::AccountId,
+        to: EthAddress,
+        asset_id: AssetIdOf<T>,
+        amount: Balance,
+        network_id: BridgeNetworkId<T>,
+    ) -> Result<H256, DispatchError> {
+        let nonce = frame_system::Pallet::<T>::account_nonce(&from);
+        let timepoint = bridge_multisig::Pallet::<T>::thischain_timepoint();
+        let request = OffchainRequest::outgoing(OutgoingRequest::Transfer(OutgoingTransfer {
+            from: from.clone(),
+            to,
+            asset_id,
+            amount,
+            nonce,
+            network_id,
+            timepoint,
+        }));
+        let tx_hash = request.hash();
+        Self::add_request(&request)?;
+        frame_system::Pallet::<T>::inc_account_nonce(&from);
+        Ok(tx_hash)
+    }
+
+    /// Validates the outgoing transfer, reserves the transferred amount on the sender's account
+    /// and puts the transfer to the queue.
+    fn queue_outflow(outflow: QueuedOutflow<T>) -> Result<u64, DispatchError> {
+        ensure!(
+            BridgeStatuses::<T>::contains_key(outflow.network_id),
+            Error::<T>::UnknownNetwork
+        );
+        OutgoingTransfer::<T> {
+            from: outflow.from.clone(),
+            to: outflow.to,
+            asset_id: outflow.asset_id.clone(),
+            amount: outflow.amount,
+            nonce: frame_system::Pallet::<T>::account_nonce(&outflow.from),
+            network_id: outflow.network_id,
+            timepoint: bridge_multisig::Pallet::<T>::thischain_timepoint(),
+        }
+        .validate()?;
+        assets::Pallet::<T>::reserve(&outflow.asset_id, &outflow.from, outflow.amount)?;
+        let outflow_id = NextQueuedOutflowId::<T>::mutate(|id| {
+            let current = *id;
+            *id = id.wrapping_add(1);
+            current
+        });
+        Self::deposit_event(Event::OutflowQueued(
+            outflow_id,
+            outflow.from.clone(),
+            outflow.asset_id.clone(),
+            outflow.amount,
+            outflow.release_at,
+        ));
+        QueuedOutflows::<T>::insert(outflow_id, outflow);
+        Ok(outflow_id)
+    }
+
     /// Registers the given off-chain request.
     ///
     /// Conditions for registering:
@@ -1775,9 +2206,13 @@ impl<T: Config> Pallet<T> {
         let need_sigs = majority(Self::peers(net_id).len()) + pending_peers_len;
         let current_status =
             RequestStatuses::<T>::get(net_id, &hash).ok_or(Error::<T>::UnknownRequest)?;
+        ensure!(
+            current_status == RequestStatus::Pending,
+            Error::<T>::ExpectedPendingRequest
+        );
         approvals.insert(signature_params);
         RequestApprovals::<T>::insert(net_id, &hash, &approvals);
-        if current_status == RequestStatus::Pending && approvals.len() == need_sigs {
+        if approvals.len() == need_sigs {
             if let Err(err) = request.finalize(hash) {
                 error!("Outgoing request finalization failed: {:?}", err);
                 RequestStatuses::<T>::insert(net_id, hash, RequestStatus::Failed(err));
@@ -1831,21 +2266,12 @@ impl<T: Config> BridgeApp<T::AccountId, EthAddress, T::AssetId, Balance> for Pal
     ) -> Result<H256, DispatchError> {
         debug!("called BridgeApp::transfer");
         let network_id = Self::ensure_generic_network(network_id)?;
-        let nonce = frame_system::Pallet::<T>::account_nonce(&sender);
-        let timepoint = bridge_multisig::Pallet::<T>::thischain_timepoint();
-        let request = OffchainRequest::outgoing(OutgoingRequest::Transfer(OutgoingTransfer {
-            from: sender.clone(),
-            to: recipient,
-            asset_id,
-            amount,
-            nonce,
-            network_id,
-            timepoint,
-        }));
-        let tx_hash = request.hash();
-        Self::add_request(&request)?;
-        frame_system::Pallet::<T>::inc_account_nonce(&sender);
-        Ok(tx_hash)
+        // Outflow limits of the transfers made through the bridge proxy are applied by the proxy.
+        ensure!(
+            !PausedNetworks::<T>::get(network_id),
+            Error::<T>::NetworkPaused
+        );
+        Self::add_outgoing_transfer(sender, recipient, asset_id, amount, network_id)
     }
 
     fn refund(
@@ -37,10 +37,10 @@ use crate::requests::{
 };
 use crate::types::SubstrateBlockLimited;
 use crate::{
-    Call, Config, Error, Pallet, RequestStatuses, Requests, RequestsQueue, CONFIRMATION_INTERVAL,
-    MAX_FAILED_SEND_SIGNED_TX_RETRIES, MAX_GET_LOGS_ITEMS, MAX_PENDING_TX_BLOCKS_PERIOD,
-    MAX_SUCCESSFUL_SENT_SIGNED_TX_PER_ONCE, RE_HANDLE_TXS_PERIOD,
-    STORAGE_FAILED_PENDING_TRANSACTIONS_KEY, STORAGE_PEER_SECRET_KEY,
+    Call, Config, Error, Pallet, RequestStatuses, Requests, RequestsQueue,
+    SidechainNetworkSettings, SidechainNetworkSettingsUpdatedAt, MAX_FAILED_SEND_SIGNED_TX_RETRIES,
+    MAX_GET_LOGS_ITEMS, MAX_PENDING_TX_BLOCKS_PERIOD, MAX_SUCCESSFUL_SENT_SIGNED_TX_PER_ONCE,
+    RE_HANDLE_TXS_PERIOD, STORAGE_FAILED_PENDING_TRANSACTIONS_KEY, STORAGE_PEER_SECRET_KEY,
     STORAGE_PENDING_TRANSACTIONS_KEY, STORAGE_SUB_TO_HANDLE_FROM_HEIGHT_KEY,
     SUBSTRATE_HANDLE_BLOCK_COUNT_PER_BLOCK, SUBSTRATE_MAX_BLOCK_NUM_EXPECTING_UNTIL_FINALIZATION,
 };
@@ -114,7 +114,7 @@ impl<T: Config> Pallet<T> {
     /// For an incoming request, a premise for its finalization will be block confirmation in PoW
     /// consensus. Since the confirmation is probabilistic, we need to choose a relatively large
     /// number of how many blocks should be mined after a corresponding transaction
-    /// (`CONFIRMATION_INTERVAL`).
+    /// (`NetworkSettings::confirmations`).
     ///
     /// An off-chain worker keeps track of already handled requests in local storage.
     fn handle_pending_incoming_requests(
@@ -487,7 +487,37 @@ impl<T: Config> Pallet<T> {
         Ok(substrate_finalized_height)
     }
 
+    /// Checks that the sidechain node serves the chain set in the network settings. The verified
+    /// chain id is saved to the local storage to not query the node on each block. The saved value
+    /// is invalidated when the network settings are changed.
+    fn ensure_sidechain_chain_id(network_id: T::NetworkId) -> Result<(), Error<T>> {
+        let expected_chain_id = match SidechainNetworkSettings::<T>::get(network_id) {
+            Some(settings) => settings.chain_id,
+            None => return Ok(()),
+        };
+        let updated_at = SidechainNetworkSettingsUpdatedAt::<T>::get(network_id);
+        let string = format!("eth-bridge-ocw::verified-chain-id-{:?}", network_id);
+        let s_chain_id = StorageValueRef::persistent(string.as_bytes());
+        if s_chain_id.get::<(u64, T::BlockNumber)>().ok().flatten()
+            == Some((expected_chain_id, updated_at))
+        {
+            return Ok(());
+        }
+        let chain_id = Self::load_chain_id(network_id)?;
+        if chain_id != expected_chain_id {
+            error!(
+                "Sidechain node of network {:?} serves chain {}, expected {}.",
+                network_id, chain_id, expected_chain_id
+            );
+            return Err(Error::<T>::SidechainChainIdMismatch);
+        }
+        s_chain_id.set(&(chain_id, updated_at));
+        Ok(())
+    }
+
     fn handle_ethereum(network_id: T::NetworkId) -> Result<u64, Error<T>> {
+        Self::ensure_sidechain_chain_id(network_id)?;
+        let confirmations = Self::network_settings(network_id).confirmations;
         let string = format!("eth-bridge-ocw::eth-height-{:?}", network_id);
         let s_eth_height = StorageValueRef::persistent(string.as_bytes());
         let current_eth_height = match Self::load_current_height(network_id) {
@@ -519,7 +549,7 @@ impl<T: Config> Pallet<T> {
         // `MAX_GET_LOGS_ITEMS` if the OCW is lagging behind Ethereum to avoid downloading too many
         // logs.
         let to_block_opt = current_eth_height
-            .checked_sub(CONFIRMATION_INTERVAL)
+            .checked_sub(confirmations)
             .map(|to_block| (from_block + MAX_GET_LOGS_ITEMS).min(to_block));
         if let Some(to_block) = to_block_opt {
             if to_block >= from_block {
@@ -566,7 +596,7 @@ impl<T: Config> Pallet<T> {
                 }
             };
             debug!("Re-handling ethereum height {}", from_block);
-            // +1 block should be ok, because MAX_PENDING_TX_BLOCKS_PERIOD > CONFIRMATION_INTERVAL.
+            // +1 block should be ok, because MAX_PENDING_TX_BLOCKS_PERIOD > confirmations.
             let err_opt = Self::handle_logs(from_block, from_block + 1, &mut 0, network_id).err();
             if let Some(err) = err_opt {
                 warn!("Failed to re-handle logs: {:?}.", err);
@@ -613,6 +643,7 @@ impl<T: Config> Pallet<T> {
             Self::handle_pending_multisig_calls(network_id, current_eth_height);
         }
 
+        let confirmations = Self::network_settings(network_id).confirmations;
         for request_hash in RequestsQueue::<T>::get(network_id) {
             let request = match Requests::<T>::get(network_id, request_hash) {
                 Some(v) => v,
@@ -640,7 +671,7 @@ impl<T: Config> Pallet<T> {
             };
             let confirmed = match &request {
                 OffchainRequest::Incoming(request, _) => {
-                    current_eth_height.saturating_sub(request.at_height()) >= CONFIRMATION_INTERVAL
+                    current_eth_height.saturating_sub(request.at_height()) >= confirmations
                 }
                 _ => true,
             };
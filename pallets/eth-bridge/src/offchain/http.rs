@@ -240,6 +240,12 @@ impl<T: Config> Pallet<T> {
             .map(|x| x.as_u64())
     }
 
+    /// Queries chain id of Sidechain.
+    pub fn load_chain_id(network_id: T::NetworkId) -> Result<u64, Error<T>> {
+        Self::eth_json_rpc_request::<_, types::U64>("eth_chainId", &(), network_id)
+            .map(|x| x.as_u64())
+    }
+
     /// Loads a Sidechain transaction by the hash and ensures that it came from a known contract.
     pub fn load_tx(hash: H256, network_id: T::NetworkId) -> Result<Transaction, Error<T>> {
         let hash = types::H256(hash.0);
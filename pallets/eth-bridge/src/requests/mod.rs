@@ -912,6 +912,7 @@ impl OutgoingRequestEncoded {
 /// - ApprovalsReady: request was approved and can be used in the sidechain.
 /// - Failed: an error occurred in one of the previous stages.
 /// - Done: request was finalized.
+/// - Refunded: the pending outgoing transfer was refunded to its author.
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum RequestStatus {
@@ -922,6 +923,7 @@ pub enum RequestStatus {
     Done,
     /// Request is broken. Tried to abort with the first error but got another one when cancelling.
     Broken(DispatchError, DispatchError),
+    Refunded,
 }
 
 /// A type of asset registered on a bridge.
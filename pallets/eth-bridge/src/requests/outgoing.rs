@@ -223,6 +223,18 @@ impl<T: Config> OutgoingTransfer<T> {
         })
     }
 
+    /// Returns the reserved amount of a pending request to the sender.
+    pub fn refund(&self, tx_hash: H256) -> Result<(), DispatchError> {
+        self.cancel()?;
+        T::MessageStatusNotifier::update_status(
+            GenericNetworkId::EVMLegacy(self.network_id.unique_saturated_into()),
+            tx_hash,
+            MessageStatus::Refunded,
+            GenericTimepoint::Unknown,
+        );
+        Ok(())
+    }
+
     /// Validates the request again, then, if the asset is originated in Sidechain, it gets burned.
     pub fn finalize(&self, tx_hash: H256) -> Result<(), DispatchError> {
         self.validate()?;
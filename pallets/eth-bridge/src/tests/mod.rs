@@ -35,7 +35,7 @@ use crate::requests::{
 use crate::tests::mock::*;
 use crate::util::majority;
 use common::eth;
-use frame_support::dispatch::{Pays, PostDispatchInfo};
+use frame_support::dispatch::{DispatchResultWithPostInfo, Pays, PostDispatchInfo};
 use frame_support::{assert_ok, ensure};
 
 use secp256k1::{PublicKey, SecretKey};
@@ -47,9 +47,12 @@ mod cancel;
 mod ethabi;
 mod incoming_transfer;
 pub mod mock;
+mod network;
 mod ocw;
+mod outflow;
 mod outgoing_tranfser;
 mod peer;
+mod refund;
 
 pub(crate) type Error = crate::Error<Runtime>;
 pub(crate) type Assets = assets::Pallet<Runtime>;
@@ -99,6 +102,11 @@ pub fn approve_request(
     let mut approvals = BTreeSet::new();
     let keypairs = &state.networks[&net_id].ocw_keypairs;
     for (i, (_signer, account_id, seed)) in keypairs.iter().enumerate() {
+        let current_status = crate::RequestStatuses::<Runtime>::get(net_id, &request_hash).unwrap();
+        // Approvals are only accepted while the request is pending.
+        if current_status != RequestStatus::Pending {
+            break;
+        }
         let secret = SecretKey::parse_slice(seed).unwrap();
         let public = PublicKey::from_secret_key(&secret);
         let msg = eth::prepare_message(encoded.as_raw());
@@ -111,7 +119,6 @@ pub fn approve_request(
             0
         };
         let sigs_needed = majority(keypairs.len()) + additional_sigs;
-        let current_status = crate::RequestStatuses::<Runtime>::get(net_id, &request_hash).unwrap();
         ensure!(
             EthBridge::approve_request(
                 RuntimeOrigin::signed(account_id.clone()),
@@ -123,7 +130,7 @@ pub fn approve_request(
             .is_ok(),
             None
         );
-        if current_status == RequestStatus::Pending && i + 1 == sigs_needed {
+        if i + 1 == sigs_needed {
             match last_event().ok_or(None)? {
                 RuntimeEvent::EthBridge(bridge_event) => match bridge_event {
                     crate::Event::ApprovalsCollected(h) => {
@@ -151,6 +158,29 @@ pub fn approve_request(
     Ok(())
 }
 
+/// Sends an approval of the request signed by the peer with the given index.
+pub fn approve_request_by_peer(
+    state: &State,
+    request: &OutgoingRequest<Runtime>,
+    request_hash: H256,
+    peer_index: usize,
+) -> DispatchResultWithPostInfo {
+    let encoded = request.to_eth_abi(request_hash).unwrap();
+    let net_id = request.network_id();
+    let (_signer, account_id, seed) = &state.networks[&net_id].ocw_keypairs[peer_index];
+    let secret = SecretKey::parse_slice(seed).unwrap();
+    let public = PublicKey::from_secret_key(&secret);
+    let msg = eth::prepare_message(encoded.as_raw());
+    let sig_pair = secp256k1::sign(&msg, &secret);
+    EthBridge::approve_request(
+        RuntimeOrigin::signed(account_id.clone()),
+        ecdsa::Public::from_raw(public.serialize_compressed()),
+        request_hash,
+        get_signature_params(&sig_pair),
+        net_id,
+    )
+}
+
 pub fn last_request(net_id: u32) -> Option<OffchainRequest<Runtime>> {
     let request_hash = crate::RequestsQueue::<Runtime>::get(net_id)
         .last()
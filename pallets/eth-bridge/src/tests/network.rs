@@ -0,0 +1,173 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::mock::*;
+use super::{assert_last_event, Error, ETH_NETWORK_ID};
+use crate::tests::mock::{get_account_id_from_seed, ExtBuilder};
+use crate::{
+    BridgeSignatureVersion, EthAddress, GasStrategy, NetworkSettings,
+    SidechainNetworkSettingsUpdatedAt, CONFIRMATION_INTERVAL,
+};
+use frame_support::sp_runtime::app_crypto::sp_core::sr25519;
+use frame_support::sp_runtime::DispatchError;
+use frame_support::{assert_noop, assert_ok};
+
+fn bsc_settings() -> NetworkSettings {
+    NetworkSettings {
+        chain_id: 56,
+        confirmations: 15,
+        gas_strategy: GasStrategy::Eip1559,
+    }
+}
+
+#[test]
+fn should_use_ethereum_settings_by_default() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let settings = EthBridge::network_settings(ETH_NETWORK_ID);
+        assert_eq!(settings, NetworkSettings::ethereum());
+        assert_eq!(settings.confirmations, CONFIRMATION_INTERVAL);
+        assert_eq!(settings.gas_strategy, GasStrategy::Legacy);
+    });
+}
+
+#[test]
+fn should_register_bridge_with_network_settings() {
+    let (mut ext, state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let net_id = ETH_NETWORK_ID + 1;
+        let peers: Vec<_> = state.networks[&ETH_NETWORK_ID]
+            .config
+            .initial_peers
+            .iter()
+            .cloned()
+            .collect();
+        assert_ok!(EthBridge::register_bridge(
+            RuntimeOrigin::root(),
+            EthAddress::from([1; 20]),
+            peers.clone(),
+            BridgeSignatureVersion::V3,
+            bsc_settings(),
+        ));
+        assert_eq!(EthBridge::network_settings(net_id), bsc_settings());
+        assert_eq!(EthBridge::peers(net_id), peers.into_iter().collect());
+        // Settings of the other networks are not affected.
+        assert_eq!(
+            EthBridge::network_settings(ETH_NETWORK_ID),
+            NetworkSettings::ethereum()
+        );
+    });
+}
+
+#[test]
+fn should_not_register_bridge_with_invalid_settings() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let peers = vec![get_account_id_from_seed::<sr25519::Public>("Alice")];
+        for settings in [
+            NetworkSettings {
+                chain_id: 0,
+                ..bsc_settings()
+            },
+            NetworkSettings {
+                confirmations: 0,
+                ..bsc_settings()
+            },
+            NetworkSettings {
+                confirmations: 1000,
+                ..bsc_settings()
+            },
+        ] {
+            assert_noop!(
+                EthBridge::register_bridge(
+                    RuntimeOrigin::root(),
+                    EthAddress::from([1; 20]),
+                    peers.clone(),
+                    BridgeSignatureVersion::V3,
+                    settings,
+                ),
+                Error::InvalidNetworkSettings
+            );
+        }
+    });
+}
+
+#[test]
+fn should_set_network_settings() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(EthBridge::set_network_settings(
+            RuntimeOrigin::root(),
+            ETH_NETWORK_ID,
+            bsc_settings(),
+        ));
+        assert_eq!(EthBridge::network_settings(ETH_NETWORK_ID), bsc_settings());
+        // Off-chain workers re-verify the sidechain node after the settings change.
+        assert_eq!(
+            SidechainNetworkSettingsUpdatedAt::<Runtime>::get(ETH_NETWORK_ID),
+            5
+        );
+        assert_last_event::<Runtime>(
+            crate::Event::NetworkSettingsChanged(ETH_NETWORK_ID, bsc_settings()).into(),
+        );
+    });
+}
+
+#[test]
+fn should_not_set_network_settings() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        assert_noop!(
+            EthBridge::set_network_settings(
+                RuntimeOrigin::signed(alice),
+                ETH_NETWORK_ID,
+                bsc_settings(),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            EthBridge::set_network_settings(RuntimeOrigin::root(), 10, bsc_settings()),
+            Error::UnknownNetwork
+        );
+        assert_noop!(
+            EthBridge::set_network_settings(
+                RuntimeOrigin::root(),
+                ETH_NETWORK_ID,
+                NetworkSettings {
+                    chain_id: 0,
+                    ..bsc_settings()
+                },
+            ),
+            Error::InvalidNetworkSettings
+        );
+    });
+}
@@ -0,0 +1,263 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::mock::*;
+use super::{assert_last_event, last_outgoing_request, Assets, Error, ETH_NETWORK_ID};
+use crate::requests::{OutgoingRequest, OutgoingTransfer};
+use crate::EthAddress;
+use common::{AssetInfoProvider, OutflowLimit, XOR};
+use frame_support::sp_runtime::app_crypto::sp_core::sr25519;
+use frame_support::sp_runtime::DispatchError;
+use frame_support::{assert_noop, assert_ok};
+
+fn limit() -> OutflowLimit<u64> {
+    OutflowLimit {
+        max_amount: 100u32.into(),
+        window: 10,
+        release_delay: 20,
+    }
+}
+
+fn setup_alice() -> AccountId {
+    let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+    Assets::mint_to(&XOR.into(), &alice, &alice, 100000u32.into()).unwrap();
+    assert_ok!(EthBridge::set_outflow_limit(
+        RuntimeOrigin::root(),
+        ETH_NETWORK_ID,
+        XOR.into(),
+        Some(limit()),
+    ));
+    alice
+}
+
+fn transfer(who: &AccountId, amount: u32) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    EthBridge::transfer_to_sidechain(
+        RuntimeOrigin::signed(who.clone()),
+        XOR.into(),
+        EthAddress::from([1; 20]),
+        amount.into(),
+        ETH_NETWORK_ID,
+    )
+}
+
+#[test]
+fn should_set_outflow_limit() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_noop!(
+            EthBridge::set_outflow_limit(
+                RuntimeOrigin::signed(get_account_id_from_seed::<sr25519::Public>("Alice")),
+                ETH_NETWORK_ID,
+                XOR.into(),
+                Some(limit()),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            EthBridge::set_outflow_limit(
+                RuntimeOrigin::root(),
+                ETH_NETWORK_ID,
+                XOR.into(),
+                Some(OutflowLimit {
+                    window: 0,
+                    ..limit()
+                }),
+            ),
+            Error::InvalidOutflowLimit
+        );
+        assert_noop!(
+            EthBridge::set_outflow_limit(
+                RuntimeOrigin::root(),
+                ETH_NETWORK_ID + 10,
+                XOR.into(),
+                Some(limit()),
+            ),
+            Error::UnknownNetwork
+        );
+        assert_ok!(EthBridge::set_outflow_limit(
+            RuntimeOrigin::root(),
+            ETH_NETWORK_ID,
+            XOR.into(),
+            Some(limit()),
+        ));
+        assert_eq!(
+            EthBridge::outflow_limit(ETH_NETWORK_ID, XOR.into()),
+            Some(limit())
+        );
+        assert_last_event::<Runtime>(
+            crate::Event::OutflowLimitSet(ETH_NETWORK_ID, XOR.into(), Some(limit())).into(),
+        );
+        assert_ok!(EthBridge::set_outflow_limit(
+            RuntimeOrigin::root(),
+            ETH_NETWORK_ID,
+            XOR.into(),
+            None,
+        ));
+        assert_eq!(EthBridge::outflow_limit(ETH_NETWORK_ID, XOR.into()), None);
+    });
+}
+
+#[test]
+fn should_queue_transfer_exceeding_outflow_limit() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = setup_alice();
+        assert_ok!(transfer(&alice, 60));
+        assert_ok!(transfer(&alice, 40));
+        let (request, _) = last_outgoing_request(ETH_NETWORK_ID).unwrap();
+        assert!(matches!(
+            request,
+            OutgoingRequest::Transfer(OutgoingTransfer { amount: 40, .. })
+        ));
+
+        assert_ok!(transfer(&alice, 1));
+        // The queued transfer is not registered as a request.
+        let (request, _) = last_outgoing_request(ETH_NETWORK_ID).unwrap();
+        assert!(matches!(
+            request,
+            OutgoingRequest::Transfer(OutgoingTransfer { amount: 40, .. })
+        ));
+        assert_last_event::<Runtime>(
+            crate::Event::OutflowQueued(0, alice.clone(), XOR.into(), 1, 21).into(),
+        );
+        let outflow = EthBridge::queued_outflow(0).unwrap();
+        assert_eq!(outflow.amount, 1);
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &alice).unwrap(),
+            (100000u32 - 101).into()
+        );
+        assert_eq!(
+            Assets::total_balance(&XOR.into(), &alice).unwrap(),
+            (100000u32 - 100).into()
+        );
+    });
+}
+
+#[test]
+fn should_release_queued_outflow() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = setup_alice();
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        assert_ok!(transfer(&alice, 150));
+        assert_noop!(
+            EthBridge::release_queued_outflow(RuntimeOrigin::signed(bob.clone()), 0),
+            Error::QueuedOutflowIsLocked
+        );
+        assert_noop!(
+            EthBridge::release_queued_outflow(RuntimeOrigin::signed(bob.clone()), 1),
+            Error::QueuedOutflowNotFound
+        );
+
+        System::set_block_number(21);
+        assert_ok!(EthBridge::release_queued_outflow(
+            RuntimeOrigin::signed(bob),
+            0
+        ));
+        let (request, hash) = last_outgoing_request(ETH_NETWORK_ID).unwrap();
+        assert!(matches!(
+            request,
+            OutgoingRequest::Transfer(OutgoingTransfer { amount: 150, .. })
+        ));
+        assert_last_event::<Runtime>(crate::Event::QueuedOutflowReleased(0, hash).into());
+        assert!(EthBridge::queued_outflow(0).is_none());
+        assert_eq!(
+            Assets::total_balance(&XOR.into(), &alice).unwrap(),
+            (100000u32 - 150).into()
+        );
+        // Released outflow is counted in the current window
+        assert_ok!(transfer(&alice, 1));
+        assert!(EthBridge::queued_outflow(1).is_some());
+    });
+}
+
+#[test]
+fn should_allow_root_to_release_queued_outflow_early() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = setup_alice();
+        assert_ok!(transfer(&alice, 150));
+        assert_ok!(EthBridge::release_queued_outflow(RuntimeOrigin::root(), 0));
+        assert!(EthBridge::queued_outflow(0).is_none());
+    });
+}
+
+#[test]
+fn should_cancel_queued_outflow() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = setup_alice();
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        assert_ok!(transfer(&alice, 150));
+        assert_noop!(
+            EthBridge::cancel_queued_outflow(RuntimeOrigin::signed(bob), 0),
+            Error::Forbidden
+        );
+        assert_ok!(EthBridge::cancel_queued_outflow(
+            RuntimeOrigin::signed(alice.clone()),
+            0
+        ));
+        assert_last_event::<Runtime>(crate::Event::QueuedOutflowCancelled(0).into());
+        assert!(EthBridge::queued_outflow(0).is_none());
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &alice).unwrap(),
+            100000u32.into()
+        );
+    });
+}
+
+#[test]
+fn should_pause_outgoing_transfers() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = setup_alice();
+        assert_ok!(transfer(&alice, 150));
+        assert_ok!(EthBridge::set_network_paused(
+            RuntimeOrigin::root(),
+            ETH_NETWORK_ID,
+            true
+        ));
+        assert_last_event::<Runtime>(
+            crate::Event::NetworkPauseChanged(ETH_NETWORK_ID, true).into(),
+        );
+        assert_noop!(transfer(&alice, 10), Error::NetworkPaused);
+        assert_noop!(
+            EthBridge::release_queued_outflow(RuntimeOrigin::root(), 0),
+            Error::NetworkPaused
+        );
+        assert_ok!(EthBridge::set_network_paused(
+            RuntimeOrigin::root(),
+            ETH_NETWORK_ID,
+            false
+        ));
+        assert!(!EthBridge::is_network_paused(ETH_NETWORK_ID));
+        assert_ok!(transfer(&alice, 10));
+    });
+}
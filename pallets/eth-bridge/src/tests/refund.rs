@@ -0,0 +1,163 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::mock::*;
+use super::{
+    approve_last_request, approve_request_by_peer, assert_last_event, last_outgoing_request,
+    Assets, Error, ETH_NETWORK_ID,
+};
+use crate::requests::RequestStatus;
+use crate::{EthAddress, OUTGOING_TRANSFER_REFUND_TIMEOUT};
+use common::{AssetInfoProvider, XOR};
+use frame_support::dispatch::{DispatchErrorWithPostInfo, Pays};
+use frame_support::sp_runtime::app_crypto::sp_core::{sr25519, H256};
+use frame_support::{assert_noop, assert_ok};
+
+fn transfer_xor(who: &AccountId) -> H256 {
+    Assets::mint_to(&XOR.into(), who, who, 100u32.into()).unwrap();
+    assert_ok!(EthBridge::transfer_to_sidechain(
+        RuntimeOrigin::signed(who.clone()),
+        XOR.into(),
+        EthAddress::from([1; 20]),
+        100_u32.into(),
+        ETH_NETWORK_ID,
+    ));
+    assert_eq!(Assets::total_balance(&XOR.into(), who).unwrap(), 0);
+    last_outgoing_request(ETH_NETWORK_ID).unwrap().1
+}
+
+fn skip_refund_timeout() {
+    System::set_block_number(System::block_number() + OUTGOING_TRANSFER_REFUND_TIMEOUT as u64);
+}
+
+#[test]
+fn should_refund_pending_outgoing_transfer() {
+    let (mut ext, _state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+        let hash = transfer_xor(&alice);
+        assert_noop!(
+            EthBridge::request_refund(RuntimeOrigin::signed(alice.clone()), hash, ETH_NETWORK_ID),
+            Error::RequestIsNotExpired
+        );
+        skip_refund_timeout();
+        assert_noop!(
+            EthBridge::request_refund(RuntimeOrigin::signed(bob), hash, ETH_NETWORK_ID),
+            Error::Forbidden
+        );
+        assert_ok!(EthBridge::request_refund(
+            RuntimeOrigin::signed(alice.clone()),
+            hash,
+            ETH_NETWORK_ID
+        ));
+        assert_last_event::<Runtime>(crate::Event::RequestRefunded(hash).into());
+        assert_eq!(
+            EthBridge::request_status(ETH_NETWORK_ID, hash),
+            Some(RequestStatus::Refunded)
+        );
+        assert!(!EthBridge::requests_queue(ETH_NETWORK_ID).contains(&hash));
+        assert_eq!(
+            Assets::total_balance(&XOR.into(), &alice).unwrap(),
+            100u32.into()
+        );
+        assert_noop!(
+            EthBridge::request_refund(RuntimeOrigin::signed(alice), hash, ETH_NETWORK_ID),
+            Error::RefundIsNotAvailable
+        );
+    });
+}
+
+#[test]
+fn should_not_refund_approved_transfer() {
+    let (mut ext, state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        let hash = transfer_xor(&alice);
+        approve_last_request(&state, ETH_NETWORK_ID).expect("request wasn't approved");
+        skip_refund_timeout();
+        // Approved request can still be executed on Sidechain.
+        assert_noop!(
+            EthBridge::request_refund(RuntimeOrigin::signed(alice.clone()), hash, ETH_NETWORK_ID),
+            Error::RefundIsNotAvailable
+        );
+        assert_eq!(
+            EthBridge::request_status(ETH_NETWORK_ID, hash),
+            Some(RequestStatus::ApprovalsReady)
+        );
+        assert_eq!(Assets::total_balance(&XOR.into(), &alice).unwrap(), 0);
+    });
+}
+
+#[test]
+fn should_not_refund_partially_approved_transfer() {
+    let (mut ext, state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        transfer_xor(&alice);
+        let (request, hash) = last_outgoing_request(ETH_NETWORK_ID).unwrap();
+        assert_ok!(approve_request_by_peer(&state, &request, hash, 0));
+        skip_refund_timeout();
+        // Signatures are public, so the rest of them can still be collected.
+        assert_noop!(
+            EthBridge::request_refund(RuntimeOrigin::signed(alice.clone()), hash, ETH_NETWORK_ID),
+            Error::RefundIsNotAvailable
+        );
+        assert_eq!(
+            EthBridge::request_status(ETH_NETWORK_ID, hash),
+            Some(RequestStatus::Pending)
+        );
+        assert_eq!(Assets::total_balance(&XOR.into(), &alice).unwrap(), 0);
+    });
+}
+
+#[test]
+fn should_not_approve_refunded_transfer() {
+    let (mut ext, state) = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+        transfer_xor(&alice);
+        let (request, hash) = last_outgoing_request(ETH_NETWORK_ID).unwrap();
+        skip_refund_timeout();
+        assert_ok!(EthBridge::request_refund(
+            RuntimeOrigin::signed(alice),
+            hash,
+            ETH_NETWORK_ID
+        ));
+        assert_noop!(
+            approve_request_by_peer(&state, &request, hash, 0),
+            DispatchErrorWithPostInfo {
+                post_info: Pays::No.into(),
+                error: Error::ExpectedPendingRequest.into()
+            }
+        );
+        assert!(crate::RequestApprovals::<Runtime>::get(ETH_NETWORK_ID, hash).is_empty());
+    });
+}
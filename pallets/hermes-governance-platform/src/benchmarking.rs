@@ -8,6 +8,7 @@ use codec::{Decode, Encode};
 use common::{balance, HERMES_ASSET_ID};
 use frame_benchmarking::benchmarks;
 use frame_support::assert_ok;
+use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use frame_support::PalletId;
 use frame_system::{EventRecord, RawOrigin};
@@ -26,6 +27,49 @@ fn alice<T: Config>() -> T::AccountId {
     T::AccountId::decode(&mut &bytes[..]).unwrap()
 }
 
+fn bob<T: Config>() -> T::AccountId {
+    let bytes = hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48");
+    T::AccountId::decode(&mut &bytes[..]).unwrap()
+}
+
+fn setup_poll<T: Config>(caller: &T::AccountId, number_of_hermes: Balance) -> H256 {
+    let hermes_locked = pallet::MinimumHermesAmountForCreatingPoll::<T>::get();
+    let poll_start_timestamp = Timestamp::<T>::get();
+    let poll_end_timestamp = Timestamp::<T>::get() + (14400 * 1000u32).into();
+    let owner: T::AccountId =
+        assets::AssetOwners::<T>::get::<T::AssetId>(HERMES_ASSET_ID.clone().into()).unwrap();
+    let nonce = frame_system::Pallet::<T>::account_nonce(caller);
+    let encoded: [u8; 32] = (caller, nonce).using_encoded(blake2_256);
+    let poll_id = H256::from(encoded);
+    let mut options = BoundedVec::default();
+    options.try_push("Yes".try_into().unwrap()).unwrap();
+    options.try_push("No".try_into().unwrap()).unwrap();
+
+    Assets::<T>::mint(
+        RawOrigin::Signed(owner).into(),
+        HERMES_ASSET_ID.into(),
+        caller.clone(),
+        number_of_hermes,
+    )
+    .unwrap();
+
+    let hermes_poll_info = HermesPollInfo {
+        creator: caller.clone(),
+        hermes_locked,
+        poll_start_timestamp,
+        poll_end_timestamp,
+        title: "Title".try_into().unwrap(),
+        description: "Description".try_into().unwrap(),
+        creator_hermes_withdrawn: false,
+        options,
+        mode: PollMode::Standard,
+    };
+
+    pallet::HermesPollData::<T>::insert(&poll_id, &hermes_poll_info);
+
+    poll_id
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     let events = frame_system::Pallet::<T>::events();
     let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -67,7 +111,8 @@ benchmarks! {
             title: title.try_into().unwrap(),
             description: description.try_into().unwrap(),
             creator_hermes_withdrawn: false,
-            options
+            options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<T>::insert(&poll_id, &hermes_poll_info);
@@ -76,6 +121,7 @@ benchmarks! {
             RawOrigin::Signed(caller.clone()).into(),
             poll_id.clone(),
             voting_option.try_into().unwrap(),
+            Conviction::None,
         ).unwrap();
     }
     verify{
@@ -108,7 +154,8 @@ benchmarks! {
             poll_end_timestamp,
             title.try_into().unwrap(),
             descripton.try_into().unwrap(),
-            options
+            options,
+            PollMode::Standard
         );
     }
     verify{
@@ -148,7 +195,8 @@ benchmarks! {
             title: title.try_into().unwrap(),
             description: description.try_into().unwrap(),
             creator_hermes_withdrawn: false,
-            options
+            options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<T>::insert(&poll_id, &hermes_poll_info);
@@ -157,6 +205,7 @@ benchmarks! {
             RawOrigin::Signed(caller.clone()).into(),
             poll_id.clone(),
             voting_option.try_into().unwrap(),
+            Conviction::None,
         );
 
         let hermes_voting_info = pallet::HermesVotings::<T>::get(&poll_id, &caller).unwrap();
@@ -203,7 +252,8 @@ benchmarks! {
             title: title.try_into().unwrap(),
             description: description.try_into().unwrap(),
             creator_hermes_withdrawn: false,
-            options
+            options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<T>::insert(&poll_id, &hermes_poll_info);
@@ -254,6 +304,243 @@ benchmarks! {
         assert_last_event::<T>(Event::MinimumHermesForCreatingPollChanged(hermes_amount).into())
     }
 
+    change_vote {
+        let caller = alice::<T>();
+        let poll_id = setup_poll::<T>(&caller, balance!(1000));
+
+        assert_ok!(HermesGovernancePlatform::<T>::vote(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            "Yes".try_into().unwrap(),
+            Conviction::Locked2x,
+        ));
+    }: {
+        let _ = HermesGovernancePlatform::<T>::change_vote(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            "No".try_into().unwrap(),
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::VoteChanged(caller, poll_id, "No".try_into().unwrap()).into())
+    }
+
+    delegate {
+        let caller = alice::<T>();
+        let target = bob::<T>();
+        let number_of_hermes = balance!(1000);
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(HERMES_ASSET_ID.clone().into()).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            number_of_hermes
+        ).unwrap();
+    }: {
+        let _ = HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            target.clone(),
+            number_of_hermes,
+            Conviction::Locked3x,
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::Delegated(caller, target, number_of_hermes).into())
+    }
+
+    undelegate {
+        let caller = alice::<T>();
+        let target = bob::<T>();
+        let number_of_hermes = balance!(1000);
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(HERMES_ASSET_ID.clone().into()).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            number_of_hermes
+        ).unwrap();
+
+        assert_ok!(HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            target.clone(),
+            number_of_hermes,
+            Conviction::Locked3x,
+        ));
+    }: {
+        let _ = HermesGovernancePlatform::<T>::undelegate(
+            RawOrigin::Signed(caller.clone()).into(),
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::Undelegated(caller, target).into())
+    }
+
+    withdraw_delegation {
+        let caller = alice::<T>();
+        let target = bob::<T>();
+        let number_of_hermes = balance!(1000);
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(HERMES_ASSET_ID.clone().into()).unwrap();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            number_of_hermes
+        ).unwrap();
+
+        assert_ok!(HermesGovernancePlatform::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            target,
+            number_of_hermes,
+            Conviction::None,
+        ));
+        assert_ok!(HermesGovernancePlatform::<T>::undelegate(
+            RawOrigin::Signed(caller.clone()).into(),
+        ));
+
+        let unlock_timestamp = pallet::HermesDelegations::<T>::get(&caller)
+            .unwrap()
+            .unlock_timestamp
+            .unwrap();
+        pallet_timestamp::Now::<T>::put(unlock_timestamp + 1u32.into());
+    }: {
+        let _ = HermesGovernancePlatform::<T>::withdraw_delegation(
+            RawOrigin::Signed(caller.clone()).into(),
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::DelegationWithdrawn(caller, number_of_hermes).into())
+    }
+
+    close_poll {
+        let r in 0 .. HermesGovernancePlatform::<T>::max_ranked_ballots();
+        let caller = alice::<T>();
+        let poll_id = setup_poll::<T>(&caller, balance!(1000));
+
+        // Ranked-choice poll with `OptionsLimit` options and `r` ballots with distinct rankings
+        let options = T::OptionsLimit::get();
+        let names = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        pallet::HermesPollData::<T>::mutate(&poll_id, |poll_info| {
+            if let Some(poll_info) = poll_info {
+                for i in poll_info.options.len()..options as usize {
+                    poll_info.options.try_push(names[i..i + 1].try_into().unwrap()).unwrap();
+                }
+                poll_info.mode = PollMode::RankedChoice;
+            }
+        });
+        let mut ballots = 0;
+        for len in 1..=options {
+            for code in 0..options.pow(len) {
+                if ballots == r {
+                    break;
+                }
+                let ranking: Vec<u32> = (0..len).map(|i| code / options.pow(i) % options).collect();
+                let mut ranked = ranking.clone();
+                ranked.sort();
+                ranked.dedup();
+                if ranked.len() == ranking.len() {
+                    let ranking: BoundedVec<u32, T::OptionsLimit> = ranking.try_into().unwrap();
+                    pallet::HermesRankedBallots::<T>::insert(&poll_id, ranking, balance!(1) + code as u128);
+                    ballots += 1;
+                }
+            }
+        }
+
+        pallet_timestamp::Now::<T>::put(Timestamp::<T>::get() + (14401*1000u32).into());
+    }: {
+        let _ = HermesGovernancePlatform::<T>::close_poll(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone()
+        );
+    }
+    verify {
+        assert!(pallet::HermesPollResults::<T>::contains_key(&poll_id));
+    }
+
+    create_proposal {
+        let caller = alice::<T>();
+        let hermes_amount = balance!(200000);
+        let poll_start_timestamp = Timestamp::<T>::get();
+        let poll_end_timestamp = Timestamp::<T>::get() + (14400*1000u32).into();
+        let owner: T::AccountId = assets::AssetOwners::<T>::get::<T::AssetId>(HERMES_ASSET_ID.clone().into()).unwrap();
+        let nonce = frame_system::Pallet::<T>::account_nonce(&caller);
+        let encoded: [u8; 32] = (&caller, nonce).using_encoded(blake2_256);
+        let poll_id = H256::from(encoded);
+        let call: <T as Config>::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+        Assets::<T>::mint(
+            RawOrigin::Signed(owner).into(),
+            HERMES_ASSET_ID.into(),
+            caller.clone(),
+            hermes_amount
+        ).unwrap();
+    }: {
+        let _ = HermesGovernancePlatform::<T>::create_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_start_timestamp,
+            poll_end_timestamp,
+            "Title".try_into().unwrap(),
+            "Description".try_into().unwrap(),
+            Box::new(call)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::ProposalCreated(caller, poll_id).into())
+    }
+
+    execute_proposal {
+        let caller = alice::<T>();
+        let poll_id = setup_poll::<T>(&caller, T::ProposalQuorum::get());
+        let call: <T as Config>::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        pallet::HermesProposals::<T>::insert(&poll_id, call);
+
+        pallet::MinimumHermesVotingAmount::<T>::put(T::ProposalQuorum::get());
+        assert_ok!(HermesGovernancePlatform::<T>::vote(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            "Yes".try_into().unwrap(),
+            Conviction::None,
+        ));
+
+        let poll_end_timestamp = pallet::HermesPollData::<T>::get(&poll_id).unwrap().poll_end_timestamp;
+        pallet_timestamp::Now::<T>::put(poll_end_timestamp + T::ProposalTimelock::get() + 1u32.into());
+    }: {
+        let _ = HermesGovernancePlatform::<T>::execute_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            Weight::MAX
+        );
+    }
+    verify {
+        assert!(pallet::HermesProposals::<T>::get(&poll_id).is_none());
+    }
+
+    vote_ranked {
+        let caller = alice::<T>();
+        let poll_id = setup_poll::<T>(&caller, balance!(1000));
+        pallet::HermesPollData::<T>::mutate(&poll_id, |poll_info| {
+            if let Some(poll_info) = poll_info {
+                poll_info.mode = PollMode::RankedChoice;
+            }
+        });
+        let mut ranking: BoundedVec<_, T::OptionsLimit> = BoundedVec::default();
+        ranking.try_push("No".try_into().unwrap()).unwrap();
+        ranking.try_push("Yes".try_into().unwrap()).unwrap();
+    }: {
+        let _ = HermesGovernancePlatform::<T>::vote_ranked(
+            RawOrigin::Signed(caller.clone()).into(),
+            poll_id.clone(),
+            ranking.clone(),
+            pallet::MinimumHermesVotingAmount::<T>::get(),
+            Conviction::None,
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::RankedVoted(caller, poll_id, ranking).into())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ExtBuilder::default().build(),
@@ -14,11 +14,72 @@ mod tests;
 
 extern crate alloc;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use common::{Balance, BoundedString};
+use frame_support::traits::EnsureOrigin;
 use frame_support::BoundedVec;
+use frame_support::RuntimeDebug;
 pub use weights::WeightInfo;
 
+/// Conviction of a vote. Voting power is multiplied by the conviction, and locked Hermes
+/// can't be withdrawn for one `CONVICTION_LOCK_PERIOD` per each step above `None`
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum Conviction {
+    /// 1x voting power, Hermes are unlocked when the poll ends
+    None,
+    /// 2x voting power, Hermes are locked for one period after the poll ends
+    Locked2x,
+    /// 3x voting power, Hermes are locked for two periods after the poll ends
+    Locked3x,
+    /// 4x voting power, Hermes are locked for three periods after the poll ends
+    Locked4x,
+    /// 5x voting power, Hermes are locked for four periods after the poll ends
+    Locked5x,
+    /// 6x voting power, Hermes are locked for five periods after the poll ends
+    Locked6x,
+}
+
+impl Conviction {
+    /// Voting power multiplier
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+            Conviction::Locked6x => 6,
+        }
+    }
+
+    /// Number of lock periods after the poll ends
+    pub fn lock_periods(&self) -> u32 {
+        self.multiplier() - 1
+    }
+
+    /// Voting power of `number_of_hermes` locked with this conviction
+    pub fn votes(&self, number_of_hermes: Balance) -> Balance {
+        number_of_hermes.saturating_mul(self.multiplier() as Balance)
+    }
+}
+
+/// Tallying mode of a poll
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub enum PollMode {
+    /// Voting power is proportional to the locked Hermes
+    Standard,
+    /// Voting power is the square root of the locked Hermes
+    Quadratic,
+    /// Voters rank options and the winner is determined by instant-runoff
+    RankedChoice,
+}
+
+impl Default for PollMode {
+    fn default() -> Self {
+        PollMode::Standard
+    }
+}
+
 #[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
 #[scale_info(skip_type_params(StringLimit))]
@@ -29,6 +90,33 @@ pub struct HermesVotingInfo<StringLimit: sp_core::Get<u32>> {
     number_of_hermes: Balance,
     /// Hermes withdrawn
     hermes_withdrawn: bool,
+    /// Conviction of the vote
+    conviction: Conviction,
+    /// Voting power including power delegated to the voter
+    votes: Balance,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct HermesDelegationInfo<AccountId, Moment> {
+    /// Account which receives voting power
+    pub target: AccountId,
+    /// Number of delegated hermes
+    pub number_of_hermes: Balance,
+    /// Conviction of delegated hermes
+    pub conviction: Conviction,
+    /// Timestamp after which hermes can be withdrawn, set on undelegation
+    pub unlock_timestamp: Option<Moment>,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[scale_info(skip_type_params(StringLimit, OptionsLimit))]
+pub struct HermesPollResult<StringLimit: sp_core::Get<u32>, OptionsLimit: sp_core::Get<u32>> {
+    /// Voting power for each option, in the order of poll options
+    pub votes: BoundedVec<Balance, OptionsLimit>,
+    /// Option with the most votes, `None` if there are no votes or there is a tie
+    pub winning_option: Option<BoundedString<StringLimit>>,
 }
 
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
@@ -58,26 +146,64 @@ pub struct HermesPollInfo<
     pub creator_hermes_withdrawn: bool,
     /// Options
     pub options: BoundedVec<BoundedString<StringLimit>, OptionsLimit>,
+    /// Tallying mode
+    pub mode: PollMode,
+}
+
+/// Origin of calls dispatched by approved proposals.
+#[derive(
+    PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub enum RawOrigin {
+    /// Call is dispatched by an approved proposal
+    Proposal,
+}
+
+/// Ensures that origin is an approved proposal of this pallet.
+pub struct EnsureProposal;
+
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> EnsureOrigin<O> for EnsureProposal {
+    type Success = ();
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().map(|RawOrigin::Proposal| ())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::Proposal))
+    }
 }
 
 /// Storage version.
-#[derive(Encode, Decode, Eq, PartialEq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Eq, PartialEq, RuntimeDebug, scale_info::TypeInfo)]
 pub enum StorageVersion {
     /// Initial version
     V1,
     /// After migrating 'voting_option' to String, and new 'options' field in HermesPollInfo
     V2,
+    /// After adding conviction voting, delegation and poll tallies
+    V3,
+    /// After adding 'mode' field in HermesPollInfo
+    V4,
 }
 
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::{migrations, HermesPollInfo, HermesVotingInfo, StorageVersion, WeightInfo};
+    use crate::{
+        migrations, Conviction, HermesDelegationInfo, HermesPollInfo, HermesPollResult,
+        HermesVotingInfo, PollMode, RawOrigin, StorageVersion, WeightInfo,
+    };
     use common::prelude::Balance;
     use common::{balance, AssetInfoProvider, BoundedString};
+    use frame_support::dispatch::{
+        DispatchResult, Dispatchable, GetDispatchInfo, PostDispatchInfo,
+    };
     use frame_support::pallet_prelude::*;
     use frame_support::sp_runtime::traits::AccountIdConversion;
+    use frame_support::traits::Contains;
     use frame_support::transactional;
     use frame_support::PalletId;
     use frame_system::ensure_signed;
@@ -86,7 +212,11 @@ pub mod pallet {
     use pallet_timestamp as timestamp;
     use sp_core::H256;
     use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::IntegerSquareRoot;
+    use sp_runtime::Perbill;
+    use sp_std::boxed::Box;
     use sp_std::collections::btree_set::BTreeSet;
+    use sp_std::vec::Vec;
 
     const PALLET_ID: PalletId = PalletId(*b"hermsgov");
 
@@ -101,6 +231,9 @@ pub mod pallet {
         /// Maximum duration of poll represented in milliseconds
         const MAX_DURATION_OF_POLL: Self::Moment;
 
+        /// Lock duration for each conviction step represented in milliseconds
+        const CONVICTION_LOCK_PERIOD: Self::Moment;
+
         /// String limit
         type StringLimit: Get<u32>;
 
@@ -119,6 +252,29 @@ pub mod pallet {
         /// Hermes asset id
         type HermesAssetId: Get<Self::AssetId>;
 
+        /// The outer origin type
+        type RuntimeOrigin: From<RawOrigin>;
+
+        /// The outer call dispatched by approved proposals
+        type Proposal: Parameter
+            + Dispatchable<
+                RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+                PostInfo = PostDispatchInfo,
+            > + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// Calls which can be dispatched by proposals
+        type ProposalFilter: Contains<<Self as Config>::Proposal>;
+
+        /// Delay between the end of the poll and the execution of the proposal in milliseconds
+        type ProposalTimelock: Get<Self::Moment>;
+
+        /// Minimum voting power for the proposal to be valid
+        type ProposalQuorum: Get<Balance>;
+
+        /// Minimum share of voting power for the proposal to be approved
+        type ProposalThreshold: Get<Perbill>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -126,6 +282,14 @@ pub mod pallet {
     type Assets<T> = assets::Pallet<T>;
     pub type Timestamp<T> = timestamp::Pallet<T>;
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub type HermesPollInfoOf<T> = HermesPollInfo<
+        AccountIdOf<T>,
+        <T as timestamp::Config>::Moment,
+        <T as Config>::StringLimit,
+        <T as Config>::OptionsLimit,
+        <T as Config>::TitleLimit,
+        <T as Config>::DescriptionLimit,
+    >;
 
     #[pallet::pallet]
     #[pallet::generate_store(pub (super) trait Store)]
@@ -162,6 +326,60 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Voting power for each option of a poll which is not closed yet
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_poll_tallies)]
+    pub type HermesPollTallies<T: Config> =
+        StorageMap<_, Identity, H256, BoundedVec<Balance, T::OptionsLimit>, ValueQuery>;
+
+    /// Total voting power for each distinct ranking of a ranked-choice poll which is not
+    /// closed yet
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_ranked_ballots)]
+    pub type HermesRankedBallots<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        H256,
+        Blake2_128Concat,
+        BoundedVec<u32, T::OptionsLimit>,
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Results of closed polls
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_poll_results)]
+    pub type HermesPollResults<T: Config> = StorageMap<
+        _,
+        Identity,
+        H256,
+        HermesPollResult<T::StringLimit, T::OptionsLimit>,
+        OptionQuery,
+    >;
+
+    /// Delegation of voting power of a particular user
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_delegations)]
+    pub type HermesDelegations<T: Config> = StorageMap<
+        _,
+        Identity,
+        AccountIdOf<T>,
+        HermesDelegationInfo<AccountIdOf<T>, T::Moment>,
+        OptionQuery,
+    >;
+
+    /// Voting power delegated to a particular user
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_delegated_votes)]
+    pub type HermesDelegatedVotes<T: Config> =
+        StorageMap<_, Identity, AccountIdOf<T>, Balance, ValueQuery>;
+
+    /// Calls attached to proposal polls
+    #[pallet::storage]
+    #[pallet::getter(fn hermes_proposals)]
+    pub type HermesProposals<T: Config> =
+        StorageMap<_, Identity, H256, <T as Config>::Proposal, OptionQuery>;
+
     #[pallet::type_value]
     pub fn DefaultMinimumHermesVotingAmount<T: Config>() -> Balance {
         balance!(1000)
@@ -205,6 +423,9 @@ pub mod pallet {
     pub type PalletStorageVersion<T: Config> =
         StorageValue<_, StorageVersion, ValueQuery, DefaultForPalletStorageVersion<T>>;
 
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -225,6 +446,28 @@ pub mod pallet {
         MinimumHermesForVotingChanged(Balance),
         /// Change minimum Hermes for creating poll [balance]
         MinimumHermesForCreatingPollChanged(Balance),
+        /// Vote changed [who, poll, option]
+        VoteChanged(AccountIdOf<T>, H256, BoundedString<T::StringLimit>),
+        /// Voting power delegated [who, target, balance]
+        Delegated(AccountIdOf<T>, AccountIdOf<T>, Balance),
+        /// Delegation removed [who, target]
+        Undelegated(AccountIdOf<T>, AccountIdOf<T>),
+        /// Delegated Hermes withdrawn [who, balance]
+        DelegationWithdrawn(AccountIdOf<T>, Balance),
+        /// Poll closed [poll, winning option]
+        PollClosed(H256, Option<BoundedString<T::StringLimit>>),
+        /// Ranked vote [who, poll, ranking]
+        RankedVoted(
+            AccountIdOf<T>,
+            H256,
+            BoundedVec<BoundedString<T::StringLimit>, T::OptionsLimit>,
+        ),
+        /// Proposal created [who, poll]
+        ProposalCreated(AccountIdOf<T>, H256),
+        /// Proposal executed [poll, result]
+        ProposalExecuted(H256, DispatchResult),
+        /// Proposal rejected [poll]
+        ProposalRejected(H256),
     }
 
     #[pallet::error]
@@ -267,6 +510,32 @@ pub mod pallet {
         InvalidOption,
         /// Duplicate options
         DuplicateOptions,
+        /// Funds Are Locked By Conviction
+        FundsAreLocked,
+        /// Voting Power Is Delegated
+        VotingPowerIsDelegated,
+        /// Cannot Delegate To Self
+        CannotDelegateToSelf,
+        /// Already Delegating
+        AlreadyDelegating,
+        /// Not Delegating
+        NotDelegating,
+        /// Delegation Is Active
+        DelegationIsActive,
+        /// Poll Already Closed
+        PollAlreadyClosed,
+        /// Call Not Allowed For Proposals
+        CallNotAllowed,
+        /// Proposal Does Not Exist
+        ProposalDoesNotExist,
+        /// Proposal Is Timelocked
+        ProposalIsTimelocked,
+        /// Proposal Weight Is Bigger Than Provided
+        WrongProposalWeight,
+        /// Call Is Not Supported In This Poll Mode
+        InvalidPollMode,
+        /// Invalid Ranking Of Options
+        InvalidRanking,
     }
 
     #[pallet::call]
@@ -279,60 +548,17 @@ pub mod pallet {
             origin: OriginFor<T>,
             poll_id: H256,
             voting_option: BoundedString<T::StringLimit>,
+            conviction: Conviction,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
 
-            let current_timestamp = Timestamp::<T>::get();
-            let hermes_poll_info =
-                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
-
-            ensure!(
-                current_timestamp >= hermes_poll_info.poll_start_timestamp,
-                Error::<T>::PollIsNotStarted
-            );
-
-            ensure!(
-                current_timestamp <= hermes_poll_info.poll_end_timestamp,
-                Error::<T>::PollIsFinished
-            );
-
-            ensure!(
-                hermes_poll_info.options.contains(&voting_option),
-                Error::<T>::InvalidOption
-            );
-
-            ensure!(
-                MinimumHermesVotingAmount::<T>::get()
-                    <= Assets::<T>::free_balance(&T::HermesAssetId::get().into(), &user)
-                        .unwrap_or(0),
-                Error::<T>::NotEnoughHermesForVoting
-            );
-
-            ensure!(
-                !<HermesVotings<T>>::contains_key(&poll_id, &user),
-                Error::<T>::AlreadyVoted
-            );
-
-            let hermes_voting_info = HermesVotingInfo {
-                voting_option: voting_option.clone(),
-                number_of_hermes: MinimumHermesVotingAmount::<T>::get(),
-                hermes_withdrawn: false,
-            };
-
-            // Transfer Hermes to pallet
-            Assets::<T>::transfer_from(
-                &T::HermesAssetId::get().into(),
-                &user,
-                &Self::account_id(),
-                hermes_voting_info.number_of_hermes,
-            )
-            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
-
-            // Update storage
-            <HermesVotings<T>>::insert(&poll_id, &user, hermes_voting_info);
-
-            // Emit event
-            Self::deposit_event(Event::<T>::Voted(user, poll_id, voting_option));
+            Self::vote_for_option(
+                user,
+                poll_id,
+                voting_option,
+                MinimumHermesVotingAmount::<T>::get(),
+                conviction,
+            )?;
 
             // Return a successful DispatchResult
             Ok(().into())
@@ -348,83 +574,19 @@ pub mod pallet {
             title: BoundedString<T::TitleLimit>,
             description: BoundedString<T::DescriptionLimit>,
             options: BoundedVec<BoundedString<T::StringLimit>, T::OptionsLimit>,
+            mode: PollMode,
         ) -> DispatchResultWithPostInfo {
             let user = ensure_signed(origin)?;
-            let current_timestamp = Timestamp::<T>::get();
-
-            ensure!(
-                poll_start_timestamp >= current_timestamp,
-                Error::<T>::InvalidStartTimestamp
-            );
-
-            ensure!(
-                poll_end_timestamp > poll_start_timestamp,
-                Error::<T>::InvalidEndTimestamp
-            );
-
-            ensure!(
-                (poll_end_timestamp - poll_start_timestamp) >= T::MIN_DURATION_OF_POLL,
-                Error::<T>::InvalidMinimumDurationOfPoll
-            );
-
-            ensure!(
-                (poll_end_timestamp - poll_start_timestamp) <= T::MAX_DURATION_OF_POLL,
-                Error::<T>::InvalidMaximumDurationOfPoll
-            );
-
-            ensure!(
-                MinimumHermesAmountForCreatingPoll::<T>::get()
-                    <= Assets::<T>::free_balance(&T::HermesAssetId::get().into(), &user)
-                        .unwrap_or(0),
-                Error::<T>::NotEnoughHermesForCreatingPoll
-            );
-
-            let nonce = frame_system::Pallet::<T>::account_nonce(&user);
-            let encoded: [u8; 32] = (&user, nonce).using_encoded(blake2_256);
-            let poll_id = H256::from(encoded);
-
-            let options_len = options.len();
-            if options_len < 2 {
-                return Err(Error::<T>::InvalidVotingOptions.into());
-            }
-            if options_len > 5 {
-                return Err(Error::<T>::TooManyVotingOptions.into());
-            }
 
-            let options_set = BTreeSet::from_iter(&options);
-            if options_set.len() != options_len {
-                return Err(Error::<T>::DuplicateOptions.into());
-            }
-
-            let hermes_poll_info = HermesPollInfo {
-                creator: user.clone(),
-                hermes_locked: MinimumHermesAmountForCreatingPoll::<T>::get(),
+            Self::create(
+                user,
                 poll_start_timestamp,
                 poll_end_timestamp,
-                title: title.clone(),
+                title,
                 description,
-                creator_hermes_withdrawn: false,
                 options,
-            };
-
-            // Transfer Hermes to pallet
-            Assets::<T>::transfer_from(
-                &T::HermesAssetId::get().into(),
-                &user.clone(),
-                &Self::account_id(),
-                hermes_poll_info.hermes_locked,
-            )
-            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForCreatingPoll)?;
-
-            <HermesPollData<T>>::insert(&poll_id, hermes_poll_info);
-
-            // Emit event
-            Self::deposit_event(Event::<T>::Created(
-                user.clone(),
-                title,
-                poll_start_timestamp,
-                poll_end_timestamp,
-            ));
+                mode,
+            )?;
 
             // Return a successful DispatchResult
             Ok(().into())
@@ -432,7 +594,7 @@ pub mod pallet {
 
         /// Withdraw funds voter
         #[pallet::call_index(2)]
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_funds_voter())]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_funds_voter().saturating_add(<T as Config>::WeightInfo::close_poll(Pallet::<T>::max_ranked_ballots())))]
         pub fn withdraw_funds_voter(
             origin: OriginFor<T>,
             poll_id: H256,
@@ -455,6 +617,21 @@ pub mod pallet {
                 Error::<T>::FundsAlreadyWithdrawn
             );
 
+            ensure!(
+                current_timestamp
+                    > Self::unlock_timestamp(
+                        hermes_poll_info.poll_end_timestamp,
+                        hermes_voting_info.conviction
+                    ),
+                Error::<T>::FundsAreLocked
+            );
+
+            let mut weight = <T as Config>::WeightInfo::withdraw_funds_voter();
+            if !<HermesPollResults<T>>::contains_key(&poll_id) {
+                let ballots = Self::close(&poll_id, &hermes_poll_info);
+                weight = weight.saturating_add(<T as Config>::WeightInfo::close_poll(ballots));
+            }
+
             // Withdraw Hermes
             Assets::<T>::transfer_from(
                 &T::HermesAssetId::get().into(),
@@ -473,12 +650,12 @@ pub mod pallet {
             ));
 
             // Return a successful DispatchResult
-            Ok(().into())
+            Ok(Some(weight).into())
         }
 
         /// Withdraw funds creator
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_funds_creator())]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_funds_creator().saturating_add(<T as Config>::WeightInfo::close_poll(Pallet::<T>::max_ranked_ballots())))]
         pub fn withdraw_funds_creator(
             origin: OriginFor<T>,
             poll_id: H256,
@@ -503,6 +680,12 @@ pub mod pallet {
                 Error::<T>::FundsAlreadyWithdrawn
             );
 
+            let mut weight = <T as Config>::WeightInfo::withdraw_funds_creator();
+            if !<HermesPollResults<T>>::contains_key(&poll_id) {
+                let ballots = Self::close(&poll_id, &hermes_poll_info);
+                weight = weight.saturating_add(<T as Config>::WeightInfo::close_poll(ballots));
+            }
+
             // Withdraw Creator Hermes
             Assets::<T>::transfer_from(
                 &T::HermesAssetId::get().into(),
@@ -521,7 +704,7 @@ pub mod pallet {
             ));
 
             // Return a successful DispatchResult
-            Ok(().into())
+            Ok(Some(weight).into())
         }
 
         /// Change minimum Hermes for voting
@@ -569,25 +752,810 @@ pub mod pallet {
             // Return a successful DispatchResult
             Ok(().into())
         }
-    }
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_runtime_upgrade() -> Weight {
-            if Self::pallet_storage_version() == StorageVersion::V1 {
-                let weight = migrations::migrate::<T>();
-                PalletStorageVersion::<T>::put(StorageVersion::V2);
-                weight
-            } else {
-                Weight::zero()
-            }
+        /// Change option of an existing vote before the poll ends
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::change_vote())]
+        pub fn change_vote(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            voting_option: BoundedString<T::StringLimit>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let current_timestamp = Timestamp::<T>::get();
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                current_timestamp <= hermes_poll_info.poll_end_timestamp,
+                Error::<T>::PollIsFinished
+            );
+
+            ensure!(
+                hermes_poll_info.mode != PollMode::RankedChoice,
+                Error::<T>::InvalidPollMode
+            );
+
+            ensure!(
+                hermes_poll_info.options.contains(&voting_option),
+                Error::<T>::InvalidOption
+            );
+
+            let mut hermes_voting_info =
+                <HermesVotings<T>>::get(&poll_id, &user).ok_or(Error::<T>::NotVoted)?;
+
+            // Move voting power to the new option
+            Self::remove_from_tally(
+                &poll_id,
+                &hermes_poll_info,
+                &hermes_voting_info.voting_option,
+                hermes_voting_info.votes,
+            );
+            Self::add_to_tally(
+                &poll_id,
+                &hermes_poll_info,
+                &voting_option,
+                hermes_voting_info.votes,
+            );
+
+            hermes_voting_info.voting_option = voting_option.clone();
+            <HermesVotings<T>>::insert(&poll_id, &user, hermes_voting_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::VoteChanged(user, poll_id, voting_option));
+
+            // Return a successful DispatchResult
+            Ok(().into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// The account ID of pallet
-        fn account_id() -> T::AccountId {
-            PALLET_ID.into_account_truncating()
+        /// Delegate voting power to another account
+        #[transactional]
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            target: AccountIdOf<T>,
+            number_of_hermes: Balance,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(user != target, Error::<T>::CannotDelegateToSelf);
+
+            ensure!(
+                !<HermesDelegations<T>>::contains_key(&user),
+                Error::<T>::AlreadyDelegating
+            );
+
+            ensure!(
+                number_of_hermes >= MinimumHermesVotingAmount::<T>::get(),
+                Error::<T>::NotEnoughHermesForVoting
+            );
+
+            // Transfer Hermes to pallet
+            Assets::<T>::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &user,
+                &Self::account_id(),
+                number_of_hermes,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
+
+            <HermesDelegatedVotes<T>>::mutate(&target, |votes| {
+                *votes = votes.saturating_add(conviction.votes(number_of_hermes))
+            });
+            <HermesDelegations<T>>::insert(
+                &user,
+                HermesDelegationInfo {
+                    target: target.clone(),
+                    number_of_hermes,
+                    conviction,
+                    unlock_timestamp: None,
+                },
+            );
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Delegated(user, target, number_of_hermes));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Remove delegation. Delegated Hermes stay locked until polls in which they could have
+        /// been used are finished, extended by the conviction lock.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+        pub fn undelegate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let mut delegation_info =
+                <HermesDelegations<T>>::get(&user).ok_or(Error::<T>::NotDelegating)?;
+            ensure!(
+                delegation_info.unlock_timestamp.is_none(),
+                Error::<T>::NotDelegating
+            );
+
+            let delegated_votes = delegation_info
+                .conviction
+                .votes(delegation_info.number_of_hermes);
+            <HermesDelegatedVotes<T>>::mutate_exists(&delegation_info.target, |votes| {
+                let remaining = votes.unwrap_or(0).saturating_sub(delegated_votes);
+                *votes = if remaining == 0 {
+                    None
+                } else {
+                    Some(remaining)
+                };
+            });
+
+            delegation_info.unlock_timestamp = Some(Self::unlock_timestamp(
+                Timestamp::<T>::get() + T::MAX_DURATION_OF_POLL,
+                delegation_info.conviction,
+            ));
+            let target = delegation_info.target.clone();
+            <HermesDelegations<T>>::insert(&user, delegation_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Undelegated(user, target));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Withdraw Hermes of a removed delegation
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_delegation())]
+        pub fn withdraw_delegation(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let delegation_info =
+                <HermesDelegations<T>>::get(&user).ok_or(Error::<T>::NotDelegating)?;
+            let unlock_timestamp = delegation_info
+                .unlock_timestamp
+                .ok_or(Error::<T>::DelegationIsActive)?;
+
+            ensure!(
+                Timestamp::<T>::get() > unlock_timestamp,
+                Error::<T>::FundsAreLocked
+            );
+
+            // Withdraw Hermes
+            Assets::<T>::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &Self::account_id(),
+                &user,
+                delegation_info.number_of_hermes,
+            )?;
+
+            <HermesDelegations<T>>::remove(&user);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::DelegationWithdrawn(
+                user,
+                delegation_info.number_of_hermes,
+            ));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Store results of a finished poll
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::close_poll(Pallet::<T>::max_ranked_ballots()))]
+        pub fn close_poll(origin: OriginFor<T>, poll_id: H256) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let current_timestamp = Timestamp::<T>::get();
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                current_timestamp > hermes_poll_info.poll_end_timestamp,
+                Error::<T>::PollIsNotFinished
+            );
+
+            ensure!(
+                !<HermesPollResults<T>>::contains_key(&poll_id),
+                Error::<T>::PollAlreadyClosed
+            );
+
+            let ballots = Self::close(&poll_id, &hermes_poll_info);
+
+            // Return a successful DispatchResult
+            Ok(Some(<T as Config>::WeightInfo::close_poll(ballots)).into())
+        }
+
+        /// Create poll with "Yes" and "No" options which dispatches the call when approved
+        #[transactional]
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_proposal())]
+        pub fn create_proposal(
+            origin: OriginFor<T>,
+            poll_start_timestamp: T::Moment,
+            poll_end_timestamp: T::Moment,
+            title: BoundedString<T::TitleLimit>,
+            description: BoundedString<T::DescriptionLimit>,
+            call: Box<<T as Config>::Proposal>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            ensure!(
+                T::ProposalFilter::contains(&call),
+                Error::<T>::CallNotAllowed
+            );
+
+            let mut options = BoundedVec::default();
+            options
+                .try_push(BoundedString::truncate_from("Yes"))
+                .map_err(|_| Error::<T>::InvalidVotingOptions)?;
+            options
+                .try_push(BoundedString::truncate_from("No"))
+                .map_err(|_| Error::<T>::InvalidVotingOptions)?;
+
+            let poll_id = Self::create(
+                user.clone(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                title,
+                description,
+                options,
+                PollMode::Standard,
+            )?;
+
+            <HermesProposals<T>>::insert(&poll_id, *call);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::ProposalCreated(user, poll_id));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Dispatch the call of the proposal if quorum and threshold are met, otherwise reject it
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::execute_proposal().saturating_add(<T as Config>::WeightInfo::close_poll(Pallet::<T>::max_ranked_ballots())).saturating_add(*max_weight))]
+        pub fn execute_proposal(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            max_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let call =
+                <HermesProposals<T>>::get(&poll_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                Timestamp::<T>::get()
+                    > hermes_poll_info.poll_end_timestamp + T::ProposalTimelock::get(),
+                Error::<T>::ProposalIsTimelocked
+            );
+
+            ensure!(
+                call.get_dispatch_info().weight.all_lte(max_weight),
+                Error::<T>::WrongProposalWeight
+            );
+
+            let mut weight = <T as Config>::WeightInfo::execute_proposal();
+            if !<HermesPollResults<T>>::contains_key(&poll_id) {
+                let ballots = Self::close(&poll_id, &hermes_poll_info);
+                weight = weight.saturating_add(<T as Config>::WeightInfo::close_poll(ballots));
+            }
+            <HermesProposals<T>>::remove(&poll_id);
+
+            let votes = <HermesPollResults<T>>::get(&poll_id)
+                .map(|result| result.votes)
+                .unwrap_or_default();
+            let ayes = votes.get(0).copied().unwrap_or(0);
+            let nays = votes.get(1).copied().unwrap_or(0);
+
+            if !Self::is_approved(ayes, nays) {
+                // Emit event
+                Self::deposit_event(Event::<T>::ProposalRejected(poll_id));
+                return Ok(Some(weight).into());
+            }
+
+            let result = call.clone().dispatch(RawOrigin::Proposal.into());
+            let actual_call_weight =
+                frame_support::dispatch::extract_actual_weight(&result, &call.get_dispatch_info());
+
+            // Emit event
+            Self::deposit_event(Event::<T>::ProposalExecuted(
+                poll_id,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+
+            Ok(Some(weight.saturating_add(actual_call_weight)).into())
+        }
+
+        /// Vote in a ranked-choice poll by ordering options from the most to the least
+        /// preferred one. Options which are not ranked are never counted for the ballot.
+        #[transactional]
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_ranked())]
+        pub fn vote_ranked(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            ranking: BoundedVec<BoundedString<T::StringLimit>, T::OptionsLimit>,
+            number_of_hermes: Balance,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                hermes_poll_info.mode == PollMode::RankedChoice,
+                Error::<T>::InvalidPollMode
+            );
+
+            let mut indices: BoundedVec<u32, T::OptionsLimit> = BoundedVec::default();
+            for option in ranking.iter() {
+                let index = hermes_poll_info
+                    .options
+                    .iter()
+                    .position(|poll_option| poll_option == option)
+                    .ok_or(Error::<T>::InvalidRanking)? as u32;
+                ensure!(!indices.contains(&index), Error::<T>::InvalidRanking);
+                indices
+                    .try_push(index)
+                    .map_err(|_| Error::<T>::InvalidRanking)?;
+            }
+            let first_choice = ranking.first().ok_or(Error::<T>::InvalidRanking)?;
+
+            let votes = Self::do_vote(
+                &user,
+                &poll_id,
+                &hermes_poll_info,
+                first_choice,
+                number_of_hermes,
+                conviction,
+            )?;
+            <HermesRankedBallots<T>>::mutate(&poll_id, &indices, |ballot_votes| {
+                *ballot_votes = ballot_votes.saturating_add(votes)
+            });
+
+            // Emit event
+            Self::deposit_event(Event::<T>::RankedVoted(user, poll_id, ranking));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Vote for some option by locking `number_of_hermes`, which can't be less than the
+        /// minimum amount for voting. In quadratic polls the voting power grows with the square
+        /// root of the locked amount.
+        #[transactional]
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote())]
+        pub fn vote_with_stake(
+            origin: OriginFor<T>,
+            poll_id: H256,
+            voting_option: BoundedString<T::StringLimit>,
+            number_of_hermes: Balance,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            Self::vote_for_option(user, poll_id, voting_option, number_of_hermes, conviction)?;
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            match Self::pallet_storage_version() {
+                StorageVersion::V1 => {
+                    let weight = migrations::migrate::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V2 => {
+                    // Poll data has to be readable before tallies are built
+                    let weight = migrations::migrate_poll_mode::<T>()
+                        .saturating_add(migrations::migrate_to_conviction_voting::<T>());
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V3 => {
+                    let weight = migrations::migrate_poll_mode::<T>();
+                    PalletStorageVersion::<T>::put(StorageVersion::V4);
+                    weight
+                }
+                StorageVersion::V4 => Weight::zero(),
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account ID of pallet
+        fn account_id() -> T::AccountId {
+            PALLET_ID.into_account_truncating()
+        }
+
+        fn create(
+            user: AccountIdOf<T>,
+            poll_start_timestamp: T::Moment,
+            poll_end_timestamp: T::Moment,
+            title: BoundedString<T::TitleLimit>,
+            description: BoundedString<T::DescriptionLimit>,
+            options: BoundedVec<BoundedString<T::StringLimit>, T::OptionsLimit>,
+            mode: PollMode,
+        ) -> Result<H256, DispatchError> {
+            let current_timestamp = Timestamp::<T>::get();
+
+            ensure!(
+                poll_start_timestamp >= current_timestamp,
+                Error::<T>::InvalidStartTimestamp
+            );
+
+            ensure!(
+                poll_end_timestamp > poll_start_timestamp,
+                Error::<T>::InvalidEndTimestamp
+            );
+
+            ensure!(
+                (poll_end_timestamp - poll_start_timestamp) >= T::MIN_DURATION_OF_POLL,
+                Error::<T>::InvalidMinimumDurationOfPoll
+            );
+
+            ensure!(
+                (poll_end_timestamp - poll_start_timestamp) <= T::MAX_DURATION_OF_POLL,
+                Error::<T>::InvalidMaximumDurationOfPoll
+            );
+
+            ensure!(
+                MinimumHermesAmountForCreatingPoll::<T>::get()
+                    <= Assets::<T>::free_balance(&T::HermesAssetId::get().into(), &user)
+                        .unwrap_or(0),
+                Error::<T>::NotEnoughHermesForCreatingPoll
+            );
+
+            let nonce = frame_system::Pallet::<T>::account_nonce(&user);
+            let encoded: [u8; 32] = (&user, nonce).using_encoded(blake2_256);
+            let poll_id = H256::from(encoded);
+
+            let options_len = options.len();
+            if options_len < 2 {
+                return Err(Error::<T>::InvalidVotingOptions.into());
+            }
+            if options_len > 5 {
+                return Err(Error::<T>::TooManyVotingOptions.into());
+            }
+
+            let options_set = BTreeSet::from_iter(&options);
+            if options_set.len() != options_len {
+                return Err(Error::<T>::DuplicateOptions.into());
+            }
+
+            let hermes_poll_info = HermesPollInfo {
+                creator: user.clone(),
+                hermes_locked: MinimumHermesAmountForCreatingPoll::<T>::get(),
+                poll_start_timestamp,
+                poll_end_timestamp,
+                title: title.clone(),
+                description,
+                creator_hermes_withdrawn: false,
+                options,
+                mode,
+            };
+
+            // Transfer Hermes to pallet
+            Assets::<T>::transfer_from(
+                &T::HermesAssetId::get().into(),
+                &user.clone(),
+                &Self::account_id(),
+                hermes_poll_info.hermes_locked,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForCreatingPoll)?;
+
+            <HermesPollData<T>>::insert(&poll_id, hermes_poll_info);
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Created(
+                user.clone(),
+                title,
+                poll_start_timestamp,
+                poll_end_timestamp,
+            ));
+
+            Ok(poll_id)
+        }
+
+        /// Vote for the option of a standard or quadratic poll by locking `number_of_hermes`
+        fn vote_for_option(
+            user: AccountIdOf<T>,
+            poll_id: H256,
+            voting_option: BoundedString<T::StringLimit>,
+            number_of_hermes: Balance,
+            conviction: Conviction,
+        ) -> DispatchResult {
+            let hermes_poll_info =
+                <HermesPollData<T>>::get(&poll_id).ok_or(Error::<T>::PollDoesNotExist)?;
+
+            ensure!(
+                hermes_poll_info.mode != PollMode::RankedChoice,
+                Error::<T>::InvalidPollMode
+            );
+
+            ensure!(
+                hermes_poll_info.options.contains(&voting_option),
+                Error::<T>::InvalidOption
+            );
+
+            Self::do_vote(
+                &user,
+                &poll_id,
+                &hermes_poll_info,
+                &voting_option,
+                number_of_hermes,
+                conviction,
+            )?;
+
+            // Emit event
+            Self::deposit_event(Event::<T>::Voted(user, poll_id, voting_option));
+
+            Ok(())
+        }
+
+        /// Lock Hermes of the user in the poll and add the voting power to the chosen option
+        fn do_vote(
+            user: &AccountIdOf<T>,
+            poll_id: &H256,
+            hermes_poll_info: &HermesPollInfoOf<T>,
+            voting_option: &BoundedString<T::StringLimit>,
+            number_of_hermes: Balance,
+            conviction: Conviction,
+        ) -> Result<Balance, DispatchError> {
+            let current_timestamp = Timestamp::<T>::get();
+
+            ensure!(
+                current_timestamp >= hermes_poll_info.poll_start_timestamp,
+                Error::<T>::PollIsNotStarted
+            );
+
+            ensure!(
+                current_timestamp <= hermes_poll_info.poll_end_timestamp,
+                Error::<T>::PollIsFinished
+            );
+
+            ensure!(
+                number_of_hermes >= MinimumHermesVotingAmount::<T>::get(),
+                Error::<T>::NotEnoughHermesForVoting
+            );
+
+            ensure!(
+                !<HermesVotings<T>>::contains_key(poll_id, user),
+                Error::<T>::AlreadyVoted
+            );
+
+            ensure!(
+                !Self::is_delegating(user),
+                Error::<T>::VotingPowerIsDelegated
+            );
+
+            let votes = Self::voting_power(
+                hermes_poll_info.mode,
+                conviction
+                    .votes(number_of_hermes)
+                    .saturating_add(HermesDelegatedVotes::<T>::get(user)),
+            );
+            let hermes_voting_info = HermesVotingInfo {
+                voting_option: voting_option.clone(),
+                number_of_hermes,
+                hermes_withdrawn: false,
+                conviction,
+                votes,
+            };
+
+            // Transfer Hermes to pallet
+            Assets::<T>::transfer_from(
+                &T::HermesAssetId::get().into(),
+                user,
+                &Self::account_id(),
+                hermes_voting_info.number_of_hermes,
+            )
+            .map_err(|_assets_err| Error::<T>::NotEnoughHermesForVoting)?;
+
+            // Update storage
+            Self::add_to_tally(poll_id, hermes_poll_info, voting_option, votes);
+            <HermesVotings<T>>::insert(poll_id, user, hermes_voting_info);
+
+            Ok(votes)
+        }
+
+        /// Voting power of `votes` in a poll with the given mode. In quadratic polls it is the
+        /// square root of the amount of Hermes, so 100 HER result in 10 HER of voting power.
+        pub fn voting_power(mode: PollMode, votes: Balance) -> Balance {
+            match mode {
+                PollMode::Quadratic => votes.integer_sqrt().saturating_mul(1_000_000_000),
+                PollMode::Standard | PollMode::RankedChoice => votes,
+            }
+        }
+
+        /// Timestamp after which Hermes locked with `conviction` can be withdrawn
+        fn unlock_timestamp(end_timestamp: T::Moment, conviction: Conviction) -> T::Moment {
+            end_timestamp + T::CONVICTION_LOCK_PERIOD * conviction.lock_periods().into()
+        }
+
+        /// Check if proposal voting power meets quorum and threshold
+        pub fn is_approved(ayes: Balance, nays: Balance) -> bool {
+            let total = ayes.saturating_add(nays);
+            ayes > 0
+                && total >= T::ProposalQuorum::get()
+                && Perbill::from_rational(ayes, total) >= T::ProposalThreshold::get()
+        }
+
+        /// Check if user has active delegation
+        pub fn is_delegating(user: &AccountIdOf<T>) -> bool {
+            <HermesDelegations<T>>::get(user)
+                .map(|delegation_info| delegation_info.unlock_timestamp.is_none())
+                .unwrap_or(false)
+        }
+
+        pub(crate) fn add_to_tally(
+            poll_id: &H256,
+            hermes_poll_info: &HermesPollInfoOf<T>,
+            voting_option: &BoundedString<T::StringLimit>,
+            votes: Balance,
+        ) {
+            Self::update_tally(poll_id, hermes_poll_info, voting_option, |option_votes| {
+                option_votes.saturating_add(votes)
+            });
+        }
+
+        fn remove_from_tally(
+            poll_id: &H256,
+            hermes_poll_info: &HermesPollInfoOf<T>,
+            voting_option: &BoundedString<T::StringLimit>,
+            votes: Balance,
+        ) {
+            Self::update_tally(poll_id, hermes_poll_info, voting_option, |option_votes| {
+                option_votes.saturating_sub(votes)
+            });
+        }
+
+        fn update_tally(
+            poll_id: &H256,
+            hermes_poll_info: &HermesPollInfoOf<T>,
+            voting_option: &BoundedString<T::StringLimit>,
+            f: impl FnOnce(Balance) -> Balance,
+        ) {
+            let index = match hermes_poll_info
+                .options
+                .iter()
+                .position(|option| option == voting_option)
+            {
+                Some(index) => index,
+                None => return,
+            };
+
+            <HermesPollTallies<T>>::mutate(poll_id, |tally| {
+                while tally.len() < hermes_poll_info.options.len() {
+                    if tally.try_push(0).is_err() {
+                        return;
+                    }
+                }
+                tally[index] = f(tally[index]);
+            });
+        }
+
+        /// Move poll tally to results and determine the winning option. Ranked-choice polls
+        /// iterate all ballots, so callers must charge `WeightInfo::close_poll` for the returned
+        /// number of ballots.
+        fn close(poll_id: &H256, hermes_poll_info: &HermesPollInfoOf<T>) -> u32 {
+            let mut votes = <HermesPollTallies<T>>::take(poll_id);
+            let mut ballots_count = 0;
+            while votes.len() < hermes_poll_info.options.len() {
+                if votes.try_push(0).is_err() {
+                    break;
+                }
+            }
+
+            let winning_index = if hermes_poll_info.mode == PollMode::RankedChoice {
+                let ballots: Vec<(BoundedVec<u32, T::OptionsLimit>, Balance)> =
+                    <HermesRankedBallots<T>>::drain_prefix(poll_id).collect();
+                ballots_count = ballots.len() as u32;
+                Self::instant_runoff(&ballots, &mut votes)
+            } else {
+                let max_votes = votes.iter().copied().max().unwrap_or(0);
+                if max_votes == 0
+                    || votes
+                        .iter()
+                        .filter(|option_votes| **option_votes == max_votes)
+                        .count()
+                        > 1
+                {
+                    None
+                } else {
+                    votes
+                        .iter()
+                        .position(|option_votes| *option_votes == max_votes)
+                }
+            };
+            let winning_option =
+                winning_index.and_then(|index| hermes_poll_info.options.get(index).cloned());
+
+            <HermesPollResults<T>>::insert(
+                poll_id,
+                HermesPollResult {
+                    votes,
+                    winning_option: winning_option.clone(),
+                },
+            );
+
+            Self::deposit_event(Event::<T>::PollClosed(*poll_id, winning_option));
+
+            ballots_count
+        }
+
+        /// Maximum number of ranked-choice ballots of a poll, i.e. the number of all rankings
+        /// which can be made of `OptionsLimit` options.
+        pub fn max_ranked_ballots() -> u32 {
+            let options = T::OptionsLimit::get();
+            let mut rankings: u32 = 1;
+            let mut total: u32 = 0;
+            for ranked in 0..options {
+                rankings = rankings.saturating_mul(options - ranked);
+                total = total.saturating_add(rankings);
+            }
+            total
+        }
+
+        /// Determine the winner of a ranked-choice poll. In each round every ballot counts for
+        /// its most preferred option which is not eliminated. The option with more than half of
+        /// the counted votes wins, otherwise the options with the fewest votes are eliminated.
+        /// Ballots are aggregated by ranking, so the work is bounded by the number of possible
+        /// rankings times the number of options. `votes` is set to the last round counts.
+        fn instant_runoff(
+            ballots: &[(BoundedVec<u32, T::OptionsLimit>, Balance)],
+            votes: &mut BoundedVec<Balance, T::OptionsLimit>,
+        ) -> Option<usize> {
+            let mut eliminated: Vec<bool> = votes.iter().map(|_| false).collect();
+
+            loop {
+                votes.iter_mut().for_each(|option_votes| *option_votes = 0);
+                for (ranking, ballot_votes) in ballots.iter() {
+                    if let Some(index) = ranking
+                        .iter()
+                        .map(|index| *index as usize)
+                        .find(|index| *index < votes.len() && !eliminated[*index])
+                    {
+                        votes[index] = votes[index].saturating_add(*ballot_votes);
+                    }
+                }
+
+                let total = votes.iter().fold(0, |acc: Balance, option_votes| {
+                    acc.saturating_add(*option_votes)
+                });
+                if total == 0 {
+                    return None;
+                }
+
+                let remaining: Vec<usize> = (0..votes.len()).filter(|i| !eliminated[*i]).collect();
+                let (winner, max_votes) = remaining
+                    .iter()
+                    .map(|i| (*i, votes[*i]))
+                    .max_by_key(|(_, option_votes)| *option_votes)?;
+                if max_votes.saturating_mul(2) > total {
+                    return Some(winner);
+                }
+
+                let min_votes = remaining.iter().map(|i| votes[*i]).min()?;
+                if min_votes == max_votes {
+                    return None;
+                }
+                for i in remaining {
+                    if votes[i] == min_votes {
+                        eliminated[i] = true;
+                    }
+                }
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    AccountIdOf, Balance, Config, HermesPollData, HermesPollInfo, HermesVotingInfo, HermesVotings,
+    AccountIdOf, Balance, Config, Conviction, HermesPollData, HermesPollInfo, HermesVotingInfo,
+    HermesVotings, Pallet, PollMode,
 };
 use alloc::string::String;
 use codec::{Decode, Encode};
@@ -16,6 +17,27 @@ pub enum VotingOption {
     No,
 }
 
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct OldHermesVotingInfo {
+    /// Voting option
+    pub voting_option: VotingOption,
+    /// Number of hermes
+    pub number_of_hermes: Balance,
+    /// Hermes withdrawn
+    pub hermes_withdrawn: bool,
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(StringLimit))]
+pub struct HermesVotingInfoV2<StringLimit: sp_core::Get<u32>> {
+    /// Voting option
+    pub voting_option: BoundedString<StringLimit>,
+    /// Number of hermes
+    pub number_of_hermes: Balance,
+    /// Hermes withdrawn
+    pub hermes_withdrawn: bool,
+}
+
 #[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
 pub struct OldHermesPollInfo<AccountId, Moment> {
     /// Creator of poll
@@ -34,16 +56,99 @@ pub struct OldHermesPollInfo<AccountId, Moment> {
     pub creator_hermes_withdrawn: bool,
 }
 
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(StringLimit, OptionsLimit, TitleLimit, DescriptionLimit))]
+pub struct HermesPollInfoV3<
+    AccountId,
+    Moment,
+    StringLimit: sp_core::Get<u32>,
+    OptionsLimit: sp_core::Get<u32>,
+    TitleLimit: sp_core::Get<u32>,
+    DescriptionLimit: sp_core::Get<u32>,
+> {
+    /// Creator of poll
+    pub creator: AccountId,
+    /// Hermes Locked
+    pub hermes_locked: Balance,
+    /// Poll start timestamp
+    pub poll_start_timestamp: Moment,
+    /// Poll end timestamp
+    pub poll_end_timestamp: Moment,
+    /// Poll title
+    pub title: BoundedString<TitleLimit>,
+    /// Description
+    pub description: BoundedString<DescriptionLimit>,
+    /// Creator Hermes withdrawn
+    pub creator_hermes_withdrawn: bool,
+    /// Options
+    pub options: BoundedVec<BoundedString<StringLimit>, OptionsLimit>,
+}
+
 pub fn migrate<T: Config>() -> Weight {
     sp_runtime::runtime_logger::RuntimeLogger::init();
-    migrate_voting_and_poll_data::<T>()
+    migrate_voting_and_poll_data::<T>().saturating_add(build_poll_tallies::<T>())
+}
+
+pub fn migrate_to_conviction_voting<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    HermesVotings::<T>::translate_values::<HermesVotingInfoV2<T::StringLimit>, _>(|v| {
+        weight += 1;
+
+        Some(HermesVotingInfo {
+            voting_option: v.voting_option,
+            number_of_hermes: v.number_of_hermes,
+            hermes_withdrawn: v.hermes_withdrawn,
+            conviction: Conviction::None,
+            votes: v.number_of_hermes,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "HermesVotingInfo migrated to new version with 'conviction' and 'votes' fields"
+    );
+
+    T::DbWeight::get()
+        .reads_writes(weight, weight)
+        .saturating_add(build_poll_tallies::<T>())
+}
+
+pub fn build_poll_tallies<T: Config>() -> Weight {
+    let mut weight: u64 = 0;
+
+    for (poll_id, _, voting_info) in HermesVotings::<T>::iter() {
+        weight += 1;
+
+        if let Some(poll_info) = HermesPollData::<T>::get(&poll_id) {
+            weight += 1;
+            Pallet::<T>::add_to_tally(
+                &poll_id,
+                &poll_info,
+                &voting_info.voting_option,
+                voting_info.votes,
+            );
+        }
+    }
+
+    log::info!(
+        target: "runtime",
+        "HermesPollTallies built from existing votes"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
 }
 
 pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
     let mut weight: u64 = 0;
 
-    HermesVotings::<T>::translate_values::<(VotingOption, Balance, bool), _>(
-        |(voting_option, number_of_hermes, hermes_withdrawn)| {
+    HermesVotings::<T>::translate_values::<OldHermesVotingInfo, _>(
+        |OldHermesVotingInfo {
+             voting_option,
+             number_of_hermes,
+             hermes_withdrawn,
+         }| {
             weight += 1;
 
             let new_voting_option;
@@ -58,6 +163,8 @@ pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
                 voting_option: BoundedString::truncate_from(new_voting_option),
                 number_of_hermes,
                 hermes_withdrawn,
+                conviction: Conviction::None,
+                votes: number_of_hermes,
             })
         },
     );
@@ -78,6 +185,7 @@ pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
             description: BoundedString::truncate_from(v.description.as_str()),
             creator_hermes_withdrawn: v.creator_hermes_withdrawn,
             options,
+            mode: PollMode::Standard,
         })
     });
 
@@ -88,3 +196,41 @@ pub fn migrate_voting_and_poll_data<T: Config>() -> Weight {
 
     T::DbWeight::get().reads_writes(weight, weight)
 }
+
+pub fn migrate_poll_mode<T: Config>() -> Weight {
+    sp_runtime::runtime_logger::RuntimeLogger::init();
+    let mut weight: u64 = 0;
+
+    HermesPollData::<T>::translate_values::<
+        HermesPollInfoV3<
+            AccountIdOf<T>,
+            T::Moment,
+            T::StringLimit,
+            T::OptionsLimit,
+            T::TitleLimit,
+            T::DescriptionLimit,
+        >,
+        _,
+    >(|v| {
+        weight += 1;
+
+        Some(HermesPollInfo {
+            creator: v.creator,
+            hermes_locked: v.hermes_locked,
+            poll_start_timestamp: v.poll_start_timestamp,
+            poll_end_timestamp: v.poll_end_timestamp,
+            title: v.title,
+            description: v.description,
+            creator_hermes_withdrawn: v.creator_hermes_withdrawn,
+            options: v.options,
+            mode: PollMode::Standard,
+        })
+    });
+
+    log::info!(
+        target: "runtime",
+        "HermesPollInfo migrated to new version with 'mode' field"
+    );
+
+    T::DbWeight::get().reads_writes(weight, weight)
+}
@@ -43,9 +43,9 @@ construct_runtime! {
         PoolXYK: pool_xyk::{Pallet, Call, Storage, Event<T>},
         PswapDistribution: pswap_distribution::{Pallet, Call, Config<T>, Storage, Event<T>},
         CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>},
-        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Event<T>},
+        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Origin, Event<T>},
         DemeterFarmingPlatform: demeter_farming_platform::{Pallet, Call, Storage, Event<T>},
-        HermesGovernancePlatform: hermes_governance_platform::{Pallet, Call, Storage, Event<T>},
+        HermesGovernancePlatform: hermes_governance_platform::{Pallet, Call, Storage, Origin, Event<T>},
     }
 }
 
@@ -116,17 +116,27 @@ parameter_types! {
     pub const OptionsLimit: u32 = 5;
     pub const TitleLimit: u32 = 128;
     pub const DescriptionLimit: u32 = 4096;
+    pub const ProposalTimelock: u64 = 86_400_000;
+    pub const ProposalQuorum: Balance = balance!(1000);
+    pub ProposalThreshold: Perbill = Perbill::from_percent(60);
 }
 
 impl crate::Config for Runtime {
     const MIN_DURATION_OF_POLL: Self::Moment = 14_400_000;
     const MAX_DURATION_OF_POLL: Self::Moment = 604_800_000;
+    const CONVICTION_LOCK_PERIOD: Self::Moment = 86_400_000;
     type StringLimit = StringLimit;
     type OptionsLimit = OptionsLimit;
     type TitleLimit = TitleLimit;
     type DescriptionLimit = DescriptionLimit;
     type RuntimeEvent = RuntimeEvent;
     type HermesAssetId = HermesAssetId;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type ProposalFilter = Everything;
+    type ProposalTimelock = ProposalTimelock;
+    type ProposalQuorum = ProposalQuorum;
+    type ProposalThreshold = ProposalThreshold;
     type WeightInfo = ();
 }
 
@@ -179,12 +189,19 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = hermes_governance_platform::EnsureProposal;
     type WeightInfo = ();
 }
 
 impl ceres_governance_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type CeresAssetId = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type ProposalFilter = Everything;
+    type ProposalTimelock = ProposalTimelock;
+    type ProposalQuorum = ProposalQuorum;
+    type ProposalThreshold = ProposalThreshold;
     type WeightInfo = ();
 }
 
@@ -201,6 +218,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -1,12 +1,17 @@
-use crate::migrations::{OldHermesPollInfo, VotingOption};
+use crate::migrations::{
+    HermesPollInfoV3, HermesVotingInfoV2, OldHermesPollInfo, OldHermesVotingInfo, VotingOption,
+};
 use crate::mock::*;
 use crate::AccountIdOf;
-use crate::{pallet, Error, HermesPollInfo};
+use crate::{pallet, Conviction, Error, HermesPollInfo, PollMode, StorageVersion, WeightInfo};
 use codec::Encode;
-use common::{balance, generate_storage_instance, AssetInfoProvider, HERMES_ASSET_ID};
+use common::{
+    balance, generate_storage_instance, AssetInfoProvider, BoundedString, HERMES_ASSET_ID,
+};
 use frame_support::pallet_prelude::{StorageDoubleMap, StorageMap};
 use frame_support::storage::types::OptionQuery;
 use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use frame_support::PalletId;
 use frame_support::{assert_err, assert_ok, Identity};
@@ -35,7 +40,8 @@ fn create_poll_invalid_start_timestamp() {
                 current_timestamp + 10,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::InvalidStartTimestamp
         );
@@ -61,7 +67,8 @@ fn create_poll_invalid_end_timestamp() {
                 current_timestamp,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::InvalidEndTimestamp
         );
@@ -86,7 +93,8 @@ fn create_poll_invalid_minimum_duration_of_poll() {
                 current_timestamp + 15,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::InvalidMinimumDurationOfPoll
         );
@@ -111,7 +119,8 @@ fn create_poll_invalid_maximum_duration_of_poll() {
                 current_timestamp + 604800001,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::InvalidMaximumDurationOfPoll
         );
@@ -138,7 +147,8 @@ fn create_poll_not_enough_hermes_for_creating_poll() {
                 current_timestamp + 14_400_000,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::NotEnoughHermesForCreatingPoll
         );
@@ -162,7 +172,8 @@ fn create_poll_invalid_voting_options() {
                 current_timestamp + 14_400_000,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::InvalidVotingOptions
         );
@@ -188,7 +199,8 @@ fn create_poll_duplicate_options() {
                 current_timestamp + 14_400_000,
                 title.try_into().unwrap(),
                 description.try_into().unwrap(),
-                options
+                options,
+                PollMode::Standard
             ),
             Error::<Runtime>::DuplicateOptions
         );
@@ -218,7 +230,8 @@ fn create_poll_ok() {
             poll_end_timestamp,
             title.try_into().unwrap(),
             description.try_into().unwrap(),
-            options
+            options,
+            PollMode::Standard
         ));
 
         for (_, p_info) in pallet::HermesPollData::<Runtime>::iter() {
@@ -256,7 +269,12 @@ fn vote_poll_does_not_exist() {
         let poll_id = H256::from(encoded);
 
         assert_err!(
-            HermesGovernancePlatform::vote(RuntimeOrigin::signed(ALICE), poll_id, voting_option,),
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                voting_option,
+                Conviction::None
+            ),
             Error::<Runtime>::PollDoesNotExist
         );
     });
@@ -290,12 +308,18 @@ fn vote_poll_is_not_started() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
 
         assert_err!(
-            HermesGovernancePlatform::vote(RuntimeOrigin::signed(ALICE), poll_id, voting_option),
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                voting_option,
+                Conviction::None
+            ),
             Error::<Runtime>::PollIsNotStarted
         );
     });
@@ -328,13 +352,19 @@ fn vote_poll_is_finished() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
 
         assert_err!(
-            HermesGovernancePlatform::vote(RuntimeOrigin::signed(ALICE), poll_id, voting_option,),
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                voting_option,
+                Conviction::None
+            ),
             Error::<Runtime>::PollIsFinished
         );
     });
@@ -366,12 +396,18 @@ fn vote_invalid_option() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
 
         assert_err!(
-            HermesGovernancePlatform::vote(RuntimeOrigin::signed(ALICE), poll_id, voting_option,),
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                voting_option,
+                Conviction::None
+            ),
             Error::<Runtime>::InvalidOption
         );
     });
@@ -388,7 +424,7 @@ fn vote_not_enough_hermes_for_voting() {
         let nonce = frame_system::Pallet::<Runtime>::account_nonce(&user);
         let encoded: [u8; 32] = (&user, nonce).using_encoded(blake2_256);
         let poll_id = H256::from(encoded);
-        let voting_option = "Option 1".try_into().unwrap();
+        let voting_option: BoundedString<StringLimit> = "Option 1".try_into().unwrap();
         let mut options = BoundedVec::default();
         options.try_push("Option 1".try_into().unwrap()).unwrap();
         options.try_push("Option 2".try_into().unwrap()).unwrap();
@@ -402,12 +438,29 @@ fn vote_not_enough_hermes_for_voting() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
 
         assert_err!(
-            HermesGovernancePlatform::vote(RuntimeOrigin::signed(BOB), poll_id, voting_option),
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(BOB),
+                poll_id,
+                voting_option.clone(),
+                Conviction::None
+            ),
+            Error::<Runtime>::NotEnoughHermesForVoting
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::vote_with_stake(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                voting_option,
+                balance!(999),
+                Conviction::None
+            ),
             Error::<Runtime>::NotEnoughHermesForVoting
         );
     });
@@ -439,6 +492,7 @@ fn vote_already_voted() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -446,14 +500,16 @@ fn vote_already_voted() {
         assert_ok!(HermesGovernancePlatform::vote(
             RuntimeOrigin::signed(ALICE),
             poll_id.clone(),
-            voting_option.try_into().unwrap()
+            voting_option.try_into().unwrap(),
+            Conviction::None
         ));
 
         assert_err!(
             HermesGovernancePlatform::vote(
                 RuntimeOrigin::signed(ALICE),
                 poll_id.clone(),
-                voting_option.try_into().unwrap()
+                voting_option.try_into().unwrap(),
+                Conviction::None
             ),
             Error::<Runtime>::AlreadyVoted
         );
@@ -488,6 +544,7 @@ fn vote_ok() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -496,6 +553,7 @@ fn vote_ok() {
             RuntimeOrigin::signed(ALICE),
             poll_id.clone(),
             voting_option.try_into().unwrap(),
+            Conviction::None
         ));
 
         let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
@@ -565,6 +623,7 @@ fn withdraw_funds_voter_poll_is_not_finished() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -573,6 +632,7 @@ fn withdraw_funds_voter_poll_is_not_finished() {
             RuntimeOrigin::signed(ALICE),
             poll_id.clone(),
             voting_option,
+            Conviction::None
         ));
 
         assert_err!(
@@ -608,6 +668,7 @@ fn withdraw_funds_voter_not_voted() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -647,6 +708,7 @@ fn withdraw_funds_voter_funds_already_withdrawn() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -655,6 +717,7 @@ fn withdraw_funds_voter_funds_already_withdrawn() {
             RuntimeOrigin::signed(ALICE),
             poll_id.clone(),
             voting_option,
+            Conviction::None
         ));
 
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604900000);
@@ -702,6 +765,7 @@ fn withdraw_funds_voter_ok() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -709,7 +773,8 @@ fn withdraw_funds_voter_ok() {
         assert_ok!(HermesGovernancePlatform::vote(
             RuntimeOrigin::signed(ALICE),
             poll_id.clone(),
-            voting_option.try_into().unwrap()
+            voting_option.try_into().unwrap(),
+            Conviction::None
         ));
 
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604900000);
@@ -785,6 +850,7 @@ fn withdraw_funds_creator_you_are_not_creator() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -826,6 +892,7 @@ fn withdraw_funds_creator_poll_is_not_finished() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -867,6 +934,7 @@ fn withdraw_funds_creator_funds_already_withdrawn() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -922,6 +990,7 @@ fn withdraw_funds_creator_ok() {
             description: "Description".try_into().unwrap(),
             creator_hermes_withdrawn: false,
             options,
+            mode: PollMode::Standard,
         };
 
         pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
@@ -1100,8 +1169,18 @@ fn hermes_governance_storage_migration_works() {
         let voting_b = pallet::HermesVotings::<Runtime>::get(&poll_id_b, &user1).unwrap();
         assert_eq!(poll_b.options, options);
         assert_eq!(voting_b.voting_option, "No".try_into().unwrap());
+        assert_eq!(voting_b.conviction, Conviction::None);
+        assert_eq!(voting_b.votes, number_of_hermes);
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id_a).into_inner(),
+            vec![number_of_hermes, number_of_hermes]
+        );
+        assert_eq!(
+            pallet::PalletStorageVersion::<Runtime>::get(),
+            StorageVersion::V4
+        );
 
-        // Storage version should be V2 so no changes made
+        // Storage version should be V4 so no changes made
         pallet_timestamp::Pallet::<Runtime>::set_timestamp(11000000);
         run_to_block(10);
 
@@ -1119,3 +1198,928 @@ fn hermes_governance_storage_migration_works() {
         assert_eq!(voting_b.voting_option, "No".try_into().unwrap());
     });
 }
+
+fn insert_poll(creator: AccountId, options: &[&str]) -> H256 {
+    insert_poll_with_mode(creator, options, PollMode::Standard)
+}
+
+fn insert_poll_with_mode(creator: AccountId, options: &[&str], mode: PollMode) -> H256 {
+    let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+    let poll_end_timestamp = poll_start_timestamp + 604800000;
+    let nonce = frame_system::Pallet::<Runtime>::account_nonce(&creator);
+    let encoded: [u8; 32] = (&creator, nonce).using_encoded(blake2_256);
+    let poll_id = H256::from(encoded);
+    let mut poll_options = BoundedVec::default();
+    for option in options {
+        poll_options
+            .try_push((*option).try_into().unwrap())
+            .unwrap();
+    }
+
+    let hermes_poll_info = HermesPollInfo {
+        creator,
+        hermes_locked: pallet::MinimumHermesAmountForCreatingPoll::<Runtime>::get(),
+        poll_start_timestamp,
+        poll_end_timestamp,
+        title: "Title".try_into().unwrap(),
+        description: "Description".try_into().unwrap(),
+        creator_hermes_withdrawn: false,
+        options: poll_options,
+        mode,
+    };
+
+    pallet::HermesPollData::<Runtime>::insert(&poll_id, &hermes_poll_info);
+
+    poll_id
+}
+
+#[test]
+fn vote_with_conviction_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+        let number_of_hermes = pallet::MinimumHermesVotingAmount::<Runtime>::get();
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::Locked3x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "No".try_into().unwrap(),
+            Conviction::None
+        ));
+
+        let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(hermes_voting_info.conviction, Conviction::Locked3x);
+        assert_eq!(hermes_voting_info.number_of_hermes, number_of_hermes);
+        assert_eq!(hermes_voting_info.votes, number_of_hermes * 3);
+
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![number_of_hermes * 3, number_of_hermes]
+        );
+
+        // Only the minimum amount is locked regardless of conviction
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &ALICE).expect("Failed to query free balance."),
+            balance!(299000)
+        );
+    });
+}
+
+#[test]
+fn withdraw_funds_voter_funds_are_locked() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::Locked3x
+        ));
+
+        // Poll is finished, but Hermes are locked for two more periods
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + 2 * 86400000,
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_funds_voter(RuntimeOrigin::signed(ALICE), poll_id),
+            Error::<Runtime>::FundsAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + 2 * 86400000 + 1,
+        );
+
+        assert_ok!(HermesGovernancePlatform::withdraw_funds_voter(
+            RuntimeOrigin::signed(ALICE),
+            poll_id
+        ));
+
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &ALICE).expect("Failed to query free balance."),
+            balance!(300000)
+        );
+
+        // Withdrawal closes the poll
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(result.winning_option, Some("Yes".try_into().unwrap()));
+    });
+}
+
+#[test]
+fn change_vote_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+        let number_of_hermes = pallet::MinimumHermesVotingAmount::<Runtime>::get();
+
+        assert_err!(
+            HermesGovernancePlatform::change_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                "No".try_into().unwrap()
+            ),
+            Error::<Runtime>::NotVoted
+        );
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::Locked2x
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::change_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                "Maybe".try_into().unwrap()
+            ),
+            Error::<Runtime>::InvalidOption
+        );
+
+        assert_ok!(HermesGovernancePlatform::change_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "No".try_into().unwrap()
+        ));
+
+        let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(hermes_voting_info.voting_option, "No".try_into().unwrap());
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![0, number_of_hermes * 2]
+        );
+    });
+}
+
+#[test]
+fn change_vote_poll_is_finished() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::None
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
+
+        assert_err!(
+            HermesGovernancePlatform::change_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                "No".try_into().unwrap()
+            ),
+            Error::<Runtime>::PollIsFinished
+        );
+    });
+}
+
+#[test]
+fn delegate_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+        let number_of_hermes = pallet::MinimumHermesVotingAmount::<Runtime>::get();
+
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(CHARLES),
+                CHARLES,
+                balance!(2000),
+                Conviction::None
+            ),
+            Error::<Runtime>::CannotDelegateToSelf
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(CHARLES),
+                ALICE,
+                balance!(1),
+                Conviction::None
+            ),
+            Error::<Runtime>::NotEnoughHermesForVoting
+        );
+
+        assert_ok!(HermesGovernancePlatform::delegate(
+            RuntimeOrigin::signed(CHARLES),
+            ALICE,
+            balance!(2000),
+            Conviction::Locked2x
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::delegate(
+                RuntimeOrigin::signed(CHARLES),
+                ALICE,
+                balance!(2000),
+                Conviction::None
+            ),
+            Error::<Runtime>::AlreadyDelegating
+        );
+
+        assert_eq!(
+            pallet::HermesDelegatedVotes::<Runtime>::get(&ALICE),
+            balance!(4000)
+        );
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &CHARLES)
+                .expect("Failed to query free balance."),
+            balance!(298000)
+        );
+
+        // Delegator can't vote while delegation is active
+        assert_err!(
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(CHARLES),
+                poll_id,
+                "No".try_into().unwrap(),
+                Conviction::None
+            ),
+            Error::<Runtime>::VotingPowerIsDelegated
+        );
+
+        // Delegated power is added to the vote of the target
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::None
+        ));
+
+        let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(hermes_voting_info.votes, number_of_hermes + balance!(4000));
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![number_of_hermes + balance!(4000), 0]
+        );
+    });
+}
+
+#[test]
+fn undelegate_and_withdraw_delegation_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+
+        assert_err!(
+            HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::NotDelegating
+        );
+
+        assert_ok!(HermesGovernancePlatform::delegate(
+            RuntimeOrigin::signed(CHARLES),
+            ALICE,
+            balance!(2000),
+            Conviction::Locked2x
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_delegation(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::DelegationIsActive
+        );
+
+        assert_ok!(HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(
+            CHARLES
+        )));
+
+        assert_eq!(pallet::HermesDelegatedVotes::<Runtime>::get(&ALICE), 0);
+        let unlock_timestamp = current_timestamp + 604800000 + 86400000;
+        assert_eq!(
+            pallet::HermesDelegations::<Runtime>::get(&CHARLES)
+                .unwrap()
+                .unlock_timestamp,
+            Some(unlock_timestamp)
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::undelegate(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::NotDelegating
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(unlock_timestamp);
+
+        assert_err!(
+            HermesGovernancePlatform::withdraw_delegation(RuntimeOrigin::signed(CHARLES)),
+            Error::<Runtime>::FundsAreLocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(unlock_timestamp + 1);
+
+        assert_ok!(HermesGovernancePlatform::withdraw_delegation(
+            RuntimeOrigin::signed(CHARLES)
+        ));
+
+        assert!(pallet::HermesDelegations::<Runtime>::get(&CHARLES).is_none());
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &CHARLES)
+                .expect("Failed to query free balance."),
+            balance!(300000)
+        );
+    });
+}
+
+#[test]
+fn close_poll_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll(ALICE, &["Option 1", "Option 2", "Option 3"]);
+        let number_of_hermes = pallet::MinimumHermesVotingAmount::<Runtime>::get();
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Option 3".try_into().unwrap(),
+            Conviction::None
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "Option 2".try_into().unwrap(),
+            Conviction::Locked2x
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::close_poll(RuntimeOrigin::signed(BOB), poll_id),
+            Error::<Runtime>::PollIsNotFinished
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
+
+        assert_ok!(HermesGovernancePlatform::close_poll(
+            RuntimeOrigin::signed(BOB),
+            poll_id
+        ));
+
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(
+            result.votes.into_inner(),
+            vec![0, number_of_hermes * 2, number_of_hermes]
+        );
+        assert_eq!(result.winning_option, Some("Option 2".try_into().unwrap()));
+        assert!(!pallet::HermesPollTallies::<Runtime>::contains_key(
+            &poll_id
+        ));
+
+        assert_err!(
+            HermesGovernancePlatform::close_poll(RuntimeOrigin::signed(BOB), poll_id),
+            Error::<Runtime>::PollAlreadyClosed
+        );
+    });
+}
+
+#[test]
+fn close_poll_tie_has_no_winner() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::None
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "No".try_into().unwrap(),
+            Conviction::None
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
+
+        assert_ok!(HermesGovernancePlatform::close_poll(
+            RuntimeOrigin::signed(BOB),
+            poll_id
+        ));
+
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(result.winning_option, None);
+    });
+}
+
+#[test]
+fn hermes_governance_conviction_voting_migration_works() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        generate_storage_instance!(HermesGovernancePlatform, HermesVotings);
+
+        let poll_id = insert_poll(ALICE, &["Yes", "No"]);
+        let number_of_hermes = pallet::MinimumHermesVotingAmount::<Runtime>::get();
+
+        type HermesVotingsV2 = StorageDoubleMap<
+            HermesVotingsOldInstance,
+            Identity,
+            H256,
+            Identity,
+            AccountIdOf<Runtime>,
+            HermesVotingInfoV2<StringLimit>,
+            OptionQuery,
+        >;
+
+        HermesVotingsV2::insert(
+            &poll_id,
+            ALICE,
+            HermesVotingInfoV2 {
+                voting_option: "No".try_into().unwrap(),
+                number_of_hermes,
+                hermes_withdrawn: false,
+            },
+        );
+
+        pallet::PalletStorageVersion::<Runtime>::put(StorageVersion::V2);
+
+        // Storage migration
+        pallet::Pallet::<Runtime>::on_runtime_upgrade();
+
+        let hermes_voting_info = pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE).unwrap();
+        assert_eq!(hermes_voting_info.voting_option, "No".try_into().unwrap());
+        assert_eq!(hermes_voting_info.conviction, Conviction::None);
+        assert_eq!(hermes_voting_info.votes, number_of_hermes);
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![0, number_of_hermes]
+        );
+        assert_eq!(
+            pallet::PalletStorageVersion::<Runtime>::get(),
+            StorageVersion::V4
+        );
+    });
+}
+
+#[test]
+fn hermes_governance_poll_mode_migration_works() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        generate_storage_instance!(HermesGovernancePlatform, HermesPollData);
+
+        let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = H256::from([1; 32]);
+        let mut options = BoundedVec::default();
+        options.try_push("Yes".try_into().unwrap()).unwrap();
+        options.try_push("No".try_into().unwrap()).unwrap();
+
+        type HermesPollDataV3 = StorageMap<
+            HermesPollDataOldInstance,
+            Identity,
+            H256,
+            HermesPollInfoV3<
+                AccountIdOf<Runtime>,
+                u64,
+                StringLimit,
+                OptionsLimit,
+                TitleLimit,
+                DescriptionLimit,
+            >,
+            OptionQuery,
+        >;
+
+        HermesPollDataV3::insert(
+            &poll_id,
+            HermesPollInfoV3 {
+                creator: ALICE,
+                hermes_locked: pallet::MinimumHermesAmountForCreatingPoll::<Runtime>::get(),
+                poll_start_timestamp,
+                poll_end_timestamp: poll_start_timestamp + 604800000,
+                title: "Title".try_into().unwrap(),
+                description: "Description".try_into().unwrap(),
+                creator_hermes_withdrawn: false,
+                options: options.clone(),
+            },
+        );
+
+        pallet::PalletStorageVersion::<Runtime>::put(StorageVersion::V3);
+
+        // Storage migration
+        pallet::Pallet::<Runtime>::on_runtime_upgrade();
+
+        let hermes_poll_info = pallet::HermesPollData::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(hermes_poll_info.options, options);
+        assert_eq!(hermes_poll_info.mode, PollMode::Standard);
+        assert_eq!(
+            pallet::PalletStorageVersion::<Runtime>::get(),
+            StorageVersion::V4
+        );
+    });
+}
+
+#[test]
+fn quadratic_voting_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_poll_with_mode(ALICE, &["Yes", "No"], PollMode::Quadratic);
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::None
+        ));
+        assert_ok!(HermesGovernancePlatform::vote_with_stake(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "No".try_into().unwrap(),
+            balance!(4000),
+            Conviction::None
+        ));
+
+        // sqrt(1000 HER) and sqrt(4000 HER)
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![31_622_776_601_000_000_000, 63_245_553_203_000_000_000]
+        );
+        assert_eq!(
+            pallet::HermesVotings::<Runtime>::get(&poll_id, &ALICE)
+                .unwrap()
+                .votes,
+            31_622_776_601_000_000_000
+        );
+        assert_eq!(
+            pallet::HermesVotings::<Runtime>::get(&poll_id, &CHARLES)
+                .unwrap()
+                .votes,
+            63_245_553_203_000_000_000
+        );
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &CHARLES).unwrap(),
+            balance!(296000)
+        );
+    });
+}
+
+fn ranking(options: &[&str]) -> BoundedVec<BoundedString<StringLimit>, OptionsLimit> {
+    let mut ranking = BoundedVec::default();
+    for option in options {
+        ranking.try_push((*option).try_into().unwrap()).unwrap();
+    }
+    ranking
+}
+
+#[test]
+fn vote_ranked_invalid_poll_mode() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let standard_poll_id = insert_poll(ALICE, &["Yes", "No"]);
+        let ranked_poll_id = insert_poll_with_mode(CHARLES, &["Yes", "No"], PollMode::RankedChoice);
+
+        assert_err!(
+            HermesGovernancePlatform::vote_ranked(
+                RuntimeOrigin::signed(ALICE),
+                standard_poll_id,
+                ranking(&["Yes", "No"]),
+                balance!(1000),
+                Conviction::None
+            ),
+            Error::<Runtime>::InvalidPollMode
+        );
+        assert_err!(
+            HermesGovernancePlatform::vote(
+                RuntimeOrigin::signed(ALICE),
+                ranked_poll_id,
+                "Yes".try_into().unwrap(),
+                Conviction::None
+            ),
+            Error::<Runtime>::InvalidPollMode
+        );
+    });
+}
+
+#[test]
+fn vote_ranked_invalid_ranking() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = insert_poll_with_mode(ALICE, &["A", "B", "C"], PollMode::RankedChoice);
+
+        for invalid_ranking in [ranking(&[]), ranking(&["A", "A"]), ranking(&["A", "D"])] {
+            assert_err!(
+                HermesGovernancePlatform::vote_ranked(
+                    RuntimeOrigin::signed(ALICE),
+                    poll_id,
+                    invalid_ranking,
+                    balance!(1000),
+                    Conviction::None
+                ),
+                Error::<Runtime>::InvalidRanking
+            );
+        }
+    });
+}
+
+#[test]
+fn ranked_choice_instant_runoff_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll_with_mode(ALICE, &["A", "B", "C"], PollMode::RankedChoice);
+        let number_of_hermes = balance!(100);
+        pallet::MinimumHermesVotingAmount::<Runtime>::put(number_of_hermes);
+
+        assert_ok!(HermesGovernancePlatform::vote_ranked(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ranking(&["A"]),
+            balance!(1000),
+            Conviction::Locked4x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote_ranked(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            ranking(&["B", "A"]),
+            balance!(1000),
+            Conviction::Locked3x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote_ranked(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            ranking(&["C", "B", "A"]),
+            balance!(1000),
+            Conviction::Locked2x
+        ));
+
+        // First choices are tallied while the poll is active
+        assert_eq!(
+            pallet::HermesPollTallies::<Runtime>::get(&poll_id).into_inner(),
+            vec![
+                number_of_hermes * 4,
+                number_of_hermes * 3,
+                number_of_hermes * 2
+            ]
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
+
+        assert_ok!(HermesGovernancePlatform::close_poll(
+            RuntimeOrigin::signed(BOB),
+            poll_id
+        ));
+
+        // "C" is eliminated and its votes go to "B"
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(
+            result.votes.into_inner(),
+            vec![number_of_hermes * 4, number_of_hermes * 5, 0]
+        );
+        assert_eq!(result.winning_option, Some("B".try_into().unwrap()));
+        assert_eq!(
+            pallet::HermesRankedBallots::<Runtime>::iter_prefix(&poll_id).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn ranked_choice_tie_has_no_winner() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = insert_poll_with_mode(ALICE, &["A", "B", "C"], PollMode::RankedChoice);
+
+        assert_ok!(HermesGovernancePlatform::vote_ranked(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ranking(&["A", "C"]),
+            balance!(1000),
+            Conviction::None
+        ));
+        assert_ok!(HermesGovernancePlatform::vote_ranked(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            ranking(&["B", "C"]),
+            balance!(1000),
+            Conviction::None
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(current_timestamp + 604800001);
+
+        // Only the stored ballots are charged
+        let post_info =
+            HermesGovernancePlatform::close_poll(RuntimeOrigin::signed(BOB), poll_id).unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::close_poll(2))
+        );
+        assert_eq!(HermesGovernancePlatform::max_ranked_ballots(), 325);
+
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(result.winning_option, None);
+    });
+}
+
+fn add_demeter_pool() {
+    let authority = demeter_farming_platform::AuthorityAccount::<Runtime>::get();
+    assert_ok!(DemeterFarmingPlatform::register_token(
+        RuntimeOrigin::signed(authority),
+        HERMES_ASSET_ID,
+        balance!(1),
+        balance!(0.6),
+        balance!(0.2),
+        balance!(0.2),
+        ALICE
+    ));
+    assert_ok!(DemeterFarmingPlatform::add_pool(
+        RuntimeOrigin::signed(authority),
+        HERMES_ASSET_ID,
+        HERMES_ASSET_ID,
+        HERMES_ASSET_ID,
+        false,
+        1,
+        balance!(0),
+        true
+    ));
+}
+
+fn change_multiplier_call(new_multiplier: u32) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::DemeterFarmingPlatform(
+        demeter_farming_platform::Call::change_pool_multiplier {
+            base_asset: HERMES_ASSET_ID,
+            pool_asset: HERMES_ASSET_ID,
+            reward_asset: HERMES_ASSET_ID,
+            is_farm: false,
+            new_multiplier,
+        },
+    ))
+}
+
+fn pool_multiplier() -> u32 {
+    demeter_farming_platform::Pools::<Runtime>::get(&HERMES_ASSET_ID, &HERMES_ASSET_ID)[0]
+        .multiplier
+}
+
+fn create_test_proposal(new_multiplier: u32) -> H256 {
+    let poll_start_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+    let nonce = frame_system::Pallet::<Runtime>::account_nonce(&ALICE);
+    let encoded: [u8; 32] = (&ALICE, nonce).using_encoded(blake2_256);
+
+    assert_ok!(HermesGovernancePlatform::create_proposal(
+        RuntimeOrigin::signed(ALICE),
+        poll_start_timestamp,
+        poll_start_timestamp + 604800000,
+        "Title".try_into().unwrap(),
+        "Description".try_into().unwrap(),
+        change_multiplier_call(new_multiplier)
+    ));
+
+    H256::from(encoded)
+}
+
+#[test]
+fn create_proposal_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let poll_id = create_test_proposal(2);
+
+        let hermes_poll_info = pallet::HermesPollData::<Runtime>::get(&poll_id).unwrap();
+        let options: Vec<BoundedString<StringLimit>> =
+            vec!["Yes".try_into().unwrap(), "No".try_into().unwrap()];
+        assert_eq!(hermes_poll_info.options.into_inner(), options);
+        assert_eq!(hermes_poll_info.creator, ALICE);
+
+        assert_eq!(
+            pallet::HermesProposals::<Runtime>::get(&poll_id),
+            Some(*change_multiplier_call(2))
+        );
+
+        // Creator Hermes are locked as for regular polls
+        assert_eq!(
+            Assets::free_balance(&HERMES_ASSET_ID, &ALICE).expect("Failed to query free balance."),
+            balance!(200000)
+        );
+    });
+}
+
+#[test]
+fn execute_proposal_is_timelocked() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_err!(
+            HermesGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                H256::zero(),
+                Weight::MAX
+            ),
+            Error::<Runtime>::ProposalDoesNotExist
+        );
+
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        let poll_id = create_test_proposal(2);
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + ProposalTimelock::get(),
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                poll_id,
+                Weight::MAX
+            ),
+            Error::<Runtime>::ProposalIsTimelocked
+        );
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + ProposalTimelock::get() + 1,
+        );
+
+        assert_err!(
+            HermesGovernancePlatform::execute_proposal(
+                RuntimeOrigin::signed(BOB),
+                poll_id,
+                Weight::zero()
+            ),
+            Error::<Runtime>::WrongProposalWeight
+        );
+    });
+}
+
+#[test]
+fn execute_proposal_ok() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        add_demeter_pool();
+        let poll_id = create_test_proposal(2);
+
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::Locked2x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "No".try_into().unwrap(),
+            Conviction::None
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + ProposalTimelock::get() + 1,
+        );
+
+        assert_ok!(HermesGovernancePlatform::execute_proposal(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            Weight::MAX
+        ));
+
+        assert_eq!(pool_multiplier(), 2);
+        assert!(pallet::HermesProposals::<Runtime>::get(&poll_id).is_none());
+        let result = pallet::HermesPollResults::<Runtime>::get(&poll_id).unwrap();
+        assert_eq!(result.winning_option, Some("Yes".try_into().unwrap()));
+    });
+}
+
+#[test]
+fn execute_proposal_rejected() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let current_timestamp = pallet_timestamp::Pallet::<Runtime>::get();
+        add_demeter_pool();
+        let poll_id = create_test_proposal(2);
+
+        // 2000 for and 2000 against, threshold is not met
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            "Yes".try_into().unwrap(),
+            Conviction::Locked2x
+        ));
+        assert_ok!(HermesGovernancePlatform::vote(
+            RuntimeOrigin::signed(CHARLES),
+            poll_id,
+            "No".try_into().unwrap(),
+            Conviction::Locked2x
+        ));
+
+        pallet_timestamp::Pallet::<Runtime>::set_timestamp(
+            current_timestamp + 604800000 + ProposalTimelock::get() + 1,
+        );
+
+        assert_ok!(HermesGovernancePlatform::execute_proposal(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            Weight::MAX
+        ));
+
+        assert_eq!(pool_multiplier(), 1);
+        assert!(pallet::HermesProposals::<Runtime>::get(&poll_id).is_none());
+    });
+}
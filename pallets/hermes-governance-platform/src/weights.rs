@@ -65,6 +65,14 @@ pub trait WeightInfo {
 	fn withdraw_funds_creator() -> Weight;
 	fn change_min_hermes_for_voting() -> Weight;
 	fn change_min_hermes_for_creating_poll() -> Weight;
+	fn change_vote() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_delegation() -> Weight;
+	fn close_poll(r: u32, ) -> Weight;
+	fn create_proposal() -> Weight;
+	fn execute_proposal() -> Weight;
+	fn vote_ranked() -> Weight;
 }
 
 /// Weights for hermes_governance_platform using the Substrate node and recommended hardware.
@@ -172,6 +180,168 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `9870`
+		// Minimum execution time: 46_120_000 picoseconds.
+		Weight::from_parts(47_310_000, 9870)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `898`
+		//  Estimated: `14622`
+		// Minimum execution time: 92_140_000 picoseconds.
+		Weight::from_parts(93_871_000, 14622)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6221`
+		// Minimum execution time: 38_502_000 picoseconds.
+		Weight::from_parts(39_418_000, 6221)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `941`
+		//  Estimated: `11604`
+		// Minimum execution time: 78_215_000 picoseconds.
+		Weight::from_parts(79_902_000, 11604)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollResults (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesRankedBallots (r:326 w:325)
+	/// Proof Skipped: HermesGovernancePlatform HermesRankedBallots (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 325]`.
+	fn close_poll(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903 + r * (74 ±0)`
+		//  Estimated: `8866 + r * (2575 ±0)`
+		// Minimum execution time: 35_117_000 picoseconds.
+		Weight::from_parts(40_157_000, 8866)
+			// Standard Error: 9_314
+			.saturating_add(Weight::from_parts(3_050_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(r.into()))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform MinimumHermesAmountForCreatingPoll (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesAmountForCreatingPoll (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:0 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesProposals (r:0 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesProposals (max_values: None, max_size: None, mode: Measured)
+	fn create_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1180`
+		//  Estimated: `15421`
+		// Minimum execution time: 111_348_000 picoseconds.
+		Weight::from_parts(113_206_000, 15421)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesProposals (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollResults (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	fn execute_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `11930`
+		// Minimum execution time: 41_772_000 picoseconds.
+		Weight::from_parts(42_913_000, 11930)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesRankedBallots (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesRankedBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn vote_ranked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1047`
+		//  Estimated: `27893`
+		// Minimum execution time: 128_410_000 picoseconds.
+		Weight::from_parts(130_962_000, 27893)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -278,4 +448,166 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	fn change_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `9870`
+		// Minimum execution time: 46_120_000 picoseconds.
+		Weight::from_parts(47_310_000, 9870)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `898`
+		//  Estimated: `14622`
+		// Minimum execution time: 92_140_000 picoseconds.
+		Weight::from_parts(93_871_000, 14622)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6221`
+		// Minimum execution time: 38_502_000 picoseconds.
+		Weight::from_parts(39_418_000, 6221)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `941`
+		//  Estimated: `11604`
+		// Minimum execution time: 78_215_000 picoseconds.
+		Weight::from_parts(79_902_000, 11604)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollResults (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesRankedBallots (r:326 w:325)
+	/// Proof Skipped: HermesGovernancePlatform HermesRankedBallots (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 325]`.
+	fn close_poll(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903 + r * (74 ±0)`
+		//  Estimated: `8866 + r * (2575 ±0)`
+		// Minimum execution time: 35_117_000 picoseconds.
+		Weight::from_parts(40_157_000, 8866)
+			// Standard Error: 9_314
+			.saturating_add(Weight::from_parts(3_050_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(r.into()))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform MinimumHermesAmountForCreatingPoll (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesAmountForCreatingPoll (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:0 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesProposals (r:0 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesProposals (max_values: None, max_size: None, mode: Measured)
+	fn create_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1180`
+		//  Estimated: `15421`
+		// Minimum execution time: 111_348_000 picoseconds.
+		Weight::from_parts(113_206_000, 15421)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: HermesGovernancePlatform HermesProposals (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollResults (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollResults (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	fn execute_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `11930`
+		// Minimum execution time: 41_772_000 picoseconds.
+		Weight::from_parts(42_913_000, 11930)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesPollData (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollData (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform MinimumHermesVotingAmount (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform MinimumHermesVotingAmount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: HermesGovernancePlatform HermesVotings (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesVotings (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegations (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesDelegatedVotes (r:1 w:0)
+	/// Proof Skipped: HermesGovernancePlatform HermesDelegatedVotes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesPollTallies (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesPollTallies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: HermesGovernancePlatform HermesRankedBallots (r:1 w:1)
+	/// Proof Skipped: HermesGovernancePlatform HermesRankedBallots (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn vote_ranked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1047`
+		//  Estimated: `27893`
+		// Minimum execution time: 128_410_000 picoseconds.
+		Weight::from_parts(130_962_000, 27893)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -61,6 +61,7 @@ pub const CHARLIE: AccountId = 3;
 pub const MINTING_ACCOUNT: AccountId = 4;
 pub const REFERRALS_RESERVES_ACC: AccountId = 22;
 pub const BUY_BACK_ACCOUNT: AccountId = 23;
+pub const REFERRALS_REWARDS_ACC: AccountId = 24;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
@@ -73,6 +74,8 @@ parameter_types! {
     pub const DepositFactor: u64 = 1;
     pub const MaxSignatories: u16 = 4;
     pub const ReferralsReservesAcc: AccountId = REFERRALS_RESERVES_ACC;
+    pub const ReferralsRewardsAcc: AccountId = REFERRALS_REWARDS_ACC;
+    pub const MaxReferralTiers: u32 = 10;
 }
 
 construct_runtime!(
@@ -89,7 +92,7 @@ construct_runtime!(
         Assets: assets::{Pallet, Call, Storage, Config<T>, Event<T>},
         Technical: technical::{Pallet, Call, Config<T>, Event<T>},
         Permissions: permissions::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Referrals: referrals::{Pallet, Call, Storage, Config<T>},
+        Referrals: referrals::{Pallet, Call, Storage, Config<T>, Event<T>},
         IrohaMigration: iroha_migration::{Pallet, Call, Storage, Config<T>, Event<T>}
     }
 );
@@ -201,7 +204,10 @@ impl tokens::Config for Runtime {
 }
 
 impl referrals::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservesAcc = ReferralsReservesAcc;
+    type RewardsAcc = ReferralsRewardsAcc;
+    type MaxReferralTiers = MaxReferralTiers;
     type WeightInfo = ();
 }
 
@@ -360,6 +360,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -375,6 +376,7 @@ impl pool_xyk::Config for Runtime {
     type EnsureDEXManager = dex_manager::Pallet<Runtime>;
     type OnPoolCreated = pswap_distribution::Pallet<Runtime>;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type GetFee = GetXykFee;
     type WeightInfo = ();
     type XSTMarketInfo = ();
@@ -0,0 +1,167 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use common::utils::{string_serialization, string_serialization_opt};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct CollateralReservesInfo<AssetId, Balance> {
+    /// Collateral asset
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "AssetId: std::fmt::Display",
+                deserialize = "AssetId: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub asset_id: AssetId,
+    /// Amount of collateral asset stored in TBC reserves
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub reserves: Balance,
+    /// Value of stored reserves in reference asset, `None` if the collateral can't be valued
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub reference_value: Option<Balance>,
+    /// Value of reserves in reference asset required to back the whole XOR supply, `None` if the collateral can't be valued
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub ideal_reference_value: Option<Balance>,
+    /// Ratio of actual to ideal reserves value, fixed point with 18 decimals, `None` if the collateral can't be valued
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub collateralized_fraction: Option<Balance>,
+    /// Fee penalty applied when selling XOR for the collateral, fixed point with 18 decimals, `None` if the collateral can't be valued
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub sell_penalty: Option<Balance>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub struct ReservesReport<AssetId, Balance> {
+    /// Asset which collaterals are valued in
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "AssetId: std::fmt::Display",
+                deserialize = "AssetId: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub reference_asset_id: AssetId,
+    /// Value of reserves of all collaterals in reference asset, `None` if any collateral can't be valued
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization_opt"
+        )
+    )]
+    pub total_reference_value: Option<Balance>,
+    /// Reserves of every enabled collateral
+    #[cfg_attr(
+        feature = "std",
+        serde(bound(
+            serialize = "AssetId: std::fmt::Display, Balance: std::fmt::Display",
+            deserialize = "AssetId: std::str::FromStr, Balance: std::str::FromStr"
+        ))
+    )]
+    pub collaterals: Vec<CollateralReservesInfo<AssetId, Balance>>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait MulticollateralBondingCurvePoolApi<AssetId, Balance> where
+        AssetId: Codec,
+        Balance: Codec + MaybeFromStr + MaybeDisplay,
+    {
+        /// Reserves of the collateral asset with their valuation and current sell penalty tier
+        fn collateral_reserves(collateral_asset_id: AssetId) -> Option<CollateralReservesInfo<AssetId, Balance>>;
+
+        /// Reserves of all enabled collaterals backing XOR issued by TBC
+        fn proof_of_reserves() -> Option<ReservesReport<AssetId, Balance>>;
+    }
+}
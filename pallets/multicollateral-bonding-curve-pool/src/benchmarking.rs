@@ -310,6 +310,25 @@ benchmarks! {
         assert_eq!(InitialPrice::<T>::get(), FixedWrapper::from(balance!(253)).get().unwrap());
     }
 
+    set_bonding_curve {
+        // the current linear curve selected explicitly, other shapes can't continue both
+        // the price and the ideal reserves of the arbitrary supply
+        let curve = BondingCurveShape::Linear(curves::LinearCurve {
+            initial_price: InitialPrice::<T>::get(),
+            price_change_step: PriceChangeStep::<T>::get(),
+            price_change_rate: PriceChangeRate::<T>::get(),
+        });
+    }: {
+        Pallet::<T>::set_bonding_curve(
+            RawOrigin::Root.into(),
+            Some(curve.clone())
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::<T>::BondingCurveChanged(Some(curve.clone())).into());
+        assert_eq!(SelectedBondingCurve::<T>::get(), Some(curve));
+    }
+
     quote {
         let caller = alice::<T>();
         frame_system::Pallet::<T>::inc_providers(&caller);
@@ -0,0 +1,290 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Shapes of the price curve the main asset is issued along.
+//!
+//! Every curve maps the main asset total supply to the buy price of the main asset expressed in the
+//! reference asset. The amount of reference asset needed to mint a given amount of main asset is the
+//! area under the curve, and the amount of main asset minted for a given reference value is its inverse.
+
+use codec::{Decode, Encode};
+use common::fixed_wrapper;
+use common::fixnum::ops::Zero as _;
+use common::fixnum::ArithmeticError;
+use common::prelude::{Fixed, FixedWrapper};
+
+/// Number of Taylor series terms used to approximate `e^x` on `[-1, 1]`.
+const EXP_SERIES_TERMS: u32 = 24;
+
+/// Upper limit on halvings applied to the argument of `e^x` before the series is evaluated.
+const EXP_MAX_HALVINGS: u32 = 64;
+
+/// Upper limit on bisection steps when inverting the cost function, enough to narrow any
+/// representable range down to the smallest `Fixed` unit.
+const BISECTION_ITERATIONS: u32 = 128;
+
+/// Price curve of the main asset with regards to its total supply.
+///
+/// Implementations must be strictly increasing in supply while they are valid, which keeps the
+/// cost function invertible.
+pub trait BondingCurve {
+    /// Price of the main asset in reference asset at the given total supply.
+    fn price(&self, supply: FixedWrapper) -> FixedWrapper;
+
+    /// Reference asset value required to increase total supply from `supply` by `amount`,
+    /// i.e. the integral of the price over `[supply, supply + amount]`.
+    fn cost(&self, supply: FixedWrapper, amount: FixedWrapper) -> FixedWrapper;
+
+    /// Amount of main asset issued for `cost` of reference asset value starting at `supply`.
+    ///
+    /// Solved by bisection by default, rounding down so the buyer never receives more than paid for.
+    fn amount_for_cost(&self, supply: FixedWrapper, cost: FixedWrapper) -> FixedWrapper {
+        // price only grows with supply, so buying everything at the current price is an upper bound
+        let mut high = cost.clone() / self.price(supply.clone());
+        if high.clone().get().is_err() {
+            return high;
+        }
+        let mut low = fixed_wrapper!(0);
+        let precision: FixedWrapper = Fixed::from_bits(1).into();
+        for _ in 0..BISECTION_ITERATIONS {
+            if high.clone() - low.clone() <= precision {
+                break;
+            }
+            let middle = (low.clone() + high.clone()) / fixed_wrapper!(2);
+            let middle_cost = self.cost(supply.clone(), middle.clone());
+            if middle_cost.clone().get().is_err() {
+                return middle_cost;
+            }
+            if middle_cost <= cost {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    /// Checks that the curve parameters describe a positive strictly increasing price.
+    fn is_valid(&self) -> bool;
+}
+
+/// `price = supply / (price_change_step * price_change_rate) + initial_price`
+///
+/// For every `price_change_step` tokens the price goes up by `price_change_rate`. This is the
+/// original XOR curve, its cost is the area of a right trapezoid and has closed-form inverse.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct LinearCurve {
+    pub initial_price: Fixed,
+    pub price_change_step: Fixed,
+    pub price_change_rate: Fixed,
+}
+
+impl LinearCurve {
+    fn price_change_coeff(&self) -> FixedWrapper {
+        FixedWrapper::from(self.price_change_step) * self.price_change_rate
+    }
+}
+
+impl BondingCurve for LinearCurve {
+    fn price(&self, supply: FixedWrapper) -> FixedWrapper {
+        supply / self.price_change_coeff() + self.initial_price
+    }
+
+    /// `cost = ((price(supply) + price(supply + amount)) / 2) * amount`
+    fn cost(&self, supply: FixedWrapper, amount: FixedWrapper) -> FixedWrapper {
+        let current_state = self.price(supply.clone());
+        let new_state = self.price(supply + amount.clone());
+        ((current_state + new_state) / fixed_wrapper!(2.0)) * amount
+    }
+
+    /// Positive root of the trapezoid area equation with regards to `amount`:
+    ///
+    /// `amount = √(P * (price(supply)² * P + 2 * cost)) - price(supply) * P`, where `P = price_change_step * price_change_rate`
+    fn amount_for_cost(&self, supply: FixedWrapper, cost: FixedWrapper) -> FixedWrapper {
+        let price_change_coeff = self.price_change_coeff();
+        let current_state = self.price(supply);
+        let sqrt = (current_state.clone() * current_state.clone() * price_change_coeff.clone()
+            + (fixed_wrapper!(2.0) * cost))
+            .multiply_and_sqrt(&price_change_coeff);
+        sqrt - current_state * price_change_coeff
+    }
+
+    fn is_valid(&self) -> bool {
+        self.initial_price > Fixed::ZERO
+            && self.price_change_step > Fixed::ZERO
+            && self.price_change_rate > Fixed::ZERO
+    }
+}
+
+/// `price = initial_price * e^(growth_rate * supply)`
+///
+/// Price grows by the same fraction for every issued token, `growth_rate` is expected to be small
+/// (e.g. `0.00001` per token) as the price overflows quickly otherwise.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct ExponentialCurve {
+    pub initial_price: Fixed,
+    pub growth_rate: Fixed,
+}
+
+impl BondingCurve for ExponentialCurve {
+    fn price(&self, supply: FixedWrapper) -> FixedWrapper {
+        exp(supply * self.growth_rate) * self.initial_price
+    }
+
+    /// `cost = initial_price / growth_rate * (e^(growth_rate * (supply + amount)) - e^(growth_rate * supply))`
+    fn cost(&self, supply: FixedWrapper, amount: FixedWrapper) -> FixedWrapper {
+        let growth_rate: FixedWrapper = self.growth_rate.into();
+        let current = exp(supply.clone() * growth_rate.clone());
+        let new = exp((supply + amount) * growth_rate.clone());
+        (new - current) * self.initial_price / growth_rate
+    }
+
+    fn is_valid(&self) -> bool {
+        self.initial_price > Fixed::ZERO && self.growth_rate > Fixed::ZERO
+    }
+}
+
+/// `price = min_price + (max_price - min_price) / 2 * (1 + z / √(1 + z²))`, where `z = (supply - midpoint) / steepness`
+///
+/// Algebraic sigmoid: starts at `min_price`, reaches the middle of the range at `midpoint` supply and
+/// saturates towards `max_price`. The bigger `steepness` the wider the supply range of the transition.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+pub struct SigmoidCurve {
+    pub min_price: Fixed,
+    pub max_price: Fixed,
+    pub midpoint: Fixed,
+    pub steepness: Fixed,
+}
+
+impl SigmoidCurve {
+    fn half_range(&self) -> FixedWrapper {
+        (FixedWrapper::from(self.max_price) - self.min_price) / fixed_wrapper!(2)
+    }
+
+    fn normalized(&self, supply: FixedWrapper) -> FixedWrapper {
+        (supply - self.midpoint) / self.steepness
+    }
+
+    /// `√(1 + z²)`, antiderivative of `z / √(1 + z²)` with regards to `z`
+    fn hypot(&self, supply: FixedWrapper) -> FixedWrapper {
+        let z = self.normalized(supply);
+        (fixed_wrapper!(1) + z.clone() * z).sqrt_accurate()
+    }
+}
+
+impl BondingCurve for SigmoidCurve {
+    fn price(&self, supply: FixedWrapper) -> FixedWrapper {
+        let z = self.normalized(supply.clone());
+        let hypot = self.hypot(supply);
+        FixedWrapper::from(self.min_price) + self.half_range() * (fixed_wrapper!(1) + z / hypot)
+    }
+
+    /// `cost = (min_price + max_price) / 2 * amount + (max_price - min_price) / 2 * steepness * (√(1 + z₂²) - √(1 + z₁²))`
+    fn cost(&self, supply: FixedWrapper, amount: FixedWrapper) -> FixedWrapper {
+        let middle_price =
+            (FixedWrapper::from(self.min_price) + self.max_price) / fixed_wrapper!(2);
+        let current = self.hypot(supply.clone());
+        let new = self.hypot(supply + amount.clone());
+        middle_price * amount + self.half_range() * self.steepness * (new - current)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.min_price > Fixed::ZERO
+            && self.max_price > self.min_price
+            && self.midpoint >= Fixed::ZERO
+            && self.steepness > Fixed::ZERO
+    }
+}
+
+/// Bonding curve selected by governance for a main asset.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+pub enum BondingCurveShape {
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+    Sigmoid(SigmoidCurve),
+}
+
+impl BondingCurveShape {
+    fn inner(&self) -> &dyn BondingCurve {
+        match self {
+            BondingCurveShape::Linear(curve) => curve,
+            BondingCurveShape::Exponential(curve) => curve,
+            BondingCurveShape::Sigmoid(curve) => curve,
+        }
+    }
+}
+
+impl BondingCurve for BondingCurveShape {
+    fn price(&self, supply: FixedWrapper) -> FixedWrapper {
+        self.inner().price(supply)
+    }
+
+    fn cost(&self, supply: FixedWrapper, amount: FixedWrapper) -> FixedWrapper {
+        self.inner().cost(supply, amount)
+    }
+
+    fn amount_for_cost(&self, supply: FixedWrapper, cost: FixedWrapper) -> FixedWrapper {
+        self.inner().amount_for_cost(supply, cost)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.inner().is_valid()
+    }
+}
+
+/// Approximates `e^x` as `(e^(x / 2^k))^(2^k)`, where `k` halvings bring the argument into `[-1, 1]`
+/// and the inner exponent is evaluated by its Taylor series.
+pub fn exp(x: FixedWrapper) -> FixedWrapper {
+    let mut reduced = x;
+    let mut halvings = 0;
+    while reduced.clone().abs() > fixed_wrapper!(1) {
+        if halvings == EXP_MAX_HALVINGS {
+            return FixedWrapper::from(Err::<Fixed, _>(ArithmeticError::Overflow));
+        }
+        reduced = reduced / fixed_wrapper!(2);
+        halvings += 1;
+    }
+    if reduced.clone().get().is_err() {
+        return reduced;
+    }
+
+    let mut term = fixed_wrapper!(1);
+    let mut sum = fixed_wrapper!(1);
+    let mut n = fixed_wrapper!(0);
+    for _ in 0..EXP_SERIES_TERMS {
+        n = n + fixed_wrapper!(1);
+        term = term * reduced.clone() / n.clone();
+        sum = sum + term.clone();
+    }
+    for _ in 0..halvings {
+        sum = sum.clone() * sum;
+    }
+    sum
+}
@@ -32,6 +32,7 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
+pub mod curves;
 pub mod weights;
 
 #[cfg(test)]
@@ -58,12 +59,13 @@ use common::{
     ManagementMode, PriceVariant, RewardReason, TradingPairSourceManager, VestedRewardsPallet,
     PSWAP, TBCD, VAL, XOR, XST,
 };
+use curves::{BondingCurve, BondingCurveShape, LinearCurve};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{ensure, fail};
 use permissions::{Scope, BURN, MINT};
 use sp_arithmetic::traits::Zero;
-use sp_runtime::{DispatchError, DispatchResult};
+use sp_runtime::{DispatchError, DispatchResult, Percent};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 pub use weights::WeightInfo;
@@ -85,6 +87,13 @@ pub const TECH_ACCOUNT_FREE_RESERVES: &[u8] = b"free_reserves";
 
 pub const RETRY_DISTRIBUTION_FREQUENCY: u32 = 1000;
 
+/// Maximum change of the main asset price at its current supply caused by the bonding curve change.
+pub const MAX_BONDING_CURVE_PRICE_CHANGE: Percent = Percent::from_percent(5);
+
+/// Maximum change of the ideal reserves at the current supply of the main asset caused by the
+/// bonding curve change, it bounds the change of the sell penalty.
+pub const MAX_BONDING_CURVE_RESERVES_CHANGE: Percent = Percent::from_percent(5);
+
 pub use pallet::*;
 
 #[derive(Debug, Encode, Decode, Clone, PartialEq, scale_info::TypeInfo)]
@@ -177,6 +186,23 @@ impl<DistributionAccountData: Default> Default for DistributionAccounts<Distribu
     }
 }
 
+/// Reserves of a collateral asset and their valuation in reference asset, used to verify TBC backing.
+/// Valuation fields are `None` if the collateral can't be valued at the moment, e.g. because its
+/// reference price is not available yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollateralReservesInfo {
+    /// Amount of collateral asset stored in reserves account.
+    pub reserves: Balance,
+    /// Value of stored reserves in reference asset.
+    pub reference_value: Option<Balance>,
+    /// Value in reference asset of reserves required to back the whole main asset supply.
+    pub ideal_reference_value: Option<Balance>,
+    /// Ratio of actual reserves value to ideal reserves value.
+    pub collateralized_fraction: Option<Fixed>,
+    /// Fee penalty applied when selling main asset for the collateral asset.
+    pub sell_penalty: Option<Fixed>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -343,6 +369,30 @@ pub mod pallet {
             ));
             Ok(().into())
         }
+
+        /// Selects the bonding curve the main asset (XOR) is priced along.
+        /// `None` value restores the linear curve driven by price bias and price change config.
+        ///
+        /// The new curve must price the current supply of the main asset within
+        /// [`MAX_BONDING_CURVE_PRICE_CHANGE`] of the current price and keep the ideal reserves
+        /// within [`MAX_BONDING_CURVE_RESERVES_CHANGE`] of the current ones, so neither the asset
+        /// nor the sell penalty are repriced instantly.
+        #[pallet::call_index(5)]
+        #[pallet::weight(< T as Config >::WeightInfo::set_bonding_curve())]
+        pub fn set_bonding_curve(
+            origin: OriginFor<T>,
+            curve: Option<BondingCurveShape>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(curve) = &curve {
+                ensure!(curve.is_valid(), Error::<T>::InvalidBondingCurve);
+            }
+            let new_curve = curve.clone().unwrap_or_else(Self::default_bonding_curve);
+            Self::ensure_bonding_curve_continuity(&new_curve)?;
+            SelectedBondingCurve::<T>::set(curve.clone());
+            Self::deposit_event(Event::BondingCurveChanged(curve));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -358,6 +408,8 @@ pub mod pallet {
         PriceBiasChanged(Balance),
         /// Price change config was changed. [New Price Change Rate, New Price Change Step]
         PriceChangeConfigChanged(Balance, Balance),
+        /// Bonding curve of the main asset was changed. [New Bonding Curve]
+        BondingCurveChanged(Option<BondingCurveShape>),
     }
 
     #[pallet::error]
@@ -392,6 +444,12 @@ pub mod pallet {
         ArithmeticError,
         /// Free reserves account is not set
         FreeReservesAccountNotSet,
+        /// Bonding curve parameters don't describe a positive increasing price.
+        InvalidBondingCurve,
+        /// Bonding curve change moves the current price of the main asset too far.
+        BondingCurvePriceChangeTooBig,
+        /// Bonding curve change moves the ideal reserves of the main asset too far.
+        BondingCurveReservesChangeTooBig,
     }
 
     /// Technical account used to store collateral tokens.
@@ -531,6 +589,13 @@ pub mod pallet {
     pub(super) type InitialPswapRewardsSupply<T: Config> =
         StorageValue<_, Balance, ValueQuery, DefaultForInitialPswapRewardsSupply>;
 
+    /// Bonding curve of the main asset selected by governance.
+    ///
+    /// Without it the main asset is priced along the linear curve driven by `InitialPrice`,
+    /// `PriceChangeStep` and `PriceChangeRate`.
+    #[pallet::storage]
+    pub type SelectedBondingCurve<T: Config> = StorageValue<_, BondingCurveShape>;
+
     /// Current reserves balance for collateral tokens, used for client usability.
     #[pallet::storage]
     pub(super) type CollateralReserves<T: Config> =
@@ -903,12 +968,63 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Bonding curve the main asset is priced along, the linear curve driven by `InitialPrice`,
+    /// `PriceChangeStep` and `PriceChangeRate` unless governance selected another one.
+    pub fn bonding_curve() -> BondingCurveShape {
+        SelectedBondingCurve::<T>::get().unwrap_or_else(Self::default_bonding_curve)
+    }
+
+    /// Linear curve driven by `InitialPrice`, `PriceChangeStep` and `PriceChangeRate`.
+    fn default_bonding_curve() -> BondingCurveShape {
+        BondingCurveShape::Linear(LinearCurve {
+            initial_price: Self::initial_price(),
+            price_change_step: Self::price_change_step(),
+            price_change_rate: Self::price_change_rate(),
+        })
+    }
+
+    /// Ensure that `new_curve` prices the current supply of the main asset within
+    /// [`MAX_BONDING_CURVE_PRICE_CHANGE`] of the price along the current curve and the area
+    /// under it, i.e. the ideal reserves, within [`MAX_BONDING_CURVE_RESERVES_CHANGE`].
+    fn ensure_bonding_curve_continuity(new_curve: &BondingCurveShape) -> DispatchResult {
+        let total_supply: FixedWrapper =
+            Assets::<T>::total_issuance(&T::GetBaseAssetId::get())?.into();
+        let current_curve = Self::bonding_curve();
+        let is_within = |current: FixedWrapper, new: FixedWrapper, max_change: Percent| {
+            let current = current
+                .get()
+                .map_err(|_| Error::<T>::PriceCalculationFailed)?;
+            let new = new.get().map_err(|_| Error::<T>::PriceCalculationFailed)?;
+            let max_change =
+                FixedWrapper::from(current) * FixedWrapper::from(max_change * balance!(1));
+            Ok::<_, DispatchError>((FixedWrapper::from(new) - current).abs() <= max_change)
+        };
+        ensure!(
+            is_within(
+                current_curve.price(total_supply.clone()),
+                new_curve.price(total_supply.clone()),
+                MAX_BONDING_CURVE_PRICE_CHANGE
+            )?,
+            Error::<T>::BondingCurvePriceChangeTooBig
+        );
+        ensure!(
+            is_within(
+                current_curve.cost(fixed_wrapper!(0), total_supply.clone()),
+                new_curve.cost(fixed_wrapper!(0), total_supply),
+                MAX_BONDING_CURVE_RESERVES_CHANGE
+            )?,
+            Error::<T>::BondingCurveReservesChangeTooBig
+        );
+        Ok(())
+    }
+
     /// Buy function with regards to asset total supply and its change delta. It represents the amount of
     /// input collateral required from the User to receive the requested XOR amount, i.e., the price the User buys XOR at.
     ///
     /// XOR is also referred as main asset.
     /// Value of `delta` is assumed to be either positive or negative.
-    /// For every `price_change_step` tokens the price goes up by `price_change_rate`.
+    /// The price is taken from the bonding curve of the main asset, by default for every `price_change_step`
+    /// tokens the price goes up by `price_change_rate`.
     ///
     /// `buy_price_usd = (xor_total_supply + xor_supply_delta) / (price_change_step * price_change_rate) + initial_price_usd`
     ///
@@ -929,12 +1045,7 @@ impl<T: Config> Pallet<T> {
         } else {
             // Everything other than TBCD
             let total_supply: FixedWrapper = Assets::<T>::total_issuance(main_asset_id)?.into();
-            let initial_price: FixedWrapper = Self::initial_price().into();
-            let price_change_step: FixedWrapper = Self::price_change_step().into();
-            let price_change_rate: FixedWrapper = Self::price_change_rate().into();
-
-            let price =
-                (total_supply + delta) / (price_change_step * price_change_rate) + initial_price;
+            let price = Self::bonding_curve().price(total_supply + delta);
             price
                 .get()
                 .map_err(|_| Error::<T>::PriceCalculationFailed.into())
@@ -993,14 +1104,15 @@ impl<T: Config> Pallet<T> {
     /// xor_supply_delta = √price_change_coefficient * √(buy_function(xor_total_supply)² * price_change_coefficient + 2 * buy_price_usd)
     ///                    - buy_function(xor_total_supply) * price_change_coefficient
     /// ```
+    /// Formulas above describe the default linear curve, for other curves the area is calculated and
+    /// inverted by the selected [`BondingCurve`].
     pub fn buy_price(
         main_asset_id: &T::AssetId,
         collateral_asset_id: &T::AssetId,
         quantity: QuoteAmount<Balance>,
     ) -> Result<Fixed, DispatchError> {
-        let price_change_step = FixedWrapper::from(Self::price_change_step());
-        let price_change_rate = Self::price_change_rate();
-        let price_change_coeff = price_change_step * price_change_rate;
+        let curve = Self::bonding_curve();
+        let total_supply: FixedWrapper = Assets::<T>::total_issuance(main_asset_id)?.into();
 
         let current_state: FixedWrapper = Self::buy_function(
             main_asset_id,
@@ -1022,12 +1134,7 @@ impl<T: Config> Pallet<T> {
                 let main_out = if collateral_asset_id == &TBCD.into() {
                     collateral_reference_in / current_state
                 } else {
-                    let sqrt = (current_state.clone()
-                        * current_state.clone()
-                        * price_change_coeff.clone()
-                        + (fixed_wrapper!(2.0) * collateral_reference_in.clone()))
-                    .multiply_and_sqrt(&price_change_coeff);
-                    sqrt - current_state * price_change_coeff
+                    curve.amount_for_cost(total_supply, collateral_reference_in)
                 };
 
                 main_out
@@ -1038,17 +1145,11 @@ impl<T: Config> Pallet<T> {
             QuoteAmount::WithDesiredOutput {
                 desired_amount_out: main_quantity,
             } => {
-                let new_state: FixedWrapper = Self::buy_function(
-                    main_asset_id,
-                    collateral_asset_id,
-                    PriceVariant::Buy,
-                    FixedWrapper::from(main_quantity)
-                        .get()
-                        .map_err(|_| Error::<T>::PriceCalculationFailed)?,
-                )?
-                .into();
-                let collateral_reference_in =
-                    ((current_state + new_state) / fixed_wrapper!(2.0)) * main_quantity;
+                let collateral_reference_in = if collateral_asset_id == &TBCD.into() {
+                    current_state * main_quantity
+                } else {
+                    curve.cost(total_supply, main_quantity.into())
+                };
                 let collateral_quantity =
                     collateral_reference_in / collateral_price_per_reference_unit;
                 collateral_quantity
@@ -1241,12 +1342,11 @@ impl<T: Config> Pallet<T> {
         let reserves_account_id =
             Technical::<T>::tech_account_id_to_account_id(&Self::reserves_account_id())?;
         // USD price for XOR supply on network
-        let ideal_reserves_price: FixedWrapper = Self::ideal_reserves_reference_price(
+        let ideal_reserves_price = Self::ideal_reserves_reference_price(
             collateral_asset_id,
             PriceVariant::Sell,
             Fixed::ZERO,
-        )?
-        .into();
+        )?;
         // USD price for amount of indicated collateral asset stored in reserves
         let collateral_reserves_price = Self::actual_reserves_reference_price(
             &reserves_account_id,
@@ -1257,16 +1357,78 @@ impl<T: Config> Pallet<T> {
             !collateral_reserves_price.is_zero(),
             Error::<T>::NotEnoughReserves
         );
-        // ratio of stored reserves to ideal reserves
-        let collateralized_fraction = (FixedWrapper::from(collateral_reserves_price)
-            / ideal_reserves_price)
-            .get()
-            .map_err(|_| Error::<T>::FeeCalculationFailed)?;
+        let collateralized_fraction =
+            Self::collateralized_fraction(collateral_reserves_price, ideal_reserves_price)?;
         Ok(Self::map_collateralized_fraction_to_penalty(
             collateralized_fraction,
         ))
     }
 
+    /// Ratio of stored reserves to ideal reserves, both expressed in reference asset.
+    /// Reserves are considered fully collateralized when there is nothing to back.
+    fn collateralized_fraction(
+        collateral_reserves_price: Balance,
+        ideal_reserves_price: Balance,
+    ) -> Result<Fixed, DispatchError> {
+        if ideal_reserves_price.is_zero() {
+            return Ok(fixed!(1));
+        }
+        (FixedWrapper::from(collateral_reserves_price) / FixedWrapper::from(ideal_reserves_price))
+            .get()
+            .map_err(|_| Error::<T>::FeeCalculationFailed.into())
+    }
+
+    /// Reserves of the collateral asset together with their valuation in reference asset and the sell penalty tier
+    /// they currently fall into. Unlike `sell_penalty`, empty reserves are reported as zero collateralized fraction.
+    pub fn collateral_reserves_info(
+        collateral_asset_id: &T::AssetId,
+    ) -> Result<CollateralReservesInfo, DispatchError> {
+        ensure!(
+            Self::enabled_targets().contains(collateral_asset_id),
+            Error::<T>::UnsupportedCollateralAssetId
+        );
+        let reserves_account_id =
+            Technical::<T>::tech_account_id_to_account_id(&Self::reserves_account_id())?;
+        let reserves = Assets::<T>::free_balance(collateral_asset_id, &reserves_account_id)?;
+        let reference_value = Self::actual_reserves_reference_price(
+            &reserves_account_id,
+            collateral_asset_id,
+            PriceVariant::Sell,
+        )
+        .ok();
+        let ideal_reference_value = Self::ideal_reserves_reference_price(
+            collateral_asset_id,
+            PriceVariant::Sell,
+            Fixed::ZERO,
+        )
+        .ok();
+        let collateralized_fraction = match (reference_value, ideal_reference_value) {
+            (Some(reference_value), Some(ideal_reference_value)) => {
+                Self::collateralized_fraction(reference_value, ideal_reference_value).ok()
+            }
+            _ => None,
+        };
+        Ok(CollateralReservesInfo {
+            reserves,
+            reference_value,
+            ideal_reference_value,
+            collateralized_fraction,
+            sell_penalty: collateralized_fraction.map(Self::map_collateralized_fraction_to_penalty),
+        })
+    }
+
+    /// Reserves info of every enabled collateral asset.
+    pub fn collateral_reserves_report(
+    ) -> Result<Vec<(T::AssetId, CollateralReservesInfo)>, DispatchError> {
+        Self::enabled_targets()
+            .into_iter()
+            .map(|collateral_asset_id| {
+                Self::collateral_reserves_info(&collateral_asset_id)
+                    .map(|info| (collateral_asset_id, info))
+            })
+            .collect()
+    }
+
     /// Decompose SwapAmount into particular sell quotation query.
     ///
     /// Returns ordered pair: (input_amount, output_amount, fee_amount).
@@ -1449,7 +1611,8 @@ impl<T: Config> Pallet<T> {
             .map_err(|_| Error::<T>::PriceCalculationFailed.into())
     }
 
-    /// Calculate USD price for all XOR in network, this is done by applying ideal sell function to XOR total supply.
+    /// Calculate USD price for all XOR in network, this is done by applying ideal sell function to XOR total supply,
+    /// i.e. the area under the bonding curve from zero to the total supply.
     /// - `delta` is a XOR supply offset from current total supply.
     fn ideal_reserves_reference_price(
         collateral_asset_id: &T::AssetId,
@@ -1458,12 +1621,16 @@ impl<T: Config> Pallet<T> {
     ) -> Result<Balance, DispatchError> {
         let base_asset_id = T::GetBaseAssetId::get();
         let base_total_supply = Assets::<T>::total_issuance(&base_asset_id)?;
-        let initial_state = FixedWrapper::from(Self::initial_price());
-        let current_state =
-            Self::buy_function(&base_asset_id, collateral_asset_id, price_variant, delta)?;
-
-        let price = ((initial_state + current_state) / fixed_wrapper!(2.0))
-            * (FixedWrapper::from(base_total_supply) + delta);
+        let supply = FixedWrapper::from(base_total_supply) + delta;
+        let price = if collateral_asset_id == &TBCD.into() {
+            // TBCD is priced by XOR market price instead of the bonding curve
+            let initial_state = FixedWrapper::from(Self::initial_price());
+            let current_state =
+                Self::buy_function(&base_asset_id, collateral_asset_id, price_variant, delta)?;
+            ((initial_state + current_state) / fixed_wrapper!(2.0)) * supply
+        } else {
+            Self::bonding_curve().cost(fixed_wrapper!(0), supply)
+        };
         price
             .try_into_balance()
             .map_err(|_| Error::<T>::PriceCalculationFailed.into())
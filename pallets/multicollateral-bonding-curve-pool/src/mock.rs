@@ -369,6 +369,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -385,6 +386,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -658,6 +660,9 @@ impl PriceToolsPallet<AssetId> for MockDEXApi {
         output_asset_id: &AssetId,
         _price_variant: PriceVariant,
     ) -> Result<Balance, DispatchError> {
+        if !MockPrices::contains_key(&(*input_asset_id, *output_asset_id)) {
+            return Err(DispatchError::Other("UnavailableExchangePath"));
+        }
         Ok(Self::inner_quote(
             &DEXId::Polkaswap.into(),
             input_asset_id,
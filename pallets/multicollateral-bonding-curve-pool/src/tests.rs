@@ -29,9 +29,12 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 mod tests {
+    use crate::curves::{
+        BondingCurve, BondingCurveShape, ExponentialCurve, LinearCurve, SigmoidCurve,
+    };
     use crate::{
-        mock::*, DistributionAccount, DistributionAccountData, DistributionAccounts, Error, Pallet,
-        RETRY_DISTRIBUTION_FREQUENCY,
+        mock::*, CollateralReservesInfo, DistributionAccount, DistributionAccountData,
+        DistributionAccounts, Error, Pallet, SelectedBondingCurve, RETRY_DISTRIBUTION_FREQUENCY,
     };
     use common::assert_approx_eq;
     use common::{
@@ -1286,6 +1289,241 @@ mod tests {
         });
     }
 
+    fn init_val_pool() {
+        MockDEXApi::init().unwrap();
+        let _ = bonding_curve_pool_init(Vec::new()).unwrap();
+        TradingPair::register(
+            RuntimeOrigin::signed(alice()),
+            DEXId::Polkaswap.into(),
+            XOR,
+            VAL,
+        )
+        .expect("Failed to register trading pair.");
+        MBCPool::initialize_pool_unchecked(VAL, false).expect("Failed to initialize pool.");
+    }
+
+    fn xor_total_supply() -> Fixed {
+        FixedWrapper::from(Assets::total_issuance(&XOR).unwrap())
+            .get()
+            .unwrap()
+    }
+
+    fn assert_buy_price_round_trip(main_quantity: Balance) {
+        let collateral_in =
+            MBCPool::buy_price(&XOR, &VAL, QuoteAmount::with_desired_output(main_quantity))
+                .expect("failed to calculate buy assets price");
+        let main_out = MBCPool::buy_price(
+            &XOR,
+            &VAL,
+            QuoteAmount::with_desired_input(FixedWrapper::from(collateral_in).into_balance()),
+        )
+        .expect("failed to calculate buy assets price");
+        assert_approx_eq!(main_out, main_quantity, balance!(0.000001));
+    }
+
+    /// Sigmoid curve reaching the middle of its narrow price range `price ± 1` at current XOR supply
+    fn sigmoid_curve_at(price: Fixed) -> BondingCurveShape {
+        BondingCurveShape::Sigmoid(SigmoidCurve {
+            min_price: (FixedWrapper::from(price) - fixed_wrapper!(1))
+                .get()
+                .unwrap(),
+            max_price: (FixedWrapper::from(price) + fixed_wrapper!(1))
+                .get()
+                .unwrap(),
+            midpoint: xor_total_supply(),
+            steepness: fixed!(50000),
+        })
+    }
+
+    /// Linear curve starting at `initial_price` and reaching `price` at current XOR supply
+    fn linear_curve_through(price: Fixed, initial_price: Fixed) -> BondingCurveShape {
+        BondingCurveShape::Linear(LinearCurve {
+            initial_price,
+            price_change_step: (FixedWrapper::from(xor_total_supply())
+                / (FixedWrapper::from(price) - initial_price))
+                .get()
+                .unwrap(),
+            price_change_rate: fixed!(1),
+        })
+    }
+
+    #[test]
+    fn should_set_bonding_curve() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_val_pool();
+            let linear = BondingCurveShape::Linear(LinearCurve {
+                initial_price: MBCPool::initial_price(),
+                price_change_step: MBCPool::price_change_step(),
+                price_change_rate: MBCPool::price_change_rate(),
+            });
+            assert_eq!(MBCPool::bonding_curve(), linear);
+            let linear_price =
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, Fixed::ZERO).unwrap();
+            let linear_price_after =
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, fixed!(100000)).unwrap();
+
+            let steeper = linear_curve_through(
+                linear_price,
+                (FixedWrapper::from(MBCPool::initial_price()) - fixed_wrapper!(10))
+                    .get()
+                    .unwrap(),
+            );
+            assert_noop!(
+                MBCPool::set_bonding_curve(RuntimeOrigin::signed(alice()), Some(steeper.clone())),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                MBCPool::set_bonding_curve(
+                    RuntimeOrigin::root(),
+                    Some(BondingCurveShape::Sigmoid(SigmoidCurve {
+                        min_price: fixed!(1000),
+                        max_price: fixed!(100),
+                        midpoint: fixed!(0),
+                        steepness: fixed!(1),
+                    }))
+                ),
+                Error::<Runtime>::InvalidBondingCurve
+            );
+            // the curve can't reprice current supply by more than 5%
+            let jump = (FixedWrapper::from(linear_price) * fixed_wrapper!(1.06))
+                .get()
+                .unwrap();
+            assert_noop!(
+                MBCPool::set_bonding_curve(RuntimeOrigin::root(), Some(sigmoid_curve_at(jump))),
+                Error::<Runtime>::BondingCurvePriceChangeTooBig
+            );
+            // nor change the ideal reserves by more than 5% while keeping the price
+            assert_noop!(
+                MBCPool::set_bonding_curve(
+                    RuntimeOrigin::root(),
+                    Some(sigmoid_curve_at(linear_price))
+                ),
+                Error::<Runtime>::BondingCurveReservesChangeTooBig
+            );
+            let half_initial_price = (FixedWrapper::from(MBCPool::initial_price())
+                / fixed_wrapper!(2))
+            .get()
+            .unwrap();
+            assert_noop!(
+                MBCPool::set_bonding_curve(
+                    RuntimeOrigin::root(),
+                    Some(linear_curve_through(linear_price, half_initial_price))
+                ),
+                Error::<Runtime>::BondingCurveReservesChangeTooBig
+            );
+
+            MBCPool::set_bonding_curve(RuntimeOrigin::root(), Some(steeper.clone()))
+                .expect("Failed to set bonding curve");
+            assert_eq!(
+                SelectedBondingCurve::<Runtime>::get(),
+                Some(steeper.clone())
+            );
+            assert_eq!(MBCPool::bonding_curve(), steeper);
+            assert_approx_eq!(
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, Fixed::ZERO).unwrap(),
+                linear_price,
+                fixed!(0.000001)
+            );
+            assert_ne!(
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, fixed!(100000)).unwrap(),
+                linear_price_after
+            );
+
+            MBCPool::set_bonding_curve(RuntimeOrigin::root(), None)
+                .expect("Failed to reset bonding curve");
+            assert_eq!(SelectedBondingCurve::<Runtime>::get(), None);
+            assert_eq!(MBCPool::bonding_curve(), linear);
+            assert_eq!(
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, Fixed::ZERO).unwrap(),
+                linear_price
+            );
+        });
+    }
+
+    #[test]
+    fn should_calculate_price_along_exponential_curve() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_val_pool();
+            let curve = ExponentialCurve {
+                initial_price: fixed!(100),
+                growth_rate: fixed!(0.00001),
+            };
+            SelectedBondingCurve::<Runtime>::put(BondingCurveShape::Exponential(curve.clone()));
+
+            let supply = FixedWrapper::from(xor_total_supply())
+                .to_fraction()
+                .unwrap();
+            let expected = 100.0 * (supply * 0.00001).exp();
+            let price = MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, Fixed::ZERO)
+                .expect("failed to calculate buy price");
+            assert_approx_eq!(price, FixedWrapper::from(expected), fixed!(0.000001));
+
+            // the cost of the supply increment is the area under the curve
+            let cost = curve.cost(xor_total_supply().into(), fixed_wrapper!(1000));
+            let expected_cost =
+                100.0 / 0.00001 * (((supply + 1000.0) * 0.00001).exp() - (supply * 0.00001).exp());
+            assert_approx_eq!(cost, FixedWrapper::from(expected_cost), fixed!(0.0001));
+
+            assert_buy_price_round_trip(balance!(1000));
+            assert_buy_price_round_trip(balance!(100000));
+        });
+    }
+
+    #[test]
+    fn should_calculate_price_along_sigmoid_curve() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_val_pool();
+            let curve = SigmoidCurve {
+                min_price: fixed!(100),
+                max_price: fixed!(1000),
+                midpoint: xor_total_supply(),
+                steepness: fixed!(50000),
+            };
+            SelectedBondingCurve::<Runtime>::put(BondingCurveShape::Sigmoid(curve.clone()));
+
+            // middle of the price range is reached at midpoint supply
+            assert_eq!(
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, Fixed::ZERO).unwrap(),
+                fixed!(550)
+            );
+            // price is symmetric around midpoint
+            let above =
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, fixed!(50000)).unwrap();
+            let below =
+                MBCPool::buy_function(&XOR, &VAL, PriceVariant::Buy, fixed!(-50000)).unwrap();
+            assert_approx_eq!(
+                FixedWrapper::from(above) + below,
+                fixed_wrapper!(1100),
+                fixed!(0.000001)
+            );
+            assert!(above < fixed!(1000));
+            assert!(below > fixed!(100));
+
+            // cost over a range symmetric around midpoint is its length times the middle price
+            let cost = curve.cost(
+                FixedWrapper::from(xor_total_supply()) - fixed_wrapper!(1000),
+                fixed_wrapper!(2000),
+            );
+            assert_approx_eq!(cost, fixed_wrapper!(1100000), fixed!(0.000001));
+
+            // ideal reserves are the area under the curve up to the current supply
+            assert_approx_eq!(
+                FixedWrapper::from(
+                    MBCPool::ideal_reserves_reference_price(&VAL, PriceVariant::Buy, Fixed::ZERO)
+                        .unwrap()
+                ),
+                curve.cost(fixed_wrapper!(0), xor_total_supply().into()),
+                fixed!(0.000001)
+            );
+
+            assert_buy_price_round_trip(balance!(1000));
+            assert_buy_price_round_trip(balance!(100000));
+        });
+    }
+
     #[test]
     fn test_deducing_fee() {
         let mut ext = ExtBuilder::new(vec![
@@ -2007,6 +2245,80 @@ mod tests {
         });
     }
 
+    #[test]
+    fn should_report_collateral_reserves() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            init_val_pool();
+            let reserves_account_id = crate::mock::get_pool_reserves_account_id();
+
+            assert_noop!(
+                MBCPool::collateral_reserves_info(&DAI),
+                Error::<Runtime>::UnsupportedCollateralAssetId
+            );
+
+            // empty reserves fall into the highest penalty tier
+            let info = MBCPool::collateral_reserves_info(&VAL).unwrap();
+            assert_eq!(info.reserves, balance!(0));
+            assert_eq!(info.reference_value, Some(balance!(0)));
+            assert!(info.ideal_reference_value.unwrap() > balance!(0));
+            assert_eq!(info.collateralized_fraction, Some(fixed!(0)));
+            assert_eq!(info.sell_penalty, Some(fixed!(0.09)));
+
+            MBCPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap.into(),
+                &VAL,
+                &XOR,
+                SwapAmount::with_desired_input(balance!(100000), Balance::zero()),
+            )
+            .unwrap();
+
+            let reference_value = MBCPool::actual_reserves_reference_price(
+                &reserves_account_id,
+                &VAL,
+                PriceVariant::Sell,
+            )
+            .unwrap();
+            let ideal_reference_value =
+                MBCPool::ideal_reserves_reference_price(&VAL, PriceVariant::Sell, Fixed::ZERO)
+                    .unwrap();
+            let info = CollateralReservesInfo {
+                reserves: Assets::free_balance(&VAL, &reserves_account_id).unwrap(),
+                reference_value: Some(reference_value),
+                ideal_reference_value: Some(ideal_reference_value),
+                collateralized_fraction: (FixedWrapper::from(reference_value)
+                    / FixedWrapper::from(ideal_reference_value))
+                .get()
+                .ok(),
+                sell_penalty: MBCPool::sell_penalty(&VAL).ok(),
+            };
+            assert!(info.reserves > balance!(0));
+            assert_eq!(MBCPool::collateral_reserves_info(&VAL).unwrap(), info);
+            assert_eq!(MBCPool::collateral_reserves_report(), Ok(vec![(VAL, info)]));
+
+            // collaterals which can't be valued are still reported, without valuation
+            MockPrices::remove(&(VAL, USDT));
+            let info = MBCPool::collateral_reserves_info(&VAL).unwrap();
+            assert!(info.reserves > balance!(0));
+            assert_eq!(info.reference_value, None);
+            assert_eq!(info.collateralized_fraction, None);
+            assert_eq!(info.sell_penalty, None);
+            assert_eq!(MBCPool::collateral_reserves_report(), Ok(vec![(VAL, info)]));
+
+            // nothing to back means reserves are fully collateralized
+            assert_eq!(
+                MBCPool::collateralized_fraction(balance!(0), balance!(0)),
+                Ok(fixed!(1))
+            );
+            assert_eq!(
+                MBCPool::collateralized_fraction(balance!(10), balance!(0)),
+                Ok(fixed!(1))
+            );
+        });
+    }
+
     #[test]
     fn fees_for_equivalent_trades_should_match() {
         let mut ext = ExtBuilder::new(vec![
@@ -69,6 +69,7 @@ pub trait WeightInfo {
 	fn exchange() -> Weight;
 	fn can_exchange() -> Weight;
 	fn check_rewards() -> Weight;
+	fn set_bonding_curve() -> Weight;
 }
 
 /// Weights for multicollateral_bonding_curve_pool using the Substrate node and recommended hardware.
@@ -314,6 +315,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(84_994_000, 58172)
 			.saturating_add(T::DbWeight::get().reads(13_u64))
 	}
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: MulticollateralBondingCurvePool SelectedBondingCurve (r:1 w:1)
+	/// Proof Skipped: MulticollateralBondingCurvePool SelectedBondingCurve (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool InitialPrice (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool InitialPrice (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool PriceChangeStep (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool PriceChangeStep (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool PriceChangeRate (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool PriceChangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_bonding_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `544`
+		//  Estimated: `7561`
+		// Minimum execution time: 61_318_000 picoseconds.
+		Weight::from_parts(62_490_000, 7561)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -558,4 +578,23 @@ impl WeightInfo for () {
 		Weight::from_parts(84_994_000, 58172)
 			.saturating_add(RocksDbWeight::get().reads(13_u64))
 	}
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: MulticollateralBondingCurvePool SelectedBondingCurve (r:1 w:1)
+	/// Proof Skipped: MulticollateralBondingCurvePool SelectedBondingCurve (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool InitialPrice (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool InitialPrice (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool PriceChangeStep (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool PriceChangeStep (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: MulticollateralBondingCurvePool PriceChangeRate (r:1 w:0)
+	/// Proof Skipped: MulticollateralBondingCurvePool PriceChangeRate (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_bonding_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `544`
+		//  Estimated: `7561`
+		// Minimum execution time: 61_318_000 picoseconds.
+		Weight::from_parts(62_490_000, 7561)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -59,6 +59,23 @@ pub trait OracleProxyApi<BlockHash, Symbol, Rate, ResolveTime> {
         &self,
         at: Option<BlockHash>,
     ) -> RpcResult<Result<Vec<(Symbol, ResolveTime)>, DispatchError>>;
+
+    #[method(name = "oracleProxy_rateAt")]
+    fn rate_at(
+        &self,
+        symbol: Symbol,
+        timestamp: ResolveTime,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Rate>>;
+
+    #[method(name = "oracleProxy_twap")]
+    fn twap(
+        &self,
+        symbol: Symbol,
+        from: ResolveTime,
+        to: ResolveTime,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Rate>>;
 }
 
 pub struct OracleProxyClient<C, B> {
@@ -114,4 +131,35 @@ where
         api.list_enabled_symbols(&at)
             .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
     }
+
+    fn rate_at(
+        &self,
+        symbol: Symbol,
+        timestamp: ResolveTime,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RateInfo>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.rate_at(&at, symbol, timestamp)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
+
+    fn twap(
+        &self,
+        symbol: Symbol,
+        from: ResolveTime,
+        to: ResolveTime,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RateInfo>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.twap(&at, symbol, from, to)
+            .map_err(|e| RpcError::Call(CallError::Failed(e.into())))
+    }
 }
@@ -69,6 +69,7 @@ pub struct RateInfo {
 }
 
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait OracleProxyAPI<Symbol, ResolveTime> where
         Symbol: Codec,
         ResolveTime: Codec
@@ -78,5 +79,19 @@ sp_api::decl_runtime_apis! {
         ) -> Result<Option<RateInfo>, DispatchError>;
 
         fn list_enabled_symbols() -> Result<Vec<(Symbol, ResolveTime)>, DispatchError>;
+
+        /// Rate of the symbol which was actual at `timestamp`
+        fn rate_at(
+            symbol: Symbol,
+            timestamp: ResolveTime,
+        ) -> Option<RateInfo>;
+
+        /// Time-weighted average rate of the symbol over the `[from, to]` window,
+        /// `last_updated` is the update time of the latest rate within the window
+        fn twap(
+            symbol: Symbol,
+            from: ResolveTime,
+            to: ResolveTime,
+        ) -> Option<RateInfo>;
     }
 }
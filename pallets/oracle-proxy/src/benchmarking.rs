@@ -34,10 +34,25 @@ use super::*;
 use crate::Oracle;
 use crate::Pallet as OracleProxy;
 use codec::alloc::collections::BTreeSet;
+use common::fixed;
 use frame_benchmarking::benchmarks;
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
+fn symbol<T: Config>(sym: &str) -> T::Symbol {
+    let bytes = sym.encode();
+    T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+}
+
+fn symbols<T: Config>(n: u32) -> Vec<T::Symbol> {
+    (0..n)
+        .map(|i| {
+            let bytes = vec![b'S', b'A' + (i / 26) as u8, b'A' + (i % 26) as u8].encode();
+            T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+        })
+        .collect()
+}
+
 benchmarks! {
     enable_oracle {
         let oracle = Oracle::BandChainFeed;
@@ -54,5 +69,48 @@ benchmarks! {
         assert_eq!(OracleProxy::<T>::enabled_oracles(), BTreeSet::new());
     }
 
+    set_symbol_policy {
+        let euro = symbol::<T>("EURO");
+        let policy = OraclePolicy::Fallback {
+            primary: Oracle::BandChainFeed,
+            fallback: Oracle::PriceFeed,
+        };
+    }: _(RawOrigin::Root, euro.clone(), Some(policy))
+    verify {
+        assert_eq!(OracleProxy::<T>::symbol_policies(euro), Some(policy));
+    }
+
+    on_rates_relayed {
+        let n in 1 .. 100;
+        let symbols = symbols::<T>(n);
+        let capacity = T::MaxRateHistory::get();
+        for symbol in symbols.iter() {
+            let mut history = RateHistory::default();
+            for timestamp in 0..capacity {
+                history.push(HistoricalRate { value: 1, timestamp: timestamp.into() }, capacity);
+            }
+            SymbolRatesHistory::<T>::insert(Oracle::BandChainFeed, symbol, history);
+        }
+        let rates = symbols
+            .iter()
+            .map(|symbol| {
+                let rate = Rate {
+                    value: 2,
+                    last_updated: capacity.into(),
+                    dynamic_fee: fixed!(0),
+                };
+                (symbol.clone(), rate)
+            })
+            .collect();
+    }: {
+        <OracleProxy<T> as OnNewSymbolsRelayed<T::Symbol>>::on_rates_relayed(Oracle::BandChainFeed, rates);
+    }
+    verify {
+        for symbol in symbols {
+            let history = OracleProxy::<T>::symbol_rates_history(Oracle::BandChainFeed, symbol);
+            assert_eq!(history.last().map(|rate| rate.timestamp), Some(capacity.into()));
+        }
+    }
+
     impl_benchmark_test_suite!(OracleProxy, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
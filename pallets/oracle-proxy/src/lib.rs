@@ -32,11 +32,13 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
-use common::{DataFeed, OnNewSymbolsRelayed, Oracle, Rate};
+use common::{Balance, DataFeed, OnNewSymbolsRelayed, Oracle, Rate};
 use frame_support;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
-use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use sp_runtime::{PerThing, Percent};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 
@@ -51,44 +53,279 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Policy of resolving the symbol rate from the oracles
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq)]
+pub enum OraclePolicy {
+    /// Rate is taken from the single oracle.
+    Primary(Oracle),
+    /// Rate is taken from the `fallback` oracle when the `primary` rate is stale or missing.
+    Fallback { primary: Oracle, fallback: Oracle },
+    /// Rate is taken from the `primary` oracle if it doesn't deviate from the `secondary` rate
+    /// by more than `max_deviation`. When one of the rates is stale or missing, the other one
+    /// is used without validation.
+    CrossValidated {
+        primary: Oracle,
+        secondary: Oracle,
+        max_deviation: Percent,
+    },
+}
+
+impl OraclePolicy {
+    /// Oracles used by the policy in the order of priority
+    pub fn oracles(&self) -> Vec<Oracle> {
+        match *self {
+            OraclePolicy::Primary(oracle) => vec![oracle],
+            OraclePolicy::Fallback { primary, fallback } => vec![primary, fallback],
+            OraclePolicy::CrossValidated {
+                primary, secondary, ..
+            } => vec![primary, secondary],
+        }
+    }
+}
+
+/// Symbol rate recorded in the history
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq)]
+pub struct HistoricalRate {
+    /// Rate value in USD.
+    pub value: Balance,
+    /// Update time of the rate in seconds.
+    pub timestamp: u64,
+}
+
+/// Ring buffer of the latest symbol rates
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Default)]
+pub struct RateHistory {
+    /// Recorded rates, `head` points to the oldest one.
+    pub rates: Vec<HistoricalRate>,
+    /// Index of the oldest rate.
+    pub head: u32,
+}
+
+impl RateHistory {
+    /// Rates ordered by time, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &HistoricalRate> {
+        let (newer, older) = self.rates.split_at(self.head as usize);
+        older.iter().chain(newer.iter())
+    }
+
+    /// The latest recorded rate.
+    pub fn last(&self) -> Option<&HistoricalRate> {
+        let len = self.rates.len();
+        if len == 0 {
+            return None;
+        }
+        self.rates.get((self.head as usize + len - 1) % len)
+    }
+
+    /// Record `rate` overwriting the oldest one if `capacity` is reached.
+    ///
+    /// Rates older than the latest recorded one are ignored, the rate with the same timestamp is replaced.
+    pub fn push(&mut self, rate: HistoricalRate, capacity: u32) {
+        let len = self.rates.len();
+        match self.last().map(|last| last.timestamp) {
+            Some(timestamp) if timestamp > rate.timestamp => return,
+            Some(timestamp) if timestamp == rate.timestamp => {
+                self.rates[(self.head as usize + len - 1) % len] = rate;
+                return;
+            }
+            _ => {}
+        }
+
+        if (len as u32) < capacity {
+            if self.head == 0 {
+                self.rates.push(rate);
+            } else {
+                self.rates.insert(self.head as usize, rate);
+                self.head += 1;
+            }
+        } else if len > 0 {
+            self.rates[self.head as usize] = rate;
+            self.head = (self.head + 1) % len as u32;
+        }
+    }
+}
+
 impl<T: Config> DataFeed<T::Symbol, Rate, u64> for Pallet<T> {
     fn quote(symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
-        let enabled_oracles = Self::enabled_oracles();
-
-        Self::enabled_symbols(symbol)
-            .into_iter()
-            .filter(|oracle| enabled_oracles.contains(&oracle))
-            .map(|oracle| match oracle {
-                Oracle::BandChainFeed => T::BandChainOracle::quote(symbol),
-            })
-            .next()
-            .unwrap_or(Ok(None))
+        let policy = if let Some(policy) = Self::symbol_policy(symbol) {
+            policy
+        } else {
+            return Ok(None);
+        };
+
+        match policy {
+            OraclePolicy::Primary(oracle) => Self::quote_enabled(oracle, symbol),
+            OraclePolicy::Fallback { primary, fallback } => {
+                let primary_rate = Self::quote_enabled(primary, symbol);
+                if let Ok(Some(_)) = primary_rate {
+                    return primary_rate;
+                }
+                match Self::quote_enabled(fallback, symbol) {
+                    Ok(None) => primary_rate,
+                    fallback_rate => fallback_rate,
+                }
+            }
+            OraclePolicy::CrossValidated {
+                primary,
+                secondary,
+                max_deviation,
+            } => {
+                let primary_rate = Self::quote_enabled(primary, symbol);
+                let secondary_rate = Self::quote_enabled(secondary, symbol);
+                match (primary_rate, secondary_rate) {
+                    (Ok(Some(primary_rate)), Ok(Some(secondary_rate))) => {
+                        ensure!(
+                            primary_rate.value.abs_diff(secondary_rate.value)
+                                <= max_deviation.mul_floor(secondary_rate.value),
+                            Error::<T>::OracleRatesDeviate
+                        );
+                        Ok(Some(primary_rate))
+                    }
+                    (Ok(Some(primary_rate)), _) => Ok(Some(primary_rate)),
+                    (primary_rate, Ok(None)) => primary_rate,
+                    (_, secondary_rate) => secondary_rate,
+                }
+            }
+        }
     }
 
     fn list_enabled_symbols() -> Result<Vec<(T::Symbol, u64)>, DispatchError> {
-        let symbols_rates = Self::enabled_oracles()
+        let mut symbols_rates = BTreeMap::new();
+        Self::enabled_oracles()
             .into_iter()
-            .flat_map(|oracle| match oracle {
-                Oracle::BandChainFeed => match T::BandChainOracle::list_enabled_symbols() {
-                    Ok(list) => list,
-                    _ => Vec::new(),
-                },
+            .flat_map(|oracle| match Self::oracle_list_enabled_symbols(oracle) {
+                Ok(list) => list,
+                _ => Vec::new(),
             })
-            .collect();
-        Ok(symbols_rates)
+            .for_each(|(symbol, last_updated)| {
+                let entry = symbols_rates.entry(symbol).or_insert(last_updated);
+                *entry = (*entry).max(last_updated);
+            });
+        Ok(symbols_rates.into_iter().collect())
     }
 
     fn quote_unchecked(symbol: &T::Symbol) -> Option<Rate> {
         let enabled_oracles = Self::enabled_oracles();
 
-        Self::enabled_symbols(symbol)
+        // The freshest rate is used, the oracle with higher priority wins on equal timestamps
+        Self::symbol_policy(symbol)?
+            .oracles()
             .into_iter()
-            .filter(|oracle| enabled_oracles.contains(&oracle))
-            .map(|oracle| match oracle {
-                Oracle::BandChainFeed => T::BandChainOracle::quote_unchecked(symbol),
+            .filter(|oracle| enabled_oracles.contains(oracle))
+            .filter_map(|oracle| Self::oracle_quote_unchecked(oracle, symbol))
+            .fold(None, |freshest: Option<Rate>, rate| match freshest {
+                Some(freshest) if freshest.last_updated >= rate.last_updated => Some(freshest),
+                _ => Some(rate),
             })
-            .next()
-            .unwrap_or(None)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Policy of the `symbol`, defaults to the provider of the symbol if the policy isn't set.
+    pub fn symbol_policy(symbol: &T::Symbol) -> Option<OraclePolicy> {
+        Self::symbol_policies(symbol)
+            .or_else(|| Self::enabled_symbols(symbol).map(OraclePolicy::Primary))
+    }
+
+    /// Quote the `symbol` rate from the `oracle` if it's enabled.
+    fn quote_enabled(oracle: Oracle, symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
+        if Self::enabled_oracles().contains(&oracle) {
+            Self::oracle_quote(oracle, symbol)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn oracle_quote(oracle: Oracle, symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
+        match oracle {
+            Oracle::BandChainFeed => T::BandChainOracle::quote(symbol),
+            Oracle::PriceFeed => T::PriceFeedOracle::quote(symbol),
+        }
+    }
+
+    fn oracle_quote_unchecked(oracle: Oracle, symbol: &T::Symbol) -> Option<Rate> {
+        match oracle {
+            Oracle::BandChainFeed => T::BandChainOracle::quote_unchecked(symbol),
+            Oracle::PriceFeed => T::PriceFeedOracle::quote_unchecked(symbol),
+        }
+    }
+
+    /// Recorded rates of the `symbol` ordered by time, oldest first.
+    ///
+    /// Rates are taken from the first enabled oracle of the symbol policy with non-empty history.
+    pub fn rate_history(symbol: &T::Symbol) -> Vec<HistoricalRate> {
+        let enabled_oracles = Self::enabled_oracles();
+        Self::symbol_policy(symbol)
+            .map(|policy| policy.oracles())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|oracle| enabled_oracles.contains(oracle))
+            .map(|oracle| Self::symbol_rates_history(oracle, symbol))
+            .find(|history| !history.rates.is_empty())
+            .map(|history| history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// The `symbol` rate which was actual at `timestamp` (in seconds).
+    pub fn rate_at(symbol: &T::Symbol, timestamp: u64) -> Option<HistoricalRate> {
+        Self::rate_history(symbol)
+            .into_iter()
+            .take_while(|rate| rate.timestamp <= timestamp)
+            .last()
+    }
+
+    /// Time-weighted average rate of the `symbol` over the `[from, to]` window (in seconds).
+    ///
+    /// The window starts at the first recorded rate if there is no rate actual at `from`.
+    /// Returned `timestamp` is the update time of the latest rate within the window.
+    pub fn twap(symbol: &T::Symbol, from: u64, to: u64) -> Option<HistoricalRate> {
+        if from > to {
+            return None;
+        }
+
+        let mut weighted_sum: Balance = 0;
+        let mut total_period: u64 = 0;
+        let mut current: Option<HistoricalRate> = None;
+        let mut cursor = from;
+        for rate in Self::rate_history(symbol) {
+            if rate.timestamp <= from {
+                current = Some(rate);
+                continue;
+            }
+            if rate.timestamp > to {
+                break;
+            }
+            if let Some(current) = current {
+                let period = rate.timestamp - cursor;
+                weighted_sum =
+                    weighted_sum.saturating_add(current.value.saturating_mul(period.into()));
+                total_period += period;
+            }
+            cursor = rate.timestamp;
+            current = Some(rate);
+        }
+
+        let current = current?;
+        let period = to - cursor;
+        weighted_sum = weighted_sum.saturating_add(current.value.saturating_mul(period.into()));
+        total_period += period;
+
+        let value = if total_period == 0 {
+            current.value
+        } else {
+            weighted_sum / Balance::from(total_period)
+        };
+        Some(HistoricalRate {
+            value,
+            timestamp: current.timestamp,
+        })
+    }
+
+    fn oracle_list_enabled_symbols(oracle: Oracle) -> Result<Vec<(T::Symbol, u64)>, DispatchError> {
+        match oracle {
+            Oracle::BandChainFeed => T::BandChainOracle::list_enabled_symbols(),
+            Oracle::PriceFeed => T::PriceFeedOracle::list_enabled_symbols(),
+        }
     }
 }
 
@@ -102,6 +339,25 @@ impl<T: Config> OnNewSymbolsRelayed<T::Symbol> for Pallet<T> {
         });
         Ok(())
     }
+
+    fn on_rates_relayed(oracle_variant: Oracle, rates: Vec<(T::Symbol, Rate)>) {
+        let capacity = T::MaxRateHistory::get();
+        rates.into_iter().for_each(|(symbol, rate)| {
+            SymbolRatesHistory::<T>::mutate(oracle_variant, symbol, |history| {
+                history.push(
+                    HistoricalRate {
+                        value: rate.value,
+                        timestamp: rate.last_updated,
+                    },
+                    capacity,
+                )
+            });
+        });
+    }
+
+    fn on_rates_relayed_weight(rates: u32) -> Weight {
+        <T as Config>::WeightInfo::on_rates_relayed(rates)
+    }
 }
 
 #[frame_support::pallet]
@@ -117,6 +373,10 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         type Symbol: Parameter + Ord;
         type BandChainOracle: DataFeed<Self::Symbol, Rate, u64>;
+        type PriceFeedOracle: DataFeed<Self::Symbol, Rate, u64>;
+        /// Maximum number of the rates kept in the history of each symbol
+        #[pallet::constant]
+        type MaxRateHistory: Get<u32>;
     }
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -135,6 +395,24 @@ pub mod pallet {
     #[pallet::getter(fn enabled_symbols)]
     pub type SymbolProviders<T: Config> = StorageMap<_, Blake2_128Concat, T::Symbol, Oracle>;
 
+    /// Latest rates of the symbols relayed by the oracles
+    #[pallet::storage]
+    #[pallet::getter(fn symbol_rates_history)]
+    pub type SymbolRatesHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Oracle,
+        Blake2_128Concat,
+        T::Symbol,
+        RateHistory,
+        ValueQuery,
+    >;
+
+    /// Policies of resolving the symbol rates, take precedence over `SymbolProviders`
+    #[pallet::storage]
+    #[pallet::getter(fn symbol_policies)]
+    pub type SymbolPolicies<T: Config> = StorageMap<_, Blake2_128Concat, T::Symbol, OraclePolicy>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -142,12 +420,18 @@ pub mod pallet {
         OracleEnabled(Oracle),
         /// Oracle was successfully disabled. [oracle]
         OracleDisabled(Oracle),
+        /// Symbol policy was changed. [symbol, policy]
+        SymbolPolicySet(T::Symbol, Option<OraclePolicy>),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         OracleAlreadyEnabled,
         OracleAlreadyDisabled,
+        /// Policy uses the same oracle more than once
+        InvalidOraclePolicy,
+        /// Rates of the cross-validated oracles deviate too much
+        OracleRatesDeviate,
     }
 
     #[pallet::call]
@@ -195,6 +479,36 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Sets a policy of resolving the symbol rate
+        ///
+        /// Checks if the caller is root
+        ///
+        /// - `origin`: the sudo account
+        /// - `symbol`: symbol which policy should be changed
+        /// - `policy`: new policy, the symbol provider is used if `None`
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_symbol_policy())]
+        pub fn set_symbol_policy(
+            origin: OriginFor<T>,
+            symbol: T::Symbol,
+            policy: Option<OraclePolicy>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if let Some(policy) = policy {
+                let oracles = policy.oracles();
+                ensure!(
+                    oracles.iter().collect::<BTreeSet<_>>().len() == oracles.len(),
+                    Error::<T>::InvalidOraclePolicy
+                );
+            }
+
+            SymbolPolicies::<T>::set(symbol.clone(), policy);
+
+            Self::deposit_event(Event::SymbolPolicySet(symbol, policy));
+
+            Ok(().into())
+        }
     }
 
     #[pallet::genesis_config]
@@ -30,13 +30,17 @@
 
 use crate::{self as oracle_proxy, Config};
 use band;
+use common::{DataFeed, Rate};
 use frame_support::traits::{ConstU16, ConstU64};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
+    DispatchError,
 };
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -88,6 +92,49 @@ impl Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type BandChainOracle = band::Pallet<Runtime>;
+    type PriceFeedOracle = MockPriceFeed;
+    type MaxRateHistory = frame_support::traits::ConstU32<3>;
+}
+
+thread_local! {
+    static PRICE_FEED_RATES: RefCell<BTreeMap<String, (Rate, bool)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Second oracle with the rates set directly by the tests
+pub struct MockPriceFeed;
+
+impl MockPriceFeed {
+    pub fn set_rate(symbol: &str, rate: Rate, is_stale: bool) {
+        PRICE_FEED_RATES.with(|rates| {
+            rates
+                .borrow_mut()
+                .insert(symbol.to_owned(), (rate, is_stale))
+        });
+    }
+}
+
+impl DataFeed<String, Rate, u64> for MockPriceFeed {
+    fn quote(symbol: &String) -> Result<Option<Rate>, DispatchError> {
+        match PRICE_FEED_RATES.with(|rates| rates.borrow().get(symbol).cloned()) {
+            Some((_, true)) => Err(DispatchError::Other("RateExpired")),
+            Some((rate, false)) => Ok(Some(rate)),
+            None => Ok(None),
+        }
+    }
+
+    fn list_enabled_symbols() -> Result<Vec<(String, u64)>, DispatchError> {
+        Ok(PRICE_FEED_RATES.with(|rates| {
+            rates
+                .borrow()
+                .iter()
+                .map(|(symbol, (rate, _))| (symbol.clone(), rate.last_updated))
+                .collect()
+        }))
+    }
+
+    fn quote_unchecked(symbol: &String) -> Option<Rate> {
+        PRICE_FEED_RATES.with(|rates| rates.borrow().get(symbol).map(|(rate, _)| *rate))
+    }
 }
 
 frame_support::parameter_types! {
@@ -113,10 +160,12 @@ impl band::Config for Runtime {
     type GetBandRateStaleBlockPeriod = GetBandRateStaleBlockPeriod;
     type OnSymbolDisabledHook = ();
     type MaxRelaySymbols = frame_support::traits::ConstU32<100>;
+    type MaxDeviatingRounds = frame_support::traits::ConstU32<3>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    PRICE_FEED_RATES.with(|rates| rates.borrow_mut().clear());
     system::GenesisConfig::default()
         .build_storage::<Runtime>()
         .unwrap()
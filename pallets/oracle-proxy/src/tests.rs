@@ -29,11 +29,12 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use codec::alloc::collections::HashSet;
-use common::{fixed, DataFeed};
-use frame_support::{assert_err, error::BadOrigin};
+use common::{fixed, DataFeed, OnNewSymbolsRelayed};
+use frame_support::{assert_err, assert_noop, assert_ok, error::BadOrigin};
 use sp_core::TryCollect;
+use sp_runtime::Percent;
 
-use crate::{mock::*, Oracle, Rate};
+use crate::{mock::*, Error, HistoricalRate, Oracle, OraclePolicy, Rate};
 
 fn relay_symbols() {
     let symbols = vec!["USD".to_owned(), "RUB".to_owned(), "YEN".to_owned()];
@@ -55,6 +56,50 @@ fn relay_symbols() {
     .expect("Failed to relay rates");
 }
 
+fn relay_usd(value: u64, resolve_time: u64) {
+    Band::relay(
+        RuntimeOrigin::signed(1),
+        vec![("USD".to_owned(), value)].try_into().unwrap(),
+        resolve_time,
+        0,
+    )
+    .expect("Failed to relay rates");
+}
+
+fn historical_rate(value: u64, timestamp: u64) -> HistoricalRate {
+    HistoricalRate {
+        value: band_rate(value).value,
+        timestamp,
+    }
+}
+
+fn enable_oracles() {
+    OracleProxy::enable_oracle(RuntimeOrigin::root(), Oracle::BandChainFeed)
+        .expect("Failed to enable oracle");
+    OracleProxy::enable_oracle(RuntimeOrigin::root(), Oracle::PriceFeed)
+        .expect("Failed to enable oracle");
+}
+
+fn band_rate(value: u64) -> Rate {
+    Rate {
+        value: Band::raw_rate_into_balance(value).expect("failed to convert rate into Balance"),
+        last_updated: 0,
+        dynamic_fee: fixed!(0),
+    }
+}
+
+fn price_feed_rate(value: u128, last_updated: u64) -> Rate {
+    Rate {
+        value,
+        last_updated,
+        dynamic_fee: fixed!(0),
+    }
+}
+
+fn quote(symbol: &str) -> Result<Option<Rate>, sp_runtime::DispatchError> {
+    <OracleProxy as DataFeed<String, Rate, u64>>::quote(&symbol.to_owned())
+}
+
 #[test]
 fn enable_and_disable_oracles_should_work() {
     new_test_ext().execute_with(|| {
@@ -150,3 +195,293 @@ fn quote_and_list_enabled_symbols_should_not_work_with_disabled_oracle() {
         )
     });
 }
+
+#[test]
+fn set_symbol_policy_should_work() {
+    new_test_ext().execute_with(|| {
+        let symbol = "USD".to_owned();
+        let policy = OraclePolicy::Fallback {
+            primary: Oracle::BandChainFeed,
+            fallback: Oracle::PriceFeed,
+        };
+        assert_noop!(
+            OracleProxy::set_symbol_policy(RuntimeOrigin::signed(1), symbol.clone(), Some(policy)),
+            BadOrigin
+        );
+        assert_noop!(
+            OracleProxy::set_symbol_policy(
+                RuntimeOrigin::root(),
+                symbol.clone(),
+                Some(OraclePolicy::Fallback {
+                    primary: Oracle::PriceFeed,
+                    fallback: Oracle::PriceFeed,
+                })
+            ),
+            Error::<Runtime>::InvalidOraclePolicy
+        );
+
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            symbol.clone(),
+            Some(policy)
+        ));
+        assert_eq!(OracleProxy::symbol_policy(&symbol), Some(policy));
+
+        relay_symbols();
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            symbol.clone(),
+            None
+        ));
+        assert_eq!(
+            OracleProxy::symbol_policy(&symbol),
+            Some(OraclePolicy::Primary(Oracle::BandChainFeed))
+        );
+    });
+}
+
+#[test]
+fn fallback_policy_should_failover_on_stale_rate() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        enable_oracles();
+        let fallback_rate = price_feed_rate(band_rate(1).value * 2, 100);
+        MockPriceFeed::set_rate("USD", fallback_rate, false);
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            "USD".to_owned(),
+            Some(OraclePolicy::Fallback {
+                primary: Oracle::BandChainFeed,
+                fallback: Oracle::PriceFeed,
+            })
+        ));
+
+        assert_eq!(quote("USD"), Ok(Some(band_rate(1))));
+
+        Timestamp::set_timestamp(GetBandRateStalePeriod::get());
+        assert_eq!(quote("USD"), Ok(Some(fallback_rate)));
+        // Symbols without a policy are not failed over
+        assert_err!(quote("RUB"), band::Error::<Runtime>::RateExpired);
+
+        // Error of the primary oracle is returned when the fallback has no rate
+        OracleProxy::disable_oracle(RuntimeOrigin::root(), Oracle::PriceFeed)
+            .expect("Failed to disable oracle");
+        assert_err!(quote("USD"), band::Error::<Runtime>::RateExpired);
+    });
+}
+
+#[test]
+fn cross_validated_policy_should_check_deviation() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        enable_oracles();
+        let value = band_rate(1).value;
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            "USD".to_owned(),
+            Some(OraclePolicy::CrossValidated {
+                primary: Oracle::BandChainFeed,
+                secondary: Oracle::PriceFeed,
+                max_deviation: Percent::from_percent(10),
+            })
+        ));
+
+        // Secondary rate is missing, primary rate isn't validated
+        assert_eq!(quote("USD"), Ok(Some(band_rate(1))));
+
+        MockPriceFeed::set_rate("USD", price_feed_rate(value + value / 10, 100), false);
+        assert_eq!(quote("USD"), Ok(Some(band_rate(1))));
+
+        MockPriceFeed::set_rate("USD", price_feed_rate(value + value / 5, 100), false);
+        assert_err!(quote("USD"), Error::<Runtime>::OracleRatesDeviate);
+
+        // Stale secondary rate is ignored
+        MockPriceFeed::set_rate("USD", price_feed_rate(value + value / 5, 100), true);
+        assert_eq!(quote("USD"), Ok(Some(band_rate(1))));
+
+        // Stale primary rate fails over to the secondary
+        MockPriceFeed::set_rate("USD", price_feed_rate(value + value / 5, 100), false);
+        Timestamp::set_timestamp(GetBandRateStalePeriod::get());
+        assert_eq!(
+            quote("USD"),
+            Ok(Some(price_feed_rate(value + value / 5, 100)))
+        );
+    });
+}
+
+#[test]
+fn quote_unchecked_and_list_enabled_symbols_should_use_freshest_rates() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        enable_oracles();
+        let fresh_rate = price_feed_rate(band_rate(1).value * 2, 100);
+        MockPriceFeed::set_rate("USD", fresh_rate, false);
+        MockPriceFeed::set_rate("EUR", price_feed_rate(band_rate(1).value, 100), false);
+
+        // Only the provider of the symbol is used without a policy
+        assert_eq!(
+            <OracleProxy as DataFeed<String, Rate, u64>>::quote_unchecked(&"USD".to_owned()),
+            Some(band_rate(1))
+        );
+
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            "USD".to_owned(),
+            Some(OraclePolicy::Fallback {
+                primary: Oracle::BandChainFeed,
+                fallback: Oracle::PriceFeed,
+            })
+        ));
+        assert_eq!(
+            <OracleProxy as DataFeed<String, Rate, u64>>::quote_unchecked(&"USD".to_owned()),
+            Some(fresh_rate)
+        );
+
+        assert_eq!(
+            <OracleProxy as DataFeed<String, Rate, u64>>::list_enabled_symbols(),
+            Ok(vec![
+                ("EUR".to_owned(), 100),
+                ("RUB".to_owned(), 0),
+                ("USD".to_owned(), 100),
+                ("YEN".to_owned(), 0),
+            ])
+        );
+    });
+}
+
+#[test]
+fn rate_history_should_keep_latest_rates() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        relay_usd(2, 100);
+        relay_usd(3, 200);
+
+        let usd = "USD".to_owned();
+        // History of the disabled oracle is not used
+        assert!(OracleProxy::rate_history(&usd).is_empty());
+
+        OracleProxy::enable_oracle(RuntimeOrigin::root(), Oracle::BandChainFeed)
+            .expect("Failed to enable oracle");
+        assert_eq!(
+            OracleProxy::rate_history(&usd),
+            vec![
+                historical_rate(1, 0),
+                historical_rate(2, 100),
+                historical_rate(3, 200)
+            ]
+        );
+
+        // The oldest rate is overwritten
+        relay_usd(4, 300);
+        assert_eq!(
+            OracleProxy::rate_history(&usd),
+            vec![
+                historical_rate(2, 100),
+                historical_rate(3, 200),
+                historical_rate(4, 300)
+            ]
+        );
+        assert_eq!(
+            OracleProxy::symbol_rates_history(Oracle::BandChainFeed, &usd).head,
+            1
+        );
+
+        // Rate with the same timestamp is replaced
+        relay_usd(5, 300);
+        relay_usd(6, 400);
+        assert_eq!(
+            OracleProxy::rate_history(&usd),
+            vec![
+                historical_rate(3, 200),
+                historical_rate(5, 300),
+                historical_rate(6, 400)
+            ]
+        );
+        assert_eq!(
+            OracleProxy::rate_history(&"RUB".to_owned()),
+            vec![historical_rate(2, 0)]
+        );
+    });
+}
+
+#[test]
+fn rate_at_and_twap_should_work() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        relay_usd(2, 100);
+        relay_usd(3, 200);
+        OracleProxy::enable_oracle(RuntimeOrigin::root(), Oracle::BandChainFeed)
+            .expect("Failed to enable oracle");
+
+        let usd = "USD".to_owned();
+        assert_eq!(OracleProxy::rate_at(&usd, 0), Some(historical_rate(1, 0)));
+        assert_eq!(
+            OracleProxy::rate_at(&usd, 150),
+            Some(historical_rate(2, 100))
+        );
+        assert_eq!(
+            OracleProxy::rate_at(&usd, 1000),
+            Some(historical_rate(3, 200))
+        );
+        assert_eq!(OracleProxy::rate_at(&"EUR".to_owned(), 1000), None);
+
+        // (50 * 1 + 100 * 2 + 50 * 3) / 200 = 2
+        assert_eq!(
+            OracleProxy::twap(&usd, 50, 250),
+            Some(historical_rate(2, 200))
+        );
+        assert_eq!(
+            OracleProxy::twap(&usd, 0, 100),
+            Some(HistoricalRate {
+                value: band_rate(1).value,
+                timestamp: 100,
+            })
+        );
+        assert_eq!(
+            OracleProxy::twap(&usd, 150, 150),
+            Some(historical_rate(2, 100))
+        );
+        assert_eq!(OracleProxy::twap(&usd, 200, 100), None);
+
+        // The window starts at the first recorded rate
+        relay_usd(4, 300);
+        assert_eq!(OracleProxy::rate_at(&usd, 50), None);
+        assert_eq!(
+            OracleProxy::twap(&usd, 0, 150),
+            Some(historical_rate(2, 100))
+        );
+        assert_eq!(OracleProxy::twap(&usd, 0, 50), None);
+    });
+}
+
+#[test]
+fn rate_history_should_follow_symbol_policy() {
+    new_test_ext().execute_with(|| {
+        relay_symbols();
+        enable_oracles();
+        OracleProxy::on_rates_relayed(
+            Oracle::PriceFeed,
+            vec![("USD".to_owned(), price_feed_rate(band_rate(2).value, 100))],
+        );
+
+        let usd = "USD".to_owned();
+        assert_eq!(OracleProxy::rate_history(&usd), vec![historical_rate(1, 0)]);
+
+        assert_ok!(OracleProxy::set_symbol_policy(
+            RuntimeOrigin::root(),
+            usd.clone(),
+            Some(OraclePolicy::Fallback {
+                primary: Oracle::PriceFeed,
+                fallback: Oracle::BandChainFeed,
+            })
+        ));
+        assert_eq!(
+            OracleProxy::rate_history(&usd),
+            vec![historical_rate(2, 100)]
+        );
+
+        OracleProxy::disable_oracle(RuntimeOrigin::root(), Oracle::PriceFeed)
+            .expect("Failed to disable oracle");
+        assert_eq!(OracleProxy::rate_history(&usd), vec![historical_rate(1, 0)]);
+    });
+}
@@ -34,6 +34,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `TRX40`, CPU: `AMD Ryzen Threadripper 3960X 24-Core Processor`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("local"), DB CACHE: 1024
+//!
+//! `on_rates_relayed` is a hand estimate for histories filled up to `MaxRateHistory` = 360 rates,
+//! it has to be replaced with the output of its benchmark.
 
 // Executed Command:
 // ./target/release/framenode
@@ -61,6 +64,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn enable_oracle() -> Weight;
 	fn disable_oracle() -> Weight;
+	fn set_symbol_policy() -> Weight;
+	fn on_rates_relayed(n: u32, ) -> Weight;
 }
 
 /// Weights for oracle_proxy using the Substrate node and recommended hardware.
@@ -88,6 +93,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: OracleProxy SymbolPolicies (r:0 w:1)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	fn set_symbol_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: OracleProxy SymbolRatesHistory (r:100 w:100)
+	/// Proof Skipped: OracleProxy SymbolRatesHistory (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn on_rates_relayed(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 8750).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -114,4 +138,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: OracleProxy SymbolPolicies (r:0 w:1)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	fn set_symbol_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: OracleProxy SymbolRatesHistory (r:100 w:100)
+	/// Proof Skipped: OracleProxy SymbolRatesHistory (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn on_rates_relayed(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 8750).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
 }
\ No newline at end of file
@@ -34,7 +34,7 @@ use frame_support::weights::Weight;
 use frame_support::{dispatch, ensure};
 
 use common::prelude::{Balance, FixedWrapper};
-use common::{balance, AssetInfoProvider};
+use common::{balance, AssetInfoProvider, OnSwapFeeCharged};
 
 use crate::to_fixed_wrapper;
 
@@ -390,6 +390,31 @@ impl<T: Config> common::SwapAction<AccountIdOf<T>, TechAccountIdOf<T>, AssetIdOf
                 )?;
             }
 
+            let (fee_asset, volume) = if self.get_fee_from_destination.unwrap() {
+                (
+                    &self.destination.asset,
+                    self.destination
+                        .amount
+                        .unwrap()
+                        .checked_add(self.fee.unwrap())
+                        .ok_or(Error::<T>::CalculatedValueIsOutOfDesiredBounds)?,
+                )
+            } else {
+                (&self.source.asset, self.source.amount.unwrap())
+            };
+            // Fee hook failure must not fail the swap, its changes are reverted separately
+            if let Err(err) = common::with_transaction(|| {
+                T::OnSwapFeeCharged::on_swap_fee_charged(
+                    source,
+                    &fee_account_repr_sys,
+                    fee_asset,
+                    self.fee.unwrap(),
+                    volume,
+                )
+            }) {
+                frame_support::log::warn!("Swap fee hook failed: {:?}", err);
+            }
+
             let pool_account_repr_sys =
                 technical::Pallet::<T>::tech_account_id_to_account_id(&self.pool_account)?;
             let balance_a =
@@ -45,8 +45,9 @@ use common::prelude::{
 };
 use common::{
     fixed_wrapper, AssetInfoProvider, DexInfoProvider, EnsureTradingPairExists, GetPoolReserves,
-    LiquiditySource, LiquiditySourceType, ManagementMode, OnPoolReservesChanged, PoolXykPallet,
-    RewardReason, TechAccountId, TechPurpose, ToFeeAccount, TradingPair, TradingPairSourceManager,
+    LiquiditySource, LiquiditySourceType, ManagementMode, OnPoolReservesChanged, OnSwapFeeCharged,
+    PoolXykPallet, RewardReason, TechAccountId, TechPurpose, ToFeeAccount, TradingPair,
+    TradingPairSourceManager,
 };
 
 mod aliases;
@@ -158,6 +159,37 @@ impl<T: Config> PoolXykPallet<T::AccountId, T::AssetId> for Pallet<T> {
 
         Ok(())
     }
+
+    fn deposit_liquidity(
+        source: T::AccountId,
+        base_asset_id: T::AssetId,
+        target_asset_id: T::AssetId,
+        base_desired: Balance,
+        target_desired: Balance,
+        base_min: Balance,
+        target_min: Balance,
+    ) -> Result<(), DispatchError> {
+        // Pool is identified by the DEX which has the given base asset
+        let dex_id = T::DexInfoProvider::list_dex_ids()
+            .into_iter()
+            .find(|dex_id| {
+                T::DexInfoProvider::get_dex_info(dex_id)
+                    .map(|dex_info| dex_info.base_asset_id == base_asset_id)
+                    .unwrap_or(false)
+            })
+            .ok_or(Error::<T>::PoolIsInvalid)?;
+
+        Pallet::<T>::deposit_liquidity_unchecked(
+            source,
+            dex_id,
+            base_asset_id,
+            target_asset_id,
+            base_desired,
+            target_desired,
+            base_min,
+            target_min,
+        )
+    }
 }
 
 impl<T: Config> Pallet<T> {
@@ -623,6 +655,7 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
 
     fn exchange_weight() -> Weight {
         <T as Config>::WeightInfo::swap_pair()
+            .saturating_add(T::OnSwapFeeCharged::on_swap_fee_charged_weight())
     }
 
     fn check_rewards_weight() -> Weight {
@@ -680,6 +713,8 @@ pub mod pallet {
         type GetFee: Get<Fixed>;
         type OnPoolCreated: OnPoolCreated<AccountId = AccountIdOf<Self>, DEXId = DEXIdOf<Self>>;
         type OnPoolReservesChanged: OnPoolReservesChanged<Self::AssetId>;
+        /// Hook to be called when a swap fee is charged from the client account.
+        type OnSwapFeeCharged: OnSwapFeeCharged<Self::AccountId, Self::AssetId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         type GetTradingPairRestrictedFlag: GetByKey<TradingPair<Self::AssetId>, bool>;
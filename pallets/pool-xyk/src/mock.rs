@@ -266,6 +266,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -277,8 +278,16 @@ parameter_types! {
         );
         tech_account_id
     };
+    pub GetCdpCollateralTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            xst::TECH_ACCOUNT_PREFIX.to_vec(),
+            xst::TECH_ACCOUNT_CDP_COLLATERAL.to_vec(),
+        );
+        tech_account_id
+    };
     pub GetSyntheticBaseAssetId: AssetId = BatteryForMusicPlayer.into();
     pub const GetSyntheticBaseBuySellLimit: Balance = balance!(10000000000);
+    pub const GetCdpLiquidationPenalty: Percent = Percent::from_percent(10);
     pub const GetBandRateStalePeriod: Moment = 60*5*1000; // 5 minutes
     pub const GetBandRateStaleBlockPeriod: u64 = 600; // 1 hour
 }
@@ -293,6 +302,7 @@ impl band::Config for Runtime {
     type GetBandRateStaleBlockPeriod = GetBandRateStaleBlockPeriod;
     type OnSymbolDisabledHook = ();
     type MaxRelaySymbols = frame_support::traits::ConstU32<100>;
+    type MaxDeviatingRounds = frame_support::traits::ConstU32<3>;
 }
 
 impl oracle_proxy::Config for Runtime {
@@ -300,19 +310,27 @@ impl oracle_proxy::Config for Runtime {
     type WeightInfo = ();
     type Symbol = <Runtime as band::Config>::Symbol;
     type BandChainOracle = band::Pallet<Runtime>;
+    type PriceFeedOracle = ();
+    type MaxRateHistory = frame_support::traits::ConstU32<100>;
 }
 
 impl xst::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type GetSyntheticBaseAssetId = GetSyntheticBaseAssetId;
     type GetXSTPoolPermissionedTechAccountId = GetXSTPoolPermissionedTechAccountId;
+    type GetCdpCollateralTechAccountId = GetCdpCollateralTechAccountId;
     type EnsureDEXManager = DexManager;
     type PriceToolsPallet = ();
     type WeightInfo = ();
     type Oracle = OracleProxy;
     type Symbol = SymbolName;
     type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
+    type OnSwapFeeCharged = ();
     type TradingPairSourceManager = trading_pair::Pallet<Runtime>;
+    type LeverageRebalancePeriod = frame_support::traits::ConstU64<14400>;
+    type LiquidityProxy = ();
+    type CdpLiquidationPenalty = GetCdpLiquidationPenalty;
+    type Time = Timestamp;
 }
 
 parameter_type_with_key! {
@@ -340,6 +358,7 @@ impl Config for Runtime {
     type GetFee = GetFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = xst::Pallet<Runtime>;
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
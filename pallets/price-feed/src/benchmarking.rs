@@ -0,0 +1,114 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! PriceFeed module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use crate::Pallet as PriceFeed;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use hex_literal::hex;
+
+fn feeder<T: Config>() -> T::AccountId {
+    let bytes = hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48");
+    T::AccountId::decode(&mut &bytes[..]).expect("Failed to decode feeder ID")
+}
+
+fn symbol<T: Config>(sym: &str) -> T::Symbol {
+    let bytes = sym.encode();
+    T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+}
+
+fn symbols<T: Config>(n: u32) -> Vec<T::Symbol> {
+    (0..n)
+        .map(|i| {
+            let bytes = vec![b'S', b'A' + (i / 26) as u8, b'A' + (i % 26) as u8].encode();
+            T::Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
+        })
+        .collect()
+}
+
+benchmarks! {
+    submit_prices {
+        let n in 1 .. T::MaxSubmitSymbols::get();
+        let feeder = feeder::<T>();
+        let symbols = symbols::<T>(n);
+        let prices: Vec<_> = symbols.iter().cloned().map(|symbol| (symbol, 2)).collect();
+        let now: u64 = T::Time::now().unique_saturated_into();
+        let timestamp = now / MILLISECS_MULTIPLIER;
+        let other_feeders: Vec<T::AccountId> = (0..9).map(|i| account("feeder", i, 0)).collect();
+        PriceFeed::<T>::add_feeders(RawOrigin::Root.into(), other_feeders.clone())?;
+        for other_feeder in other_feeders {
+            PriceFeed::<T>::submit_prices(
+                RawOrigin::Signed(other_feeder).into(),
+                prices.clone().try_into().unwrap(),
+                timestamp,
+            )?;
+        }
+        PriceFeed::<T>::add_feeders(RawOrigin::Root.into(), vec![feeder.clone()])?;
+    }: _(RawOrigin::Signed(feeder), prices.try_into().unwrap(), timestamp)
+    verify {
+        for symbol in symbols {
+            assert_eq!(PriceFeed::<T>::rates(symbol), Some(FeedRate {
+                value: 2,
+                last_updated: timestamp,
+                last_updated_block: 1u32.into(),
+            }));
+        }
+    }
+
+    add_feeders {
+        let feeder = feeder::<T>();
+    }: _(RawOrigin::Root, vec![feeder.clone()])
+    verify {
+        assert!(PriceFeed::<T>::feeders().contains(&feeder));
+    }
+
+    remove_feeders {
+        let feeder = feeder::<T>();
+        PriceFeed::<T>::add_feeders(RawOrigin::Root.into(), vec![feeder.clone()])?;
+    }: _(RawOrigin::Root, vec![feeder.clone()])
+    verify {
+        assert!(!PriceFeed::<T>::feeders().contains(&feeder));
+    }
+
+    set_feed_source {
+        let url = b"https://prices.example.com/usd".to_vec();
+        let euro = symbol::<T>("EURO");
+    }: _(RawOrigin::Root, url.clone(), vec![(euro, b"eur".to_vec())])
+    verify {
+        assert_eq!(PriceFeed::<T>::feed_source().map(|source| source.url), Some(url));
+    }
+
+    impl_benchmark_test_suite!(PriceFeed, crate::mock::new_test_ext(), crate::mock::Runtime);
+}
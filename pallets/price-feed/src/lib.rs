@@ -0,0 +1,605 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// TODO #167: fix clippy warnings
+#![allow(clippy::all)]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use common::{fixed, Balance, DataFeed, OnNewSymbolsRelayed, Oracle, Rate};
+use frame_support::log::{debug, warn};
+use frame_support::pallet_prelude::*;
+use frame_support::sp_io;
+use frame_support::sp_runtime::offchain as rt_offchain;
+use frame_support::sp_runtime::traits::{UniqueSaturatedInto, Zero};
+use frame_support::sp_runtime::KeyTypeId;
+use frame_support::traits::Time;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use frame_system::pallet_prelude::*;
+use serde_json::Value;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod benchmarking;
+
+pub mod weights;
+
+pub use pallet::*;
+
+/// Key type of the feeder accounts used by the off-chain workers
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"pfed");
+
+/// Multiplier to convert rate last_update timestamp to Moment
+pub const MILLISECS_MULTIPLIER: u64 = 1_000;
+
+/// Maximum difference in milliseconds between the submitted prices timestamp and the chain time
+pub const MAX_TIMESTAMP_DRIFT_MILLISECS: u64 = 60_000;
+
+/// Maximum length of the feed source URL
+pub const MAX_URL_LENGTH: u32 = 256;
+
+/// Timeout of the feed source HTTP request
+pub const HTTP_REQUEST_TIMEOUT_MILLISECS: u64 = 10_000;
+
+/// Number of decimals of the fed prices
+pub const PRICE_DECIMALS: u32 = 18;
+
+/// Cryptography used by off-chain workers.
+pub mod crypto {
+    use crate::KEY_TYPE;
+
+    use frame_support::sp_runtime::app_crypto::{app_crypto, sr25519};
+    use frame_support::sp_runtime::{MultiSignature, MultiSigner};
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    pub struct AuthorityId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+        type RuntimeAppPublic = Public;
+        type GenericPublic = sr25519::Public;
+        type GenericSignature = sr25519::Signature;
+    }
+}
+
+/// Symbol rate
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Copy, Clone, PartialEq, Eq)]
+pub struct FeedRate<BlockNumber> {
+    /// Rate value in USD.
+    pub value: Balance,
+    /// Last updated timestamp in seconds.
+    pub last_updated: u64,
+    /// Last updated block number
+    pub last_updated_block: BlockNumber,
+}
+
+impl<BlockNumber> From<FeedRate<BlockNumber>> for Rate {
+    fn from(value: FeedRate<BlockNumber>) -> Rate {
+        Rate {
+            value: value.value,
+            last_updated: value.last_updated,
+            dynamic_fee: fixed!(0),
+        }
+    }
+}
+
+/// HTTP source of the prices fetched by the off-chain workers
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq, Eq)]
+pub struct FeedSource<Symbol> {
+    /// URL which responds with JSON object containing prices in USD
+    pub url: Vec<u8>,
+    /// Symbols and their keys in the JSON object
+    pub symbols: Vec<(Symbol, Vec<u8>)>,
+}
+
+impl<T: Config> DataFeed<T::Symbol, Rate, u64> for Pallet<T> {
+    fn quote(symbol: &T::Symbol) -> Result<Option<Rate>, DispatchError> {
+        let rate = if let Some(rate) = Self::rates(symbol) {
+            rate
+        } else {
+            return Ok(None);
+        };
+
+        let current_time = T::Time::now();
+        let stale_period = T::GetRateStalePeriod::get();
+        let last_updated = rate
+            .last_updated
+            .saturating_mul(MILLISECS_MULTIPLIER)
+            .try_into()
+            .map_err(|_| Error::<T>::RateHasInvalidTimestamp)?;
+
+        ensure!(
+            last_updated <= current_time,
+            Error::<T>::RateHasInvalidTimestamp
+        );
+
+        let current_period = current_time - last_updated;
+
+        ensure!(current_period < stale_period, Error::<T>::RateExpired);
+
+        Ok(Some(rate.into()))
+    }
+
+    fn list_enabled_symbols() -> Result<Vec<(T::Symbol, u64)>, DispatchError> {
+        Ok(SymbolRates::<T>::iter()
+            .map(|(symbol, rate)| (symbol, rate.last_updated))
+            .collect())
+    }
+
+    fn quote_unchecked(symbol: &T::Symbol) -> Option<Rate> {
+        Self::rates(symbol).map(|rate| rate.into())
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::traits::StorageVersion;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// `PriceFeed` pallet stores symbol prices submitted by trusted feeders.
+    ///
+    /// Off-chain workers of the feeders fetch the prices from the HTTP source set by
+    /// governance (aka *ROOT* user) every [`Config::UpdatePeriod`] blocks and submit them
+    /// with [`submit_prices`](Pallet::submit_prices()) extrinsic.
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+        /// Type of the symbol to be fed.
+        type Symbol: Parameter + Ord;
+        /// Event type of this pallet.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Keys of the feeders used by the off-chain workers to sign transactions.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Hook which is being executed when some new symbols were fed
+        type OnNewSymbolsRelayedHook: OnNewSymbolsRelayed<Self::Symbol>;
+        /// Time used for checking if rate expired
+        type Time: Time;
+        /// Rate expiration period in milliseconds.
+        #[pallet::constant]
+        type GetRateStalePeriod: Get<<<Self as Config>::Time as Time>::Moment>;
+        /// Maximum number of symbols that can be submitted within a single call.
+        #[pallet::constant]
+        type MaxSubmitSymbols: Get<u32>;
+        /// Period in blocks between price updates made by the off-chain workers.
+        #[pallet::constant]
+        type UpdatePeriod: Get<Self::BlockNumber>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn feeders)]
+    pub type Feeders<T: Config> = StorageValue<_, BTreeSet<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn rates)]
+    pub type SymbolRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Symbol, FeedRate<T::BlockNumber>, OptionQuery>;
+
+    /// Latest prices submitted by each feeder, aggregated into [`SymbolRates`]
+    #[pallet::storage]
+    #[pallet::getter(fn feeder_prices)]
+    pub type FeederPrices<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Symbol,
+        Blake2_128Concat,
+        T::AccountId,
+        FeedRate<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn feed_source)]
+    pub type PriceFeedSource<T: Config> = StorageValue<_, FeedSource<T::Symbol>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New symbol prices were submitted. [feeder, symbols]
+        PricesSubmitted(T::AccountId, Vec<(T::Symbol, Balance)>),
+        /// Added new feeder accounts. [feeders]
+        FeedersAdded(Vec<T::AccountId>),
+        /// Feeder accounts were removed. [feeders]
+        FeedersRemoved(Vec<T::AccountId>),
+        /// Feed source was changed. [url]
+        FeedSourceSet(Vec<u8>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// An untrusted account tried to submit prices.
+        UnauthorizedFeeder,
+        /// A request to add an account, which is already a feeder, was supplied.
+        AlreadyAFeeder,
+        /// A request to remove an account, which is not a feeder, was supplied.
+        NoSuchFeeder,
+        /// Rate has invalid timestamp.
+        RateHasInvalidTimestamp,
+        /// Rate is expired and can't be used until next update.
+        RateExpired,
+        /// Feed source URL or symbols are invalid.
+        InvalidFeedSource,
+        /// Feed source is not set.
+        FeedSourceNotSet,
+        /// Failed to fetch prices from the feed source.
+        HttpFetchingError,
+        /// No local feeder account to sign the transaction.
+        NoLocalFeeder,
+        /// Failed to submit the prices transaction.
+        FailedToSubmitPrices,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn offchain_worker(now: T::BlockNumber) {
+            let update_period = T::UpdatePeriod::get();
+            if update_period.is_zero() || !(now % update_period).is_zero() {
+                return;
+            }
+            if let Err(err) = Self::fetch_and_submit_prices() {
+                warn!("Failed to feed prices at {:?}: {:?}", now, err);
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit prices of the symbols fetched from the feed source.
+        ///
+        /// Symbol rate is set to the median of the fresh prices once more than a half of the
+        /// feeders have submitted them. Prices with `timestamp` lower than the one previously
+        /// submitted by the feeder are ignored, `timestamp` must be close to the chain time.
+        ///
+        /// - `origin`: the feeder account on whose behalf the transaction is being executed,
+        /// - `prices`: symbols with prices in USD with precision = 18,
+        /// - `timestamp`: time of the prices in seconds.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_prices(prices.len() as u32).saturating_add(T::OnNewSymbolsRelayedHook::on_rates_relayed_weight(prices.len() as u32)))]
+        pub fn submit_prices(
+            origin: OriginFor<T>,
+            prices: BoundedVec<(T::Symbol, Balance), T::MaxSubmitSymbols>,
+            timestamp: u64,
+        ) -> DispatchResultWithPostInfo {
+            let feeder = ensure_signed(origin)?;
+            ensure!(
+                Self::feeders().contains(&feeder),
+                Error::<T>::UnauthorizedFeeder
+            );
+
+            let now_millis: u64 = T::Time::now().unique_saturated_into();
+            let stale_period: u64 = T::GetRateStalePeriod::get().unique_saturated_into();
+            let timestamp_millis = timestamp.saturating_mul(MILLISECS_MULTIPLIER);
+            ensure!(
+                timestamp_millis <= now_millis.saturating_add(MAX_TIMESTAMP_DRIFT_MILLISECS)
+                    && timestamp_millis.saturating_add(stale_period) > now_millis,
+                Error::<T>::RateHasInvalidTimestamp
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let feeders = Self::feeders();
+            let mut new_symbols = BTreeSet::new();
+            let mut submitted_prices = Vec::new();
+            let mut relayed_rates = Vec::new();
+            for (symbol, value) in prices {
+                if matches!(
+                    Self::feeder_prices(&symbol, &feeder),
+                    Some(rate) if rate.last_updated > timestamp
+                ) {
+                    continue;
+                }
+                FeederPrices::<T>::insert(
+                    &symbol,
+                    &feeder,
+                    FeedRate {
+                        value,
+                        last_updated: timestamp,
+                        last_updated_block: now,
+                    },
+                );
+                submitted_prices.push((symbol.clone(), value));
+
+                let rate = match Self::aggregate_prices(&symbol, &feeders, now_millis, stale_period)
+                {
+                    Some(rate) => rate,
+                    None => continue,
+                };
+                if !SymbolRates::<T>::contains_key(&symbol) {
+                    new_symbols.insert(symbol.clone());
+                }
+                SymbolRates::<T>::insert(&symbol, rate);
+                relayed_rates.push((symbol, rate.into()));
+            }
+
+            T::OnNewSymbolsRelayedHook::on_new_symbols_relayed(Oracle::PriceFeed, new_symbols)?;
+            T::OnNewSymbolsRelayedHook::on_rates_relayed(Oracle::PriceFeed, relayed_rates);
+
+            Self::deposit_event(Event::PricesSubmitted(feeder, submitted_prices));
+            Ok(().into())
+        }
+
+        /// Add `account_ids` to the list of feeders.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `account_ids`: list of new feeders to add.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_feeders())]
+        pub fn add_feeders(
+            origin: OriginFor<T>,
+            account_ids: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let to_add = BTreeSet::from_iter(account_ids);
+            Feeders::<T>::try_mutate(|feeders| {
+                ensure!(feeders.is_disjoint(&to_add), Error::<T>::AlreadyAFeeder);
+                feeders.extend(to_add.iter().cloned());
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::FeedersAdded(to_add.into_iter().collect()));
+            Ok(().into())
+        }
+
+        /// Remove `account_ids` from the list of feeders.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `account_ids`: list of feeders to remove.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_feeders())]
+        pub fn remove_feeders(
+            origin: OriginFor<T>,
+            account_ids: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let to_remove = BTreeSet::from_iter(account_ids);
+            Feeders::<T>::try_mutate(|feeders| {
+                ensure!(to_remove.is_subset(feeders), Error::<T>::NoSuchFeeder);
+                feeders.retain(|feeder| !to_remove.contains(feeder));
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::FeedersRemoved(to_remove.into_iter().collect()));
+            Ok(().into())
+        }
+
+        /// Set HTTP source of the prices fetched by the off-chain workers.
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `url`: URL which responds with JSON object containing prices in USD,
+        /// - `symbols`: symbols and their keys in the JSON object.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_feed_source())]
+        pub fn set_feed_source(
+            origin: OriginFor<T>,
+            url: Vec<u8>,
+            symbols: Vec<(T::Symbol, Vec<u8>)>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                !url.is_empty()
+                    && url.len() as u32 <= MAX_URL_LENGTH
+                    && sp_std::str::from_utf8(&url).is_ok()
+                    && symbols.len() as u32 <= T::MaxSubmitSymbols::get(),
+                Error::<T>::InvalidFeedSource
+            );
+
+            PriceFeedSource::<T>::put(FeedSource {
+                url: url.clone(),
+                symbols,
+            });
+
+            Self::deposit_event(Event::FeedSourceSet(url));
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Aggregate fresh prices of the `symbol` submitted by the `feeders` into the median rate.
+    ///
+    /// Returns `None` if no more than a half of the feeders have submitted fresh prices.
+    fn aggregate_prices(
+        symbol: &T::Symbol,
+        feeders: &BTreeSet<T::AccountId>,
+        now_millis: u64,
+        stale_period: u64,
+    ) -> Option<FeedRate<T::BlockNumber>> {
+        let fresh_rates: Vec<_> = FeederPrices::<T>::iter_prefix(symbol)
+            .filter(|(feeder, rate)| {
+                feeders.contains(feeder)
+                    && rate
+                        .last_updated
+                        .saturating_mul(MILLISECS_MULTIPLIER)
+                        .saturating_add(stale_period)
+                        > now_millis
+            })
+            .map(|(_, rate)| rate)
+            .collect();
+        if fresh_rates.len() * 2 <= feeders.len() {
+            return None;
+        }
+
+        let mut values: Vec<_> = fresh_rates.iter().map(|rate| rate.value).collect();
+        values.sort_unstable();
+        let middle = values.len() / 2;
+        let value = if values.len() % 2 == 0 {
+            let (lower, upper) = (values[middle - 1], values[middle]);
+            lower + (upper - lower) / 2
+        } else {
+            values[middle]
+        };
+        // Rate can't be newer than the chain time, otherwise it can't be quoted
+        let last_updated = fresh_rates
+            .iter()
+            .map(|rate| rate.last_updated)
+            .min()?
+            .min(now_millis / MILLISECS_MULTIPLIER);
+        let last_updated_block = fresh_rates
+            .iter()
+            .map(|rate| rate.last_updated_block)
+            .max()?;
+
+        Some(FeedRate {
+            value,
+            last_updated,
+            last_updated_block,
+        })
+    }
+
+    /// Fetch prices from the feed source and submit them on behalf of the local feeder account.
+    fn fetch_and_submit_prices() -> Result<(), Error<T>> {
+        let source = Self::feed_source().ok_or(Error::<T>::FeedSourceNotSet)?;
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        if !signer.can_sign() {
+            return Err(Error::<T>::NoLocalFeeder);
+        }
+
+        let url = sp_std::str::from_utf8(&source.url).map_err(|_| Error::<T>::InvalidFeedSource)?;
+        let response = Self::http_get(url)?;
+        let prices = Self::parse_prices(&response, source.symbols);
+        if prices.is_empty() {
+            debug!("No prices were fetched from the feed source");
+            return Ok(());
+        }
+
+        let prices: BoundedVec<_, T::MaxSubmitSymbols> = BoundedVec::truncate_from(prices);
+        let timestamp = sp_io::offchain::timestamp().unix_millis() / MILLISECS_MULTIPLIER;
+        match signer.send_signed_transaction(|_| Call::submit_prices {
+            prices: prices.clone(),
+            timestamp,
+        }) {
+            Some((_, Ok(()))) => Ok(()),
+            _ => Err(Error::<T>::FailedToSubmitPrices),
+        }
+    }
+
+    /// Make off-chain HTTP GET request.
+    fn http_get(url: &str) -> Result<Vec<u8>, Error<T>> {
+        let deadline = sp_io::offchain::timestamp().add(rt_offchain::Duration::from_millis(
+            HTTP_REQUEST_TIMEOUT_MILLISECS,
+        ));
+        let pending = rt_offchain::http::Request::get(url)
+            .deadline(deadline)
+            .send()
+            .map_err(|e| {
+                warn!("Failed to send a request {:?}", e);
+                Error::<T>::HttpFetchingError
+            })?;
+        let response = pending
+            .try_wait(deadline)
+            .map_err(|e| {
+                warn!("Failed to get a response: {:?}", e);
+                Error::<T>::HttpFetchingError
+            })?
+            .map_err(|e| {
+                warn!("Failed to get a response: {:?}", e);
+                Error::<T>::HttpFetchingError
+            })?;
+        if response.code != 200 {
+            warn!("Unexpected http request status code: {}", response.code);
+            return Err(Error::<T>::HttpFetchingError);
+        }
+        Ok(response.body().collect())
+    }
+
+    /// Parse JSON object with prices of the `symbols`.
+    ///
+    /// Symbols with missing, malformed or zero prices are skipped.
+    pub fn parse_prices(
+        response: &[u8],
+        symbols: Vec<(T::Symbol, Vec<u8>)>,
+    ) -> Vec<(T::Symbol, Balance)> {
+        let object = match serde_json::from_slice::<Value>(response) {
+            Ok(Value::Object(object)) => object,
+            _ => {
+                warn!("Feed source response is not a JSON object");
+                return Vec::new();
+            }
+        };
+
+        symbols
+            .into_iter()
+            .filter_map(|(symbol, key)| {
+                let key = sp_std::str::from_utf8(&key).ok()?;
+                let price = match object.get(key) {
+                    Some(Value::String(price)) => Self::parse_price(price),
+                    Some(Value::Number(price)) => Self::parse_price(&price.to_string()),
+                    _ => None,
+                }
+                .filter(|price| !price.is_zero());
+                if price.is_none() {
+                    warn!("Failed to parse price of {}", key);
+                }
+                price.map(|price| (symbol, price))
+            })
+            .collect()
+    }
+
+    /// Parse decimal number into balance with precision = 18, extra decimals are truncated.
+    pub fn parse_price(price: &str) -> Option<Balance> {
+        let (integer, fraction) = price.split_once('.').unwrap_or((price, ""));
+        if integer.is_empty()
+            || !integer.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let fraction = &fraction[..fraction.len().min(PRICE_DECIMALS as usize)];
+
+        let integer: Balance = integer.parse().ok()?;
+        let fraction_value: Balance = if fraction.is_empty() {
+            0
+        } else {
+            fraction.parse().ok()?
+        };
+        integer
+            .checked_mul(10u128.pow(PRICE_DECIMALS))?
+            .checked_add(fraction_value * 10u128.pow(PRICE_DECIMALS - fraction.len() as u32))
+    }
+}
@@ -0,0 +1,365 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Decode;
+use common::{balance, DataFeed, Rate};
+use frame_support::traits::Hooks;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use sp_core::offchain::testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt};
+use sp_core::offchain::{OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+use sp_runtime::testing::UintAuthorityId;
+use std::collections::BTreeSet;
+
+use crate::{mock::*, Error, FeedRate, FeedSource};
+
+const FEEDER: u64 = 1;
+const URL: &str = "https://prices.example.com/usd";
+
+fn feed_source() -> Vec<(String, Vec<u8>)> {
+    vec![
+        ("USD".to_owned(), b"usd".to_vec()),
+        ("EUR".to_owned(), b"eur".to_vec()),
+        ("RUB".to_owned(), b"rub".to_vec()),
+    ]
+}
+
+#[test]
+fn add_and_remove_feeders_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PriceFeed::add_feeders(RuntimeOrigin::root(), vec![1, 2, 3]));
+        assert_eq!(PriceFeed::feeders(), BTreeSet::from([1, 2, 3]));
+
+        assert_noop!(
+            PriceFeed::add_feeders(RuntimeOrigin::root(), vec![3, 4]),
+            Error::<Runtime>::AlreadyAFeeder
+        );
+        assert_noop!(
+            PriceFeed::remove_feeders(RuntimeOrigin::root(), vec![1, 4]),
+            Error::<Runtime>::NoSuchFeeder
+        );
+        assert_noop!(
+            PriceFeed::add_feeders(RuntimeOrigin::signed(1), vec![4]),
+            BadOrigin
+        );
+
+        assert_ok!(PriceFeed::remove_feeders(RuntimeOrigin::root(), vec![1, 3]));
+        assert_eq!(PriceFeed::feeders(), BTreeSet::from([2]));
+    });
+}
+
+#[test]
+fn submit_prices_should_work() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(100_000);
+        assert_noop!(
+            PriceFeed::submit_prices(
+                RuntimeOrigin::signed(FEEDER),
+                vec![("USD".to_owned(), balance!(1))].try_into().unwrap(),
+                100
+            ),
+            Error::<Runtime>::UnauthorizedFeeder
+        );
+
+        assert_ok!(PriceFeed::add_feeders(RuntimeOrigin::root(), vec![FEEDER]));
+        assert_ok!(PriceFeed::submit_prices(
+            RuntimeOrigin::signed(FEEDER),
+            vec![
+                ("USD".to_owned(), balance!(1)),
+                ("EUR".to_owned(), balance!(1.1))
+            ]
+            .try_into()
+            .unwrap(),
+            100
+        ));
+        assert_eq!(
+            PriceFeed::rates("USD"),
+            Some(FeedRate {
+                value: balance!(1),
+                last_updated: 100,
+                last_updated_block: 0,
+            })
+        );
+
+        // Older prices are ignored
+        assert_ok!(PriceFeed::submit_prices(
+            RuntimeOrigin::signed(FEEDER),
+            vec![
+                ("USD".to_owned(), balance!(2)),
+                ("EUR".to_owned(), balance!(1.2))
+            ]
+            .try_into()
+            .unwrap(),
+            99
+        ));
+        assert_eq!(PriceFeed::rates("EUR").unwrap().value, balance!(1.1));
+
+        assert_ok!(PriceFeed::submit_prices(
+            RuntimeOrigin::signed(FEEDER),
+            vec![("EUR".to_owned(), balance!(1.2))].try_into().unwrap(),
+            101
+        ));
+        assert_eq!(PriceFeed::rates("EUR").unwrap().value, balance!(1.2));
+        assert_eq!(PriceFeed::rates("USD").unwrap().value, balance!(1));
+    });
+}
+
+#[test]
+fn submit_prices_should_check_timestamp() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(400_000);
+        assert_ok!(PriceFeed::add_feeders(RuntimeOrigin::root(), vec![FEEDER]));
+
+        // Prices from the future
+        assert_noop!(
+            PriceFeed::submit_prices(
+                RuntimeOrigin::signed(FEEDER),
+                vec![("USD".to_owned(), balance!(1))].try_into().unwrap(),
+                461
+            ),
+            Error::<Runtime>::RateHasInvalidTimestamp
+        );
+        // Prices which are already expired
+        assert_noop!(
+            PriceFeed::submit_prices(
+                RuntimeOrigin::signed(FEEDER),
+                vec![("USD".to_owned(), balance!(1))].try_into().unwrap(),
+                100
+            ),
+            Error::<Runtime>::RateHasInvalidTimestamp
+        );
+
+        // Timestamp within the allowed drift is limited by the chain time
+        assert_ok!(PriceFeed::submit_prices(
+            RuntimeOrigin::signed(FEEDER),
+            vec![("USD".to_owned(), balance!(1))].try_into().unwrap(),
+            460
+        ));
+        assert_eq!(
+            PriceFeed::feeder_prices("USD", FEEDER)
+                .unwrap()
+                .last_updated,
+            460
+        );
+        assert_eq!(PriceFeed::rates("USD").unwrap().last_updated, 400);
+    });
+}
+
+#[test]
+fn submit_prices_should_aggregate_feeders_prices() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(100_000);
+        assert_ok!(PriceFeed::add_feeders(RuntimeOrigin::root(), vec![1, 2, 3]));
+        let submit = |feeder: u64, value, timestamp| {
+            PriceFeed::submit_prices(
+                RuntimeOrigin::signed(feeder),
+                vec![("USD".to_owned(), value)].try_into().unwrap(),
+                timestamp,
+            )
+        };
+
+        // Rate is not set until more than a half of the feeders submit prices
+        assert_ok!(submit(1, balance!(1), 100));
+        assert_eq!(PriceFeed::rates("USD"), None);
+
+        assert_ok!(submit(2, balance!(3), 99));
+        assert_eq!(
+            PriceFeed::rates("USD"),
+            Some(FeedRate {
+                value: balance!(2),
+                last_updated: 99,
+                last_updated_block: 0,
+            })
+        );
+
+        // A single feeder can't move the rate far from the other ones
+        assert_ok!(submit(3, balance!(100), 100));
+        assert_eq!(PriceFeed::rates("USD").unwrap().value, balance!(3));
+
+        // Prices of the removed feeders are not taken into account
+        assert_ok!(PriceFeed::remove_feeders(RuntimeOrigin::root(), vec![3]));
+        assert_ok!(submit(1, balance!(4), 101));
+        assert_eq!(PriceFeed::rates("USD").unwrap().value, balance!(3.5));
+
+        // Expired prices are not taken into account
+        Timestamp::set_timestamp(98_000 + GetRateStalePeriod::get());
+        assert_ok!(submit(1, balance!(5), 390));
+        assert_eq!(PriceFeed::rates("USD").unwrap().value, balance!(4));
+        Timestamp::set_timestamp(101_000 + GetRateStalePeriod::get());
+        assert_ok!(submit(1, balance!(6), 400));
+        assert_eq!(PriceFeed::rates("USD").unwrap().value, balance!(4));
+    });
+}
+
+#[test]
+fn quote_should_check_rate_timestamp() {
+    new_test_ext().execute_with(|| {
+        Timestamp::set_timestamp(100_000);
+        assert_ok!(PriceFeed::add_feeders(RuntimeOrigin::root(), vec![FEEDER]));
+        assert_ok!(PriceFeed::submit_prices(
+            RuntimeOrigin::signed(FEEDER),
+            vec![("USD".to_owned(), balance!(1))].try_into().unwrap(),
+            100
+        ));
+
+        let rate = Rate {
+            value: balance!(1),
+            last_updated: 100,
+            dynamic_fee: common::fixed!(0),
+        };
+        Timestamp::set_timestamp(200_000);
+        assert_eq!(
+            <PriceFeed as DataFeed<String, Rate, u64>>::quote(&"USD".to_owned()),
+            Ok(Some(rate))
+        );
+        assert_eq!(
+            <PriceFeed as DataFeed<String, Rate, u64>>::quote(&"EUR".to_owned()),
+            Ok(None)
+        );
+
+        Timestamp::set_timestamp(100_000 + GetRateStalePeriod::get());
+        assert_eq!(
+            <PriceFeed as DataFeed<String, Rate, u64>>::quote(&"USD".to_owned()),
+            Err(Error::<Runtime>::RateExpired.into())
+        );
+        assert_eq!(
+            <PriceFeed as DataFeed<String, Rate, u64>>::quote_unchecked(&"USD".to_owned()),
+            Some(rate)
+        );
+        assert_eq!(
+            <PriceFeed as DataFeed<String, Rate, u64>>::list_enabled_symbols(),
+            Ok(vec![("USD".to_owned(), 100)])
+        );
+    });
+}
+
+#[test]
+fn set_feed_source_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PriceFeed::set_feed_source(RuntimeOrigin::signed(1), URL.into(), feed_source()),
+            BadOrigin
+        );
+        assert_noop!(
+            PriceFeed::set_feed_source(RuntimeOrigin::root(), vec![], feed_source()),
+            Error::<Runtime>::InvalidFeedSource
+        );
+        assert_noop!(
+            PriceFeed::set_feed_source(
+                RuntimeOrigin::root(),
+                URL.into(),
+                vec![("USD".to_owned(), b"usd".to_vec()); 11]
+            ),
+            Error::<Runtime>::InvalidFeedSource
+        );
+
+        assert_ok!(PriceFeed::set_feed_source(
+            RuntimeOrigin::root(),
+            URL.into(),
+            feed_source()
+        ));
+        assert_eq!(
+            PriceFeed::feed_source(),
+            Some(FeedSource {
+                url: URL.into(),
+                symbols: feed_source(),
+            })
+        );
+    });
+}
+
+#[test]
+fn parse_price_should_work() {
+    assert_eq!(PriceFeed::parse_price("12"), Some(balance!(12)));
+    assert_eq!(PriceFeed::parse_price("1.5"), Some(balance!(1.5)));
+    assert_eq!(
+        PriceFeed::parse_price("0.1234567890123456789"),
+        Some(balance!(0.123456789012345678))
+    );
+    assert_eq!(PriceFeed::parse_price(".5"), None);
+    assert_eq!(PriceFeed::parse_price("-1"), None);
+    assert_eq!(PriceFeed::parse_price("1.2.3"), None);
+    assert_eq!(PriceFeed::parse_price("1e3"), None);
+}
+
+#[test]
+fn offchain_worker_should_submit_prices() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    UintAuthorityId::set_all_keys(vec![FEEDER]);
+
+    {
+        let mut state = offchain_state.write();
+        state.timestamp = sp_core::offchain::Timestamp::from_unix_millis(100_000);
+        state.expect_request(PendingRequest {
+            method: "GET".into(),
+            uri: URL.into(),
+            response: Some(br#"{"usd": "1.5", "eur": 2, "rub": "n/a"}"#.to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+    }
+
+    ext.execute_with(|| {
+        assert_ok!(PriceFeed::set_feed_source(
+            RuntimeOrigin::root(),
+            URL.into(),
+            feed_source()
+        ));
+
+        // Prices are fetched only once per update period
+        PriceFeed::offchain_worker(UpdatePeriod::get() + 1);
+        assert!(pool_state.read().transactions.is_empty());
+
+        PriceFeed::offchain_worker(UpdatePeriod::get());
+        let tx = pool_state
+            .write()
+            .transactions
+            .pop()
+            .expect("Expected submitted transaction");
+        let tx = Extrinsic::decode(&mut &*tx).expect("Failed to decode transaction");
+        assert_eq!(tx.signature, Some((FEEDER, ())));
+        assert_eq!(
+            tx.call,
+            RuntimeCall::PriceFeed(crate::Call::submit_prices {
+                prices: vec![
+                    ("USD".to_owned(), balance!(1.5)),
+                    ("EUR".to_owned(), balance!(2))
+                ]
+                .try_into()
+                .unwrap(),
+                timestamp: 100,
+            })
+        );
+    });
+}
@@ -0,0 +1,149 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Weights for price_feed
+//!
+//! These weights are hand estimates based on the storage accesses of the extrinsics and the
+//! benchmarked extrinsics of band pallet, they were not produced by a benchmark run. They have
+//! to be replaced with the benchmark output before the pallet is used on a live network.
+
+// Command to replace the estimates with benchmarked weights:
+// target/release/framenode
+// benchmark
+// pallet
+// --chain=local
+// --steps=50
+// --repeat=20
+// --pallet=price_feed
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=misc/file_header.txt
+// --template=misc/pallet-weight-template.hbs
+// --output=pallets/price-feed/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for price_feed.
+pub trait WeightInfo {
+	fn submit_prices(n: u32, ) -> Weight;
+	fn add_feeders() -> Weight;
+	fn remove_feeders() -> Weight;
+	fn set_feed_source() -> Weight;
+}
+
+/// Weights for price_feed using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: PriceFeed Feeders (r:1 w:0)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PriceFeed FeederPrices (r:11 w:1)
+	/// Proof Skipped: PriceFeed FeederPrices (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceFeed SymbolRates (r:1 w:1)
+	/// Proof Skipped: PriceFeed SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:1)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn submit_prices(n: u32, ) -> Weight {
+		Weight::from_parts(160_000_000, 998)
+			.saturating_add(Weight::from_parts(250_000_000, 29040).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: PriceFeed Feeders (r:1 w:1)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	fn add_feeders() -> Weight {
+		Weight::from_parts(119_000_000, 499)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceFeed Feeders (r:1 w:1)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	fn remove_feeders() -> Weight {
+		Weight::from_parts(135_000_000, 590)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceFeed PriceFeedSource (r:0 w:1)
+	/// Proof Skipped: PriceFeed PriceFeedSource (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_feed_source() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: PriceFeed Feeders (r:1 w:0)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: PriceFeed FeederPrices (r:11 w:1)
+	/// Proof Skipped: PriceFeed FeederPrices (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceFeed SymbolRates (r:1 w:1)
+	/// Proof Skipped: PriceFeed SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:1)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn submit_prices(n: u32, ) -> Weight {
+		Weight::from_parts(160_000_000, 998)
+			.saturating_add(Weight::from_parts(250_000_000, 29040).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: PriceFeed Feeders (r:1 w:1)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	fn add_feeders() -> Weight {
+		Weight::from_parts(119_000_000, 499)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceFeed Feeders (r:1 w:1)
+	/// Proof Skipped: PriceFeed Feeders (max_values: Some(1), max_size: None, mode: Measured)
+	fn remove_feeders() -> Weight {
+		Weight::from_parts(135_000_000, 590)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: PriceFeed PriceFeedSource (r:0 w:1)
+	/// Proof Skipped: PriceFeed PriceFeedSource (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_feed_source() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
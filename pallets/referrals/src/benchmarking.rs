@@ -28,11 +28,15 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{Config, Pallet, ReferrerBalances, Referrers};
+use crate::{
+    Config, Pallet, ReferralTier, ReferralTiers, ReferrerBalances, ReferrerRewards, Referrers,
+};
 use codec::Decode;
 use common::weights::constants::SMALL_FEE;
-use common::{balance, AssetInfoProvider, XOR};
+use common::{balance, AssetInfoProvider, OnSwapFeeCharged, XOR};
 use frame_benchmarking::benchmarks;
+use frame_support::sp_runtime::Permill;
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_std::prelude::*;
@@ -83,6 +87,54 @@ benchmarks! {
         assert_eq!(Referrers::<T>::get(&alice), Some(bob));
     }
 
+    claim_referral_rewards {
+        let alice = alice::<T>();
+        T::Currency::deposit(XOR.into(), &T::RewardsAcc::get(), SMALL_FEE).unwrap();
+        ReferrerRewards::<T>::insert(&alice, SMALL_FEE);
+    }: {
+        Pallet::<T>::claim_referral_rewards(RawOrigin::Signed(alice.clone()).into()).unwrap();
+    }
+    verify {
+        assert_eq!(ReferrerRewards::<T>::get(&alice), 0);
+    }
+
+    set_referral_tiers {
+        let tiers: Vec<_> = (0..T::MaxReferralTiers::get())
+            .map(|i| ReferralTier {
+                min_volume: balance!(1000) * i as u128,
+                reward_share: Permill::from_percent(i),
+            })
+            .collect();
+    }: {
+        Pallet::<T>::set_referral_tiers(RawOrigin::Root.into(), tiers.clone()).unwrap();
+    }
+    verify {
+        assert_eq!(ReferralTiers::<T>::get(), tiers);
+    }
+
+    on_swap_fee_charged {
+        let alice = alice::<T>();
+        let bob = bob::<T>();
+        let fee_account = T::RewardsAcc::get();
+        Referrers::<T>::insert(&alice, bob.clone());
+        ReferralTiers::<T>::put(vec![ReferralTier {
+            min_volume: 0,
+            reward_share: Permill::from_percent(10),
+        }]);
+        T::Currency::deposit(XOR.into(), &fee_account, balance!(10)).unwrap();
+    }: {
+        <Pallet<T> as OnSwapFeeCharged<T::AccountId, T::AssetId>>::on_swap_fee_charged(
+            &alice,
+            &fee_account,
+            &XOR.into(),
+            balance!(10),
+            balance!(1000),
+        ).unwrap();
+    }
+    verify {
+        assert_eq!(ReferrerRewards::<T>::get(&bob), balance!(1));
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::test_ext(),
@@ -40,12 +40,27 @@ mod mock;
 
 pub mod weights;
 
-use common::Balance;
-use frame_support::ensure;
-use frame_support::sp_runtime::DispatchError;
+use codec::{Decode, Encode};
+use common::{Balance, OnSwapFeeCharged, XOR};
+use frame_support::dispatch::DispatchResult;
+use frame_support::sp_runtime::{DispatchError, Permill};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{ensure, RuntimeDebug};
+use sp_std::vec::Vec;
 
 pub use weights::WeightInfo;
 
+/// Referral program tier. The tier of the referrer is determined by the total swap volume
+/// of all its referrals.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, scale_info::TypeInfo)]
+pub struct ReferralTier {
+    /// Minimal referred volume (in XOR) required to reach the tier
+    pub min_volume: Balance,
+    /// Share of the swap fees paid by the referrals which goes to the referrer
+    pub reward_share: Permill,
+}
+
 impl<T: Config> Pallet<T> {
     pub fn set_referrer_to(
         referral: &T::AccountId,
@@ -69,6 +84,28 @@ impl<T: Config> Pallet<T> {
         !Referrers::<T>::contains_key(referral)
     }
 
+    /// Returns the share of the swap fees the referrer with the given referred volume gets.
+    pub fn reward_share(referred_volume: Balance) -> Permill {
+        ReferralTiers::<T>::get()
+            .iter()
+            .rev()
+            .find(|tier| tier.min_volume <= referred_volume)
+            .map(|tier| tier.reward_share)
+            .unwrap_or_default()
+    }
+
+    /// Returns the swap volume and earnings for each referral of the referrer.
+    pub fn referral_stats(referrer: &T::AccountId) -> Vec<(T::AccountId, Balance, Balance)> {
+        Referrals::<T>::get(referrer)
+            .into_iter()
+            .map(|referral| {
+                let volume = ReferralVolumes::<T>::get(&referral);
+                let earned = ReferralEarnings::<T>::get(referrer, &referral);
+                (referral, volume, earned)
+            })
+            .collect()
+    }
+
     pub fn withdraw_fee(referrer: &T::AccountId, fee: Balance) -> Result<(), DispatchError> {
         ReferrerBalances::<T>::mutate(referrer, |b| {
             let balance = b
@@ -81,6 +118,52 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> OnSwapFeeCharged<T::AccountId, T::AssetId> for Pallet<T> {
+    fn on_swap_fee_charged(
+        who: &T::AccountId,
+        fee_account: &T::AccountId,
+        fee_asset: &T::AssetId,
+        fee: Balance,
+        volume: Balance,
+    ) -> DispatchResult {
+        // Referral program operates only with XOR fees
+        if *fee_asset != XOR.into() {
+            return Ok(());
+        }
+        let referrer = match Referrers::<T>::get(who) {
+            Some(referrer) => referrer,
+            None => return Ok(()),
+        };
+
+        ReferralVolumes::<T>::mutate(who, |v| *v = v.saturating_add(volume));
+        let referred_volume = ReferrerVolumes::<T>::mutate(&referrer, |v| {
+            *v = v.saturating_add(volume);
+            *v
+        });
+
+        let reward = Self::reward_share(referred_volume).mul_floor(fee);
+        if reward == 0 {
+            return Ok(());
+        }
+
+        assets::Pallet::<T>::transfer_from(
+            &XOR.into(),
+            fee_account,
+            &T::RewardsAcc::get(),
+            reward,
+        )?;
+        ReferrerRewards::<T>::mutate(&referrer, |r| *r = r.saturating_add(reward));
+        ReferralEarnings::<T>::mutate(&referrer, who, |e| *e = e.saturating_add(reward));
+        Self::deposit_event(Event::<T>::ReferrerRewarded(referrer, who.clone(), reward));
+
+        Ok(())
+    }
+
+    fn on_swap_fee_charged_weight() -> Weight {
+        <T as Config>::WeightInfo::on_swap_fee_charged()
+    }
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -91,11 +174,17 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_std::prelude::*;
 
-    use crate::WeightInfo;
+    use crate::{ReferralTier, WeightInfo};
 
     #[pallet::config]
     pub trait Config: frame_system::Config + assets::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type ReservesAcc: Get<Self::AccountId>;
+        /// Account which holds referral rewards until they are claimed
+        type RewardsAcc: Get<Self::AccountId>;
+        /// Maximum number of referral program tiers
+        #[pallet::constant]
+        type MaxReferralTiers: Get<u32>;
         type WeightInfo: WeightInfo;
     }
 
@@ -181,6 +270,65 @@ pub mod pallet {
             Self::set_referrer_to(&referree, referrer)?;
             Ok(().into())
         }
+
+        /// Transfers accumulated referral rewards to the referrer
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_referral_rewards())]
+        pub fn claim_referral_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let referrer = ensure_signed(origin)?;
+
+            let rewards = ReferrerRewards::<T>::get(&referrer);
+            ensure!(rewards > 0, Error::<T>::NoReferralRewards);
+
+            common::with_transaction(|| {
+                assets::Pallet::<T>::transfer_from(
+                    &XOR.into(),
+                    &T::RewardsAcc::get(),
+                    &referrer,
+                    rewards,
+                )?;
+                ReferrerRewards::<T>::remove(&referrer);
+
+                Self::deposit_event(Event::<T>::ReferralRewardsClaimed(referrer, rewards));
+                Ok(().into())
+            })
+        }
+
+        /// Sets referral program tiers. Tiers must be sorted by minimal referred volume
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_referral_tiers())]
+        pub fn set_referral_tiers(
+            origin: OriginFor<T>,
+            tiers: Vec<ReferralTier>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                tiers.len() <= T::MaxReferralTiers::get() as usize,
+                Error::<T>::TooManyReferralTiers
+            );
+            ensure!(
+                tiers
+                    .windows(2)
+                    .all(|pair| pair[0].min_volume < pair[1].min_volume),
+                Error::<T>::InvalidReferralTiers
+            );
+
+            ReferralTiers::<T>::put(tiers.clone());
+            Self::deposit_event(Event::<T>::ReferralTiersSet(tiers));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Referrer received a share of the swap fee [referrer, referral, amount]
+        ReferrerRewarded(T::AccountId, T::AccountId, Balance),
+        /// Referral rewards claimed [referrer, amount]
+        ReferralRewardsClaimed(T::AccountId, Balance),
+        /// Referral program tiers set [tiers]
+        ReferralTiersSet(Vec<ReferralTier>),
     }
 
     #[pallet::error]
@@ -191,6 +339,12 @@ pub mod pallet {
         IncRefError,
         /// Referrer doesn't have enough of reserved balance
         ReferrerInsufficientBalance,
+        /// Referrer doesn't have referral rewards to claim
+        NoReferralRewards,
+        /// Referral tiers must be sorted by minimal referred volume without duplicates
+        InvalidReferralTiers,
+        /// Number of referral tiers exceeds the limit
+        TooManyReferralTiers,
     }
 
     #[pallet::storage]
@@ -206,6 +360,42 @@ pub mod pallet {
     pub type Referrals<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
 
+    /// Referral program tiers sorted by minimal referred volume
+    #[pallet::storage]
+    #[pallet::getter(fn referral_tiers)]
+    pub type ReferralTiers<T: Config> = StorageValue<_, Vec<ReferralTier>, ValueQuery>;
+
+    /// Swap volume (in XOR) of the referral
+    #[pallet::storage]
+    #[pallet::getter(fn referral_volume)]
+    pub type ReferralVolumes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Total swap volume (in XOR) of all referrals of the referrer
+    #[pallet::storage]
+    #[pallet::getter(fn referrer_volume)]
+    pub type ReferrerVolumes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Rewards the referrer has earned from the referral [referrer, referral]
+    #[pallet::storage]
+    #[pallet::getter(fn referral_earnings)]
+    pub type ReferralEarnings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Unclaimed referral rewards of the referrer
+    #[pallet::storage]
+    #[pallet::getter(fn referrer_rewards)]
+    pub type ReferrerRewards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub referrers: Vec<(T::AccountId, T::AccountId)>,
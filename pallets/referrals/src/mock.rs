@@ -54,6 +54,7 @@ pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const MINTING_ACCOUNT: AccountId = 4;
 pub const REFERRALS_RESERVES_ACC: AccountId = 22;
+pub const REFERRALS_REWARDS_ACC: AccountId = 24;
 pub const BUY_BACK_ACCOUNT: AccountId = 23;
 
 parameter_types! {
@@ -67,6 +68,8 @@ parameter_types! {
     pub const DepositFactor: u64 = 1;
     pub const MaxSignatories: u16 = 4;
     pub const ReferralsReservesAcc: AccountId = REFERRALS_RESERVES_ACC;
+    pub const ReferralsRewardsAcc: AccountId = REFERRALS_REWARDS_ACC;
+    pub const MaxReferralTiers: u32 = 10;
 }
 
 construct_runtime!(
@@ -81,7 +84,7 @@ construct_runtime!(
         Currencies: currencies::{Pallet, Call, Storage},
         Assets: assets::{Pallet, Call, Storage, Config<T>, Event<T>},
         Permissions: permissions::{Pallet, Call, Storage, Config<T>, Event<T>},
-        Referrals: referrals::{Pallet, Call, Storage, Config<T>},
+        Referrals: referrals::{Pallet, Call, Storage, Config<T>, Event<T>},
     }
 );
 
@@ -183,7 +186,10 @@ impl tokens::Config for Runtime {
 }
 
 impl referrals::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservesAcc = ReferralsReservesAcc;
+    type RewardsAcc = ReferralsRewardsAcc;
+    type MaxReferralTiers = MaxReferralTiers;
     type WeightInfo = ();
 }
 
@@ -62,6 +62,9 @@ pub trait WeightInfo {
 	fn reserve() -> Weight;
 	fn unreserve() -> Weight;
 	fn set_referrer() -> Weight;
+	fn claim_referral_rewards() -> Weight;
+	fn set_referral_tiers() -> Weight;
+	fn on_swap_fee_charged() -> Weight;
 }
 
 /// Weights for referrals using the Substrate node and recommended hardware.
@@ -108,6 +111,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Referrals ReferrerRewards (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_referral_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `511`
+		//  Estimated: `8192`
+		// Minimum execution time: 63_511_000 picoseconds.
+		Weight::from_parts(64_302_000, 8192)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Referrals ReferralTiers (r:0 w:1)
+	/// Proof Skipped: Referrals ReferralTiers (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_referral_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_720_000 picoseconds.
+		Weight::from_parts(15_120_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Referrals Referrers (r:1 w:0)
+	/// Proof Skipped: Referrals Referrers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralVolumes (r:1 w:1)
+	/// Proof Skipped: Referrals ReferralVolumes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferrerVolumes (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerVolumes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralTiers (r:1 w:0)
+	/// Proof Skipped: Referrals ReferralTiers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Referrals ReferrerRewards (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralEarnings (r:1 w:1)
+	/// Proof Skipped: Referrals ReferralEarnings (max_values: None, max_size: None, mode: Measured)
+	fn on_swap_fee_charged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `602`
+		//  Estimated: `21318`
+		// Minimum execution time: 71_904_000 picoseconds.
+		Weight::from_parts(72_861_000, 21318)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -153,4 +202,50 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Referrals ReferrerRewards (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_referral_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `511`
+		//  Estimated: `8192`
+		// Minimum execution time: 63_511_000 picoseconds.
+		Weight::from_parts(64_302_000, 8192)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Referrals ReferralTiers (r:0 w:1)
+	/// Proof Skipped: Referrals ReferralTiers (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_referral_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_720_000 picoseconds.
+		Weight::from_parts(15_120_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Referrals Referrers (r:1 w:0)
+	/// Proof Skipped: Referrals Referrers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralVolumes (r:1 w:1)
+	/// Proof Skipped: Referrals ReferralVolumes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferrerVolumes (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerVolumes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralTiers (r:1 w:0)
+	/// Proof Skipped: Referrals ReferralTiers (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Referrals ReferrerRewards (r:1 w:1)
+	/// Proof Skipped: Referrals ReferrerRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Referrals ReferralEarnings (r:1 w:1)
+	/// Proof Skipped: Referrals ReferralEarnings (max_values: None, max_size: None, mode: Measured)
+	fn on_swap_fee_charged() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `602`
+		//  Estimated: `21318`
+		// Minimum execution time: 71_904_000 picoseconds.
+		Weight::from_parts(72_861_000, 21318)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
\ No newline at end of file
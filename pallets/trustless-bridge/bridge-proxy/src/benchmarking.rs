@@ -32,7 +32,7 @@ use super::*;
 
 use bridge_types::GenericAccount;
 use bridge_types::{types::MessageDirection, EVMChainId, GenericTimepoint};
-use common::{balance, AssetId32, PredefinedAssetId, XOR};
+use common::{balance, AssetId32, OutflowLimit, PredefinedAssetId, XOR};
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use traits::MultiCurrency;
@@ -44,6 +44,34 @@ use crate::Pallet as ETHApp;
 
 use assets::Pallet as Assets;
 
+fn queue_outflow<T: Config>(caller: T::AccountId) -> Result<(), &'static str>
+where
+    T::AssetId: From<AssetId32<PredefinedAssetId>>,
+{
+    let asset_id: T::AssetId = XOR.into();
+    <T as assets::Config>::Currency::deposit(asset_id.clone(), &caller, balance!(20))?;
+    Pallet::<T>::set_outflow_limit(
+        RawOrigin::Root.into(),
+        BASE_NETWORK_ID,
+        asset_id,
+        Some(OutflowLimit {
+            max_amount: 1,
+            window: 10u32.into(),
+            release_delay: 0u32.into(),
+        }),
+    )
+    .map_err(|e| e.error)?;
+    Pallet::<T>::burn(
+        RawOrigin::Signed(caller).into(),
+        BASE_NETWORK_ID,
+        asset_id,
+        GenericAccount::EVM(H160::default()),
+        1000,
+    )
+    .map_err(|e| e.error)?;
+    Ok(())
+}
+
 benchmarks! {
     where_clause {where T::AssetId: From<AssetId32<PredefinedAssetId>> }
     // Benchmark `burn` extrinsic under worst case conditions:
@@ -74,5 +102,39 @@ benchmarks! {
         );
     }
 
+    set_outflow_limit {
+        let limit = OutflowLimit {
+            max_amount: 1000,
+            window: 10u32.into(),
+            release_delay: 10u32.into(),
+        };
+    }: _(RawOrigin::Root, BASE_NETWORK_ID, XOR.into(), Some(limit))
+    verify {
+        assert_eq!(OutflowLimits::<T>::get(BASE_NETWORK_ID, T::AssetId::from(XOR)), Some(limit));
+    }
+
+    set_network_paused {
+    }: _(RawOrigin::Root, BASE_NETWORK_ID, true)
+    verify {
+        assert!(PausedNetworks::<T>::get(BASE_NETWORK_ID));
+    }
+
+    release_queued_outflow {
+        let caller: T::AccountId = whitelisted_caller();
+        queue_outflow::<T>(caller.clone())?;
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+    verify {
+        assert!(QueuedOutflows::<T>::get(0).is_none());
+        assert!(Senders::<T>::iter_prefix(BASE_NETWORK_ID).next().is_some());
+    }
+
+    cancel_queued_outflow {
+        let caller: T::AccountId = whitelisted_caller();
+        queue_outflow::<T>(caller.clone())?;
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+    verify {
+        assert!(QueuedOutflows::<T>::get(0).is_none());
+    }
+
     impl_benchmark_test_suite!(ETHApp, crate::mock::new_tester(), crate::mock::Test,);
 }
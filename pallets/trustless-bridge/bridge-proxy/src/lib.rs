@@ -18,8 +18,9 @@ use bridge_types::{
     Address, GenericAccount, GenericNetworkId, GenericTimepoint, H160, H256,
 };
 use codec::{Decode, Encode};
-use common::Balance;
+use common::{Balance, OutflowLimit, OutflowLimiter, OutflowWindow};
 use frame_support::dispatch::{DispatchResult, RuntimeDebug};
+use frame_support::ensure;
 use frame_support::log;
 use scale_info::TypeInfo;
 use sp_core::U256;
@@ -43,6 +44,19 @@ pub struct BridgeRequest<AccountId, AssetId> {
     direction: MessageDirection,
 }
 
+/// Outgoing transfer exceeding the outflow limit. The transferred amount is reserved on the
+/// sender's account until the transfer is released or cancelled.
+#[derive(Clone, RuntimeDebug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct QueuedOutflow<AccountId, AssetId, BlockNumber> {
+    pub sender: AccountId,
+    pub network_id: GenericNetworkId,
+    pub asset_id: AssetId,
+    pub recipient: GenericAccount<AccountId>,
+    pub amount: Balance,
+    /// Block number after which anyone can release the transfer.
+    pub release_at: BlockNumber,
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -59,12 +73,17 @@ pub mod pallet {
 
     type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     type BalanceOf<T> = <<T as assets::Config>::Currency as MultiCurrency<AccountIdOf<T>>>::Balance;
+    type QueuedOutflowOf<T> = QueuedOutflow<
+        <T as frame_system::Config>::AccountId,
+        <T as assets::Config>::AssetId,
+        <T as frame_system::Config>::BlockNumber,
+    >;
 
     #[pallet::config]
     pub trait Config:
         frame_system::Config + assets::Config + pallet_timestamp::Config + technical::Config
     {
-        type RuntimeEvent: From<Event> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type EthApp: BridgeApp<Self::AccountId, H160, Self::AssetId, Balance>;
 
@@ -122,6 +141,46 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Outflow limits of assets per network. Map ((Network ID, Asset ID) => Limit).
+    #[pallet::storage]
+    #[pallet::getter(fn outflow_limit)]
+    pub(super) type OutflowLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GenericNetworkId,
+        Blake2_128Concat,
+        T::AssetId,
+        OutflowLimit<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Outflow accounting of limited assets. Map ((Network ID, Asset ID) => Window).
+    #[pallet::storage]
+    pub(super) type OutflowWindows<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GenericNetworkId,
+        Blake2_128Concat,
+        T::AssetId,
+        OutflowWindow<T::BlockNumber>,
+        ValueQuery,
+    >;
+
+    /// Networks with paused outgoing transfers.
+    #[pallet::storage]
+    #[pallet::getter(fn is_network_paused)]
+    pub(super) type PausedNetworks<T: Config> =
+        StorageMap<_, Blake2_128Concat, GenericNetworkId, bool, ValueQuery>;
+
+    /// Outgoing transfers exceeding the outflow limits.
+    #[pallet::storage]
+    #[pallet::getter(fn queued_outflow)]
+    pub(super) type QueuedOutflows<T: Config> =
+        StorageMap<_, Twox64Concat, u64, QueuedOutflowOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub(super) type NextQueuedOutflowId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     /// The current storage version.
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -137,9 +196,24 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     /// Events for the ETH module.
-    pub enum Event {
+    pub enum Event<T: Config> {
         RequestStatusUpdate(H256, MessageStatus),
         RefundFailed(H256),
+        /// Outflow limit of the asset has been set or removed. [Network Id, Asset Id, Limit]
+        OutflowLimitSet(
+            GenericNetworkId,
+            T::AssetId,
+            Option<OutflowLimit<T::BlockNumber>>,
+        ),
+        /// Outgoing transfers to the network have been paused or resumed. [Network Id, Paused]
+        NetworkPauseChanged(GenericNetworkId, bool),
+        /// Outgoing transfer exceeding the outflow limit has been queued.
+        /// [Queued Outflow Id, Sender, Asset Id, Amount, Release Block]
+        OutflowQueued(u64, T::AccountId, T::AssetId, Balance, T::BlockNumber),
+        /// Queued outgoing transfer has been released. [Queued Outflow Id]
+        QueuedOutflowReleased(u64),
+        /// Queued outgoing transfer has been cancelled and refunded. [Queued Outflow Id]
+        QueuedOutflowCancelled(u64),
     }
 
     #[pallet::error]
@@ -148,6 +222,13 @@ pub mod pallet {
         WrongAccountKind,
         NotEnoughLockedLiquidity,
         Overflow,
+        NetworkPaused,
+        InvalidOutflowLimit,
+        QueuedOutflowNotFound,
+        QueuedOutflowIsLocked,
+        Forbidden,
+        FailedToUnreserve,
+        OutflowLimitExceeded,
     }
 
     #[pallet::call]
@@ -162,6 +243,148 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
+            ensure!(
+                !PausedNetworks::<T>::get(network_id),
+                Error::<T>::NetworkPaused
+            );
+            if let Some(limit) = OutflowLimits::<T>::get(network_id, asset_id) {
+                let now = frame_system::Pallet::<T>::block_number();
+                let fits = OutflowWindows::<T>::mutate(network_id, asset_id, |window| {
+                    window.try_consume(&limit, amount, now)
+                });
+                if !fits {
+                    Self::queue_outflow(QueuedOutflow {
+                        sender,
+                        network_id,
+                        asset_id,
+                        recipient,
+                        amount,
+                        release_at: now.saturating_add(limit.release_delay),
+                    })?;
+                    return Ok(().into());
+                }
+            }
+            Self::do_burn(network_id, asset_id, sender, recipient, amount)?;
+            Ok(().into())
+        }
+
+        /// Set or remove the outflow limit of the asset for the given network.
+        ///
+        /// Transfers exceeding the limit are queued and can be released after the release delay.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_outflow_limit())]
+        pub fn set_outflow_limit(
+            origin: OriginFor<T>,
+            network_id: GenericNetworkId,
+            asset_id: T::AssetId,
+            limit: Option<OutflowLimit<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            match limit {
+                Some(limit) => {
+                    ensure!(limit.is_valid(), Error::<T>::InvalidOutflowLimit);
+                    OutflowLimits::<T>::insert(network_id, asset_id, limit);
+                }
+                None => {
+                    OutflowLimits::<T>::remove(network_id, asset_id);
+                    OutflowWindows::<T>::remove(network_id, asset_id);
+                }
+            }
+            Self::deposit_event(Event::OutflowLimitSet(network_id, asset_id, limit));
+            Ok(().into())
+        }
+
+        /// Pause or resume outgoing transfers to the given network.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_network_paused())]
+        pub fn set_network_paused(
+            origin: OriginFor<T>,
+            network_id: GenericNetworkId,
+            paused: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            if paused {
+                PausedNetworks::<T>::insert(network_id, true);
+            } else {
+                PausedNetworks::<T>::remove(network_id);
+            }
+            Self::deposit_event(Event::NetworkPauseChanged(network_id, paused));
+            Ok(().into())
+        }
+
+        /// Release the queued outgoing transfer and send it to the bridge.
+        ///
+        /// Can be called by anyone after the release delay has passed, or by root at any time.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::release_queued_outflow())]
+        pub fn release_queued_outflow(
+            origin: OriginFor<T>,
+            outflow_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = frame_system::ensure_signed_or_root(origin)?;
+            let outflow =
+                QueuedOutflows::<T>::get(outflow_id).ok_or(Error::<T>::QueuedOutflowNotFound)?;
+            if who.is_some() {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() >= outflow.release_at,
+                    Error::<T>::QueuedOutflowIsLocked
+                );
+            }
+            ensure!(
+                !PausedNetworks::<T>::get(outflow.network_id),
+                Error::<T>::NetworkPaused
+            );
+            // Released outflow is counted in the window it's released in, so the following
+            // transfers can't exceed the limit.
+            if let Some(limit) = OutflowLimits::<T>::get(outflow.network_id, outflow.asset_id) {
+                let now = frame_system::Pallet::<T>::block_number();
+                OutflowWindows::<T>::mutate(outflow.network_id, outflow.asset_id, |window| {
+                    window.consume(limit.window, outflow.amount, now)
+                });
+            }
+            QueuedOutflows::<T>::remove(outflow_id);
+            Self::unreserve_outflow(&outflow)?;
+            Self::do_burn(
+                outflow.network_id,
+                outflow.asset_id,
+                outflow.sender,
+                outflow.recipient,
+                outflow.amount,
+            )?;
+            Self::deposit_event(Event::QueuedOutflowReleased(outflow_id));
+            Ok(().into())
+        }
+
+        /// Cancel the queued outgoing transfer and return the reserved funds to the sender.
+        ///
+        /// Can be called by the sender or root.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_queued_outflow())]
+        pub fn cancel_queued_outflow(
+            origin: OriginFor<T>,
+            outflow_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = frame_system::ensure_signed_or_root(origin)?;
+            let outflow =
+                QueuedOutflows::<T>::get(outflow_id).ok_or(Error::<T>::QueuedOutflowNotFound)?;
+            if let Some(who) = who {
+                ensure!(who == outflow.sender, Error::<T>::Forbidden);
+            }
+            QueuedOutflows::<T>::remove(outflow_id);
+            Self::unreserve_outflow(&outflow)?;
+            Self::deposit_event(Event::QueuedOutflowCancelled(outflow_id));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn do_burn(
+            network_id: GenericNetworkId,
+            asset_id: T::AssetId,
+            sender: T::AccountId,
+            recipient: GenericAccount<T::AccountId>,
+            amount: Balance,
+        ) -> DispatchResult {
             match recipient {
                 GenericAccount::EVM(recipient) => {
                     if T::HashiBridge::is_asset_supported(network_id, asset_id) {
@@ -179,11 +402,43 @@ pub mod pallet {
                     return Err(Error::<T>::WrongAccountKind.into())
                 }
             }
-            Ok(().into())
+            Ok(())
+        }
+
+        /// Reserves the transferred amount on the sender's account and puts the transfer
+        /// to the queue.
+        fn queue_outflow(outflow: QueuedOutflowOf<T>) -> Result<u64, DispatchError> {
+            ensure!(
+                matches!(
+                    outflow.recipient,
+                    GenericAccount::EVM(_) | GenericAccount::Parachain(_)
+                ),
+                Error::<T>::WrongAccountKind
+            );
+            assets::Pallet::<T>::reserve(&outflow.asset_id, &outflow.sender, outflow.amount)?;
+            let outflow_id = NextQueuedOutflowId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.wrapping_add(1);
+                current
+            });
+            Self::deposit_event(Event::OutflowQueued(
+                outflow_id,
+                outflow.sender.clone(),
+                outflow.asset_id,
+                outflow.amount,
+                outflow.release_at,
+            ));
+            QueuedOutflows::<T>::insert(outflow_id, outflow);
+            Ok(outflow_id)
+        }
+
+        fn unreserve_outflow(outflow: &QueuedOutflowOf<T>) -> DispatchResult {
+            let remainder =
+                assets::Pallet::<T>::unreserve(&outflow.asset_id, &outflow.sender, outflow.amount)?;
+            ensure!(remainder == 0, Error::<T>::FailedToUnreserve);
+            Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         pub fn list_apps() -> Vec<BridgeAppInfo> {
             let mut res = vec![];
             res.extend(T::EthApp::list_apps());
@@ -380,6 +635,29 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> OutflowLimiter<GenericNetworkId, T::AssetId, Balance> for Pallet<T> {
+    /// Used by the bridge apps to check the transfers which don't go through `burn` of this
+    /// pallet. Such transfers can't be queued, so they fail if the limit is exceeded.
+    fn ensure_outflow_allowed(
+        network_id: GenericNetworkId,
+        asset_id: &T::AssetId,
+        amount: &Balance,
+    ) -> DispatchResult {
+        ensure!(
+            !PausedNetworks::<T>::get(network_id),
+            Error::<T>::NetworkPaused
+        );
+        if let Some(limit) = OutflowLimits::<T>::get(network_id, asset_id) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let fits = OutflowWindows::<T>::mutate(network_id, asset_id, |window| {
+                window.try_consume(&limit, *amount, now)
+            });
+            ensure!(fits, Error::<T>::OutflowLimitExceeded);
+        }
+        Ok(())
+    }
+}
+
 impl<T: Config> bridge_types::traits::BridgeAssetLocker<T::AccountId> for Pallet<T> {
     type AssetId = <T as assets::Config>::AssetId;
     type Balance = Balance;
@@ -77,7 +77,7 @@ frame_support::construct_runtime!(
         BridgeOutboundChannel: bridge_outbound_channel::{Pallet, Config<T>, Storage, Event<T>},
         EthApp: eth_app::{Pallet, Call, Config<T>, Storage, Event<T>},
         ERC20App: erc20_app::{Pallet, Call, Config<T>, Storage, Event<T>},
-        BridgeProxy: proxy::{Pallet, Call, Storage, Event},
+        BridgeProxy: proxy::{Pallet, Call, Storage, Event<T>},
     }
 );
 
@@ -292,6 +292,7 @@ impl eth_app::Config for Test {
     type MessageStatusNotifier = BridgeProxy;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = BridgeProxy;
+    type OutflowLimiter = BridgeProxy;
     type WeightInfo = ();
 }
 
@@ -341,6 +342,7 @@ impl erc20_app::Config for Test {
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BalancePrecisionConverter = BalancePrecisionConverterImpl;
     type BridgeAssetLocker = BridgeProxy;
+    type OutflowLimiter = BridgeProxy;
     type WeightInfo = ();
 }
 
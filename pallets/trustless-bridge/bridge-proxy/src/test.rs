@@ -40,11 +40,13 @@ use bridge_types::GenericTimepoint;
 use bridge_types::H160;
 use bridge_types::{GenericAccount, GenericNetworkId};
 use codec::Encode;
-use common::{balance, DAI, XOR};
-use frame_support::assert_noop;
+use common::{balance, OutflowLimit, DAI, XOR};
+use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::traits::Hooks;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use sp_keyring::AccountKeyring as Keyring;
+use traits::MultiCurrency;
 
 use bridge_types::evm::AdditionalEVMInboundData;
 use bridge_types::types::{AssetKind, MessageDirection, MessageId, MessageStatus};
@@ -102,10 +104,12 @@ fn burn_successfull() {
                 direction: MessageDirection::Outbound,
             })
         );
-        assert_event(crate::Event::RequestStatusUpdate(message_id, MessageStatus::InQueue).into());
+        assert_event(
+            crate::Event::<Test>::RequestStatusUpdate(message_id, MessageStatus::InQueue).into(),
+        );
         BridgeOutboundChannel::on_initialize(BridgeOutboundChannel::interval());
         assert_event(
-            crate::Event::RequestStatusUpdate(message_id, MessageStatus::Committed).into(),
+            crate::Event::<Test>::RequestStatusUpdate(message_id, MessageStatus::Committed).into(),
         );
         assert_eq!(
             Transactions::<Test>::get(
@@ -198,7 +202,9 @@ fn mint_successfull() {
                 direction: MessageDirection::Inbound,
             })
         );
-        assert_event(crate::Event::RequestStatusUpdate(message_id, MessageStatus::Done).into());
+        assert_event(
+            crate::Event::<Test>::RequestStatusUpdate(message_id, MessageStatus::Done).into(),
+        );
     })
 }
 
@@ -302,3 +308,186 @@ fn burn_no_enough_locked() {
         );
     })
 }
+
+fn set_xor_outflow_limit() {
+    BridgeProxy::set_outflow_limit(
+        RawOrigin::Root.into(),
+        BASE_EVM_NETWORK_ID.into(),
+        XOR,
+        Some(OutflowLimit {
+            max_amount: 1000,
+            window: 10,
+            release_delay: 20,
+        }),
+    )
+    .unwrap();
+}
+
+fn burn_xor(caller: &AccountId, amount: u128) -> DispatchResultWithPostInfo {
+    BridgeProxy::burn(
+        RawOrigin::Signed(caller.clone()).into(),
+        BASE_EVM_NETWORK_ID.into(),
+        XOR,
+        GenericAccount::EVM(H160::default()),
+        amount,
+    )
+}
+
+#[test]
+fn burn_exceeding_outflow_limit_is_queued() {
+    new_tester().execute_with(|| {
+        let caller: AccountId = Keyring::Alice.into();
+        Currencies::update_balance(
+            RawOrigin::Root.into(),
+            caller.clone(),
+            XOR,
+            balance!(1) as i128,
+        )
+        .unwrap();
+        set_xor_outflow_limit();
+        assert_ok!(burn_xor(&caller, 600));
+        assert_ok!(burn_xor(&caller, 600));
+        assert_eq!(
+            crate::LockedAssets::<Test>::get(GenericNetworkId::EVM(BASE_EVM_NETWORK_ID), XOR),
+            600
+        );
+        assert_eq!(Transactions::<Test>::iter().count(), 1);
+        assert_event(crate::Event::<Test>::OutflowQueued(0, caller.clone(), XOR, 600, 21).into());
+        assert_eq!(Currencies::free_balance(XOR, &caller), balance!(1) - 1200);
+
+        assert_noop!(
+            BridgeProxy::release_queued_outflow(RawOrigin::Signed(caller.clone()).into(), 0),
+            crate::Error::<Test>::QueuedOutflowIsLocked
+        );
+        System::set_block_number(21);
+        assert_ok!(BridgeProxy::release_queued_outflow(
+            RawOrigin::Signed(AccountId::from(Keyring::Bob)).into(),
+            0
+        ));
+        assert_event(crate::Event::<Test>::QueuedOutflowReleased(0).into());
+        assert!(BridgeProxy::queued_outflow(0).is_none());
+        assert_eq!(
+            crate::LockedAssets::<Test>::get(GenericNetworkId::EVM(BASE_EVM_NETWORK_ID), XOR),
+            1200
+        );
+        assert_eq!(Transactions::<Test>::iter().count(), 2);
+        assert_eq!(Currencies::free_balance(XOR, &caller), balance!(1) - 1200);
+
+        // Released outflow is counted in the current window
+        assert_ok!(burn_xor(&caller, 600));
+        assert_event(crate::Event::<Test>::OutflowQueued(1, caller.clone(), XOR, 600, 41).into());
+        assert_eq!(Transactions::<Test>::iter().count(), 2);
+    })
+}
+
+#[test]
+fn direct_app_burn_is_limited() {
+    new_tester().execute_with(|| {
+        let caller: AccountId = Keyring::Alice.into();
+        Currencies::update_balance(
+            RawOrigin::Root.into(),
+            caller.clone(),
+            XOR,
+            balance!(1) as i128,
+        )
+        .unwrap();
+        set_xor_outflow_limit();
+        assert_ok!(ERC20App::burn(
+            RawOrigin::Signed(caller.clone()).into(),
+            BASE_EVM_NETWORK_ID,
+            XOR,
+            H160::default(),
+            600,
+        ));
+        assert_noop!(
+            ERC20App::burn(
+                RawOrigin::Signed(caller.clone()).into(),
+                BASE_EVM_NETWORK_ID,
+                XOR,
+                H160::default(),
+                600,
+            ),
+            crate::Error::<Test>::OutflowLimitExceeded
+        );
+        assert_ok!(BridgeProxy::set_network_paused(
+            RawOrigin::Root.into(),
+            BASE_EVM_NETWORK_ID.into(),
+            true
+        ));
+        assert_noop!(
+            ERC20App::burn(
+                RawOrigin::Signed(caller.clone()).into(),
+                BASE_EVM_NETWORK_ID,
+                XOR,
+                H160::default(),
+                100,
+            ),
+            crate::Error::<Test>::NetworkPaused
+        );
+    })
+}
+
+#[test]
+fn cancel_queued_outflow_refunds_sender() {
+    new_tester().execute_with(|| {
+        let caller: AccountId = Keyring::Alice.into();
+        Currencies::update_balance(
+            RawOrigin::Root.into(),
+            caller.clone(),
+            XOR,
+            balance!(1) as i128,
+        )
+        .unwrap();
+        set_xor_outflow_limit();
+        assert_ok!(burn_xor(&caller, 1500));
+        assert_noop!(
+            BridgeProxy::cancel_queued_outflow(
+                RawOrigin::Signed(AccountId::from(Keyring::Bob)).into(),
+                0
+            ),
+            crate::Error::<Test>::Forbidden
+        );
+        assert_ok!(BridgeProxy::cancel_queued_outflow(
+            RawOrigin::Signed(caller.clone()).into(),
+            0
+        ));
+        assert_event(crate::Event::<Test>::QueuedOutflowCancelled(0).into());
+        assert_eq!(Currencies::free_balance(XOR, &caller), balance!(1));
+        assert_eq!(Transactions::<Test>::iter().count(), 0);
+    })
+}
+
+#[test]
+fn burn_to_paused_network_fails() {
+    new_tester().execute_with(|| {
+        let caller: AccountId = Keyring::Alice.into();
+        Currencies::update_balance(
+            RawOrigin::Root.into(),
+            caller.clone(),
+            XOR,
+            balance!(1) as i128,
+        )
+        .unwrap();
+        set_xor_outflow_limit();
+        assert_ok!(burn_xor(&caller, 1500));
+        assert_ok!(BridgeProxy::set_network_paused(
+            RawOrigin::Root.into(),
+            BASE_EVM_NETWORK_ID.into(),
+            true
+        ));
+        assert_noop!(burn_xor(&caller, 100), crate::Error::<Test>::NetworkPaused);
+        assert_noop!(
+            BridgeProxy::release_queued_outflow(RawOrigin::Root.into(), 0),
+            crate::Error::<Test>::NetworkPaused
+        );
+        assert_ok!(BridgeProxy::set_network_paused(
+            RawOrigin::Root.into(),
+            BASE_EVM_NETWORK_ID.into(),
+            false
+        ));
+        assert_ok!(BridgeProxy::release_queued_outflow(
+            RawOrigin::Root.into(),
+            0
+        ));
+    })
+}
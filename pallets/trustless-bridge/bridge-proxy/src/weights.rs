@@ -2,10 +2,26 @@ use frame_support::weights::Weight;
 
 pub trait WeightInfo {
     fn burn() -> Weight;
+    fn set_outflow_limit() -> Weight;
+    fn set_network_paused() -> Weight;
+    fn release_queued_outflow() -> Weight;
+    fn cancel_queued_outflow() -> Weight;
 }
 
 impl WeightInfo for () {
     fn burn() -> Weight {
         Default::default()
     }
+    fn set_outflow_limit() -> Weight {
+        Default::default()
+    }
+    fn set_network_paused() -> Weight {
+        Default::default()
+    }
+    fn release_queued_outflow() -> Weight {
+        Default::default()
+    }
+    fn cancel_queued_outflow() -> Weight {
+        Default::default()
+    }
 }
@@ -54,6 +54,7 @@ pub mod pallet {
 
     use bridge_types::evm::*;
     use bridge_types::traits::BridgeAssetLocker;
+    use common::OutflowLimiter;
     use bridge_types::traits::{
         AppRegistry, BalancePrecisionConverter, BridgeApp, BridgeAssetRegistry,
         MessageStatusNotifier, OutboundChannel,
@@ -124,6 +125,8 @@ pub mod pallet {
 
         type BridgeAssetLocker: BridgeAssetLocker<Self::AccountId>;
 
+        type OutflowLimiter: OutflowLimiter<GenericNetworkId, AssetIdOf<Self>, BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -326,6 +329,11 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::OutflowLimiter::ensure_outflow_allowed(
+                GenericNetworkId::EVM(network_id),
+                &asset_id,
+                &amount,
+            )?;
 
             Self::burn_inner(who, network_id, asset_id, recipient, amount)?;
 
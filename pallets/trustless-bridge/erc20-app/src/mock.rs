@@ -353,6 +353,7 @@ impl erc20_app::Config for Test {
     type AssetRegistry = BridgeAssetRegistryImpl;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = bridge_types::test_utils::BridgeAssetLockerImpl<Currencies>;
+    type OutflowLimiter = ();
 }
 
 pub fn new_tester() -> sp_io::TestExternalities {
@@ -61,6 +61,7 @@ pub mod pallet {
     use bridge_types::MainnetAssetId;
     use bridge_types::{GenericAccount, GenericNetworkId, H256};
     use bridge_types::{H160, U256};
+    use common::OutflowLimiter;
     use frame_support::pallet_prelude::*;
     use frame_support::traits::StorageVersion;
     use frame_support::transactional;
@@ -116,6 +117,8 @@ pub mod pallet {
 
         type BridgeAssetLocker: BridgeAssetLocker<Self::AccountId>;
 
+        type OutflowLimiter: OutflowLimiter<GenericNetworkId, AssetIdOf<Self>, BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -180,6 +183,13 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            let (_, asset_id, _) =
+                Addresses::<T>::get(network_id).ok_or(Error::<T>::AppIsNotRegistered)?;
+            T::OutflowLimiter::ensure_outflow_allowed(
+                GenericNetworkId::EVM(network_id),
+                &asset_id,
+                &amount,
+            )?;
             Pallet::<T>::burn_inner(who, network_id, recipient, amount)?;
             Ok(())
         }
@@ -312,6 +312,7 @@ impl eth_app::Config for Test {
     type AssetRegistry = BridgeAssetRegistryImpl;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = bridge_types::test_utils::BridgeAssetLockerImpl<Currencies>;
+    type OutflowLimiter = ();
     type WeightInfo = ();
 }
 
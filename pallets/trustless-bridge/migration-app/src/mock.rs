@@ -233,6 +233,7 @@ impl eth_app::Config for Test {
     type AssetRegistry = BridgeAssetRegistryImpl;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = bridge_types::test_utils::BridgeAssetLockerImpl<Currencies>;
+    type OutflowLimiter = ();
     type WeightInfo = ();
 }
 
@@ -343,6 +344,7 @@ impl erc20_app::Config for Test {
     type BalancePrecisionConverter = BalancePrecisionConverterImpl;
     type WeightInfo = ();
     type BridgeAssetLocker = bridge_types::test_utils::BridgeAssetLockerImpl<Currencies>;
+    type OutflowLimiter = ();
 }
 
 impl crate::Config for Test {
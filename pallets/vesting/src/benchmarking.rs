@@ -0,0 +1,153 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Vesting module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use codec::Decode;
+use common::{balance, AssetId32, PredefinedAssetId, CERES_ASSET_ID};
+use frame_benchmarking::benchmarks;
+use frame_system::{EventRecord, RawOrigin};
+use hex_literal::hex;
+use sp_std::prelude::*;
+
+use crate::Pallet as Vesting;
+use assets::Pallet as Assets;
+
+// Support Functions
+fn alice<T: Config>() -> T::AccountId {
+    let bytes = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+    T::AccountId::decode(&mut &bytes[..]).unwrap()
+}
+
+fn bob<T: Config>() -> T::AccountId {
+    let bytes = hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48");
+    T::AccountId::decode(&mut &bytes[..]).unwrap()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+fn setup_schedule<T: Config>(creator: &T::AccountId, beneficiary: &T::AccountId)
+where
+    T::AssetId: From<AssetId32<PredefinedAssetId>>,
+{
+    let asset_id = T::AssetId::from(CERES_ASSET_ID);
+    let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+    frame_system::Pallet::<T>::inc_providers(creator);
+    Assets::<T>::mint(
+        RawOrigin::Signed(asset_owner).into(),
+        asset_id,
+        creator.clone(),
+        balance!(1000),
+    )
+    .unwrap();
+    Vesting::<T>::create_vesting_schedule(
+        RawOrigin::Signed(creator.clone()).into(),
+        beneficiary.clone(),
+        asset_id,
+        frame_system::Pallet::<T>::block_number(),
+        0u32.into(),
+        1u32.into(),
+        10,
+        balance!(10),
+        true,
+    )
+    .unwrap();
+}
+
+benchmarks! {
+    where_clause {
+        where T::AssetId: From<AssetId32<PredefinedAssetId>>
+    }
+
+    create_vesting_schedule {
+        let caller = alice::<T>();
+        let beneficiary = bob::<T>();
+        let asset_id = T::AssetId::from(CERES_ASSET_ID);
+        let asset_owner = Assets::<T>::asset_owner(&asset_id).unwrap();
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        Assets::<T>::mint(
+            RawOrigin::Signed(asset_owner).into(),
+            asset_id,
+            caller.clone(),
+            balance!(1000)
+        ).unwrap();
+        let start = frame_system::Pallet::<T>::block_number();
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        beneficiary.clone(),
+        asset_id,
+        start,
+        0u32.into(),
+        1u32.into(),
+        10,
+        balance!(10),
+        true
+    )
+    verify {
+        assert_last_event::<T>(Event::<T>::VestingScheduleCreated(caller, beneficiary, 0, asset_id, balance!(100)).into());
+    }
+
+    claim {
+        let n in 1 .. T::MaxVestingSchedules::get();
+        let caller = alice::<T>();
+        let beneficiary = bob::<T>();
+        for _ in 0..n {
+            setup_schedule::<T>(&caller, &beneficiary);
+        }
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + 5u32.into());
+    }: _(RawOrigin::Signed(beneficiary.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::Claimed(beneficiary, CERES_ASSET_ID.into(), balance!(50) * n as u128).into());
+    }
+
+    revoke_vesting_schedule {
+        let caller = alice::<T>();
+        let beneficiary = bob::<T>();
+        setup_schedule::<T>(&caller, &beneficiary);
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + 5u32.into());
+    }: _(RawOrigin::Signed(caller), beneficiary.clone(), 0)
+    verify {
+        assert_last_event::<T>(Event::<T>::VestingScheduleRevoked(beneficiary, 0, balance!(50)).into());
+    }
+
+    impl_benchmark_test_suite!(Vesting, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
+}
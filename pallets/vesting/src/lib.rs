@@ -0,0 +1,405 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use common::prelude::Balance;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::RuntimeDebug;
+
+pub mod weights;
+
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub type ScheduleId = u64;
+
+/// Vesting schedule of a single beneficiary.
+///
+/// Nothing is released before `start + cliff`. After that the beneficiary is entitled to
+/// `per_period` tokens for every full `period` elapsed since `start`, up to `period_count`
+/// periods, so the amount accrued during the cliff is released at once when the cliff ends.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct VestingSchedule<AccountId, AssetId, BlockNumber> {
+    /// Account which created and funded the schedule
+    pub creator: AccountId,
+    /// Vested asset
+    pub asset_id: AssetId,
+    /// Block from which periods are counted
+    pub start: BlockNumber,
+    /// Number of blocks after `start` before anything can be claimed
+    pub cliff: BlockNumber,
+    /// Length of a single period in blocks
+    pub period: BlockNumber,
+    /// Number of periods
+    pub period_count: u32,
+    /// Amount released every period
+    pub per_period: Balance,
+    /// Amount already claimed by the beneficiary
+    pub claimed: Balance,
+    /// Whether the creator is allowed to revoke the unvested part
+    pub revocable: bool,
+}
+
+impl<AccountId, AssetId, BlockNumber> VestingSchedule<AccountId, AssetId, BlockNumber>
+where
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// Total amount locked by the schedule.
+    pub fn total_amount(&self) -> Option<Balance> {
+        self.per_period.checked_mul(self.period_count as Balance)
+    }
+
+    /// Amount vested at block `now`, including the already claimed part.
+    pub fn vested_amount(&self, now: BlockNumber) -> Balance {
+        if now < self.start.saturating_add(self.cliff) || self.period.is_zero() {
+            return Balance::zero();
+        }
+        let elapsed_periods: u32 = ((now - self.start) / self.period).unique_saturated_into();
+        let periods = elapsed_periods.min(self.period_count);
+        self.per_period.saturating_mul(periods as Balance)
+    }
+
+    /// Amount which is vested but not claimed yet at block `now`.
+    pub fn claimable_amount(&self, now: BlockNumber) -> Balance {
+        self.vested_amount(now).saturating_sub(self.claimed)
+    }
+
+    /// Amount which is not vested yet at block `now`.
+    pub fn unvested_amount(&self, now: BlockNumber) -> Balance {
+        self.total_amount()
+            .unwrap_or(Balance::MAX)
+            .saturating_sub(self.vested_amount(now))
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use common::AssetInfoProvider;
+    use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
+    use frame_support::PalletId;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::AccountIdConversion;
+    use sp_std::collections::btree_map::BTreeMap;
+    use sp_std::vec::Vec;
+
+    const PALLET_ID: PalletId = PalletId(*b"vestings");
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + assets::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Maximum number of active schedules per beneficiary
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+        /// Minimum total amount of a schedule, prevents filling beneficiary schedule slots with dust
+        #[pallet::constant]
+        type MinVestedTransfer: Get<Balance>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub type AssetIdOf<T> = <T as assets::Config>::AssetId;
+    pub type VestingScheduleOf<T> =
+        VestingSchedule<AccountIdOf<T>, AssetIdOf<T>, <T as frame_system::Config>::BlockNumber>;
+    type Assets<T> = assets::Pallet<T>;
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    /// Vesting schedules by beneficiary and schedule id
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Twox64Concat,
+        ScheduleId,
+        VestingScheduleOf<T>,
+        OptionQuery,
+    >;
+
+    /// Id which will be assigned to the next created schedule
+    #[pallet::storage]
+    #[pallet::getter(fn next_schedule_id)]
+    pub type NextScheduleId<T: Config> = StorageValue<_, ScheduleId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Vesting schedule created [creator, beneficiary, schedule id, asset, total amount]
+        VestingScheduleCreated(
+            AccountIdOf<T>,
+            AccountIdOf<T>,
+            ScheduleId,
+            AssetIdOf<T>,
+            Balance,
+        ),
+        /// Vested tokens claimed [beneficiary, asset, amount]
+        Claimed(AccountIdOf<T>, AssetIdOf<T>, Balance),
+        /// Vesting schedule revoked [beneficiary, schedule id, amount returned to creator]
+        VestingScheduleRevoked(AccountIdOf<T>, ScheduleId, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Period length or number of periods equals zero
+        InvalidPeriod,
+        /// Amount per period equals zero
+        InvalidAmount,
+        /// Start block cannot be in past
+        InvalidStart,
+        /// Total vested amount overflows
+        ArithmeticError,
+        /// Total vested amount is below the minimum
+        AmountLow,
+        /// Not enough funds to fund the schedule
+        NotEnoughFunds,
+        /// Beneficiary already has maximum number of schedules
+        TooManyVestingSchedules,
+        /// Nothing to claim at the moment
+        NothingToClaim,
+        /// Vesting schedule does not exist
+        VestingScheduleDoesNotExist,
+        /// Only the creator of the schedule can revoke it
+        Unauthorized,
+        /// Schedule was created as non-revocable
+        NotRevocable,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create vesting schedule for `beneficiary` funded by the caller.
+        ///
+        /// - `start`: block from which periods are counted, cannot be in past
+        /// - `cliff`: number of blocks after `start` before anything can be claimed
+        /// - `period`: length of a single period in blocks
+        /// - `period_count`: number of periods
+        /// - `per_period`: amount released every period
+        /// - `revocable`: whether the caller can take back the unvested part later
+        #[transactional]
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vesting_schedule())]
+        pub fn create_vesting_schedule(
+            origin: OriginFor<T>,
+            beneficiary: AccountIdOf<T>,
+            asset_id: AssetIdOf<T>,
+            start: T::BlockNumber,
+            cliff: T::BlockNumber,
+            period: T::BlockNumber,
+            period_count: u32,
+            per_period: Balance,
+            revocable: bool,
+        ) -> DispatchResultWithPostInfo {
+            let creator = ensure_signed(origin)?;
+
+            ensure!(
+                !period.is_zero() && period_count > 0,
+                Error::<T>::InvalidPeriod
+            );
+            ensure!(per_period > 0, Error::<T>::InvalidAmount);
+            ensure!(
+                start >= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidStart
+            );
+            ensure!(
+                (VestingSchedules::<T>::iter_prefix(&beneficiary).count() as u32)
+                    < T::MaxVestingSchedules::get(),
+                Error::<T>::TooManyVestingSchedules
+            );
+
+            let schedule = VestingSchedule {
+                creator: creator.clone(),
+                asset_id,
+                start,
+                cliff,
+                period,
+                period_count,
+                per_period,
+                claimed: 0,
+                revocable,
+            };
+            let total = schedule.total_amount().ok_or(Error::<T>::ArithmeticError)?;
+            ensure!(total >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+            ensure!(
+                total <= Assets::<T>::free_balance(&asset_id, &creator).unwrap_or(0),
+                Error::<T>::NotEnoughFunds
+            );
+
+            Assets::<T>::transfer_from(&asset_id, &creator, &Self::account_id(), total)?;
+
+            let schedule_id = NextScheduleId::<T>::mutate(|id| {
+                let current = *id;
+                *id = id.saturating_add(1);
+                current
+            });
+            VestingSchedules::<T>::insert(&beneficiary, schedule_id, schedule);
+
+            Self::deposit_event(Event::VestingScheduleCreated(
+                creator,
+                beneficiary,
+                schedule_id,
+                asset_id,
+                total,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Claim everything vested so far from all schedules of the caller.
+        #[transactional]
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+        pub fn claim(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let mut claimed_per_asset = BTreeMap::<AssetIdOf<T>, Balance>::new();
+            let schedules: Vec<_> = VestingSchedules::<T>::iter_prefix(&who).collect();
+            for (schedule_id, mut schedule) in schedules {
+                let claimable = schedule.claimable_amount(now);
+                if claimable == 0 {
+                    continue;
+                }
+                schedule.claimed = schedule.claimed.saturating_add(claimable);
+                *claimed_per_asset.entry(schedule.asset_id).or_default() += claimable;
+
+                if Some(schedule.claimed) == schedule.total_amount() {
+                    VestingSchedules::<T>::remove(&who, schedule_id);
+                } else {
+                    VestingSchedules::<T>::insert(&who, schedule_id, schedule);
+                }
+            }
+
+            ensure!(!claimed_per_asset.is_empty(), Error::<T>::NothingToClaim);
+
+            for (asset_id, amount) in claimed_per_asset {
+                Assets::<T>::transfer_from(&asset_id, &Self::account_id(), &who, amount)?;
+                Self::deposit_event(Event::Claimed(who.clone(), asset_id, amount));
+            }
+
+            Ok(().into())
+        }
+
+        /// Revoke vesting schedule. The part vested so far is paid to the beneficiary and
+        /// the unvested remainder is returned to the creator.
+        #[transactional]
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_vesting_schedule())]
+        pub fn revoke_vesting_schedule(
+            origin: OriginFor<T>,
+            beneficiary: AccountIdOf<T>,
+            schedule_id: ScheduleId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let schedule = VestingSchedules::<T>::get(&beneficiary, schedule_id)
+                .ok_or(Error::<T>::VestingScheduleDoesNotExist)?;
+            ensure!(schedule.creator == who, Error::<T>::Unauthorized);
+            ensure!(schedule.revocable, Error::<T>::NotRevocable);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let claimable = schedule.claimable_amount(now);
+            let unvested = schedule.unvested_amount(now);
+
+            if claimable > 0 {
+                Assets::<T>::transfer_from(
+                    &schedule.asset_id,
+                    &Self::account_id(),
+                    &beneficiary,
+                    claimable,
+                )?;
+                Self::deposit_event(Event::Claimed(
+                    beneficiary.clone(),
+                    schedule.asset_id,
+                    claimable,
+                ));
+            }
+            if unvested > 0 {
+                Assets::<T>::transfer_from(
+                    &schedule.asset_id,
+                    &Self::account_id(),
+                    &schedule.creator,
+                    unvested,
+                )?;
+            }
+            VestingSchedules::<T>::remove(&beneficiary, schedule_id);
+
+            Self::deposit_event(Event::VestingScheduleRevoked(
+                beneficiary,
+                schedule_id,
+                unvested,
+            ));
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account ID of pallet
+        pub fn account_id() -> T::AccountId {
+            PALLET_ID.into_account_truncating()
+        }
+
+        /// Returns (vested, claimable, unvested) amounts of `who` per asset at the current block.
+        /// Vested amount includes tokens which have already been claimed.
+        pub fn vesting_balances(
+            who: &AccountIdOf<T>,
+        ) -> Vec<(AssetIdOf<T>, Balance, Balance, Balance)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut balances = BTreeMap::<AssetIdOf<T>, (Balance, Balance, Balance)>::new();
+            for schedule in VestingSchedules::<T>::iter_prefix_values(who) {
+                let entry = balances.entry(schedule.asset_id).or_default();
+                entry.0 = entry.0.saturating_add(schedule.vested_amount(now));
+                entry.1 = entry.1.saturating_add(schedule.claimable_amount(now));
+                entry.2 = entry.2.saturating_add(schedule.unvested_amount(now));
+            }
+            balances
+                .into_iter()
+                .map(|(asset_id, (vested, claimable, unvested))| {
+                    (asset_id, vested, claimable, unvested)
+                })
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,315 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mock::*;
+use crate::{Error, Pallet, VestingSchedules};
+use common::{balance, AssetInfoProvider, CERES_ASSET_ID};
+use frame_support::{assert_err, assert_ok};
+
+fn free_balance(who: &AccountId) -> common::Balance {
+    Assets::free_balance(&CERES_ASSET_ID, who).expect("Failed to query free balance.")
+}
+
+/// Creates schedule from ALICE to BOB: start at block 10, cliff of 20 blocks,
+/// 10 periods of 10 blocks releasing 100 tokens each.
+fn create_default_schedule(revocable: bool) {
+    assert_ok!(Vesting::create_vesting_schedule(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        CERES_ASSET_ID,
+        10,
+        20,
+        10,
+        10,
+        balance!(100),
+        revocable
+    ));
+}
+
+#[test]
+fn create_vesting_schedule_invalid_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                0,
+                10,
+                balance!(100),
+                false
+            ),
+            Error::<Runtime>::InvalidPeriod
+        );
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                10,
+                0,
+                balance!(100),
+                false
+            ),
+            Error::<Runtime>::InvalidPeriod
+        );
+    });
+}
+
+#[test]
+fn create_vesting_schedule_invalid_start() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(5);
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                4,
+                0,
+                10,
+                10,
+                balance!(100),
+                false
+            ),
+            Error::<Runtime>::InvalidStart
+        );
+    });
+}
+
+#[test]
+fn create_vesting_schedule_not_enough_funds() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                10,
+                31,
+                balance!(100),
+                false
+            ),
+            Error::<Runtime>::NotEnoughFunds
+        );
+    });
+}
+
+#[test]
+fn create_vesting_schedule_too_many_schedules() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..3 {
+            assert_ok!(Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                10,
+                1,
+                balance!(1),
+                false
+            ));
+        }
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                10,
+                1,
+                balance!(1),
+                false
+            ),
+            Error::<Runtime>::TooManyVestingSchedules
+        );
+    });
+}
+
+#[test]
+fn create_vesting_schedule_amount_low() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            Vesting::create_vesting_schedule(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                CERES_ASSET_ID,
+                10,
+                0,
+                10,
+                2,
+                balance!(0.4),
+                false
+            ),
+            Error::<Runtime>::AmountLow
+        );
+    });
+}
+
+#[test]
+fn create_vesting_schedule_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(false);
+
+        assert_eq!(free_balance(&ALICE), balance!(2000));
+        assert_eq!(
+            free_balance(&Pallet::<Runtime>::account_id()),
+            balance!(1000)
+        );
+
+        let schedule = VestingSchedules::<Runtime>::get(&BOB, 0).unwrap();
+        assert_eq!(schedule.creator, ALICE);
+        assert_eq!(schedule.asset_id, CERES_ASSET_ID);
+        assert_eq!(schedule.total_amount(), Some(balance!(1000)));
+        assert_eq!(schedule.claimed, 0);
+        assert_eq!(Vesting::next_schedule_id(), 1);
+    });
+}
+
+#[test]
+fn claim_nothing_before_cliff() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(false);
+
+        run_to_block(29);
+        assert_err!(
+            Vesting::claim(RuntimeOrigin::signed(BOB)),
+            Error::<Runtime>::NothingToClaim
+        );
+    });
+}
+
+#[test]
+fn claim_releases_cliff_and_periods() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(false);
+
+        // Two periods accrued during the cliff are released at once
+        run_to_block(30);
+        assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+        assert_eq!(free_balance(&BOB), balance!(700));
+
+        run_to_block(45);
+        assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+        assert_eq!(free_balance(&BOB), balance!(800));
+        assert_eq!(
+            VestingSchedules::<Runtime>::get(&BOB, 0).unwrap().claimed,
+            balance!(300)
+        );
+
+        assert_err!(
+            Vesting::claim(RuntimeOrigin::signed(BOB)),
+            Error::<Runtime>::NothingToClaim
+        );
+
+        // Schedule is removed once everything is claimed
+        run_to_block(200);
+        assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+        assert_eq!(free_balance(&BOB), balance!(1500));
+        assert!(VestingSchedules::<Runtime>::get(&BOB, 0).is_none());
+        assert_eq!(free_balance(&Pallet::<Runtime>::account_id()), 0);
+    });
+}
+
+#[test]
+fn vesting_balances_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(false);
+
+        run_to_block(30);
+        assert_eq!(
+            Vesting::vesting_balances(&BOB),
+            vec![(CERES_ASSET_ID, balance!(200), balance!(200), balance!(800))]
+        );
+
+        assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB)));
+        run_to_block(40);
+        assert_eq!(
+            Vesting::vesting_balances(&BOB),
+            vec![(CERES_ASSET_ID, balance!(300), balance!(100), balance!(700))]
+        );
+    });
+}
+
+#[test]
+fn revoke_vesting_schedule_unauthorized() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(true);
+
+        assert_err!(
+            Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(CHARLIE), BOB, 0),
+            Error::<Runtime>::Unauthorized
+        );
+        assert_err!(
+            Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE), BOB, 1),
+            Error::<Runtime>::VestingScheduleDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn revoke_vesting_schedule_not_revocable() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(false);
+
+        assert_err!(
+            Vesting::revoke_vesting_schedule(RuntimeOrigin::signed(ALICE), BOB, 0),
+            Error::<Runtime>::NotRevocable
+        );
+    });
+}
+
+#[test]
+fn revoke_vesting_schedule_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_default_schedule(true);
+
+        run_to_block(40);
+        assert_ok!(Vesting::revoke_vesting_schedule(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            0
+        ));
+
+        // Vested part goes to beneficiary, the rest returns to creator
+        assert_eq!(free_balance(&BOB), balance!(800));
+        assert_eq!(free_balance(&ALICE), balance!(2700));
+        assert_eq!(free_balance(&Pallet::<Runtime>::account_id()), 0);
+        assert!(VestingSchedules::<Runtime>::get(&BOB, 0).is_none());
+    });
+}
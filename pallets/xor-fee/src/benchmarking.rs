@@ -32,12 +32,32 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use common::XOR;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::sp_runtime::FixedU128;
 use frame_system::RawOrigin;
 
 use crate::{Config, Pallet};
 
+fn sponsor<T: Config>() -> T::AccountId {
+    let sponsor: T::AccountId = account("sponsor", 0, 0);
+    assets::Pallet::<T>::mint_unchecked(&XOR.into(), &sponsor, balance!(100)).unwrap();
+    sponsor
+}
+
+fn sponsored_calls() -> Vec<SponsoredCall> {
+    let name = || {
+        SponsoredCallName::try_from(sp_std::vec![b'a'; MAX_SPONSORED_CALL_NAME_LENGTH as usize])
+            .unwrap()
+    };
+    (0..MAX_SPONSORED_CALLS)
+        .map(|_| SponsoredCall {
+            pallet_name: name(),
+            call_name: Some(name()),
+        })
+        .collect()
+}
+
 benchmarks! {
     where_clause {
         where T: Config + pool_xyk::Config + pallet_staking::Config
@@ -50,5 +70,76 @@ benchmarks! {
         assert_eq!(crate::Multiplier::<T>::get(), new_multiplier);
     }
 
+    whitelist_fee_asset {
+        let asset_id: T::AssetId = common::VAL.into();
+        let price_margin = Percent::from_percent(5);
+    }: _(RawOrigin::Root, asset_id, price_margin)
+    verify {
+        assert_eq!(crate::FeeAssets::<T>::get(asset_id), Some(price_margin));
+    }
+
+    remove_fee_asset {
+        let asset_id: T::AssetId = common::VAL.into();
+        crate::FeeAssets::<T>::insert(asset_id, Percent::from_percent(5));
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_eq!(crate::FeeAssets::<T>::get(asset_id), None);
+    }
+
+    set_sponsor_rules {
+        let sponsor = sponsor::<T>();
+    }: _(RawOrigin::Signed(sponsor.clone()), sponsored_calls(), balance!(1), balance!(10))
+    verify {
+        assert_eq!(crate::Sponsors::<T>::get(&sponsor).unwrap().user_cap, balance!(1));
+    }
+
+    fund_sponsor_budget {
+        let sponsor = sponsor::<T>();
+        Pallet::<T>::set_sponsor_rules(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), balance!(1), balance!(10)).unwrap();
+    }: _(RawOrigin::Signed(sponsor.clone()), balance!(10))
+    verify {
+        assert_eq!(crate::Sponsors::<T>::get(&sponsor).unwrap().budget, balance!(10));
+    }
+
+    withdraw_sponsor_budget {
+        let sponsor = sponsor::<T>();
+        Pallet::<T>::set_sponsor_rules(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), balance!(1), balance!(10)).unwrap();
+        Pallet::<T>::fund_sponsor_budget(RawOrigin::Signed(sponsor.clone()).into(), balance!(10)).unwrap();
+    }: _(RawOrigin::Signed(sponsor.clone()), balance!(4))
+    verify {
+        assert_eq!(crate::Sponsors::<T>::get(&sponsor).unwrap().budget, balance!(6));
+    }
+
+    remove_sponsor {
+        let sponsor = sponsor::<T>();
+        Pallet::<T>::set_sponsor_rules(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), balance!(1), balance!(10)).unwrap();
+        Pallet::<T>::fund_sponsor_budget(RawOrigin::Signed(sponsor.clone()).into(), balance!(10)).unwrap();
+    }: _(RawOrigin::Signed(sponsor.clone()))
+    verify {
+        assert_eq!(crate::Sponsors::<T>::get(&sponsor), None);
+    }
+
+    set_fee_sponsor {
+        let sponsor = sponsor::<T>();
+        let user: T::AccountId = account("user", 0, 0);
+        Pallet::<T>::set_sponsor_rules(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), balance!(1), balance!(10)).unwrap();
+        Pallet::<T>::approve_sponsored_user(RawOrigin::Signed(sponsor.clone()).into(), user.clone(), true).unwrap();
+    }: _(RawOrigin::Signed(user.clone()), Some(sponsor.clone()))
+    verify {
+        assert_eq!(crate::UserSponsors::<T>::get(&user), Some(sponsor));
+    }
+
+    approve_sponsored_user {
+        let sponsor = sponsor::<T>();
+        let user: T::AccountId = account("user", 0, 0);
+        Pallet::<T>::set_sponsor_rules(RawOrigin::Signed(sponsor.clone()).into(), sponsored_calls(), balance!(1), balance!(10)).unwrap();
+        Pallet::<T>::approve_sponsored_user(RawOrigin::Signed(sponsor.clone()).into(), user.clone(), true).unwrap();
+        Pallet::<T>::set_fee_sponsor(RawOrigin::Signed(user.clone()).into(), Some(sponsor.clone())).unwrap();
+    }: _(RawOrigin::Signed(sponsor.clone()), user.clone(), false)
+    verify {
+        assert!(!crate::SponsoredUsers::<T>::get(&sponsor, &user));
+        assert_eq!(crate::UserSponsors::<T>::get(&user), None);
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
 }
@@ -0,0 +1,182 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::weights::WeightInfo;
+use crate::{ApplyCustomFees, Assets, BalanceOf, CallOf, Config, Pallet, WithdrawFee};
+use codec::{Decode, Encode};
+use common::{AssetInfoProvider, Balance};
+use frame_support::dispatch::{DispatchInfo, Pays};
+use frame_support::pallet_prelude::{InvalidTransaction, TransactionValidity, ValidTransaction};
+use frame_support::traits::Currency;
+use frame_support::unsigned::TransactionValidityError;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::traits::{
+    DispatchInfoOf, Dispatchable, SaturatedConversion, SignedExtension, Zero,
+};
+use sp_runtime::FixedPointOperand;
+
+type PtpBalanceOf<T> =
+    <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Allows to pay transaction fee in one of the whitelisted assets.
+///
+/// The fee asset is exchanged for XOR before the fee is withdrawn, so this extension must
+/// precede `ChargeTransactionPayment` in the runtime `SignedExtra`. The tip is not supported,
+/// as it is always zero in `ChargeTransactionPayment` of the runtime.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, scale_info::TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFeeInAsset<T: Config> {
+    asset_id: Option<T::AssetId>,
+}
+
+impl<T: Config> ChargeFeeInAsset<T> {
+    /// Fee is paid in XOR
+    pub fn new() -> Self {
+        Self { asset_id: None }
+    }
+
+    /// Fee is paid in `asset_id` if it is given
+    pub fn from(asset_id: Option<T::AssetId>) -> Self {
+        Self { asset_id }
+    }
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeFeeInAsset<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "ChargeFeeInAsset<{:?}>", self.asset_id)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config> ChargeFeeInAsset<T>
+where
+    CallOf<T>: Dispatchable<Info = DispatchInfo>,
+    PtpBalanceOf<T>: FixedPointOperand + Into<u128>,
+{
+    /// Returns the fee asset and the fee in XOR if the fee is paid by `who` in the fee asset.
+    fn fee_in_asset(
+        &self,
+        who: &T::AccountId,
+        call: &CallOf<T>,
+        info: &DispatchInfoOf<CallOf<T>>,
+        len: usize,
+    ) -> Option<(&T::AssetId, Balance)> {
+        let asset_id = self.asset_id.as_ref()?;
+        if info.pays_fee == Pays::No {
+            return None;
+        }
+
+        let fee: u128 =
+            pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero())
+                .into();
+        if fee.is_zero() {
+            return None;
+        }
+        let fee = T::CustomFees::compute_fee(call).unwrap_or(fee);
+
+        // Fee is paid by the referrer or the sponsor, so there is nothing to exchange
+        if !T::WithdrawFee::is_fee_paid_by_user(who, call, fee) {
+            return None;
+        }
+
+        Some((asset_id, fee))
+    }
+}
+
+impl<T: Config> SignedExtension for ChargeFeeInAsset<T>
+where
+    CallOf<T>: Dispatchable<Info = DispatchInfo>,
+    PtpBalanceOf<T>: Send + Sync + FixedPointOperand + Into<u128>,
+{
+    const IDENTIFIER: &'static str = "ChargeFeeInAsset";
+
+    type AccountId = T::AccountId;
+
+    type Call = CallOf<T>;
+
+    type AdditionalSigned = ();
+
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        let (asset_id, fee) = match self.fee_in_asset(who, call, info, len) {
+            Some(fee_in_asset) => fee_in_asset,
+            None => return Ok(ValidTransaction::default()),
+        };
+
+        let fee_amount =
+            Pallet::<T>::quote_fee_asset(asset_id, fee).map_err(|_| InvalidTransaction::Payment)?;
+        let balance =
+            Assets::<T>::free_balance(asset_id, who).map_err(|_| InvalidTransaction::Payment)?;
+        if balance < fee_amount {
+            return Err(InvalidTransaction::Payment.into());
+        }
+
+        // The fee asset is exchanged only in `pre_dispatch`, but `ChargeTransactionPayment`
+        // validates the fee withdrawal in XOR right after this extension. Changes made during
+        // validation are discarded afterwards, so the fee is credited to `who` here.
+        let _ = T::XorCurrency::deposit_creating(who, BalanceOf::<T>::saturated_from(fee));
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some((asset_id, fee)) = self.fee_in_asset(who, call, info, len) {
+            Pallet::<T>::exchange_fee_asset(who, asset_id, fee)
+                .map_err(|_| InvalidTransaction::Payment)?;
+            // The exchange is not included in the weight of the call
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                <T as Config>::WeightInfo::exchange_fee_asset(),
+                info.class,
+            );
+        }
+        Ok(())
+    }
+}
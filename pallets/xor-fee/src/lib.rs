@@ -32,15 +32,19 @@
 // TODO #167: fix clippy warnings
 #![allow(clippy::all)]
 
-use common::prelude::SwapAmount;
+use codec::{Decode, Encode};
+use common::prelude::{FixedWrapper, QuoteAmount, SwapAmount};
 use common::{
-    AssetInfoProvider, Balance, BuyBackHandler, FilterMode, LiquidityProxyTrait,
-    LiquiditySourceFilter, LiquiditySourceType, OnValBurned,
+    balance, AssetInfoProvider, Balance, BuyBackHandler, FilterMode, LiquidityProxyTrait,
+    LiquiditySourceFilter, LiquiditySourceType, OnValBurned, PriceToolsPallet, PriceVariant,
 };
 use frame_support::dispatch::{DispatchInfo, GetDispatchInfo, Pays};
+use frame_support::ensure;
 use frame_support::log::error;
 use frame_support::pallet_prelude::InvalidTransaction;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::traits::{
+    ConstU32, Currency, ExistenceRequirement, Get, GetCallMetadata, Imbalance, WithdrawReasons,
+};
 use frame_support::unsigned::TransactionValidityError;
 use frame_support::weights::{
     WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -54,11 +58,14 @@ use sp_runtime::traits::{
     DispatchInfoOf, Dispatchable, Extrinsic as ExtrinsicT, PostDispatchInfoOf, SaturatedConversion,
     SignedExtension, UniqueSaturatedInto, Zero,
 };
-use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, Perbill, Percent};
+use sp_runtime::{
+    BoundedVec, DispatchError, FixedPointNumber, FixedU128, Perbill, Percent, RuntimeDebug,
+};
 use sp_staking::SessionIndex;
 use sp_std::vec::Vec;
 
 mod benchmarking;
+pub mod extension;
 pub mod weights;
 
 #[cfg(test)]
@@ -69,6 +76,14 @@ mod tests;
 
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"xor-fee";
 pub const TECH_ACCOUNT_MAIN: &[u8] = b"main";
+pub const TECH_ACCOUNT_SPONSORS: &[u8] = b"sponsors";
+/// Maximum number of calls in the sponsor rules
+pub const MAX_SPONSORED_CALLS: u32 = 32;
+/// Maximum length of pallet and call names in the sponsor rules
+pub const MAX_SPONSORED_CALL_NAME_LENGTH: u32 = 64;
+
+/// Name of the pallet or the call in the sponsor rules
+pub type SponsoredCallName = BoundedVec<u8, ConstU32<MAX_SPONSORED_CALL_NAME_LENGTH>>;
 
 type NegativeImbalanceOf<T> = <<T as Config>::XorCurrency as Currency<
     <T as frame_system::Config>::AccountId,
@@ -82,12 +97,47 @@ type BalanceOf<T> =
 type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
 type Assets<T> = assets::Pallet<T>;
 
+/// Call whose fee can be paid by a sponsor.
+/// All calls of the pallet are allowed if `call_name` is not set.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct SponsoredCall {
+    /// Name of the pallet
+    pub pallet_name: SponsoredCallName,
+    /// Name of the call
+    pub call_name: Option<SponsoredCallName>,
+}
+
+impl SponsoredCall {
+    pub fn matches(&self, pallet_name: &str, call_name: &str) -> bool {
+        self.pallet_name.as_slice() == pallet_name.as_bytes()
+            && self
+                .call_name
+                .as_ref()
+                .map_or(true, |name| name.as_slice() == call_name.as_bytes())
+    }
+}
+
+/// Fee sponsor budget and rules
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct SponsorInfo {
+    /// Amount of XOR reserved by the sponsor to pay fees
+    pub budget: Balance,
+    /// Calls which are sponsored, all calls are sponsored if the list is empty
+    pub allowed_calls: Vec<SponsoredCall>,
+    /// Maximum total amount of fees paid for a single user
+    pub user_cap: Balance,
+    /// Maximum amount of fees paid in a single block
+    pub block_cap: Balance,
+}
+
 // #[cfg_attr(test, derive(PartialEq))]
 pub enum LiquidityInfo<T: Config> {
     /// Fees operate as normal
     Paid((T::AccountId, Option<NegativeImbalanceOf<T>>)),
     /// The fee payment has been postponed to after the transaction
     Postponed(T::AccountId, BalanceOf<T>),
+    /// Fees are paid from the budget of the sponsor
+    Sponsored(T::AccountId, NegativeImbalanceOf<T>),
     /// Default value
     NotPaid,
 }
@@ -101,6 +151,9 @@ impl<T: Config> sp_std::fmt::Debug for LiquidityInfo<T> {
             LiquidityInfo::Postponed(account_id, b) => {
                 write!(f, "Postponed({:?}, {:?})", account_id, b)
             }
+            LiquidityInfo::Sponsored(sponsor, b) => {
+                write!(f, "Sponsored({:?}, {:?})", sponsor, b.peek())
+            }
             LiquidityInfo::NotPaid => {
                 write!(f, "NotPaid")
             }
@@ -117,6 +170,9 @@ impl<T: Config> PartialEq for LiquidityInfo<T> {
             (LiquidityInfo::Postponed(a1, b1), LiquidityInfo::Postponed(a2, b2)) => {
                 a1 == a2 && b1 == b2
             }
+            (LiquidityInfo::Sponsored(a1, b1), LiquidityInfo::Sponsored(a2, b2)) => {
+                a1 == a2 && b1.peek() == b2.peek()
+            }
             _ => false,
         }
     }
@@ -151,7 +207,7 @@ where
     fn withdraw_fee(
         who: &T::AccountId,
         call: &CallOf<T>,
-        _dispatch_info: &DispatchInfoOf<CallOf<T>>,
+        dispatch_info: &DispatchInfoOf<CallOf<T>>,
         fee: Self::Balance,
         _tip: Self::Balance,
     ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
@@ -165,8 +221,23 @@ where
             _ => fee,
         };
 
+        // Sponsor of the user is looked up on every fee withdrawal, which is not included
+        // in the weight of the call
+        let dispatch_class = Into::<DispatchInfo>::into(dispatch_info.clone()).class;
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            T::DbWeight::get().reads(1),
+            dispatch_class,
+        );
+
         if let Ok(result) = T::WithdrawFee::withdraw_fee(who, call, final_fee.into()) {
-            return Ok(result.into());
+            if !matches!(&result, LiquidityInfo::Paid((fee_source, _)) if fee_source == who) {
+                // Budget and limits of the fee payer are updated
+                frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                    T::DbWeight::get().reads_writes(4, 4),
+                    dispatch_class,
+                );
+            }
+            return Ok(result);
         }
 
         // In case we are producing XOR, we perform exchange before fees are withdraw to allow 0-XOR accounts to trade
@@ -251,8 +322,13 @@ where
         tip: Self::Balance,
         already_withdrawn: Self::LiquidityInfo,
     ) -> Result<(), TransactionValidityError> {
+        let mut sponsor = None;
         let (fee_source, withdrawn) = match already_withdrawn {
             LiquidityInfo::Paid(opt) => opt,
+            LiquidityInfo::Sponsored(fee_source, paid) => {
+                sponsor = Some(fee_source.clone());
+                (fee_source, Some(paid))
+            }
             LiquidityInfo::Postponed(fee_source, fee) => {
                 let withdraw_reason = if tip.is_zero() {
                     WithdrawReasons::TRANSACTION_PAYMENT
@@ -292,10 +368,19 @@ where
             // Refund to the the account that paid the fees. If this fails, the
             // account might have dropped below the existential balance. In
             // that case we don't refund anything.
+            // Fees paid by the sponsor are refunded to the sponsor budget.
+            let refund_account = match sponsor {
+                Some(_) => T::GetSponsorsAccountId::get(),
+                None => fee_source.clone(),
+            };
             let refund_imbalance =
-                T::XorCurrency::deposit_into_existing(&fee_source, refund_amount).unwrap_or_else(
-                    |_| <T::XorCurrency as Currency<T::AccountId>>::PositiveImbalance::zero(),
-                );
+                T::XorCurrency::deposit_into_existing(&refund_account, refund_amount)
+                    .unwrap_or_else(|_| {
+                        <T::XorCurrency as Currency<T::AccountId>>::PositiveImbalance::zero()
+                    });
+            if let Some(sponsor) = sponsor {
+                Self::refund_sponsored_fee(&sponsor, who, refund_imbalance.peek().into());
+            }
 
             // Offset the imbalance caused by paying the fees against the refunded amount.
             let adjusted_paid = paid
@@ -441,7 +526,11 @@ pub trait WithdrawFee<T: Config> {
         who: &T::AccountId,
         call: &CallOf<T>,
         fee: Balance,
-    ) -> Result<(T::AccountId, Option<NegativeImbalanceOf<T>>), DispatchError>;
+    ) -> Result<LiquidityInfo<T>, DispatchError>;
+
+    /// Returns `true` if `fee` for `call` is withdrawn from `who` and not from
+    /// the referrer or the sponsor
+    fn is_fee_paid_by_user(who: &T::AccountId, call: &CallOf<T>, fee: Balance) -> bool;
 }
 
 /// Implementation for unchecked extrinsic.
@@ -526,6 +615,176 @@ impl<T: Config> Pallet<T> {
         res
     }
 
+    /// Returns amount of `asset_id` which is charged instead of `fee` in XOR.
+    /// The amount is calculated by average price with the safety margin of the fee asset.
+    pub fn fee_amount_in_asset(
+        asset_id: &T::AssetId,
+        fee: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let price_margin =
+            FeeAssets::<T>::get(asset_id).ok_or(Error::<T>::FeeAssetIsNotWhitelisted)?;
+        let price =
+            T::PriceTools::get_average_price(&T::XorId::get(), asset_id, PriceVariant::Buy)?;
+
+        (FixedWrapper::from(fee)
+            * FixedWrapper::from(price)
+            * FixedWrapper::from(balance!(1) + price_margin * balance!(1)))
+        .try_into_balance()
+        .map_err(|_| Error::<T>::FeeCalculationFailed.into())
+    }
+
+    /// Returns amount of `asset_id` which is charged instead of `fee` in XOR if the exchange
+    /// of this amount is quoted to cover the fee.
+    pub fn quote_fee_asset(asset_id: &T::AssetId, fee: Balance) -> Result<Balance, DispatchError> {
+        let fee_amount = Self::fee_amount_in_asset(asset_id, fee)?;
+        let dex_id = T::DEXIdValue::get();
+        let outcome = T::LiquidityProxy::quote(
+            dex_id,
+            asset_id,
+            &T::XorId::get(),
+            QuoteAmount::with_desired_input(fee_amount),
+            LiquiditySourceFilter::with_forbidden(
+                dex_id,
+                [LiquiditySourceType::MulticollateralBondingCurvePool].into(),
+            ),
+            true,
+        )?;
+        ensure!(outcome.amount >= fee, Error::<T>::FeeNotCovered);
+        Ok(fee_amount)
+    }
+
+    /// Exchanges fee asset of `who` for XOR which is then used to pay `fee`.
+    /// Returns amount of XOR received by `who`.
+    pub fn exchange_fee_asset(
+        who: &T::AccountId,
+        asset_id: &T::AssetId,
+        fee: Balance,
+    ) -> Result<Balance, DispatchError> {
+        let fee_amount = Self::fee_amount_in_asset(asset_id, fee)?;
+        let tech_account_id = <T as Config>::GetTechnicalAccountId::get();
+        let xor = T::XorId::get();
+
+        common::with_transaction(|| {
+            Assets::<T>::transfer_from(asset_id, who, &tech_account_id, fee_amount)?;
+            // Fee asset is exchanged for XOR which is burned and then reminted to the user,
+            // so the fee itself is withdrawn in XOR as usual
+            let xor_amount =
+                T::BuyBackHandler::buy_back_and_burn(&tech_account_id, asset_id, &xor, fee_amount)?;
+            ensure!(xor_amount >= fee, Error::<T>::FeeNotCovered);
+            Assets::<T>::mint_to(&xor, &tech_account_id, who, xor_amount)?;
+
+            Self::deposit_event(Event::FeeAssetExchanged(
+                who.clone(),
+                *asset_id,
+                fee_amount,
+                xor_amount,
+            ));
+            Ok(xor_amount)
+        })
+    }
+
+    /// Returns the sponsor chosen by `who` if the sponsor rules cover `fee` for `call`.
+    pub fn fee_sponsor(who: &T::AccountId, call: &CallOf<T>, fee: Balance) -> Option<T::AccountId>
+    where
+        CallOf<T>: GetCallMetadata,
+    {
+        let sponsor = UserSponsors::<T>::get(who)?;
+        if !SponsoredUsers::<T>::get(&sponsor, who) {
+            return None;
+        }
+        let info = Sponsors::<T>::get(&sponsor)?;
+
+        if !info.allowed_calls.is_empty() {
+            let metadata = call.get_call_metadata();
+            if !info
+                .allowed_calls
+                .iter()
+                .any(|c| c.matches(metadata.pallet_name, metadata.function_name))
+            {
+                return None;
+            }
+        }
+
+        if info.budget < fee {
+            return None;
+        }
+
+        let user_fees = SponsoredFees::<T>::get(&sponsor, who).saturating_add(fee);
+        if user_fees > info.user_cap {
+            return None;
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let (block, block_fees) = SponsoredBlockFees::<T>::get(&sponsor);
+        let block_fees = if block == now {
+            block_fees.saturating_add(fee)
+        } else {
+            fee
+        };
+        if block_fees > info.block_cap {
+            return None;
+        }
+
+        Some(sponsor)
+    }
+
+    /// Withdraws `fee` from the budget of the sponsor chosen by `who`.
+    /// Returns `None` if the user has no sponsor or the sponsor rules do not cover the fee,
+    /// in which case the fee is paid by the user.
+    pub fn withdraw_sponsored_fee(
+        who: &T::AccountId,
+        call: &CallOf<T>,
+        fee: Balance,
+    ) -> Option<(T::AccountId, NegativeImbalanceOf<T>)>
+    where
+        CallOf<T>: GetCallMetadata,
+    {
+        let sponsor = Self::fee_sponsor(who, call, fee)?;
+        let mut info = Sponsors::<T>::get(&sponsor)?;
+        let user_fees = SponsoredFees::<T>::get(&sponsor, who).saturating_add(fee);
+        let now = frame_system::Pallet::<T>::block_number();
+        let (block, block_fees) = SponsoredBlockFees::<T>::get(&sponsor);
+        let block_fees = if block == now {
+            block_fees.saturating_add(fee)
+        } else {
+            fee
+        };
+
+        let imbalance = T::XorCurrency::withdraw(
+            &T::GetSponsorsAccountId::get(),
+            BalanceOf::<T>::saturated_from(fee),
+            WithdrawReasons::TRANSACTION_PAYMENT,
+            ExistenceRequirement::AllowDeath,
+        )
+        .ok()?;
+        info.budget -= fee;
+        Sponsors::<T>::insert(&sponsor, info);
+        SponsoredFees::<T>::insert(&sponsor, who, user_fees);
+        SponsoredBlockFees::<T>::insert(&sponsor, (now, block_fees));
+
+        Self::deposit_event(Event::FeeSponsored(sponsor.clone(), who.clone(), fee));
+        Some((sponsor, imbalance))
+    }
+
+    /// Returns `refund` of the fee paid for `who` to the budget of `sponsor`.
+    fn refund_sponsored_fee(sponsor: &T::AccountId, who: &T::AccountId, refund: Balance) {
+        if refund.is_zero() {
+            return;
+        }
+        Sponsors::<T>::mutate(sponsor, |info| {
+            if let Some(info) = info {
+                info.budget = info.budget.saturating_add(refund);
+            }
+        });
+        SponsoredFees::<T>::mutate(sponsor, who, |fees| *fees = fees.saturating_sub(refund));
+        let now = frame_system::Pallet::<T>::block_number();
+        SponsoredBlockFees::<T>::mutate(sponsor, |(block, block_fees)| {
+            if *block == now {
+                *block_fees = block_fees.saturating_sub(refund);
+            }
+        });
+    }
+
     pub fn remint(xor_to_val: Balance) -> Result<(), DispatchError> {
         let tech_account_id = <T as Config>::GetTechnicalAccountId::get();
         let xor = T::XorId::get();
@@ -630,11 +889,15 @@ pub mod pallet {
         type OnValBurned: OnValBurned;
         type CustomFees: ApplyCustomFees<CallOf<Self>>;
         type GetTechnicalAccountId: Get<Self::AccountId>;
+        /// Account which holds the budgets of the fee sponsors
+        type GetSponsorsAccountId: Get<Self::AccountId>;
         type SessionManager: pallet_session::historical::SessionManager<
             Self::AccountId,
             <Self as pallet_session::historical::Config>::FullIdentification,
         >;
         type BuyBackHandler: BuyBackHandler<Self::AccountId, Self::AssetId>;
+        /// To retrieve average prices of fee assets
+        type PriceTools: PriceToolsPallet<Self::AssetId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         type WithdrawFee: WithdrawFee<Self>;
@@ -669,6 +932,213 @@ pub mod pallet {
             Self::deposit_event(Event::WeightToFeeMultiplierUpdated(new_multiplier));
             Ok(().into())
         }
+
+        /// Allow paying transaction fees in `asset_id`.
+        /// `price_margin` is added to the average price of the asset to cover the exchange slippage.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::whitelist_fee_asset())]
+        pub fn whitelist_fee_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            price_margin: Percent,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(asset_id != T::XorId::get(), Error::<T>::XorCannotBeFeeAsset);
+            <FeeAssets<T>>::insert(asset_id, price_margin);
+            Self::deposit_event(Event::FeeAssetWhitelisted(asset_id, price_margin));
+            Ok(().into())
+        }
+
+        /// Disallow paying transaction fees in `asset_id`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_fee_asset())]
+        pub fn remove_fee_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                <FeeAssets<T>>::contains_key(asset_id),
+                Error::<T>::FeeAssetIsNotWhitelisted
+            );
+            <FeeAssets<T>>::remove(asset_id);
+            Self::deposit_event(Event::FeeAssetRemoved(asset_id));
+            Ok(().into())
+        }
+
+        /// Register as a fee sponsor or update the rules of the sponsor.
+        ///
+        /// - `allowed_calls`: calls which are sponsored, all calls are sponsored if empty
+        /// - `user_cap`: maximum total amount of fees paid for a single user
+        /// - `block_cap`: maximum amount of fees paid in a single block
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_sponsor_rules())]
+        pub fn set_sponsor_rules(
+            origin: OriginFor<T>,
+            allowed_calls: Vec<SponsoredCall>,
+            user_cap: Balance,
+            block_cap: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let sponsor = ensure_signed(origin)?;
+            ensure!(
+                allowed_calls.len() as u32 <= MAX_SPONSORED_CALLS,
+                Error::<T>::TooManySponsoredCalls
+            );
+
+            let budget = <Sponsors<T>>::get(&sponsor).map_or(0, |info| info.budget);
+            <Sponsors<T>>::insert(
+                &sponsor,
+                SponsorInfo {
+                    budget,
+                    allowed_calls,
+                    user_cap,
+                    block_cap,
+                },
+            );
+
+            // Emit an event
+            Self::deposit_event(Event::SponsorRulesSet(sponsor));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Transfer `amount` of XOR to the sponsors account to pay fees of the sponsored users.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::fund_sponsor_budget())]
+        pub fn fund_sponsor_budget(
+            origin: OriginFor<T>,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let sponsor = ensure_signed(origin)?;
+            let mut info = <Sponsors<T>>::get(&sponsor).ok_or(Error::<T>::SponsorDoesNotExist)?;
+
+            T::XorCurrency::transfer(
+                &sponsor,
+                &T::GetSponsorsAccountId::get(),
+                BalanceOf::<T>::saturated_from(amount),
+                ExistenceRequirement::KeepAlive,
+            )?;
+            info.budget = info.budget.saturating_add(amount);
+            <Sponsors<T>>::insert(&sponsor, info);
+
+            // Emit an event
+            Self::deposit_event(Event::SponsorBudgetFunded(sponsor, amount));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Withdraw `amount` of XOR from the sponsor budget.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_sponsor_budget())]
+        pub fn withdraw_sponsor_budget(
+            origin: OriginFor<T>,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let sponsor = ensure_signed(origin)?;
+            let mut info = <Sponsors<T>>::get(&sponsor).ok_or(Error::<T>::SponsorDoesNotExist)?;
+            ensure!(info.budget >= amount, Error::<T>::InsufficientSponsorBudget);
+
+            T::XorCurrency::transfer(
+                &T::GetSponsorsAccountId::get(),
+                &sponsor,
+                BalanceOf::<T>::saturated_from(amount),
+                ExistenceRequirement::AllowDeath,
+            )?;
+            info.budget -= amount;
+            <Sponsors<T>>::insert(&sponsor, info);
+
+            // Emit an event
+            Self::deposit_event(Event::SponsorBudgetWithdrawn(sponsor, amount));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Stop sponsoring fees and withdraw the remaining budget.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_sponsor())]
+        pub fn remove_sponsor(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let sponsor = ensure_signed(origin)?;
+            let info = <Sponsors<T>>::take(&sponsor).ok_or(Error::<T>::SponsorDoesNotExist)?;
+
+            T::XorCurrency::transfer(
+                &T::GetSponsorsAccountId::get(),
+                &sponsor,
+                BalanceOf::<T>::saturated_from(info.budget),
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            // Emit an event
+            Self::deposit_event(Event::SponsorRemoved(sponsor));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Opt in to have the fees paid by `sponsor`, or opt out if `sponsor` is not set.
+        /// The user has to be approved by the sponsor.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_fee_sponsor())]
+        pub fn set_fee_sponsor(
+            origin: OriginFor<T>,
+            sponsor: Option<AccountIdOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let user = ensure_signed(origin)?;
+
+            match &sponsor {
+                Some(sponsor) => {
+                    ensure!(
+                        <Sponsors<T>>::contains_key(sponsor),
+                        Error::<T>::SponsorDoesNotExist
+                    );
+                    ensure!(
+                        <SponsoredUsers<T>>::get(sponsor, &user),
+                        Error::<T>::UserIsNotApproved
+                    );
+                    <UserSponsors<T>>::insert(&user, sponsor);
+                }
+                None => <UserSponsors<T>>::remove(&user),
+            }
+
+            // Emit an event
+            Self::deposit_event(Event::FeeSponsorSet(user, sponsor));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
+
+        /// Approve `user` to choose the caller as the fee sponsor or revoke the approval.
+        /// The user stops being sponsored when the approval is revoked.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_sponsored_user())]
+        pub fn approve_sponsored_user(
+            origin: OriginFor<T>,
+            user: AccountIdOf<T>,
+            approved: bool,
+        ) -> DispatchResultWithPostInfo {
+            let sponsor = ensure_signed(origin)?;
+            ensure!(
+                <Sponsors<T>>::contains_key(&sponsor),
+                Error::<T>::SponsorDoesNotExist
+            );
+
+            if approved {
+                <SponsoredUsers<T>>::insert(&sponsor, &user, true);
+            } else {
+                <SponsoredUsers<T>>::remove(&sponsor, &user);
+                if <UserSponsors<T>>::get(&user).as_ref() == Some(&sponsor) {
+                    <UserSponsors<T>>::remove(&user);
+                }
+            }
+
+            // Emit an event
+            Self::deposit_event(Event::SponsoredUserApproved(sponsor, user, approved));
+
+            // Return a successful DispatchResult
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -681,6 +1151,47 @@ pub mod pallet {
         /// New multiplier for weight to fee conversion is set
         /// (*1_000_000_000_000_000_000). [New value]
         WeightToFeeMultiplierUpdated(FixedU128),
+        /// Asset is allowed for fee payment. [Asset Id, Price Margin]
+        FeeAssetWhitelisted(T::AssetId, Percent),
+        /// Asset is no longer allowed for fee payment. [Asset Id]
+        FeeAssetRemoved(T::AssetId),
+        /// Fee asset has been exchanged for XOR to pay the fee.
+        /// [Account Id, Asset Id, Asset Amount, XOR Amount]
+        FeeAssetExchanged(AccountIdOf<T>, T::AssetId, Balance, Balance),
+        /// Sponsor rules are set. [Sponsor]
+        SponsorRulesSet(AccountIdOf<T>),
+        /// Sponsor budget is funded. [Sponsor, Amount]
+        SponsorBudgetFunded(AccountIdOf<T>, Balance),
+        /// Sponsor budget is withdrawn. [Sponsor, Amount]
+        SponsorBudgetWithdrawn(AccountIdOf<T>, Balance),
+        /// Sponsor is removed. [Sponsor]
+        SponsorRemoved(AccountIdOf<T>),
+        /// User has chosen the fee sponsor. [User, Sponsor]
+        FeeSponsorSet(AccountIdOf<T>, Option<AccountIdOf<T>>),
+        /// Fee of the user has been paid by the sponsor. [Sponsor, User, Fee Amount]
+        FeeSponsored(AccountIdOf<T>, AccountIdOf<T>, Balance),
+        /// Sponsor has approved or revoked the user. [Sponsor, User, Approved]
+        SponsoredUserApproved(AccountIdOf<T>, AccountIdOf<T>, bool),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Asset is not allowed for fee payment
+        FeeAssetIsNotWhitelisted,
+        /// XOR cannot be whitelisted as fee asset
+        XorCannotBeFeeAsset,
+        /// Failed to calculate fee amount in fee asset
+        FeeCalculationFailed,
+        /// Exchanged XOR amount does not cover the fee
+        FeeNotCovered,
+        /// Sponsor is not registered
+        SponsorDoesNotExist,
+        /// Number of sponsored calls exceeds the limit
+        TooManySponsoredCalls,
+        /// Sponsor budget is lower than the requested amount
+        InsufficientSponsorBudget,
+        /// User is not approved by the sponsor
+        UserIsNotApproved,
     }
 
     /// The amount of XOR to be reminted and exchanged for VAL at the end of the session
@@ -699,6 +1210,55 @@ pub mod pallet {
     #[pallet::getter(fn multiplier)]
     pub type Multiplier<T> = StorageValue<_, FixedU128, ValueQuery, DefaultForFeeMultiplier<T>>;
 
+    /// Assets which can be used to pay transaction fees and their price margins
+    #[pallet::storage]
+    #[pallet::getter(fn fee_assets)]
+    pub type FeeAssets<T: Config> = StorageMap<_, Identity, T::AssetId, Percent, OptionQuery>;
+
+    /// Fee sponsors and their budgets and rules
+    #[pallet::storage]
+    #[pallet::getter(fn sponsors)]
+    pub type Sponsors<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, SponsorInfo, OptionQuery>;
+
+    /// Sponsors chosen by users to pay their fees
+    #[pallet::storage]
+    #[pallet::getter(fn user_sponsors)]
+    pub type UserSponsors<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+    /// Users approved by the sponsor to have their fees paid
+    #[pallet::storage]
+    #[pallet::getter(fn sponsored_users)]
+    pub type SponsoredUsers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Total amount of fees paid by the sponsor for the user
+    #[pallet::storage]
+    #[pallet::getter(fn sponsored_fees)]
+    pub type SponsoredFees<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Amount of fees paid by the sponsor in the last block it has paid fees
+    #[pallet::storage]
+    #[pallet::getter(fn sponsored_block_fees)]
+    pub type SponsoredBlockFees<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (T::BlockNumber, Balance), ValueQuery>;
+
     // This affects `base_fee` and `weight_fee`. `length_fee` is too small
     // in comparison to them, so we should be fine multiplying only this parts.
     impl<T: Config> WeightToFeePolynomial for Pallet<T> {
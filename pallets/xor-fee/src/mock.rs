@@ -34,7 +34,7 @@ use common::prelude::{Balance, BlockLength, QuoteAmount, SwapAmount, SwapOutcome
 use common::{
     self, balance, fixed_from_basis_points, Amount, AssetId32, AssetName, AssetSymbol, DEXInfo,
     Fixed, LiquidityProxyTrait, LiquiditySource, LiquiditySourceFilter, LiquiditySourceType,
-    OnValBurned, PSWAP, VAL, XOR, XST,
+    OnValBurned, PriceToolsPallet, PriceVariant, PSWAP, VAL, XOR, XST,
 };
 use core::time::Duration;
 use currencies::BasicCurrencyAdapter;
@@ -82,6 +82,7 @@ impl AppCrypto<UintAuthorityId, TestSignature> for TestAppCrypto {
 }
 
 pub const BUY_BACK_ACCOUNT: AccountId = 23;
+pub const SPONSORS_ACCOUNT: AccountId = 25;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
@@ -129,6 +130,8 @@ parameter_types! {
     pub const DepositFactor: u64 = 1;
     pub const MaxSignatories: u16 = 4;
     pub const ReferralsReservesAcc: AccountId = 22;
+    pub const ReferralsRewardsAcc: AccountId = 24;
+    pub const MaxReferralTiers: u32 = 10;
 }
 
 sp_runtime::impl_opaque_keys! {
@@ -147,7 +150,7 @@ construct_runtime! {
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Pallet, Call, Config<T>, Storage},
         DexManager: dex_manager::{Pallet, Call, Config<T>, Storage},
         TradingPair: trading_pair::{Pallet, Call, Config<T>, Storage, Event<T>},
-        ReferralSystem: referrals::{Pallet, Call, Config<T>, Storage},
+        ReferralSystem: referrals::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
         Technical: technical::{Pallet, Call, Config<T>, Storage, Event<T>},
@@ -377,7 +380,10 @@ impl trading_pair::Config for Runtime {
 }
 
 impl referrals::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservesAcc = ReferralsReservesAcc;
+    type RewardsAcc = ReferralsRewardsAcc;
+    type MaxReferralTiers = MaxReferralTiers;
     type WeightInfo = ();
 }
 
@@ -432,6 +438,7 @@ parameter_types! {
     pub GetBuyBackSupplyAssets: Vec<AssetId> = vec![VAL, PSWAP];
     pub const GetBuyBackPercentage: u8 = 10;
     pub const GetBuyBackAccountId: AccountId = BUY_BACK_ACCOUNT;
+    pub const GetSponsorsAccountId: AccountId = SPONSORS_ACCOUNT;
     pub const GetBuyBackDexId: DEXId = DEXId::Polkaswap;
 }
 
@@ -475,6 +482,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -510,6 +518,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -649,9 +658,12 @@ pub struct WithdrawFee;
 impl xor_fee::WithdrawFee<Runtime> for WithdrawFee {
     fn withdraw_fee(
         who: &AccountId,
-        _call: &RuntimeCall,
+        call: &RuntimeCall,
         fee: Balance,
-    ) -> Result<(AccountId, Option<crate::NegativeImbalanceOf<Runtime>>), DispatchError> {
+    ) -> Result<crate::LiquidityInfo<Runtime>, DispatchError> {
+        if let Some((sponsor, imbalance)) = XorFee::withdraw_sponsored_fee(who, call, fee) {
+            return Ok(crate::LiquidityInfo::Sponsored(sponsor, imbalance));
+        }
         Ok((
             who.clone(),
             Some(Balances::withdraw(
@@ -660,7 +672,12 @@ impl xor_fee::WithdrawFee<Runtime> for WithdrawFee {
                 WithdrawReasons::TRANSACTION_PAYMENT,
                 ExistenceRequirement::KeepAlive,
             )?),
-        ))
+        )
+            .into())
+    }
+
+    fn is_fee_paid_by_user(who: &AccountId, call: &RuntimeCall, fee: Balance) -> bool {
+        XorFee::fee_sponsor(who, call, fee).is_none()
     }
 }
 
@@ -679,12 +696,34 @@ impl Config for Runtime {
     type OnValBurned = ValBurnedAggregator<Staking>;
     type CustomFees = CustomFees;
     type GetTechnicalAccountId = GetXorFeeAccountId;
+    type GetSponsorsAccountId = GetSponsorsAccountId;
     type SessionManager = Staking;
     type WithdrawFee = WithdrawFee;
     type BuyBackHandler = ();
+    type PriceTools = MockPriceTools;
     type WeightInfo = ();
 }
 
+pub struct MockPriceTools;
+
+impl PriceToolsPallet<AssetId> for MockPriceTools {
+    fn get_average_price(
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        _price_variant: PriceVariant,
+    ) -> Result<Balance, DispatchError> {
+        if input_asset_id == &XOR && output_asset_id == &VAL {
+            Ok(balance!(2))
+        } else {
+            Err(DispatchError::Other("UnsupportedQuotePath"))
+        }
+    }
+
+    fn register_asset(_asset_id: &AssetId) -> frame_support::dispatch::DispatchResult {
+        Ok(())
+    }
+}
+
 // Allow dead_code because we never call swap, just use its Call variant
 #[allow(dead_code)]
 #[frame_support::pallet]
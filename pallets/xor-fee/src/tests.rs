@@ -28,13 +28,19 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::extension::ChargeFeeInAsset;
 use crate::mock::*;
-use common::balance;
+use crate::{Error, LiquidityInfo, SponsoredCall};
+use common::{balance, AssetInfoProvider, VAL, XOR};
 
 use frame_support::error::BadOrigin;
+use frame_support::pallet_prelude::InvalidTransaction;
+use frame_support::traits::Imbalance;
+use frame_support::unsigned::TransactionValidityError;
 use frame_support::weights::{Weight, WeightToFee};
 use frame_support::{assert_noop, assert_ok};
-use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_runtime::traits::SignedExtension;
+use sp_runtime::{FixedPointNumber, FixedU128, Percent};
 
 fn set_weight_to_fee_multiplier(mul: u64) {
     // Set WeightToFee multiplier to one to not affect the test
@@ -115,3 +121,364 @@ fn non_root_update_fails() {
         );
     });
 }
+
+#[test]
+fn whitelist_fee_asset_works() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        assert_ok!(XorFee::whitelist_fee_asset(
+            RuntimeOrigin::root(),
+            VAL,
+            Percent::from_percent(10)
+        ));
+        assert_eq!(XorFee::fee_assets(VAL), Some(Percent::from_percent(10)));
+
+        assert_ok!(XorFee::remove_fee_asset(RuntimeOrigin::root(), VAL));
+        assert_eq!(XorFee::fee_assets(VAL), None);
+    });
+}
+
+#[test]
+fn whitelist_fee_asset_fails() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        assert_noop!(
+            XorFee::whitelist_fee_asset(RuntimeOrigin::signed(1), VAL, Percent::from_percent(10)),
+            BadOrigin
+        );
+
+        assert_noop!(
+            XorFee::whitelist_fee_asset(RuntimeOrigin::root(), XOR, Percent::from_percent(10)),
+            Error::<Runtime>::XorCannotBeFeeAsset
+        );
+
+        assert_noop!(
+            XorFee::remove_fee_asset(RuntimeOrigin::root(), VAL),
+            Error::<Runtime>::FeeAssetIsNotWhitelisted
+        );
+    });
+}
+
+#[test]
+fn fee_amount_in_asset_works() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        assert_noop!(
+            XorFee::fee_amount_in_asset(&VAL, balance!(1)),
+            Error::<Runtime>::FeeAssetIsNotWhitelisted
+        );
+
+        assert_ok!(XorFee::whitelist_fee_asset(
+            RuntimeOrigin::root(),
+            VAL,
+            Percent::from_percent(10)
+        ));
+
+        // 1 XOR = 2 VAL with 10% margin
+        assert_eq!(
+            XorFee::fee_amount_in_asset(&VAL, balance!(0.5)),
+            Ok(balance!(1.1))
+        );
+    });
+}
+
+#[test]
+fn charge_fee_in_not_whitelisted_asset_fails() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        let call: RuntimeCall = crate::Call::update_multiplier {
+            new_multiplier: FixedU128::from(1),
+        }
+        .into();
+
+        assert_eq!(
+            ChargeFeeInAsset::<Runtime>::from(Some(VAL)).pre_dispatch(
+                &FROM_ACCOUNT,
+                &call,
+                &info_from_weight(Weight::from_parts(100_000_000, 0)),
+                10
+            ),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            ))
+        );
+
+        // Fee is paid in XOR if fee asset is not given
+        assert_ok!(ChargeFeeInAsset::<Runtime>::new().pre_dispatch(
+            &FROM_ACCOUNT,
+            &call,
+            &info_from_weight(Weight::from_parts(100_000_000, 0)),
+            10
+        ));
+    });
+}
+
+#[test]
+fn charge_fee_in_asset_skips_sponsored_fee() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        let call: RuntimeCall = crate::Call::update_multiplier {
+            new_multiplier: FixedU128::from(1),
+        }
+        .into();
+        let info = info_from_weight(Weight::from_parts(100_000_000, 0));
+
+        assert_ok!(XorFee::whitelist_fee_asset(
+            RuntimeOrigin::root(),
+            VAL,
+            Percent::from_percent(10)
+        ));
+
+        // User has no VAL to exchange
+        assert_eq!(
+            ChargeFeeInAsset::<Runtime>::from(Some(VAL)).pre_dispatch(
+                &FROM_ACCOUNT,
+                &call,
+                &info,
+                10
+            ),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            ))
+        );
+
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            vec![sponsored_call()],
+            balance!(100),
+            balance!(100)
+        ));
+        assert_ok!(XorFee::fund_sponsor_budget(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            balance!(100)
+        ));
+        assert_ok!(XorFee::approve_sponsored_user(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            FROM_ACCOUNT,
+            true
+        ));
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(FROM_ACCOUNT),
+            Some(TO_ACCOUNT)
+        ));
+
+        // Fee is paid by the sponsor, so nothing is exchanged
+        assert_ok!(ChargeFeeInAsset::<Runtime>::from(Some(VAL)).pre_dispatch(
+            &FROM_ACCOUNT,
+            &call,
+            &info,
+            10
+        ));
+        assert_eq!(Assets::free_balance(&VAL, &FROM_ACCOUNT), Ok(0));
+    });
+}
+
+fn sponsored_call() -> SponsoredCall {
+    SponsoredCall {
+        pallet_name: b"XorFee".to_vec().try_into().unwrap(),
+        call_name: Some(b"update_multiplier".to_vec().try_into().unwrap()),
+    }
+}
+
+#[test]
+fn sponsor_budget_works() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        assert_noop!(
+            XorFee::fund_sponsor_budget(RuntimeOrigin::signed(TO_ACCOUNT), balance!(10)),
+            Error::<Runtime>::SponsorDoesNotExist
+        );
+        assert_noop!(
+            XorFee::set_sponsor_rules(
+                RuntimeOrigin::signed(TO_ACCOUNT),
+                vec![sponsored_call(); crate::MAX_SPONSORED_CALLS as usize + 1],
+                balance!(1),
+                balance!(1)
+            ),
+            Error::<Runtime>::TooManySponsoredCalls
+        );
+
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            vec![sponsored_call()],
+            balance!(1),
+            balance!(1)
+        ));
+        assert_ok!(XorFee::fund_sponsor_budget(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            balance!(10)
+        ));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT).unwrap().budget, balance!(10));
+        assert_eq!(Balances::free_balance(SPONSORS_ACCOUNT), balance!(10));
+
+        assert_ok!(XorFee::withdraw_sponsor_budget(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            balance!(4)
+        ));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT).unwrap().budget, balance!(6));
+        assert_eq!(Balances::free_balance(SPONSORS_ACCOUNT), balance!(6));
+        assert_noop!(
+            XorFee::withdraw_sponsor_budget(RuntimeOrigin::signed(TO_ACCOUNT), balance!(7)),
+            Error::<Runtime>::InsufficientSponsorBudget
+        );
+
+        // Updating the rules keeps the budget
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            vec![],
+            balance!(2),
+            balance!(2)
+        ));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT).unwrap().budget, balance!(6));
+
+        assert_ok!(XorFee::remove_sponsor(RuntimeOrigin::signed(TO_ACCOUNT)));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT), None);
+        assert_eq!(Balances::free_balance(SPONSORS_ACCOUNT), 0);
+        assert_eq!(Balances::free_balance(TO_ACCOUNT), initial_balance());
+    });
+}
+
+#[test]
+fn set_fee_sponsor_works() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        assert_noop!(
+            XorFee::set_fee_sponsor(RuntimeOrigin::signed(FROM_ACCOUNT), Some(TO_ACCOUNT)),
+            Error::<Runtime>::SponsorDoesNotExist
+        );
+
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            vec![],
+            balance!(1),
+            balance!(1)
+        ));
+        assert_noop!(
+            XorFee::set_fee_sponsor(RuntimeOrigin::signed(FROM_ACCOUNT), Some(TO_ACCOUNT)),
+            Error::<Runtime>::UserIsNotApproved
+        );
+        assert_noop!(
+            XorFee::approve_sponsored_user(RuntimeOrigin::signed(FROM_ACCOUNT), TO_ACCOUNT, true),
+            Error::<Runtime>::SponsorDoesNotExist
+        );
+
+        assert_ok!(XorFee::approve_sponsored_user(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            FROM_ACCOUNT,
+            true
+        ));
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(FROM_ACCOUNT),
+            Some(TO_ACCOUNT)
+        ));
+        assert_eq!(XorFee::user_sponsors(FROM_ACCOUNT), Some(TO_ACCOUNT));
+
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(FROM_ACCOUNT),
+            None
+        ));
+        assert_eq!(XorFee::user_sponsors(FROM_ACCOUNT), None);
+
+        // Revoking the approval stops the sponsorship
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(FROM_ACCOUNT),
+            Some(TO_ACCOUNT)
+        ));
+        assert_ok!(XorFee::approve_sponsored_user(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            FROM_ACCOUNT,
+            false
+        ));
+        assert_eq!(XorFee::user_sponsors(FROM_ACCOUNT), None);
+        assert_noop!(
+            XorFee::set_fee_sponsor(RuntimeOrigin::signed(FROM_ACCOUNT), Some(TO_ACCOUNT)),
+            Error::<Runtime>::UserIsNotApproved
+        );
+    });
+}
+
+#[test]
+fn sponsored_fee_works() {
+    let mut ext = ExtBuilder::build();
+    ext.execute_with(|| {
+        let call: RuntimeCall = crate::Call::update_multiplier {
+            new_multiplier: FixedU128::from(1),
+        }
+        .into();
+        let withdraw_fee = |call: &RuntimeCall| {
+            <WithdrawFee as crate::WithdrawFee<Runtime>>::withdraw_fee(
+                &FROM_ACCOUNT,
+                call,
+                balance!(0.5),
+            )
+            .map(|result| match result {
+                LiquidityInfo::Paid((fee_source, imbalance)) => {
+                    (fee_source, imbalance.unwrap().peek())
+                }
+                LiquidityInfo::Sponsored(sponsor, imbalance) => (sponsor, imbalance.peek()),
+                _ => unreachable!(),
+            })
+        };
+
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            vec![sponsored_call()],
+            balance!(1),
+            balance!(0.7)
+        ));
+        assert_ok!(XorFee::fund_sponsor_budget(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            balance!(10)
+        ));
+
+        // User pays the fee until they opt in
+        assert_eq!(withdraw_fee(&call), Ok((FROM_ACCOUNT, balance!(0.5))));
+        assert_ok!(XorFee::approve_sponsored_user(
+            RuntimeOrigin::signed(TO_ACCOUNT),
+            FROM_ACCOUNT,
+            true
+        ));
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(FROM_ACCOUNT),
+            Some(TO_ACCOUNT)
+        ));
+
+        System::set_block_number(1);
+        assert_eq!(withdraw_fee(&call), Ok((TO_ACCOUNT, balance!(0.5))));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT).unwrap().budget, balance!(9.5));
+        assert_eq!(Balances::free_balance(SPONSORS_ACCOUNT), balance!(9.5));
+        assert_eq!(
+            XorFee::sponsored_fees(TO_ACCOUNT, FROM_ACCOUNT),
+            balance!(0.5)
+        );
+        System::assert_last_event(
+            crate::Event::FeeSponsored(TO_ACCOUNT, FROM_ACCOUNT, balance!(0.5)).into(),
+        );
+
+        // Block cap is exceeded
+        assert_eq!(withdraw_fee(&call), Ok((FROM_ACCOUNT, balance!(0.5))));
+
+        // Call is not allowed by the sponsor rules
+        let not_allowed_call: RuntimeCall = crate::Call::remove_fee_asset { asset_id: VAL }.into();
+        System::set_block_number(2);
+        assert_eq!(
+            withdraw_fee(&not_allowed_call),
+            Ok((FROM_ACCOUNT, balance!(0.5)))
+        );
+
+        assert_eq!(withdraw_fee(&call), Ok((TO_ACCOUNT, balance!(0.5))));
+        assert_eq!(
+            XorFee::sponsored_fees(TO_ACCOUNT, FROM_ACCOUNT),
+            balance!(1)
+        );
+
+        // User cap is exceeded
+        System::set_block_number(3);
+        assert_eq!(withdraw_fee(&call), Ok((FROM_ACCOUNT, balance!(0.5))));
+        assert_eq!(XorFee::sponsors(TO_ACCOUNT).unwrap().budget, balance!(9));
+        assert_eq!(
+            Balances::free_balance(FROM_ACCOUNT),
+            initial_balance() - balance!(2)
+        );
+    });
+}
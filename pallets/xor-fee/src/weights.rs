@@ -60,6 +60,15 @@ use core::marker::PhantomData;
 /// Weight functions needed for xor_fee.
 pub trait WeightInfo {
 	fn update_multiplier() -> Weight;
+	fn whitelist_fee_asset() -> Weight;
+	fn remove_fee_asset() -> Weight;
+	fn set_sponsor_rules() -> Weight;
+	fn fund_sponsor_budget() -> Weight;
+	fn withdraw_sponsor_budget() -> Weight;
+	fn remove_sponsor() -> Weight;
+	fn set_fee_sponsor() -> Weight;
+	fn exchange_fee_asset() -> Weight;
+	fn approve_sponsored_user() -> Weight;
 }
 
 /// Weights for xor_fee using the Substrate node and recommended hardware.
@@ -75,6 +84,134 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(15_681_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: XorFee FeeAssets (r:0 w:1)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	fn whitelist_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_802_000 picoseconds.
+		Weight::from_parts(15_194_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee FeeAssets (r:1 w:1)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	fn remove_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2621`
+		// Minimum execution time: 18_463_000 picoseconds.
+		Weight::from_parts(18_955_000, 2621)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	fn set_sponsor_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `2517`
+		// Minimum execution time: 24_106_000 picoseconds.
+		Weight::from_parts(24_817_000, 2517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `8743`
+		// Minimum execution time: 58_412_000 picoseconds.
+		Weight::from_parts(59_637_000, 8743)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `414`
+		//  Estimated: `8785`
+		// Minimum execution time: 57_906_000 picoseconds.
+		Weight::from_parts(58_881_000, 8785)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `414`
+		//  Estimated: `8785`
+		// Minimum execution time: 57_144_000 picoseconds.
+		Weight::from_parts(58_203_000, 8785)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:0)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee SponsoredUsers (r:1 w:0)
+	/// Proof Skipped: XorFee SponsoredUsers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee UserSponsors (r:0 w:1)
+	/// Proof Skipped: XorFee UserSponsors (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `5516`
+		// Minimum execution time: 26_712_000 picoseconds.
+		Weight::from_parts(27_458_000, 5516)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee FeeAssets (r:1 w:0)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:1 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Reserves (r:1 w:1)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:1 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEXManager DEXInfos (r:1 w:0)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:2 w:2)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	fn exchange_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3421`
+		//  Estimated: `38105`
+		// Minimum execution time: 412_337_000 picoseconds.
+		Weight::from_parts(421_904_000, 38105)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:0)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee UserSponsors (r:1 w:1)
+	/// Proof Skipped: XorFee UserSponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee SponsoredUsers (r:0 w:1)
+	/// Proof Skipped: XorFee SponsoredUsers (max_values: None, max_size: None, mode: Measured)
+	fn approve_sponsored_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `5316`
+		// Minimum execution time: 25_981_000 picoseconds.
+		Weight::from_parts(26_674_000, 5316)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -89,4 +226,132 @@ impl WeightInfo for () {
 		Weight::from_parts(15_681_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: XorFee FeeAssets (r:0 w:1)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	fn whitelist_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_802_000 picoseconds.
+		Weight::from_parts(15_194_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee FeeAssets (r:1 w:1)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	fn remove_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2621`
+		// Minimum execution time: 18_463_000 picoseconds.
+		Weight::from_parts(18_955_000, 2621)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	fn set_sponsor_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `2517`
+		// Minimum execution time: 24_106_000 picoseconds.
+		Weight::from_parts(24_817_000, 2517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `8743`
+		// Minimum execution time: 58_412_000 picoseconds.
+		Weight::from_parts(59_637_000, 8743)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `414`
+		//  Estimated: `8785`
+		// Minimum execution time: 57_906_000 picoseconds.
+		Weight::from_parts(58_881_000, 8785)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:1)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `414`
+		//  Estimated: `8785`
+		// Minimum execution time: 57_144_000 picoseconds.
+		Weight::from_parts(58_203_000, 8785)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:0)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee SponsoredUsers (r:1 w:0)
+	/// Proof Skipped: XorFee SponsoredUsers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee UserSponsors (r:0 w:1)
+	/// Proof Skipped: XorFee UserSponsors (max_values: None, max_size: None, mode: Measured)
+	fn set_fee_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `5516`
+		// Minimum execution time: 26_712_000 picoseconds.
+		Weight::from_parts(27_458_000, 5516)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XorFee FeeAssets (r:1 w:0)
+	/// Proof Skipped: XorFee FeeAssets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:1 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoolXYK Reserves (r:1 w:1)
+	/// Proof Skipped: PoolXYK Reserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PoolXYK Properties (r:1 w:0)
+	/// Proof Skipped: PoolXYK Properties (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DEXManager DEXInfos (r:1 w:0)
+	/// Proof Skipped: DEXManager DEXInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:2 w:2)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	fn exchange_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3421`
+		//  Estimated: `38105`
+		// Minimum execution time: 412_337_000 picoseconds.
+		Weight::from_parts(421_904_000, 38105)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: XorFee Sponsors (r:1 w:0)
+	/// Proof Skipped: XorFee Sponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee UserSponsors (r:1 w:1)
+	/// Proof Skipped: XorFee UserSponsors (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XorFee SponsoredUsers (r:0 w:1)
+	/// Proof Skipped: XorFee SponsoredUsers (max_values: None, max_size: None, mode: Measured)
+	fn approve_sponsored_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `5316`
+		// Minimum execution time: 25_981_000 picoseconds.
+		Weight::from_parts(26_674_000, 5316)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
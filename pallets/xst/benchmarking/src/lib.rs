@@ -38,27 +38,29 @@
 use assets::Event as AssetsEvent;
 use band::Pallet as Band;
 use codec::{Decode as _, Encode as _};
-use common::prelude::{QuoteAmount, SwapAmount};
+use common::prelude::{Balance, QuoteAmount, SwapAmount};
 use common::{
     balance, fixed, AssetName, AssetSymbol, DEXId, LiquiditySource, Oracle, PriceToolsPallet,
     PriceVariant, DAI, XST, XSTUSD,
 };
 use frame_benchmarking::benchmarks;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
-use frame_support::traits::Get;
+use frame_support::traits::{Get, Hooks};
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use oracle_proxy::Pallet as OracleProxy;
 use price_tools::Pallet as PriceTools;
+use sp_runtime::Percent;
 use sp_std::prelude::*;
 use technical::Pallet as Technical;
-use xst::{Call, Event, Pallet as XSTPool};
+use xst::{
+    Call, Event, Pallet as XSTPool, PriceTransformation, VolatilityGuard, VolatilityGuardAction,
+};
 
 #[cfg(test)]
 mod mock;
 
 mod utils {
-    use common::AssetId32;
     use frame_support::{dispatch::DispatchErrorWithPostInfo, Parameter};
 
     use super::*;
@@ -70,13 +72,6 @@ mod utils {
         Symbol::decode(&mut &bytes[..]).expect("Failed to decode symbol")
     }
 
-    pub fn symbol_asset_id<T: Config>() -> T::AssetId {
-        AssetId32::<common::PredefinedAssetId>::from_synthetic_reference_symbol(&symbol::<
-            <T as xst::Config>::Symbol,
-        >())
-        .into()
-    }
-
     pub fn permissioned_account_id<T: Config>() -> T::AccountId {
         let permissioned_tech_account_id = T::GetXSTPoolPermissionedTechAccountId::get();
         Technical::<T>::tech_account_id_to_account_id(&permissioned_tech_account_id)
@@ -135,9 +130,13 @@ mod utils {
     pub fn relay_symbol<T: Config>() -> DispatchResultWithPostInfo {
         OracleProxy::<T>::enable_oracle(RawOrigin::Root.into(), Oracle::BandChainFeed)?;
         Band::<T>::add_relayers(RawOrigin::Root.into(), vec![alice::<T>()])?;
+        update_symbol_rate::<T>(1000000000)
+    }
+
+    pub fn update_symbol_rate<T: Config>(rate: u64) -> DispatchResultWithPostInfo {
         Band::<T>::relay(
             RawOrigin::Signed(alice::<T>()).into(),
-            vec![(symbol::<<T as band::Config>::Symbol>(), 1000000000)]
+            vec![(symbol::<<T as band::Config>::Symbol>(), rate)]
                 .try_into()
                 .unwrap(),
             0,
@@ -145,6 +144,54 @@ mod utils {
         )
     }
 
+    pub fn volatility_guard<T: Config>() -> VolatilityGuard<T::BlockNumber> {
+        VolatilityGuard {
+            max_price_change: Percent::from_percent(10),
+            period: 100u32.into(),
+            max_rate_age: u64::MAX,
+            action: VolatilityGuardAction::Pause,
+        }
+    }
+
+    /// Enable synthetic asset and XST as its collateral with 150% minimal collateral ratio,
+    /// open the debt position of Alice if `collateral` is not zero
+    pub fn setup_cdp<T: Config>(
+        collateral: Balance,
+        debt: Balance,
+    ) -> Result<T::AssetId, DispatchErrorWithPostInfo> {
+        setup_exchange_benchmark::<T>();
+        let asset_id = enable_synthetic_asset::<T>()?;
+        XSTPool::<T>::set_cdp_collateral_ratio(
+            RawOrigin::Root.into(),
+            XST.into(),
+            Some(fixed!(1.5)),
+        )?;
+        let amount: i128 = balance!(1000).try_into().unwrap();
+        assets::Pallet::<T>::update_balance(
+            RawOrigin::Root.into(),
+            alice::<T>().into(),
+            XST.into(),
+            amount,
+        )?;
+        if collateral > 0 {
+            XSTPool::<T>::deposit_cdp_collateral(
+                RawOrigin::Signed(alice::<T>()).into(),
+                XST.into(),
+                asset_id,
+                collateral,
+            )?;
+        }
+        if debt > 0 {
+            XSTPool::<T>::mint_cdp_synthetic(
+                RawOrigin::Signed(alice::<T>()).into(),
+                XST.into(),
+                asset_id,
+                debt,
+            )?;
+        }
+        Ok(asset_id)
+    }
+
     pub fn enable_synthetic_asset<T: Config>() -> Result<T::AssetId, DispatchErrorWithPostInfo> {
         relay_symbol::<T>()?;
         XSTPool::<T>::register_synthetic_asset(
@@ -153,6 +200,7 @@ mod utils {
             AssetName(b"Sora Synthetic EURO".to_vec()),
             symbol::<<T as xst::Config>::Symbol>(),
             fixed!(0),
+            PriceTransformation::Identity,
         )?;
 
         Ok(
@@ -214,27 +262,27 @@ benchmarks! {
     register_synthetic_asset {
         let permissioned_account_id = utils::permissioned_account_id::<T>();
         let reference_symbol = utils::symbol::<<T as xst::Config>::Symbol>();
+        // Leveraged synthetic is the most expensive one to register
+        let price_transformation = PriceTransformation::Leverage(fixed!(2));
+        let asset_id = XSTPool::<T>::synthetic_asset_id(&reference_symbol, &price_transformation);
         utils::relay_symbol::<T>()?;
     }: _(
         RawOrigin::Root,
         AssetSymbol(b"XSTEURO".to_vec()),
         AssetName(b"Sora Synthetic EURO".to_vec()),
         reference_symbol,
-        fixed!(0)
+        fixed!(0),
+        price_transformation
     )
     verify {
         utils::assert_last_assets_event::<T>(
             AssetsEvent::AssetRegistered(
-                utils::symbol_asset_id::<T>(),
+                asset_id,
                 permissioned_account_id
             ).into()
         );
-        assert!(
-            XSTPool::<T>::enabled_symbols(
-                utils::symbol::<<T as xst::Config>::Symbol>()
-            )
-            .is_some()
-        );
+        assert!(XSTPool::<T>::enabled_synthetics(asset_id).is_some());
+        assert!(XSTPool::<T>::leverage_rebalances(asset_id).is_some());
     }
 
     set_synthetic_asset_fee {
@@ -282,5 +330,111 @@ benchmarks! {
         ).unwrap();
     }
 
+    set_cdp_collateral_ratio {
+    }: _(
+        RawOrigin::Root,
+        XST.into(),
+        Some(fixed!(1.5))
+    )
+    verify {
+        utils::assert_last_event::<T>(Event::CdpCollateralRatioChanged(XST.into(), Some(fixed!(1.5))).into())
+    }
+
+    deposit_cdp_collateral {
+        let asset_id = utils::setup_cdp::<T>(0, 0)?;
+    }: _(
+        RawOrigin::Signed(utils::alice::<T>()),
+        XST.into(),
+        asset_id,
+        balance!(300)
+    )
+    verify {
+        assert_eq!(XSTPool::<T>::cdps(utils::alice::<T>(), (T::AssetId::from(XST), asset_id)).collateral, balance!(300));
+    }
+
+    withdraw_cdp_collateral {
+        let asset_id = utils::setup_cdp::<T>(balance!(300), balance!(100))?;
+    }: _(
+        RawOrigin::Signed(utils::alice::<T>()),
+        XST.into(),
+        asset_id,
+        balance!(100)
+    )
+    verify {
+        assert_eq!(XSTPool::<T>::cdps(utils::alice::<T>(), (T::AssetId::from(XST), asset_id)).collateral, balance!(200));
+    }
+
+    mint_cdp_synthetic {
+        let asset_id = utils::setup_cdp::<T>(balance!(300), balance!(100))?;
+    }: _(
+        RawOrigin::Signed(utils::alice::<T>()),
+        XST.into(),
+        asset_id,
+        balance!(50)
+    )
+    verify {
+        assert_eq!(XSTPool::<T>::cdps(utils::alice::<T>(), (T::AssetId::from(XST), asset_id)).debt, balance!(150));
+    }
+
+    repay_cdp_synthetic {
+        let asset_id = utils::setup_cdp::<T>(balance!(300), balance!(100))?;
+    }: _(
+        RawOrigin::Signed(utils::alice::<T>()),
+        XST.into(),
+        asset_id,
+        balance!(50)
+    )
+    verify {
+        assert_eq!(XSTPool::<T>::cdps(utils::alice::<T>(), (T::AssetId::from(XST), asset_id)).debt, balance!(50));
+    }
+
+    liquidate_cdp {
+        let asset_id = utils::setup_cdp::<T>(balance!(300), balance!(150))?;
+        // collateral ratio drops to 80%, so the liquidation leaves bad debt
+        utils::update_symbol_rate::<T>(2500000000)?;
+        let liquidator: T::AccountId = frame_benchmarking::account("liquidator", 0, 0);
+    }: _(
+        RawOrigin::Signed(liquidator),
+        utils::alice::<T>(),
+        XST.into(),
+        asset_id,
+        balance!(150)
+    )
+    verify {
+        assert_eq!(XSTPool::<T>::cdps(utils::alice::<T>(), (T::AssetId::from(XST), asset_id)), Default::default());
+        assert!(XSTPool::<T>::cdp_bad_debt(asset_id) > 0);
+    }
+
+    set_volatility_guard {
+        let asset_id = utils::enable_synthetic_asset::<T>()?;
+        let guard = utils::volatility_guard::<T>();
+    }: _(
+        RawOrigin::Root,
+        asset_id,
+        Some(guard)
+    )
+    verify {
+        utils::assert_last_event::<T>(Event::VolatilityGuardSet(asset_id, Some(guard)).into())
+    }
+
+    resume_synthetic_trading {
+        let asset_id = utils::enable_synthetic_asset::<T>()?;
+        XSTPool::<T>::set_volatility_guard(
+            RawOrigin::Root.into(),
+            asset_id,
+            Some(utils::volatility_guard::<T>()),
+        )?;
+        XSTPool::<T>::on_initialize(1u32.into());
+        utils::update_symbol_rate::<T>(1500000000)?;
+        XSTPool::<T>::on_initialize(2u32.into());
+        assert!(XSTPool::<T>::tripped_volatility_guard(&asset_id)?.is_some());
+    }: _(
+        RawOrigin::Root,
+        asset_id
+    )
+    verify {
+        utils::assert_last_event::<T>(Event::SyntheticTradingResumed(asset_id).into())
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Runtime);
 }
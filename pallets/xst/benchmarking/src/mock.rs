@@ -30,10 +30,11 @@
 
 use crate::Config;
 use common::mock::{ExistentialDeposits, GetTradingPairRestrictedFlag};
-use common::prelude::Balance;
+use common::prelude::{Balance, QuoteAmount, SwapAmount, SwapOutcome};
 use common::{
     self, balance, fixed, hash, Amount, AssetId32, AssetName, AssetSymbol, DEXInfo, Fixed,
-    FromGenericPair, DAI, DEFAULT_BALANCE_PRECISION, PSWAP, USDT, VAL, XOR, XST, XSTUSD,
+    FromGenericPair, LiquidityProxyTrait, LiquiditySource, LiquiditySourceFilter,
+    LiquiditySourceType, DAI, DEFAULT_BALANCE_PRECISION, PSWAP, USDT, VAL, XOR, XST, XSTUSD,
 };
 use currencies::BasicCurrencyAdapter;
 use frame_support::traits::{Everything, GenesisBuild};
@@ -46,7 +47,7 @@ use sp_core::crypto::AccountId32;
 use sp_core::H256;
 use sp_runtime::testing::Header;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Zero};
-use sp_runtime::{Perbill, Percent};
+use sp_runtime::{DispatchError, Perbill, Percent};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -102,6 +103,13 @@ parameter_types! {
         );
         tech_account_id
     };
+    pub GetCdpCollateralTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            xst::TECH_ACCOUNT_PREFIX.to_vec(),
+            xst::TECH_ACCOUNT_CDP_COLLATERAL.to_vec(),
+        );
+        tech_account_id
+    };
     pub GetXSTPoolPermissionedAccountId: AccountId = {
         let tech_account_id = GetXSTPoolPermissionedTechAccountId::get();
         let account_id =
@@ -110,6 +118,7 @@ parameter_types! {
         account_id
     };
     pub const GetSyntheticBaseBuySellLimit: Balance = balance!(10000000000000);
+    pub const GetCdpLiquidationPenalty: Percent = Percent::from_percent(10);
 }
 
 construct_runtime! {
@@ -188,15 +197,57 @@ impl xst::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type GetSyntheticBaseAssetId = GetSyntheticBaseAssetId;
     type GetXSTPoolPermissionedTechAccountId = GetXSTPoolPermissionedTechAccountId;
+    type GetCdpCollateralTechAccountId = GetCdpCollateralTechAccountId;
     type EnsureDEXManager = dex_manager::Pallet<Runtime>;
     type PriceToolsPallet = price_tools::Pallet<Runtime>;
     type Oracle = OracleProxy;
     type Symbol = <Runtime as band::Config>::Symbol;
     type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
     type TradingPairSourceManager = trading_pair::Pallet<Runtime>;
+    type LeverageRebalancePeriod = frame_support::traits::ConstU64<14400>;
+    type LiquidityProxy = MockLiquidityProxy;
+    type CdpLiquidationPenalty = GetCdpLiquidationPenalty;
+    type Time = Timestamp;
     type WeightInfo = ();
 }
 
+/// Liquidity proxy exchanging assets through XST pool only
+pub struct MockLiquidityProxy;
+
+impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockLiquidityProxy {
+    fn quote(
+        dex_id: DEXId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: QuoteAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+        deduce_fee: bool,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        XSTPool::quote(&dex_id, input_asset_id, output_asset_id, amount, deduce_fee)
+            .map(|(outcome, _)| outcome)
+    }
+
+    fn exchange(
+        dex_id: DEXId,
+        sender: &AccountId,
+        receiver: &AccountId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: SwapAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        XSTPool::exchange(
+            sender,
+            receiver,
+            &dex_id,
+            input_asset_id,
+            output_asset_id,
+            amount,
+        )
+        .map(|(outcome, _)| outcome)
+    }
+}
+
 impl band::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Symbol = common::SymbolName;
@@ -207,6 +258,7 @@ impl band::Config for Runtime {
     type GetBandRateStaleBlockPeriod = GetBandRateStaleBlockPeriod;
     type OnSymbolDisabledHook = XSTPool;
     type MaxRelaySymbols = frame_support::traits::ConstU32<100>;
+    type MaxDeviatingRounds = frame_support::traits::ConstU32<3>;
 }
 
 impl oracle_proxy::Config for Runtime {
@@ -214,6 +266,8 @@ impl oracle_proxy::Config for Runtime {
     type WeightInfo = ();
     type Symbol = <Runtime as band::Config>::Symbol;
     type BandChainOracle = Band;
+    type PriceFeedOracle = ();
+    type MaxRateHistory = frame_support::traits::ConstU32<100>;
 }
 
 impl tokens::Config for Runtime {
@@ -331,6 +385,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -347,6 +402,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -55,17 +55,18 @@ use common::prelude::{
     SwapOutcome, DEFAULT_BALANCE_PRECISION,
 };
 use common::{
-    balance, fixed, fixed_wrapper, AssetId32, AssetInfoProvider, AssetName, AssetSymbol, DEXId,
-    DataFeed, GetMarketInfo, LiquiditySource, LiquiditySourceType, OnSymbolDisabled, PriceVariant,
-    Rate, RewardReason, SyntheticInfoProvider, TradingPairSourceManager, XSTUSD,
+    balance, fixed, fixed_const, fixed_wrapper, AssetId32, AssetInfoProvider, AssetName,
+    AssetSymbol, DEXId, DataFeed, GetMarketInfo, LiquidityProxyTrait, LiquiditySource,
+    LiquiditySourceFilter, LiquiditySourceType, OnSymbolDisabled, PriceVariant, Rate, RewardReason,
+    SyntheticInfoProvider, TradingPairSourceManager, XSTUSD,
 };
 use frame_support::pallet_prelude::DispatchResult;
-use frame_support::traits::Get;
+use frame_support::traits::{Get, UnixTime};
 use frame_support::weights::Weight;
 use frame_support::{ensure, fail, RuntimeDebug};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, Percent};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
@@ -76,6 +77,10 @@ type Technical<T> = technical::Pallet<T>;
 
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"xst-pool";
 pub const TECH_ACCOUNT_PERMISSIONED: &[u8] = b"permissioned";
+pub const TECH_ACCOUNT_CDP_COLLATERAL: &[u8] = b"cdp-collateral";
+
+/// Maximal deviation of the liquidation swap price from the price used to value debt positions
+pub const CDP_LIQUIDATION_PRICE_TOLERANCE: Fixed = fixed_const!(0.05);
 
 pub use pallet::*;
 
@@ -125,12 +130,94 @@ pub struct SyntheticInfo<Symbol> {
     fee_ratio: Fixed,
 }
 
+/// Transformation of the reference symbol price into the synthetic asset price
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub enum PriceTransformation {
+    /// Synthetic price equals the reference symbol price.
+    Identity,
+    /// Synthetic price is the inverse of the reference symbol price, i.e. `1 / price`.
+    Inverse,
+    /// Synthetic price follows the reference symbol returns multiplied by the leverage factor,
+    /// the exposure is rebalanced every [`Config::LeverageRebalancePeriod`] blocks and is reset
+    /// to the reference symbol price whenever the synthetic asset is enabled.
+    Leverage(Fixed),
+}
+
+impl Default for PriceTransformation {
+    fn default() -> Self {
+        Self::Identity
+    }
+}
+
+/// Action taken while the volatility guard of the synthetic asset is tripped
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub enum VolatilityGuardAction {
+    /// Swaps of the synthetic asset are paused.
+    Pause,
+    /// Swaps of the synthetic asset are charged with the additional fee ratio.
+    SurgeFee(Fixed),
+}
+
+/// Volatility guard of the synthetic asset price
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub struct VolatilityGuard<BlockNumber> {
+    /// Maximum change of the synthetic asset price within `period`.
+    pub max_price_change: Percent,
+    /// Length of the price observation window in blocks, also the time the price must stay
+    /// stable for the tripped guard to be reset.
+    pub period: BlockNumber,
+    /// Maximum age of the oracle rate in seconds.
+    pub max_rate_age: u64,
+    /// Action taken while the guard is tripped.
+    pub action: VolatilityGuardAction,
+}
+
+/// Observed state of the volatility guard
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub struct VolatilityGuardState<BlockNumber> {
+    /// Synthetic asset price at the start of the observation window.
+    pub reference_price: Balance,
+    /// Start of the observation window.
+    pub window_start: BlockNumber,
+    /// Block at which the guard has been tripped.
+    pub tripped_at: Option<BlockNumber>,
+}
+
+/// Change of the volatility guard state
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+enum VolatilityGuardTransition {
+    Unchanged,
+    Tripped,
+    Reset,
+}
+
+/// Collateralized debt position of the synthetic asset
+#[derive(RuntimeDebug, Clone, Default, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub struct CollateralizedDebtPosition {
+    /// Amount of the locked collateral asset.
+    pub collateral: Balance,
+    /// Amount of the minted synthetic asset to be repaid.
+    pub debt: Balance,
+}
+
+/// State of the leveraged synthetic asset at the last rebalancing
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq, Encode, Decode, scale_info::TypeInfo)]
+pub struct LeverageRebalance<BlockNumber> {
+    /// Reference symbol price at the rebalancing.
+    pub reference_price: Balance,
+    /// Synthetic asset price at the rebalancing.
+    pub synthetic_price: Balance,
+    /// Block of the rebalancing.
+    pub block: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::traits::StorageVersion;
     use frame_support::{pallet_prelude::*, Parameter};
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Zero;
 
     // TODO: #441 use TradingPairSourceManager instead of trading-pair pallet
     #[pallet::config]
@@ -141,6 +228,8 @@ pub mod pallet {
         /// AssetId which is convertible to/from XSTUSD
         type GetSyntheticBaseAssetId: Get<Self::AssetId>;
         type GetXSTPoolPermissionedTechAccountId: Get<Self::TechAccountId>;
+        /// Technical account holding the collateral of the debt positions
+        type GetCdpCollateralTechAccountId: Get<Self::TechAccountId>;
         type EnsureDEXManager: EnsureDEXManager<Self::DEXId, Self::AccountId, DispatchError>;
         type PriceToolsPallet: PriceToolsPallet<Self::AssetId>;
         type Oracle: DataFeed<Self::Symbol, Rate, u64>;
@@ -150,6 +239,16 @@ pub mod pallet {
         #[pallet::constant]
         type GetSyntheticBaseBuySellLimit: Get<Balance>;
         type TradingPairSourceManager: TradingPairSourceManager<Self::DEXId, Self::AssetId>;
+        /// Period in blocks between the rebalancings of the leveraged synthetics
+        #[pallet::constant]
+        type LeverageRebalancePeriod: Get<Self::BlockNumber>;
+        /// Liquidity proxy used to sell the collateral of the liquidated debt positions
+        type LiquidityProxy: LiquidityProxyTrait<Self::DEXId, Self::AccountId, Self::AssetId>;
+        /// Share of the liquidated collateral paid to the liquidator
+        #[pallet::constant]
+        type CdpLiquidationPenalty: Get<Percent>;
+        /// Time used to check the age of the oracle rates
+        type Time: UnixTime;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -163,6 +262,18 @@ pub mod pallet {
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut weight = Self::update_volatility_guards(now);
+            let period = T::LeverageRebalancePeriod::get();
+            if !period.is_zero() && (now % period).is_zero() {
+                weight = weight.saturating_add(Self::rebalance_leveraged_synthetics(now));
+            }
+            weight
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Change reference asset which is used to determine collateral assets value.
@@ -204,6 +315,13 @@ pub mod pallet {
         }
 
         /// Register and enable new synthetic asset with `reference_symbol` price binding
+        ///
+        /// - `origin`: the sudo account on whose behalf the transaction is being executed,
+        /// - `asset_symbol`: symbol of the new synthetic asset,
+        /// - `asset_name`: name of the new synthetic asset,
+        /// - `reference_symbol`: oracle symbol the synthetic asset price is bound to,
+        /// - `fee_ratio`: fee ratio with precision = 18, so 1000000000000000000 = 1 = 100% fee,
+        /// - `price_transformation`: transformation of the reference symbol price, e.g. inverse or leverage.
         #[pallet::call_index(2)]
         #[pallet::weight(<T as Config>::WeightInfo::register_synthetic_asset())]
         pub fn register_synthetic_asset(
@@ -212,16 +330,22 @@ pub mod pallet {
             asset_name: AssetName,
             reference_symbol: T::Symbol,
             fee_ratio: Fixed,
+            price_transformation: PriceTransformation,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
+            Self::ensure_price_transformation_valid(&price_transformation)?;
 
-            let synthetic_asset_id: T::AssetId =
-                AssetId32::<common::PredefinedAssetId>::from_synthetic_reference_symbol(
-                    &reference_symbol,
-                )
-                .into();
+            let synthetic_asset_id =
+                Self::synthetic_asset_id(&reference_symbol, &price_transformation);
 
             Self::register_synthetic_asset_unchecked(synthetic_asset_id, asset_symbol, asset_name)?;
+            if price_transformation != PriceTransformation::Identity {
+                PriceTransformations::<T>::insert(synthetic_asset_id, price_transformation);
+                Self::deposit_event(Event::SyntheticAssetPriceTransformationSet(
+                    synthetic_asset_id,
+                    price_transformation,
+                ));
+            }
             Self::enable_synthetic_asset_unchecked(
                 synthetic_asset_id,
                 reference_symbol,
@@ -267,7 +391,11 @@ pub mod pallet {
                 .reference_symbol;
 
             EnabledSynthetics::<T>::remove(synthetic_asset);
-            EnabledSymbols::<T>::remove(&reference_symbol);
+            if Self::enabled_symbols(&reference_symbol) == Some(synthetic_asset) {
+                EnabledSymbols::<T>::remove(&reference_symbol);
+            }
+            PriceTransformations::<T>::remove(synthetic_asset);
+            LeverageRebalances::<T>::remove(synthetic_asset);
 
             Self::deposit_event(Event::SyntheticAssetRemoved(
                 synthetic_asset,
@@ -327,6 +455,461 @@ pub mod pallet {
             Self::deposit_event(Event::SyntheticBaseAssetFloorPriceChanged(floor_price));
             Ok(().into())
         }
+
+        /// Allow or forbid the asset to be used as collateral of debt positions
+        ///
+        /// - `origin`: root account
+        /// - `collateral_asset_id`: collateral asset
+        /// - `min_collateral_ratio`: minimal ratio of the collateral value to the debt value,
+        /// must be greater than 1, `None` forbids the collateral, so its positions can only be
+        /// repaid and withdrawn, while the ones with debt can be liquidated
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_cdp_collateral_ratio())]
+        pub fn set_cdp_collateral_ratio(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+            min_collateral_ratio: Option<Fixed>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Assets::<T>::ensure_asset_exists(&collateral_asset_id)?;
+            match min_collateral_ratio {
+                Some(ratio) => {
+                    ensure!(ratio > fixed!(1), Error::<T>::InvalidCollateralRatio);
+                    CdpCollateralRatios::<T>::insert(collateral_asset_id, ratio);
+                }
+                None => CdpCollateralRatios::<T>::remove(collateral_asset_id),
+            }
+            Self::deposit_event(Event::CdpCollateralRatioChanged(
+                collateral_asset_id,
+                min_collateral_ratio,
+            ));
+            Ok(().into())
+        }
+
+        /// Lock collateral in the debt position of the synthetic asset
+        ///
+        /// - `origin`: owner of the debt position
+        /// - `collateral_asset_id`: collateral asset allowed by [`Pallet::set_cdp_collateral_ratio`]
+        /// - `synthetic_asset_id`: synthetic asset minted against the collateral
+        /// - `amount`: amount of the collateral to lock
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit_cdp_collateral())]
+        pub fn deposit_cdp_collateral(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+            synthetic_asset_id: T::AssetId,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(amount > 0, Error::<T>::ZeroCdpAmount);
+            ensure!(
+                CdpCollateralRatios::<T>::contains_key(&collateral_asset_id)
+                    && collateral_asset_id != synthetic_asset_id,
+                Error::<T>::InvalidCdpCollateral
+            );
+            ensure!(
+                EnabledSynthetics::<T>::contains_key(&synthetic_asset_id),
+                Error::<T>::SyntheticIsNotEnabled
+            );
+
+            Assets::<T>::transfer_from(
+                &collateral_asset_id,
+                &who,
+                &Self::cdp_collateral_account_id()?,
+                amount,
+            )?;
+            Cdps::<T>::try_mutate(&who, (collateral_asset_id, synthetic_asset_id), |cdp| {
+                cdp.collateral = cdp
+                    .collateral
+                    .checked_add(amount)
+                    .ok_or(Error::<T>::CdpOverflow)?;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::CdpCollateralDeposited(
+                who,
+                collateral_asset_id,
+                synthetic_asset_id,
+                amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Unlock collateral of the debt position, the position must stay above the minimal
+        /// collateral ratio
+        ///
+        /// - `origin`: owner of the debt position
+        /// - `collateral_asset_id`: collateral asset of the position
+        /// - `synthetic_asset_id`: synthetic asset of the position
+        /// - `amount`: amount of the collateral to unlock
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_cdp_collateral())]
+        pub fn withdraw_cdp_collateral(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+            synthetic_asset_id: T::AssetId,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(amount > 0, Error::<T>::ZeroCdpAmount);
+
+            let mut cdp = Self::cdps(&who, (collateral_asset_id, synthetic_asset_id));
+            cdp.collateral = cdp
+                .collateral
+                .checked_sub(amount)
+                .ok_or(Error::<T>::InsufficientCdpCollateral)?;
+            if cdp.debt > 0 {
+                Self::ensure_cdp_prices_are_stable(&collateral_asset_id, &synthetic_asset_id)?;
+            }
+            ensure!(
+                Self::is_cdp_safe(&collateral_asset_id, &synthetic_asset_id, &cdp)?,
+                Error::<T>::CdpIsUnsafe
+            );
+
+            Assets::<T>::transfer_from(
+                &collateral_asset_id,
+                &Self::cdp_collateral_account_id()?,
+                &who,
+                amount,
+            )?;
+            Self::update_cdp(&who, collateral_asset_id, synthetic_asset_id, cdp);
+
+            Self::deposit_event(Event::CdpCollateralWithdrawn(
+                who,
+                collateral_asset_id,
+                synthetic_asset_id,
+                amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Mint synthetic asset against the collateral of the debt position, the position must
+        /// stay above the minimal collateral ratio
+        ///
+        /// The synthetic asset must be purchasable for the collateral outside of XST pool,
+        /// otherwise the position could never be liquidated.
+        ///
+        /// - `origin`: owner of the debt position
+        /// - `collateral_asset_id`: collateral asset of the position
+        /// - `synthetic_asset_id`: synthetic asset to mint
+        /// - `amount`: amount of the synthetic asset to mint
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::mint_cdp_synthetic())]
+        pub fn mint_cdp_synthetic(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+            synthetic_asset_id: T::AssetId,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(amount > 0, Error::<T>::ZeroCdpAmount);
+            ensure!(
+                CdpCollateralRatios::<T>::contains_key(&collateral_asset_id),
+                Error::<T>::InvalidCdpCollateral
+            );
+            ensure!(
+                EnabledSynthetics::<T>::contains_key(&synthetic_asset_id),
+                Error::<T>::SyntheticIsNotEnabled
+            );
+            Self::ensure_cdp_prices_are_stable(&collateral_asset_id, &synthetic_asset_id)?;
+
+            let mut cdp = Self::cdps(&who, (collateral_asset_id, synthetic_asset_id));
+            cdp.debt = cdp
+                .debt
+                .checked_add(amount)
+                .ok_or(Error::<T>::CdpOverflow)?;
+            ensure!(
+                Self::is_cdp_safe(&collateral_asset_id, &synthetic_asset_id, &cdp)?,
+                Error::<T>::CdpIsUnsafe
+            );
+            T::LiquidityProxy::quote(
+                DEXId::Polkaswap.into(),
+                &collateral_asset_id,
+                &synthetic_asset_id,
+                QuoteAmount::with_desired_output(amount),
+                Self::cdp_liquidation_filter(),
+                true,
+            )
+            .map_err(|_| Error::<T>::NoCdpLiquidationRoute)?;
+
+            Assets::<T>::mint_to(
+                &synthetic_asset_id,
+                &Self::permissioned_account_id()?,
+                &who,
+                amount,
+            )?;
+            Cdps::<T>::insert(&who, (collateral_asset_id, synthetic_asset_id), cdp);
+
+            Self::deposit_event(Event::CdpSyntheticMinted(
+                who,
+                collateral_asset_id,
+                synthetic_asset_id,
+                amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Burn synthetic asset to repay the debt of the debt position
+        ///
+        /// - `origin`: owner of the debt position
+        /// - `collateral_asset_id`: collateral asset of the position
+        /// - `synthetic_asset_id`: synthetic asset of the position
+        /// - `amount`: amount of the synthetic asset to burn, capped by the position debt
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::repay_cdp_synthetic())]
+        pub fn repay_cdp_synthetic(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+            synthetic_asset_id: T::AssetId,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(amount > 0, Error::<T>::ZeroCdpAmount);
+
+            let mut cdp = Self::cdps(&who, (collateral_asset_id, synthetic_asset_id));
+            let amount = amount.min(cdp.debt);
+            ensure!(amount > 0, Error::<T>::CdpDoesNotExist);
+            cdp.debt -= amount;
+
+            Assets::<T>::burn_from(
+                &synthetic_asset_id,
+                &Self::permissioned_account_id()?,
+                &who,
+                amount,
+            )?;
+            Self::update_cdp(&who, collateral_asset_id, synthetic_asset_id, cdp);
+
+            Self::deposit_event(Event::CdpSyntheticRepaid(
+                who,
+                collateral_asset_id,
+                synthetic_asset_id,
+                amount,
+            ));
+            Ok(().into())
+        }
+
+        /// Liquidate the debt position which is below the minimal collateral ratio.
+        ///
+        /// The share of the collateral proportional to the liquidated debt is seized,
+        /// [`Config::CdpLiquidationPenalty`] of it is paid to the liquidator and the rest is sold
+        /// through the liquidity proxy to buy back and burn the debt. If the seized collateral
+        /// can't buy back the whole debt, the uncovered part is recorded as bad debt of the
+        /// synthetic asset. Unsold collateral stays in the position, or is returned to the owner
+        /// once the whole debt is liquidated.
+        ///
+        /// The swap price may deviate from the prices used to value the position by at most
+        /// [`CDP_LIQUIDATION_PRICE_TOLERANCE`], so the liquidation can't be used to sell the
+        /// collateral into a manipulated pool.
+        ///
+        /// - `origin`: liquidator
+        /// - `owner`: owner of the debt position
+        /// - `collateral_asset_id`: collateral asset of the position
+        /// - `synthetic_asset_id`: synthetic asset of the position
+        /// - `debt_amount`: amount of the debt to liquidate, capped by the position debt
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::liquidate_cdp())]
+        pub fn liquidate_cdp(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            collateral_asset_id: T::AssetId,
+            synthetic_asset_id: T::AssetId,
+            debt_amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let liquidator = ensure_signed(origin)?;
+            ensure!(debt_amount > 0, Error::<T>::ZeroCdpAmount);
+
+            let mut cdp = Self::cdps(&owner, (collateral_asset_id, synthetic_asset_id));
+            ensure!(cdp.debt > 0, Error::<T>::CdpDoesNotExist);
+            Self::ensure_cdp_prices_are_stable(&collateral_asset_id, &synthetic_asset_id)?;
+            ensure!(
+                !Self::is_cdp_safe(&collateral_asset_id, &synthetic_asset_id, &cdp)?,
+                Error::<T>::CdpIsSafe
+            );
+
+            let debt_amount = debt_amount.min(cdp.debt);
+            let seized_collateral = if debt_amount == cdp.debt {
+                cdp.collateral
+            } else {
+                (FixedWrapper::from(cdp.collateral) * FixedWrapper::from(debt_amount)
+                    / FixedWrapper::from(cdp.debt))
+                .try_into_balance()
+                .map_err(|_| Error::<T>::PriceCalculationFailed)?
+                .min(cdp.collateral)
+            };
+            let penalty = T::CdpLiquidationPenalty::get() * seized_collateral;
+            let available_collateral = seized_collateral.saturating_sub(penalty);
+
+            let collateral_account_id = Self::cdp_collateral_account_id()?;
+            let permissioned_account_id = Self::permissioned_account_id()?;
+            let dex_id: T::DEXId = DEXId::Polkaswap.into();
+            let filter = Self::cdp_liquidation_filter();
+            // amount of the collateral per unit of the synthetic asset
+            let fair_rate = FixedWrapper::from(Self::reference_price(
+                &synthetic_asset_id,
+                PriceVariant::Buy,
+            )?) / Self::cdp_collateral_price(&collateral_asset_id)?;
+            let tolerance = FixedWrapper::from(CDP_LIQUIDATION_PRICE_TOLERANCE);
+            let max_collateral_in = (FixedWrapper::from(debt_amount)
+                * fair_rate.clone()
+                * (fixed_wrapper!(1) + tolerance.clone()))
+            .try_into_balance()
+            .map_err(|_| Error::<T>::PriceCalculationFailed)?;
+            let required_collateral = T::LiquidityProxy::quote(
+                dex_id,
+                &collateral_asset_id,
+                &synthetic_asset_id,
+                QuoteAmount::with_desired_output(debt_amount),
+                filter.clone(),
+                true,
+            )
+            .map(|outcome| outcome.amount)
+            .unwrap_or(Balance::MAX);
+            let swap_amount = if required_collateral <= available_collateral
+                || max_collateral_in < available_collateral
+            {
+                // fails if the swap is more expensive than the tolerated price
+                SwapAmount::with_desired_output(
+                    debt_amount,
+                    max_collateral_in.min(available_collateral),
+                )
+            } else {
+                // the position is underwater, all the seized collateral is sold
+                let min_amount_out = (FixedWrapper::from(available_collateral) / fair_rate
+                    * (fixed_wrapper!(1) - tolerance))
+                    .try_into_balance()
+                    .map_err(|_| Error::<T>::PriceCalculationFailed)?
+                    .min(debt_amount);
+                SwapAmount::with_desired_input(available_collateral, min_amount_out)
+            };
+            let outcome = T::LiquidityProxy::exchange(
+                dex_id,
+                &collateral_account_id,
+                &permissioned_account_id,
+                &collateral_asset_id,
+                &synthetic_asset_id,
+                swap_amount,
+                filter,
+            )?;
+            let (sold_collateral, repaid_debt) = match swap_amount {
+                SwapAmount::WithDesiredOutput { .. } => (outcome.amount, debt_amount),
+                SwapAmount::WithDesiredInput { .. } => (available_collateral, outcome.amount),
+            };
+            // synthetic bought in excess of the debt is burned as well
+            Assets::<T>::burn_from(
+                &synthetic_asset_id,
+                &permissioned_account_id,
+                &permissioned_account_id,
+                repaid_debt,
+            )?;
+            let repaid_debt = repaid_debt.min(debt_amount);
+            let bad_debt = debt_amount - repaid_debt;
+            if bad_debt > 0 {
+                CdpBadDebt::<T>::mutate(&synthetic_asset_id, |total| {
+                    *total = total.saturating_add(bad_debt)
+                });
+            }
+
+            Assets::<T>::transfer_from(
+                &collateral_asset_id,
+                &collateral_account_id,
+                &liquidator,
+                penalty,
+            )?;
+            cdp.collateral = cdp.collateral.saturating_sub(penalty + sold_collateral);
+            cdp.debt -= debt_amount;
+            if cdp.debt == 0 {
+                Assets::<T>::transfer_from(
+                    &collateral_asset_id,
+                    &collateral_account_id,
+                    &owner,
+                    cdp.collateral,
+                )?;
+                cdp.collateral = 0;
+            }
+            Self::update_cdp(&owner, collateral_asset_id, synthetic_asset_id, cdp);
+
+            Self::deposit_event(Event::CdpLiquidated(
+                owner,
+                collateral_asset_id,
+                synthetic_asset_id,
+                sold_collateral,
+                repaid_debt,
+                bad_debt,
+            ));
+            Ok(().into())
+        }
+
+        /// Set or remove the volatility guard of the synthetic asset.
+        ///
+        /// The guard is tripped if the synthetic asset price changes more than
+        /// `max_price_change` within `period` blocks or the oracle rate is older than
+        /// `max_rate_age`. The guard is reset once the price stays stable for `period` blocks
+        /// or by [`Pallet::resume_synthetic_trading`].
+        ///
+        /// - `origin`: root account
+        /// - `synthetic_asset_id`: synthetic asset to guard
+        /// - `guard`: volatility guard, `None` removes the guard
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_volatility_guard())]
+        pub fn set_volatility_guard(
+            origin: OriginFor<T>,
+            synthetic_asset_id: T::AssetId,
+            guard: Option<VolatilityGuard<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                EnabledSynthetics::<T>::contains_key(&synthetic_asset_id),
+                Error::<T>::SyntheticDoesNotExist
+            );
+
+            VolatilityGuardStates::<T>::remove(synthetic_asset_id);
+            match guard {
+                Some(guard) => {
+                    let is_fee_valid = match guard.action {
+                        VolatilityGuardAction::Pause => true,
+                        VolatilityGuardAction::SurgeFee(fee) => fee >= fixed!(0) && fee < fixed!(1),
+                    };
+                    ensure!(
+                        !guard.period.is_zero() && is_fee_valid,
+                        Error::<T>::InvalidVolatilityGuard
+                    );
+                    VolatilityGuards::<T>::insert(synthetic_asset_id, guard);
+                }
+                None => VolatilityGuards::<T>::remove(synthetic_asset_id),
+            }
+
+            Self::deposit_event(Event::VolatilityGuardSet(synthetic_asset_id, guard));
+            Ok(().into())
+        }
+
+        /// Reset the tripped volatility guard of the synthetic asset before the price stabilizes
+        ///
+        /// - `origin`: root account
+        /// - `synthetic_asset_id`: synthetic asset with the tripped volatility guard
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::resume_synthetic_trading())]
+        pub fn resume_synthetic_trading(
+            origin: OriginFor<T>,
+            synthetic_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let state = Self::volatility_guard_states(&synthetic_asset_id)
+                .filter(|state| state.tripped_at.is_some())
+                .ok_or(Error::<T>::VolatilityGuardIsNotTripped)?;
+
+            VolatilityGuardStates::<T>::insert(
+                synthetic_asset_id,
+                VolatilityGuardState {
+                    reference_price: Self::reference_price(&synthetic_asset_id, PriceVariant::Buy)
+                        .unwrap_or(state.reference_price),
+                    window_start: frame_system::Pallet::<T>::block_number(),
+                    tripped_at: None,
+                },
+            );
+
+            Self::deposit_event(Event::SyntheticTradingResumed(synthetic_asset_id));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -344,6 +927,37 @@ pub mod pallet {
         SyntheticBaseAssetFloorPriceChanged(Balance),
         /// Synthetic asset has been removed. [Synthetic Asset Id, Reference Symbol]
         SyntheticAssetRemoved(AssetIdOf<T>, T::Symbol),
+        /// Price transformation of the synthetic asset has been set. [Synthetic Asset Id, Price Transformation]
+        SyntheticAssetPriceTransformationSet(AssetIdOf<T>, PriceTransformation),
+        /// Leveraged synthetic asset has been rebalanced. [Synthetic Asset Id, Synthetic Asset Price]
+        LeveragedSyntheticRebalanced(AssetIdOf<T>, Balance),
+        /// Minimal collateral ratio of the debt positions has been changed. [Collateral Asset Id, Minimal Collateral Ratio]
+        CdpCollateralRatioChanged(AssetIdOf<T>, Option<Fixed>),
+        /// Collateral has been locked in the debt position. [Owner, Collateral Asset Id, Synthetic Asset Id, Amount]
+        CdpCollateralDeposited(T::AccountId, AssetIdOf<T>, AssetIdOf<T>, Balance),
+        /// Collateral has been unlocked from the debt position. [Owner, Collateral Asset Id, Synthetic Asset Id, Amount]
+        CdpCollateralWithdrawn(T::AccountId, AssetIdOf<T>, AssetIdOf<T>, Balance),
+        /// Synthetic asset has been minted against the debt position. [Owner, Collateral Asset Id, Synthetic Asset Id, Amount]
+        CdpSyntheticMinted(T::AccountId, AssetIdOf<T>, AssetIdOf<T>, Balance),
+        /// Debt of the debt position has been repaid. [Owner, Collateral Asset Id, Synthetic Asset Id, Amount]
+        CdpSyntheticRepaid(T::AccountId, AssetIdOf<T>, AssetIdOf<T>, Balance),
+        /// Debt position has been liquidated. [Owner, Collateral Asset Id, Synthetic Asset Id, Sold Collateral, Repaid Debt, Bad Debt]
+        CdpLiquidated(
+            T::AccountId,
+            AssetIdOf<T>,
+            AssetIdOf<T>,
+            Balance,
+            Balance,
+            Balance,
+        ),
+        /// Volatility guard of the synthetic asset has been changed. [Synthetic Asset Id, Volatility Guard]
+        VolatilityGuardSet(AssetIdOf<T>, Option<VolatilityGuard<T::BlockNumber>>),
+        /// Synthetic asset price is too volatile or stale, swaps are paused or charged with surge fee. [Synthetic Asset Id, Synthetic Asset Price]
+        VolatilityGuardTripped(AssetIdOf<T>, Balance),
+        /// Synthetic asset price has stabilized and the volatility guard has been reset. [Synthetic Asset Id]
+        VolatilityGuardReset(AssetIdOf<T>),
+        /// Tripped volatility guard has been reset by governance. [Synthetic Asset Id]
+        SyntheticTradingResumed(AssetIdOf<T>),
     }
 
     #[pallet::error]
@@ -373,6 +987,34 @@ pub mod pallet {
         CantEnableIndivisibleAsset,
         /// Input/output amount of synthetic base asset exceeds the limit
         SyntheticBaseBuySellLimitExceeded,
+        /// Leverage factor must be non-zero, differ from 1 and not exceed 10 by absolute value
+        InvalidPriceTransformation,
+        /// Minimal collateral ratio must be greater than 1
+        InvalidCollateralRatio,
+        /// Asset is not allowed to be used as collateral of the synthetic asset
+        InvalidCdpCollateral,
+        /// Debt position amount must be greater than zero
+        ZeroCdpAmount,
+        /// Debt position has no debt
+        CdpDoesNotExist,
+        /// Debt position collateral is less than the requested amount
+        InsufficientCdpCollateral,
+        /// Debt position would go below the minimal collateral ratio
+        CdpIsUnsafe,
+        /// Debt position is above the minimal collateral ratio and can't be liquidated
+        CdpIsSafe,
+        /// Debt position amount overflow
+        CdpOverflow,
+        /// Swaps of the synthetic asset are paused by the volatility guard
+        SyntheticTradingPaused,
+        /// Volatility guard period must be non-zero and surge fee ratio must be in [0, 1)
+        InvalidVolatilityGuard,
+        /// Volatility guard of the synthetic asset is not tripped
+        VolatilityGuardIsNotTripped,
+        /// Debt positions can't be changed while the volatility guard of their assets is tripped
+        CdpPausedByVolatilityGuard,
+        /// Synthetic asset can't be bought for the collateral outside of XST pool to liquidate the debt position
+        NoCdpLiquidationRoute,
     }
 
     /// Synthetic assets and their reference symbols.
@@ -383,7 +1025,7 @@ pub mod pallet {
     pub type EnabledSynthetics<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AssetId, SyntheticInfo<T::Symbol>, OptionQuery>;
 
-    /// Reference symbols and their synthetic assets.
+    /// Reference symbols and their synthetic assets without price transformation.
     ///
     /// It's a programmer responsibility to keep this collection consistent with [`EnabledSynthetics`].
     #[pallet::storage]
@@ -391,6 +1033,60 @@ pub mod pallet {
     pub type EnabledSymbols<T: Config> =
         StorageMap<_, Blake2_128Concat, T::Symbol, T::AssetId, OptionQuery>;
 
+    /// Price transformations of the synthetic assets, kept while the synthetic is disabled.
+    #[pallet::storage]
+    #[pallet::getter(fn price_transformations)]
+    pub type PriceTransformations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, PriceTransformation, ValueQuery>;
+
+    /// State of the leveraged synthetic assets at the last rebalancing.
+    #[pallet::storage]
+    #[pallet::getter(fn leverage_rebalances)]
+    pub type LeverageRebalances<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, LeverageRebalance<T::BlockNumber>, OptionQuery>;
+
+    /// Volatility guards of the synthetic assets.
+    #[pallet::storage]
+    #[pallet::getter(fn volatility_guards)]
+    pub type VolatilityGuards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, VolatilityGuard<T::BlockNumber>, OptionQuery>;
+
+    /// Observed states of the volatility guards, updated every block.
+    #[pallet::storage]
+    #[pallet::getter(fn volatility_guard_states)]
+    pub type VolatilityGuardStates<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        VolatilityGuardState<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Assets allowed as collateral of the debt positions and their minimal collateral ratios.
+    #[pallet::storage]
+    #[pallet::getter(fn cdp_collateral_ratios)]
+    pub type CdpCollateralRatios<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, Fixed, OptionQuery>;
+
+    /// Collateralized debt positions by owner and (collateral asset, synthetic asset).
+    #[pallet::storage]
+    #[pallet::getter(fn cdps)]
+    pub type Cdps<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (T::AssetId, T::AssetId),
+        CollateralizedDebtPosition,
+        ValueQuery,
+    >;
+
+    /// Debt of the liquidated positions which wasn't covered by their collateral, by synthetic asset.
+    #[pallet::storage]
+    #[pallet::getter(fn cdp_bad_debt)]
+    pub type CdpBadDebt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, Balance, ValueQuery>;
+
     /// Asset that is used to compare collateral assets by value, e.g., DAI.
     #[pallet::storage]
     #[pallet::getter(fn reference_asset_id)]
@@ -487,19 +1183,36 @@ impl<T: Config> Pallet<T> {
                 },
             );
 
-            match Self::enabled_symbols(&reference_symbol) {
-                Some(asset_id) => {
-                    if asset_id != synthetic_asset_id {
-                        Err(Error::<T>::SymbolAlreadyReferencedToSynthetic)
-                    } else {
+            match Self::price_transformations(&synthetic_asset_id) {
+                PriceTransformation::Identity => match Self::enabled_symbols(&reference_symbol) {
+                    Some(asset_id) => {
+                        if asset_id != synthetic_asset_id {
+                            Err(Error::<T>::SymbolAlreadyReferencedToSynthetic)
+                        } else {
+                            Ok(())
+                        }
+                    }
+                    None => {
+                        EnabledSymbols::<T>::insert(reference_symbol.clone(), synthetic_asset_id);
                         Ok(())
                     }
+                }?,
+                PriceTransformation::Inverse => {}
+                PriceTransformation::Leverage(_) => {
+                    // The previous rebalancing point may be stale or refer to another symbol
+                    let reference_price = T::Oracle::quote_unchecked(&reference_symbol)
+                        .map(|rate| rate.value)
+                        .ok_or(Error::<T>::OracleQuoteError)?;
+                    LeverageRebalances::<T>::insert(
+                        synthetic_asset_id,
+                        LeverageRebalance {
+                            reference_price,
+                            synthetic_price: reference_price,
+                            block: frame_system::Pallet::<T>::block_number(),
+                        },
+                    );
                 }
-                None => {
-                    EnabledSymbols::<T>::insert(reference_symbol.clone(), synthetic_asset_id);
-                    Ok(())
-                }
-            }?;
+            }
 
             Self::deposit_event(Event::SyntheticAssetEnabled(
                 synthetic_asset_id,
@@ -867,8 +1580,14 @@ impl<T: Config> Pallet<T> {
 
         let dynamic_fee_ratio: FixedWrapper = T::Oracle::quote_unchecked(&reference_symbol)
             .map_or(fixed_wrapper!(0), |rate| rate.dynamic_fee.into());
+        let surge_fee_ratio: FixedWrapper =
+            match Self::tripped_volatility_guard(synthetic_asset_id)? {
+                Some(VolatilityGuardAction::Pause) => fail!(Error::<T>::SyntheticTradingPaused),
+                Some(VolatilityGuardAction::SurgeFee(fee)) => fee.into(),
+                None => fixed_wrapper!(0),
+            };
         let fee_ratio: FixedWrapper = fee_ratio.into();
-        let resulting_fee_ratio = fee_ratio + dynamic_fee_ratio;
+        let resulting_fee_ratio = fee_ratio + dynamic_fee_ratio + surge_fee_ratio;
 
         ensure!(
             resulting_fee_ratio < fixed_wrapper!(1),
@@ -947,13 +1666,328 @@ impl<T: Config> Pallet<T> {
                 let symbol = EnabledSynthetics::<T>::get(id)
                     .ok_or(Error::<T>::SyntheticDoesNotExist)?
                     .reference_symbol;
-                T::Oracle::quote_unchecked(&symbol)
+                let price = T::Oracle::quote_unchecked(&symbol)
                     .map(|rate| rate.value)
-                    .ok_or(Error::<T>::OracleQuoteError.into())
+                    .ok_or(Error::<T>::OracleQuoteError)?;
+                Self::transform_price(id, price)
+            }
+        }
+    }
+
+    /// Apply the price transformation of the synthetic asset to the reference symbol `price`.
+    fn transform_price(asset_id: &T::AssetId, price: Balance) -> Result<Balance, DispatchError> {
+        match Self::price_transformations(asset_id) {
+            PriceTransformation::Identity => Ok(price),
+            PriceTransformation::Inverse => {
+                ensure!(price > 0, Error::<T>::PriceCalculationFailed);
+                (fixed_wrapper!(1) / FixedWrapper::from(price))
+                    .try_into_balance()
+                    .map_err(|_| Error::<T>::PriceCalculationFailed.into())
+            }
+            PriceTransformation::Leverage(factor) => {
+                let rebalance = Self::leverage_rebalances(asset_id)
+                    .ok_or(Error::<T>::PriceCalculationFailed)?;
+                Self::leveraged_price(factor, &rebalance, price)
+            }
+        }
+    }
+
+    /// Price of the leveraged synthetic asset.
+    ///
+    /// `synthetic_price = rebalanced_synthetic_price * (1 + factor * (price / rebalanced_price - 1))`
+    fn leveraged_price(
+        factor: Fixed,
+        rebalance: &LeverageRebalance<T::BlockNumber>,
+        price: Balance,
+    ) -> Result<Balance, DispatchError> {
+        ensure!(
+            rebalance.reference_price > 0,
+            Error::<T>::PriceCalculationFailed
+        );
+        let price_return = FixedWrapper::from(price)
+            / FixedWrapper::from(rebalance.reference_price)
+            - fixed_wrapper!(1);
+        let synthetic_price = FixedWrapper::from(rebalance.synthetic_price)
+            * (fixed_wrapper!(1) + FixedWrapper::from(factor) * price_return);
+        let synthetic_price = synthetic_price
+            .try_into_balance()
+            .map_err(|_| Error::<T>::PriceCalculationFailed)?;
+        ensure!(synthetic_price > 0, Error::<T>::PriceCalculationFailed);
+        Ok(synthetic_price)
+    }
+
+    /// Reset the exposure of the enabled leveraged synthetics to the current reference prices.
+    fn rebalance_leveraged_synthetics(now: T::BlockNumber) -> Weight {
+        let rebalances: Vec<_> = LeverageRebalances::<T>::iter().collect();
+        let mut weight = T::DbWeight::get().reads(rebalances.len() as u64);
+        for (asset_id, rebalance) in rebalances {
+            weight = weight.saturating_add(T::DbWeight::get().reads(2));
+            let (symbol, factor) = match (
+                Self::enabled_synthetics(&asset_id),
+                Self::price_transformations(&asset_id),
+            ) {
+                (Some(info), PriceTransformation::Leverage(factor)) => {
+                    (info.reference_symbol, factor)
+                }
+                _ => continue,
+            };
+
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            let reference_price = match T::Oracle::quote_unchecked(&symbol) {
+                Some(rate) => rate.value,
+                None => continue,
+            };
+            // Synthetic price can't go below zero and stays frozen in this case
+            let synthetic_price = match Self::leveraged_price(factor, &rebalance, reference_price) {
+                Ok(price) => price,
+                Err(_) => continue,
+            };
+            LeverageRebalances::<T>::insert(
+                asset_id,
+                LeverageRebalance {
+                    reference_price,
+                    synthetic_price,
+                    block: now,
+                },
+            );
+            Self::deposit_event(Event::LeveragedSyntheticRebalanced(
+                asset_id,
+                synthetic_price,
+            ));
+        }
+        weight
+    }
+
+    /// Asset id of the synthetic asset bound to `reference_symbol` with `price_transformation`.
+    ///
+    /// Synthetics without price transformation keep the asset id derived from the symbol only.
+    pub fn synthetic_asset_id(
+        reference_symbol: &T::Symbol,
+        price_transformation: &PriceTransformation,
+    ) -> T::AssetId {
+        match price_transformation {
+            PriceTransformation::Identity => {
+                AssetId32::<common::PredefinedAssetId>::from_synthetic_reference_symbol(
+                    reference_symbol,
+                )
+                .into()
             }
+            _ => AssetId32::<common::PredefinedAssetId>::from_synthetic_reference_symbol_with_salt(
+                reference_symbol,
+                price_transformation,
+            )
+            .into(),
+        }
+    }
+
+    /// Action of the tripped volatility guard of the synthetic asset.
+    ///
+    /// The guard state is evaluated at the current block, so the price jump is caught before
+    /// the next `on_initialize`.
+    pub fn tripped_volatility_guard(
+        asset_id: &T::AssetId,
+    ) -> Result<Option<VolatilityGuardAction>, DispatchError> {
+        let guard = match Self::volatility_guards(asset_id) {
+            Some(guard) => guard,
+            None => return Ok(None),
+        };
+        let now = frame_system::Pallet::<T>::block_number();
+        let (state, _) = Self::next_volatility_guard_state(asset_id, &guard, now)?;
+        Ok(state.tripped_at.map(|_| guard.action))
+    }
+
+    /// Ensure that the volatility guards of the debt position assets are not tripped, otherwise
+    /// their prices can't be used to value the position regardless of the guard action
+    fn ensure_cdp_prices_are_stable(
+        collateral_asset_id: &T::AssetId,
+        synthetic_asset_id: &T::AssetId,
+    ) -> DispatchResult {
+        ensure!(
+            Self::tripped_volatility_guard(synthetic_asset_id)?.is_none()
+                && Self::tripped_volatility_guard(collateral_asset_id)?.is_none(),
+            Error::<T>::CdpPausedByVolatilityGuard
+        );
+        Ok(())
+    }
+
+    /// Evaluate the volatility guards and store their states.
+    fn update_volatility_guards(now: T::BlockNumber) -> Weight {
+        let guards: Vec<_> = VolatilityGuards::<T>::iter().collect();
+        let mut weight = T::DbWeight::get().reads(guards.len() as u64);
+        for (asset_id, guard) in guards {
+            // guard state, synthetic info, price transformation and oracle rate
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 1));
+            let (state, transition) =
+                match Self::next_volatility_guard_state(&asset_id, &guard, now) {
+                    Ok(next) => next,
+                    // disabled synthetics are not tradable anyway
+                    Err(_) => continue,
+                };
+            VolatilityGuardStates::<T>::insert(asset_id, state);
+            match transition {
+                VolatilityGuardTransition::Tripped => Self::deposit_event(
+                    Event::VolatilityGuardTripped(asset_id, state.reference_price),
+                ),
+                VolatilityGuardTransition::Reset => {
+                    Self::deposit_event(Event::VolatilityGuardReset(asset_id))
+                }
+                VolatilityGuardTransition::Unchanged => {}
+            }
+        }
+        weight
+    }
+
+    /// State of the volatility guard at block `now` given the current oracle rate.
+    ///
+    /// The guard is tripped if the price moves more than allowed from the price at the start
+    /// of the observation window or the rate is stale. The tripped guard restarts the window
+    /// on every such move and is reset once the window elapses.
+    fn next_volatility_guard_state(
+        asset_id: &T::AssetId,
+        guard: &VolatilityGuard<T::BlockNumber>,
+        now: T::BlockNumber,
+    ) -> Result<
+        (
+            VolatilityGuardState<T::BlockNumber>,
+            VolatilityGuardTransition,
+        ),
+        DispatchError,
+    > {
+        let symbol = EnabledSynthetics::<T>::get(asset_id)
+            .ok_or(Error::<T>::SyntheticDoesNotExist)?
+            .reference_symbol;
+        let rate = T::Oracle::quote_unchecked(&symbol).ok_or(Error::<T>::OracleQuoteError)?;
+        let price = Self::transform_price(asset_id, rate.value)?;
+        let is_stale =
+            T::Time::now().as_secs().saturating_sub(rate.last_updated) > guard.max_rate_age;
+
+        let observed = VolatilityGuardState {
+            reference_price: price,
+            window_start: now,
+            tripped_at: None,
+        };
+        let state = match Self::volatility_guard_states(asset_id) {
+            Some(state) => state,
+            None => observed,
+        };
+        let is_volatile = is_stale
+            || price.abs_diff(state.reference_price)
+                > guard.max_price_change * state.reference_price;
+        let is_window_elapsed = now >= state.window_start.saturating_add(guard.period);
+
+        Ok(match state.tripped_at {
+            None if is_volatile => (
+                VolatilityGuardState {
+                    tripped_at: Some(now),
+                    ..observed
+                },
+                VolatilityGuardTransition::Tripped,
+            ),
+            None if is_window_elapsed => (observed, VolatilityGuardTransition::Unchanged),
+            Some(tripped_at) if is_volatile => (
+                VolatilityGuardState {
+                    tripped_at: Some(tripped_at),
+                    ..observed
+                },
+                VolatilityGuardTransition::Unchanged,
+            ),
+            Some(_) if is_window_elapsed => (observed, VolatilityGuardTransition::Reset),
+            _ => (state, VolatilityGuardTransition::Unchanged),
+        })
+    }
+
+    fn permissioned_account_id() -> Result<T::AccountId, DispatchError> {
+        Technical::<T>::tech_account_id_to_account_id(&T::GetXSTPoolPermissionedTechAccountId::get())
+    }
+
+    fn cdp_collateral_account_id() -> Result<T::AccountId, DispatchError> {
+        Technical::<T>::tech_account_id_to_account_id(&T::GetCdpCollateralTechAccountId::get())
+    }
+
+    /// Liquidity sources used to buy back the debt, XST pool would mint the synthetic asset
+    /// instead of buying it from the holders
+    fn cdp_liquidation_filter() -> LiquiditySourceFilter<T::DEXId, LiquiditySourceType> {
+        LiquiditySourceFilter::with_forbidden(
+            DEXId::Polkaswap.into(),
+            sp_std::vec![LiquiditySourceType::XSTPool],
+        )
+    }
+
+    /// Price of the debt position collateral w.r.t. reference asset
+    fn cdp_collateral_price(asset_id: &T::AssetId) -> Result<Balance, DispatchError> {
+        let reference_asset_id = ReferenceAssetId::<T>::get();
+        if *asset_id == reference_asset_id
+            || *asset_id == T::GetSyntheticBaseAssetId::get()
+            || EnabledSynthetics::<T>::contains_key(asset_id)
+        {
+            Self::reference_price(asset_id, PriceVariant::Sell)
+        } else {
+            T::PriceToolsPallet::get_average_price(
+                asset_id,
+                &reference_asset_id,
+                PriceVariant::Sell,
+            )
         }
     }
 
+    /// Check that the collateral value of the debt position covers the debt value multiplied
+    /// by the minimal collateral ratio, positions of the forbidden collateral are unsafe
+    pub fn is_cdp_safe(
+        collateral_asset_id: &T::AssetId,
+        synthetic_asset_id: &T::AssetId,
+        cdp: &CollateralizedDebtPosition,
+    ) -> Result<bool, DispatchError> {
+        if cdp.debt == 0 {
+            return Ok(true);
+        }
+        let min_collateral_ratio = match Self::cdp_collateral_ratios(collateral_asset_id) {
+            Some(ratio) => ratio,
+            None => return Ok(false),
+        };
+        let collateral_value = FixedWrapper::from(cdp.collateral)
+            * FixedWrapper::from(Self::cdp_collateral_price(collateral_asset_id)?);
+        let debt_value = FixedWrapper::from(cdp.debt)
+            * FixedWrapper::from(Self::reference_price(
+                synthetic_asset_id,
+                PriceVariant::Buy,
+            )?);
+        let required_value = (debt_value * FixedWrapper::from(min_collateral_ratio))
+            .try_into_balance()
+            .map_err(|_| Error::<T>::PriceCalculationFailed)?;
+        let collateral_value = collateral_value
+            .try_into_balance()
+            .map_err(|_| Error::<T>::PriceCalculationFailed)?;
+        Ok(collateral_value >= required_value)
+    }
+
+    fn update_cdp(
+        owner: &T::AccountId,
+        collateral_asset_id: T::AssetId,
+        synthetic_asset_id: T::AssetId,
+        cdp: CollateralizedDebtPosition,
+    ) {
+        if cdp == CollateralizedDebtPosition::default() {
+            Cdps::<T>::remove(owner, (collateral_asset_id, synthetic_asset_id));
+        } else {
+            Cdps::<T>::insert(owner, (collateral_asset_id, synthetic_asset_id), cdp);
+        }
+    }
+
+    fn ensure_price_transformation_valid(
+        price_transformation: &PriceTransformation,
+    ) -> Result<(), DispatchError> {
+        if let PriceTransformation::Leverage(factor) = *price_transformation {
+            ensure!(
+                factor != fixed!(0)
+                    && factor != fixed!(1)
+                    && factor <= fixed!(10)
+                    && factor >= fixed!(-10),
+                Error::<T>::InvalidPriceTransformation
+            );
+        }
+        Ok(())
+    }
+
     /// Check if any symbol rate is present in OracleProxy
     fn ensure_symbol_exists(reference_symbol: &T::Symbol) -> Result<(), DispatchError> {
         if *reference_symbol == common::SymbolName::usd().into() {
@@ -1206,14 +2240,14 @@ impl<T: Config> SyntheticInfoProvider<T::AssetId> for Pallet<T> {
 
 impl<T: Config> OnSymbolDisabled<T::Symbol> for Pallet<T> {
     fn disable_symbol(symbol: &T::Symbol) {
-        // error doesn't matter since we don't
-        // priorly know whether the symbol exists
-        if let Some(asset_id) = Self::enabled_symbols(symbol) {
-            if Self::enabled_synthetics(asset_id).is_some() {
-                _ = Self::disable_synthetic_asset_unchecked(asset_id);
-            }
-        } else {
-            ()
+        // Synthetics with price transformations are not listed in `EnabledSymbols`
+        let synthetics: Vec<T::AssetId> = EnabledSynthetics::<T>::iter()
+            .filter(|(_, info)| info.reference_symbol == *symbol)
+            .map(|(asset_id, _)| asset_id)
+            .collect();
+        for asset_id in synthetics {
+            // error doesn't matter since the synthetic is removed from exchanging anyway
+            _ = Self::disable_synthetic_asset_unchecked(asset_id);
         }
     }
 }
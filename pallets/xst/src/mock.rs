@@ -30,11 +30,14 @@
 
 use crate::{self as xstpool, Config};
 use common::mock::{ExistentialDeposits, GetTradingPairRestrictedFlag};
-use common::prelude::{Balance, PriceToolsPallet};
+use common::prelude::{
+    Balance, FixedWrapper, PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
+};
 use common::{
     self, balance, fixed, hash, Amount, AssetId32, AssetName, AssetSymbol, DEXInfo, Fixed,
-    FromGenericPair, PredefinedAssetId, PriceVariant, DAI, DEFAULT_BALANCE_PRECISION, PSWAP, USDT,
-    VAL, XOR, XST, XSTUSD,
+    FromGenericPair, LiquidityProxyTrait, LiquiditySourceFilter, LiquiditySourceType,
+    PredefinedAssetId, PriceVariant, DAI, DEFAULT_BALANCE_PRECISION, PSWAP, USDT, VAL, XOR, XST,
+    XSTUSD,
 };
 use currencies::BasicCurrencyAdapter;
 use frame_support::traits::{Everything, GenesisBuild};
@@ -47,7 +50,9 @@ use sp_core::crypto::AccountId32;
 use sp_core::H256;
 use sp_runtime::testing::Header;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Zero};
+use sp_runtime::DispatchError;
 use sp_runtime::{Perbill, Percent};
+use std::cell::RefCell;
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -103,6 +108,13 @@ parameter_types! {
         );
         tech_account_id
     };
+    pub GetCdpCollateralTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            crate::TECH_ACCOUNT_PREFIX.to_vec(),
+            crate::TECH_ACCOUNT_CDP_COLLATERAL.to_vec(),
+        );
+        tech_account_id
+    };
     pub GetXSTPoolPermissionedAccountId: AccountId = {
         let tech_account_id = GetXSTPoolPermissionedTechAccountId::get();
         let account_id =
@@ -110,6 +122,8 @@ parameter_types! {
                 .expect("Failed to get ordinary account id for technical account id.");
         account_id
     };
+    pub const GetLeverageRebalancePeriod: BlockNumber = 100;
+    pub const GetCdpLiquidationPenalty: Percent = Percent::from_percent(10);
     pub const GetSyntheticBaseBuySellLimit: Balance = balance!(10000000);
 }
 
@@ -189,15 +203,122 @@ impl Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type GetSyntheticBaseAssetId = GetSyntheticBaseAssetId;
     type GetXSTPoolPermissionedTechAccountId = GetXSTPoolPermissionedTechAccountId;
+    type GetCdpCollateralTechAccountId = GetCdpCollateralTechAccountId;
     type EnsureDEXManager = dex_manager::Pallet<Runtime>;
     type PriceToolsPallet = price_tools::Pallet<Runtime>;
     type Oracle = oracle_proxy::Pallet<Runtime>;
     type Symbol = <Runtime as band::Config>::Symbol;
     type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
     type TradingPairSourceManager = trading_pair::Pallet<Runtime>;
+    type LeverageRebalancePeriod = GetLeverageRebalancePeriod;
+    type LiquidityProxy = MockLiquidityProxy;
+    type CdpLiquidationPenalty = GetCdpLiquidationPenalty;
+    type Time = Timestamp;
     type WeightInfo = ();
 }
 
+/// Account providing liquidity for [`MockLiquidityProxy`]
+pub fn market_maker() -> AccountId {
+    bob()
+}
+
+thread_local! {
+    static MARKET_RATE_MULTIPLIER: RefCell<Option<Fixed>> = RefCell::new(Some(fixed!(1)));
+}
+
+/// Liquidity proxy exchanging assets with [`market_maker`] at XST pool reference prices without fees
+pub struct MockLiquidityProxy;
+
+impl MockLiquidityProxy {
+    /// Multiply the amount of the output asset per unit of the input asset by `multiplier`,
+    /// `None` makes the exchange path unavailable
+    pub fn set_rate_multiplier(multiplier: Option<Fixed>) {
+        MARKET_RATE_MULTIPLIER.with(|value| *value.borrow_mut() = multiplier);
+    }
+
+    fn amounts(
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: QuoteAmount<Balance>,
+    ) -> Result<(Balance, Balance), DispatchError> {
+        let input_price = FixedWrapper::from(XSTPool::reference_price(
+            input_asset_id,
+            PriceVariant::Sell,
+        )?);
+        let output_price = FixedWrapper::from(XSTPool::reference_price(
+            output_asset_id,
+            PriceVariant::Buy,
+        )?);
+        let multiplier = MARKET_RATE_MULTIPLIER
+            .with(|value| *value.borrow())
+            .ok_or(DispatchError::Other("UnavailableExchangePath"))?;
+        // amount of the output asset per unit of the input asset
+        let rate = input_price / output_price * multiplier;
+        match amount {
+            QuoteAmount::WithDesiredInput { desired_amount_in } => {
+                (FixedWrapper::from(desired_amount_in) * rate)
+                    .try_into_balance()
+                    .map(|amount_out| (desired_amount_in, amount_out))
+            }
+            QuoteAmount::WithDesiredOutput { desired_amount_out } => {
+                (FixedWrapper::from(desired_amount_out) / rate)
+                    .try_into_balance()
+                    .map(|amount_in| (amount_in, desired_amount_out))
+            }
+        }
+        .map_err(|_| crate::Error::<Runtime>::PriceCalculationFailed.into())
+    }
+}
+
+impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockLiquidityProxy {
+    fn quote(
+        _dex_id: DEXId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: QuoteAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+        _deduce_fee: bool,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        let (amount_in, amount_out) = Self::amounts(input_asset_id, output_asset_id, amount)?;
+        match amount {
+            QuoteAmount::WithDesiredInput { .. } => Ok(SwapOutcome::new(amount_out, 0)),
+            QuoteAmount::WithDesiredOutput { .. } => Ok(SwapOutcome::new(amount_in, 0)),
+        }
+    }
+
+    fn exchange(
+        _dex_id: DEXId,
+        sender: &AccountId,
+        receiver: &AccountId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: SwapAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        let (amount_in, amount_out) =
+            Self::amounts(input_asset_id, output_asset_id, amount.into())?;
+        let outcome = match amount {
+            SwapAmount::WithDesiredInput { min_amount_out, .. } => {
+                frame_support::ensure!(
+                    amount_out >= min_amount_out,
+                    crate::Error::<Runtime>::SlippageLimitExceeded
+                );
+                SwapOutcome::new(amount_out, 0)
+            }
+            SwapAmount::WithDesiredOutput { max_amount_in, .. } => {
+                frame_support::ensure!(
+                    amount_in <= max_amount_in,
+                    crate::Error::<Runtime>::SlippageLimitExceeded
+                );
+                SwapOutcome::new(amount_in, 0)
+            }
+        };
+        Assets::transfer_from(input_asset_id, sender, &market_maker(), amount_in)?;
+        Assets::transfer_from(output_asset_id, &market_maker(), receiver, amount_out)?;
+        Ok(outcome)
+    }
+}
+
 impl band::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Symbol = common::SymbolName;
@@ -208,6 +329,7 @@ impl band::Config for Runtime {
     type OnSymbolDisabledHook = crate::Pallet<Runtime>;
     type GetBandRateStaleBlockPeriod = GetBandRateStaleBlockPeriod;
     type MaxRelaySymbols = frame_support::traits::ConstU32<100>;
+    type MaxDeviatingRounds = frame_support::traits::ConstU32<3>;
 }
 
 impl oracle_proxy::Config for Runtime {
@@ -215,6 +337,8 @@ impl oracle_proxy::Config for Runtime {
     type WeightInfo = ();
     type Symbol = <Runtime as band::Config>::Symbol;
     type BandChainOracle = band::Pallet<Runtime>;
+    type PriceFeedOracle = ();
+    type MaxRateHistory = frame_support::traits::ConstU32<100>;
 }
 
 impl tokens::Config for Runtime {
@@ -338,6 +462,7 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = ();
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumberFor<Self> = 900;
+    type GovernanceOrigin = frame_system::EnsureNever<()>;
     type WeightInfo = ();
 }
 
@@ -354,6 +479,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnSwapFeeCharged = ();
     type WeightInfo = ();
     type XSTMarketInfo = ();
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -30,11 +30,12 @@
 
 #[rustfmt::skip]
 mod tests {
-    use crate::{Error, Pallet, mock::*, test_utils::{relay_new_symbol, relay_symbol}};
+    use crate::{CollateralizedDebtPosition, Error, Pallet, PriceTransformation, VolatilityGuard, VolatilityGuardAction, mock::*, test_utils::{relay_new_symbol, relay_symbol}};
     use band::FeeCalculationParameters;
-    use common::{self, AssetName, AssetSymbol, AssetInfoProvider, DEXId, LiquiditySource, USDT, VAL, XOR, XST, XSTUSD, DAI, balance, fixed, GetMarketInfo, assert_approx_eq, prelude::{Balance, SwapAmount, QuoteAmount, FixedWrapper, }, PriceVariant, PredefinedAssetId, AssetId32};
+    use common::{self, AssetName, AssetSymbol, AssetInfoProvider, DEXId, LiquiditySource, USDT, VAL, XOR, XST, XSTUSD, DAI, balance, fixed, GetMarketInfo, OnSymbolDisabled, assert_approx_eq, prelude::{Balance, SwapAmount, QuoteAmount, FixedWrapper, }, PriceVariant, PredefinedAssetId, AssetId32, SymbolName};
     use frame_support::{assert_ok, assert_noop};
     use sp_arithmetic::traits::Zero;
+    use sp_arithmetic::Percent;
     use frame_support::traits::Hooks;
     use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -650,6 +651,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
 
             let opt_xsteuro = XSTPool::enabled_symbols(&euro);
@@ -697,6 +699,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
 
             let xsteuro = XSTPool::enabled_symbols(&euro).expect("Expected synthetic asset");
@@ -764,6 +767,7 @@ mod tests {
                     AssetName("XST Euro".into()),
                     euro.clone(),
                     fixed!(-0.1),
+                    PriceTransformation::Identity,
                 ),
                 Err(Error::<Runtime>::InvalidFeeRatio.into())
             );
@@ -775,6 +779,7 @@ mod tests {
                     AssetName("XST Euro".into()),
                     euro.clone(),
                     fixed!(1),
+                    PriceTransformation::Identity,
                 ),
                 Err(Error::<Runtime>::InvalidFeeRatio.into())
             );
@@ -820,6 +825,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0.7),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
 
             let xsteuro = XSTPool::enabled_symbols(&euro).expect("Expected synthetic asset");
@@ -859,6 +865,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
 
             let xsteuro = XSTPool::enabled_symbols(&euro).expect("Expected synthetic asset");
@@ -1278,6 +1285,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
 
             let opt_xsteuro = XSTPool::enabled_symbols(&euro);
@@ -1327,6 +1335,7 @@ mod tests {
                 AssetName("XST Euro".into()),
                 euro.clone(),
                 fixed!(0),
+                PriceTransformation::Identity,
             ).expect("Failed to register synthetic asset");
             assert!(XSTPool::enabled_synthetics(&asset_id).is_some());
             assert!(XSTPool::enabled_symbols(&euro).is_some());
@@ -1354,4 +1363,604 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn should_register_synthetics_with_price_transformations() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTEUR".into()),
+                AssetName("XST Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                PriceTransformation::Identity,
+            ).expect("Failed to register synthetic asset");
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTIEUR".into()),
+                AssetName("XST Inverse Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                PriceTransformation::Inverse,
+            ).expect("Failed to register inverse synthetic asset");
+
+            let xsteuro = XSTPool::synthetic_asset_id(&euro, &PriceTransformation::Identity);
+            let inverse_xsteuro = XSTPool::synthetic_asset_id(&euro, &PriceTransformation::Inverse);
+            assert_ne!(xsteuro, inverse_xsteuro);
+            assert_eq!(XSTPool::enabled_symbols(&euro), Some(xsteuro));
+            assert_eq!(
+                XSTPool::enabled_synthetics(&inverse_xsteuro).expect("Failed to get synthetic asset").reference_symbol,
+                euro
+            );
+            assert_eq!(XSTPool::price_transformations(&inverse_xsteuro), PriceTransformation::Inverse);
+
+            assert_eq!(XSTPool::reference_price(&xsteuro, PriceVariant::Buy), Ok(balance!(2)));
+            assert_eq!(XSTPool::reference_price(&inverse_xsteuro, PriceVariant::Buy), Ok(balance!(0.5)));
+
+            // the same transformation can't be registered twice for the symbol
+            assert!(XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTIEUR".into()),
+                AssetName("XST Inverse Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                PriceTransformation::Inverse,
+            ).is_err());
+
+            XSTPool::remove_synthetic_asset(RuntimeOrigin::root(), inverse_xsteuro)
+                .expect("Failed to remove inverse synthetic asset");
+            assert!(XSTPool::enabled_synthetics(&inverse_xsteuro).is_none());
+            assert_eq!(XSTPool::price_transformations(&inverse_xsteuro), PriceTransformation::Identity);
+            assert_eq!(XSTPool::enabled_symbols(&euro), Some(xsteuro));
+        });
+    }
+
+    #[test]
+    fn should_disallow_invalid_leverage_factor() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+
+            for factor in [fixed!(0), fixed!(1), fixed!(10.1), fixed!(-11)] {
+                assert_noop!(
+                    XSTPool::register_synthetic_asset(
+                        RuntimeOrigin::root(),
+                        AssetSymbol("XST2EUR".into()),
+                        AssetName("XST 2x Euro".into()),
+                        euro.clone(),
+                        fixed!(0),
+                        PriceTransformation::Leverage(factor),
+                    ),
+                    Error::<Runtime>::InvalidPriceTransformation
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn leveraged_synthetic_price_should_follow_rebalancing() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let long = PriceTransformation::Leverage(fixed!(2));
+            let short = PriceTransformation::Leverage(fixed!(-1));
+
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XST2EUR".into()),
+                AssetName("XST 2x Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                long,
+            ).expect("Failed to register leveraged synthetic asset");
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTSEUR".into()),
+                AssetName("XST Short Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                short,
+            ).expect("Failed to register leveraged synthetic asset");
+
+            let long_euro = XSTPool::synthetic_asset_id(&euro, &long);
+            let short_euro = XSTPool::synthetic_asset_id(&euro, &short);
+            assert!(XSTPool::enabled_symbols(&euro).is_none());
+            assert_eq!(XSTPool::reference_price(&long_euro, PriceVariant::Buy), Ok(balance!(2)));
+            assert_eq!(XSTPool::reference_price(&short_euro, PriceVariant::Buy), Ok(balance!(2)));
+
+            // +10%
+            relay_symbol(euro.clone(), 2_200_000_000);
+            assert_eq!(XSTPool::reference_price(&long_euro, PriceVariant::Buy), Ok(balance!(2.4)));
+            assert_eq!(XSTPool::reference_price(&short_euro, PriceVariant::Buy), Ok(balance!(1.8)));
+
+            // not a rebalancing block
+            XSTPool::on_initialize(GetLeverageRebalancePeriod::get() - 1);
+            assert_eq!(
+                XSTPool::leverage_rebalances(&long_euro).expect("Expected rebalance").reference_price,
+                balance!(2)
+            );
+
+            XSTPool::on_initialize(GetLeverageRebalancePeriod::get());
+            let rebalance = XSTPool::leverage_rebalances(&long_euro).expect("Expected rebalance");
+            assert_eq!(rebalance.reference_price, balance!(2.2));
+            assert_eq!(rebalance.synthetic_price, balance!(2.4));
+            assert_eq!(rebalance.block, GetLeverageRebalancePeriod::get());
+            System::assert_has_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::LeveragedSyntheticRebalanced(short_euro, balance!(1.8))));
+
+            // +10% after the rebalancing
+            relay_symbol(euro.clone(), 2_420_000_000);
+            assert_eq!(XSTPool::reference_price(&long_euro, PriceVariant::Buy), Ok(balance!(2.88)));
+            assert_eq!(XSTPool::reference_price(&short_euro, PriceVariant::Buy), Ok(balance!(1.62)));
+        });
+    }
+
+    #[test]
+    fn enabling_leveraged_synthetic_should_reset_rebalancing() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let long = PriceTransformation::Leverage(fixed!(2));
+
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XST2EUR".into()),
+                AssetName("XST 2x Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                long,
+            ).expect("Failed to register leveraged synthetic asset");
+            let long_euro = XSTPool::synthetic_asset_id(&euro, &long);
+
+            XSTPool::disable_synthetic_asset(RuntimeOrigin::root(), long_euro)
+                .expect("Failed to disable synthetic asset");
+            // +50% while the synthetic is disabled
+            relay_symbol(euro.clone(), 3_000_000_000);
+            System::set_block_number(5u64);
+            XSTPool::enable_synthetic_asset(RuntimeOrigin::root(), long_euro, euro.clone(), fixed!(0))
+                .expect("Failed to enable synthetic asset");
+
+            let rebalance = XSTPool::leverage_rebalances(&long_euro).expect("Expected rebalance");
+            assert_eq!(rebalance.reference_price, balance!(3));
+            assert_eq!(rebalance.synthetic_price, balance!(3));
+            assert_eq!(rebalance.block, 5u64);
+            assert_eq!(XSTPool::reference_price(&long_euro, PriceVariant::Buy), Ok(balance!(3)));
+        });
+    }
+
+    #[test]
+    fn disabling_symbol_should_disable_all_its_synthetics() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTEUR".into()),
+                AssetName("XST Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                PriceTransformation::Identity,
+            ).expect("Failed to register synthetic asset");
+            XSTPool::register_synthetic_asset(
+                RuntimeOrigin::root(),
+                AssetSymbol("XSTIEUR".into()),
+                AssetName("XST Inverse Euro".into()),
+                euro.clone(),
+                fixed!(0),
+                PriceTransformation::Inverse,
+            ).expect("Failed to register inverse synthetic asset");
+
+            <XSTPool as OnSymbolDisabled<_>>::disable_symbol(&euro);
+
+            assert!(XSTPool::enabled_synthetics(XSTPool::synthetic_asset_id(&euro, &PriceTransformation::Identity)).is_none());
+            assert!(XSTPool::enabled_synthetics(XSTPool::synthetic_asset_id(&euro, &PriceTransformation::Inverse)).is_none());
+            assert_eq!(
+                XSTPool::price_transformations(XSTPool::synthetic_asset_id(&euro, &PriceTransformation::Inverse)),
+                PriceTransformation::Inverse
+            );
+        });
+    }
+
+    fn register_xsteuro(euro: &SymbolName) -> AssetId {
+        XSTPool::register_synthetic_asset(
+            RuntimeOrigin::root(),
+            AssetSymbol("XSTEUR".into()),
+            AssetName("XST Euro".into()),
+            euro.clone(),
+            fixed!(0),
+            PriceTransformation::Identity,
+        ).expect("Failed to register synthetic asset");
+        XSTPool::synthetic_asset_id(euro, &PriceTransformation::Identity)
+    }
+
+    #[test]
+    fn cdp_should_mint_and_repay_synthetic() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+
+            assert_noop!(
+                XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(300)),
+                Error::<Runtime>::InvalidCdpCollateral
+            );
+            assert_noop!(
+                XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, Some(fixed!(1))),
+                Error::<Runtime>::InvalidCollateralRatio
+            );
+            assert_ok!(XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, Some(fixed!(1.5))));
+
+            assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(300)));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(99700));
+
+            // 100 XSTEUR = 200 DAI, 150% of it is covered by the collateral
+            assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)));
+            assert_eq!(Assets::free_balance(&xsteuro, &alice()).unwrap(), balance!(100));
+            assert_noop!(
+                XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(1)),
+                Error::<Runtime>::CdpIsUnsafe
+            );
+            assert_noop!(
+                XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(1)),
+                Error::<Runtime>::CdpIsUnsafe
+            );
+
+            assert_ok!(XSTPool::repay_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(50)));
+            assert_ok!(XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(150)));
+            assert_eq!(
+                XSTPool::cdps(alice(), (DAI, xsteuro)),
+                CollateralizedDebtPosition { collateral: balance!(150), debt: balance!(50) }
+            );
+
+            // repaid amount is capped by the debt
+            assert_ok!(XSTPool::repay_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)));
+            assert_eq!(Assets::free_balance(&xsteuro, &alice()).unwrap(), balance!(0));
+            assert_noop!(
+                XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(151)),
+                Error::<Runtime>::InsufficientCdpCollateral
+            );
+            assert_ok!(XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(150)));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(100000));
+        });
+    }
+
+    #[test]
+    fn cdp_should_be_liquidated_below_collateral_ratio() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+            let liquidator = AccountId::from([4u8; 32]);
+            assert_ok!(XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, Some(fixed!(1.5))));
+
+            // market maker provides XSTEUR liquidity for the liquidation
+            assert_ok!(Assets::transfer_from(&DAI, &alice(), &market_maker(), balance!(1000)));
+            assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(market_maker()), DAI, xsteuro, balance!(1000)));
+            assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(market_maker()), DAI, xsteuro, balance!(300)));
+
+            assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(300)));
+            assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)));
+            assert_noop!(
+                XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)),
+                Error::<Runtime>::CdpIsSafe
+            );
+
+            // 100 XSTEUR = 250 DAI, only 120% of it is covered by the collateral
+            relay_symbol(euro, 2_500_000_000);
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)));
+
+            // 10% of the collateral is paid to the liquidator, 250 DAI is sold to buy back the debt
+            assert_eq!(Assets::free_balance(&DAI, &liquidator).unwrap(), balance!(30));
+            assert_eq!(Assets::free_balance(&DAI, &market_maker()).unwrap(), balance!(250));
+            assert_eq!(Assets::free_balance(&xsteuro, &market_maker()).unwrap(), balance!(200));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(98720));
+            assert_eq!(Assets::free_balance(&xsteuro, &alice()).unwrap(), balance!(100));
+            assert_eq!(Assets::total_issuance(&xsteuro).unwrap(), balance!(300));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::CdpLiquidated(
+                alice(),
+                DAI,
+                xsteuro,
+                balance!(250),
+                balance!(100),
+                balance!(0),
+            )));
+        });
+    }
+
+    /// Opens the position of Alice with 300 DAI collateral and 100 XSTEUR debt at 2 DAI per XSTEUR,
+    /// market maker provides XSTEUR liquidity for the liquidation
+    fn open_liquidated_cdp() -> (SymbolName, AssetId) {
+        let euro = relay_new_symbol("EURO", 2_000_000_000);
+        let xsteuro = register_xsteuro(&euro);
+        assert_ok!(XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, Some(fixed!(1.5))));
+        assert_ok!(Assets::transfer_from(&DAI, &alice(), &market_maker(), balance!(1000)));
+        assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(market_maker()), DAI, xsteuro, balance!(1000)));
+        assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(market_maker()), DAI, xsteuro, balance!(300)));
+        assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(300)));
+        assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)));
+        (euro, xsteuro)
+    }
+
+    #[test]
+    fn cdp_should_be_liquidated_partially() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let (euro, xsteuro) = open_liquidated_cdp();
+            let liquidator = AccountId::from([4u8; 32]);
+            relay_symbol(euro, 2_500_000_000);
+
+            assert_noop!(
+                XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, 0),
+                Error::<Runtime>::ZeroCdpAmount
+            );
+            // 40% of the debt seizes 120 DAI, 12 DAI are paid to the liquidator, 100 DAI buy back the debt
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(40)));
+            assert_eq!(Assets::free_balance(&DAI, &liquidator).unwrap(), balance!(12));
+            assert_eq!(Assets::free_balance(&DAI, &market_maker()).unwrap(), balance!(100));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(98700));
+            assert_eq!(
+                XSTPool::cdps(alice(), (DAI, xsteuro)),
+                CollateralizedDebtPosition { collateral: balance!(188), debt: balance!(60) }
+            );
+            assert_eq!(Assets::total_issuance(&xsteuro).unwrap(), balance!(360));
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::CdpLiquidated(
+                alice(),
+                DAI,
+                xsteuro,
+                balance!(100),
+                balance!(40),
+                balance!(0),
+            )));
+        });
+    }
+
+    #[test]
+    fn cdp_liquidation_should_record_bad_debt() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let (euro, xsteuro) = open_liquidated_cdp();
+            let liquidator = AccountId::from([4u8; 32]);
+
+            // 100 XSTEUR = 400 DAI, the collateral doesn't cover the debt
+            relay_symbol(euro, 4_000_000_000);
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(1000)));
+
+            // 30 DAI are paid to the liquidator, 270 DAI buy back 67.5 XSTEUR of the debt
+            assert_eq!(Assets::free_balance(&DAI, &liquidator).unwrap(), balance!(30));
+            assert_eq!(Assets::free_balance(&DAI, &market_maker()).unwrap(), balance!(270));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(98700));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+            assert_eq!(XSTPool::cdp_bad_debt(xsteuro), balance!(32.5));
+            assert_eq!(Assets::total_issuance(&xsteuro).unwrap(), balance!(332.5));
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::CdpLiquidated(
+                alice(),
+                DAI,
+                xsteuro,
+                balance!(270),
+                balance!(67.5),
+                balance!(32.5),
+            )));
+        });
+    }
+
+    #[test]
+    fn cdp_of_forbidden_collateral_should_be_liquidated() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let (_, xsteuro) = open_liquidated_cdp();
+            let liquidator = AccountId::from([4u8; 32]);
+
+            assert_ok!(XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, None));
+            assert_noop!(
+                XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(1)),
+                Error::<Runtime>::CdpIsUnsafe
+            );
+            // 30 DAI are paid to the liquidator, 200 DAI buy back the debt and 70 DAI are returned
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)));
+            assert_eq!(Assets::free_balance(&DAI, &liquidator).unwrap(), balance!(30));
+            assert_eq!(Assets::free_balance(&DAI, &alice()).unwrap(), balance!(98770));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+        });
+    }
+
+    #[test]
+    fn cdp_liquidation_should_bound_swap_price() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let (euro, xsteuro) = open_liquidated_cdp();
+            let liquidator = AccountId::from([4u8; 32]);
+            relay_symbol(euro.clone(), 2_500_000_000);
+
+            // the pool is 10% more expensive than the oracle, buying back the debt would take
+            // 277.7 DAI instead of at most 262.5 DAI
+            MockLiquidityProxy::set_rate_multiplier(Some(fixed!(0.9)));
+            assert_noop!(
+                XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)),
+                Error::<Runtime>::SlippageLimitExceeded
+            );
+
+            // underwater position, 270 DAI buy 60.75 XSTEUR instead of at least 64.125 XSTEUR
+            relay_symbol(euro, 4_000_000_000);
+            assert_noop!(
+                XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)),
+                Error::<Runtime>::SlippageLimitExceeded
+            );
+
+            // 3% is within the tolerance
+            MockLiquidityProxy::set_rate_multiplier(Some(fixed!(0.97)));
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator), alice(), DAI, xsteuro, balance!(100)));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+        });
+    }
+
+    #[test]
+    fn cdp_should_require_liquidation_route() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+            assert_ok!(XSTPool::set_cdp_collateral_ratio(RuntimeOrigin::root(), DAI, Some(fixed!(1.5))));
+            assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(300)));
+
+            // the synthetic is only traded in XST pool
+            MockLiquidityProxy::set_rate_multiplier(None);
+            assert_noop!(
+                XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)),
+                Error::<Runtime>::NoCdpLiquidationRoute
+            );
+
+            MockLiquidityProxy::set_rate_multiplier(Some(fixed!(1)));
+            assert_ok!(XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(100)));
+        });
+    }
+
+    fn volatility_guard(action: VolatilityGuardAction) -> VolatilityGuard<BlockNumber> {
+        VolatilityGuard {
+            max_price_change: Percent::from_percent(10),
+            period: 10,
+            max_rate_age: u64::MAX,
+            action,
+        }
+    }
+
+    #[test]
+    fn volatility_guard_should_pause_swaps() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+            let quote = || XSTPool::quote(&DEXId::Polkaswap.into(), &XST, &xsteuro, QuoteAmount::with_desired_input(balance!(1)), true);
+
+            assert_ok!(XSTPool::set_volatility_guard(RuntimeOrigin::root(), xsteuro, Some(volatility_guard(VolatilityGuardAction::Pause))));
+            XSTPool::on_initialize(1);
+            assert!(quote().is_ok());
+
+            // +20%, the jump is caught before the next block
+            relay_symbol(euro.clone(), 2_400_000_000);
+            assert_noop!(
+                XSTPool::exchange(&alice(), &alice(), &DEXId::Polkaswap, &XST, &xsteuro, SwapAmount::with_desired_input(balance!(1), 0)),
+                Error::<Runtime>::SyntheticTradingPaused
+            );
+
+            System::set_block_number(2u64);
+            XSTPool::on_initialize(2);
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::VolatilityGuardTripped(xsteuro, balance!(2.4))));
+            assert_noop!(quote(), Error::<Runtime>::SyntheticTradingPaused);
+
+            // the price has to stay stable for the whole period
+            System::set_block_number(11u64);
+            XSTPool::on_initialize(11);
+            assert_noop!(quote(), Error::<Runtime>::SyntheticTradingPaused);
+
+            System::set_block_number(12u64);
+            XSTPool::on_initialize(12);
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::VolatilityGuardReset(xsteuro)));
+            assert!(quote().is_ok());
+        });
+    }
+
+    #[test]
+    fn volatility_guard_should_pause_cdp_operations() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let (euro, xsteuro) = open_liquidated_cdp();
+            let liquidator = AccountId::from([4u8; 32]);
+            assert_ok!(XSTPool::set_volatility_guard(RuntimeOrigin::root(), xsteuro, Some(volatility_guard(VolatilityGuardAction::SurgeFee(fixed!(0.05))))));
+            XSTPool::on_initialize(1);
+
+            // +20%, the position is below the minimal collateral ratio, but the price is not trusted
+            relay_symbol(euro.clone(), 2_400_000_000);
+            assert_noop!(
+                XSTPool::mint_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(1)),
+                Error::<Runtime>::CdpPausedByVolatilityGuard
+            );
+            assert_noop!(
+                XSTPool::withdraw_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(1)),
+                Error::<Runtime>::CdpPausedByVolatilityGuard
+            );
+            assert_noop!(
+                XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator.clone()), alice(), DAI, xsteuro, balance!(100)),
+                Error::<Runtime>::CdpPausedByVolatilityGuard
+            );
+            // the position can still be secured
+            assert_ok!(XSTPool::deposit_cdp_collateral(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(10)));
+            assert_ok!(XSTPool::repay_cdp_synthetic(RuntimeOrigin::signed(alice()), DAI, xsteuro, balance!(10)));
+
+            System::set_block_number(2u64);
+            XSTPool::on_initialize(2);
+            assert_ok!(XSTPool::resume_synthetic_trading(RuntimeOrigin::root(), xsteuro));
+            assert_ok!(XSTPool::liquidate_cdp(RuntimeOrigin::signed(liquidator), alice(), DAI, xsteuro, balance!(90)));
+            assert!(!crate::Cdps::<Runtime>::contains_key(alice(), (DAI, xsteuro)));
+        });
+    }
+
+    #[test]
+    fn volatility_guard_should_charge_surge_fee_until_resumed() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            System::set_block_number(1u64);
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+
+            assert_ok!(XSTPool::set_volatility_guard(RuntimeOrigin::root(), xsteuro, Some(volatility_guard(VolatilityGuardAction::SurgeFee(fixed!(0.05))))));
+            XSTPool::on_initialize(1);
+            assert_eq!(XSTPool::get_aggregated_fee(&xsteuro).unwrap().try_into_balance().unwrap(), balance!(0));
+            assert_noop!(
+                XSTPool::resume_synthetic_trading(RuntimeOrigin::root(), xsteuro),
+                Error::<Runtime>::VolatilityGuardIsNotTripped
+            );
+
+            // -20%
+            relay_symbol(euro.clone(), 1_600_000_000);
+            System::set_block_number(2u64);
+            XSTPool::on_initialize(2);
+            assert!(XSTPool::volatility_guard_states(&xsteuro).unwrap().tripped_at.is_some());
+            assert_eq!(XSTPool::get_aggregated_fee(&xsteuro).unwrap().try_into_balance().unwrap(), balance!(0.05));
+            assert_ok!(XSTPool::exchange(&alice(), &alice(), &DEXId::Polkaswap, &XST, &xsteuro, SwapAmount::with_desired_input(balance!(1), 0)));
+
+            assert_ok!(XSTPool::resume_synthetic_trading(RuntimeOrigin::root(), xsteuro));
+            System::assert_last_event(RuntimeEvent::XSTPool(crate::Event::<Runtime>::SyntheticTradingResumed(xsteuro)));
+            assert_eq!(XSTPool::get_aggregated_fee(&xsteuro).unwrap().try_into_balance().unwrap(), balance!(0));
+        });
+    }
+
+    #[test]
+    fn should_disallow_invalid_volatility_guard() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            let euro = relay_new_symbol("EURO", 2_000_000_000);
+            let xsteuro = register_xsteuro(&euro);
+
+            let mut guard = volatility_guard(VolatilityGuardAction::Pause);
+            guard.period = 0;
+            assert_noop!(
+                XSTPool::set_volatility_guard(RuntimeOrigin::root(), xsteuro, Some(guard)),
+                Error::<Runtime>::InvalidVolatilityGuard
+            );
+            assert_noop!(
+                XSTPool::set_volatility_guard(RuntimeOrigin::root(), xsteuro, Some(volatility_guard(VolatilityGuardAction::SurgeFee(fixed!(1))))),
+                Error::<Runtime>::InvalidVolatilityGuard
+            );
+            assert_noop!(
+                XSTPool::set_volatility_guard(RuntimeOrigin::root(), XST, Some(volatility_guard(VolatilityGuardAction::Pause))),
+                Error::<Runtime>::SyntheticDoesNotExist
+            );
+        });
+    }
 }
@@ -69,6 +69,14 @@ pub trait WeightInfo {
 	fn set_synthetic_base_asset_floor_price() -> Weight;
 	fn quote() -> Weight;
 	fn exchange() -> Weight;
+	fn set_cdp_collateral_ratio() -> Weight;
+	fn deposit_cdp_collateral() -> Weight;
+	fn withdraw_cdp_collateral() -> Weight;
+	fn mint_cdp_synthetic() -> Weight;
+	fn repay_cdp_synthetic() -> Weight;
+	fn liquidate_cdp() -> Weight;
+	fn set_volatility_guard() -> Weight;
+	fn resume_synthetic_trading() -> Weight;
 }
 
 /// Weights for xst using the Substrate node and recommended hardware.
@@ -127,16 +135,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: XSTPool EnabledSynthetics (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
-	/// Storage: XSTPool EnabledSymbols (r:0 w:1)
+	/// Storage: XSTPool EnabledSymbols (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSymbols (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:0 w:1)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LeverageRebalances (r:0 w:1)
+	/// Proof Skipped: XSTPool LeverageRebalances (max_values: None, max_size: None, mode: Measured)
 	fn remove_synthetic_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `309`
-		//  Estimated: `3093`
-		// Minimum execution time: 211_000_000 picoseconds.
-		Weight::from_parts(213_000_000, 3093)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Estimated: `5877`
+		// Minimum execution time: 231_000_000 picoseconds.
+		Weight::from_parts(234_000_000, 5877)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets AssetOwners (r:2 w:1)
 	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
@@ -160,14 +172,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Assets AssetInfos (max_values: None, max_size: None, mode: Measured)
 	/// Storage: XSTPool EnabledSynthetics (r:0 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:1 w:1)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LeverageRebalances (r:1 w:1)
+	/// Proof Skipped: XSTPool LeverageRebalances (max_values: None, max_size: None, mode: Measured)
 	fn register_synthetic_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3175`
-		//  Estimated: `64548`
-		// Minimum execution time: 1_701_000_000 picoseconds.
-		Weight::from_parts(1_706_000_000, 64548)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+		//  Estimated: `70116`
+		// Minimum execution time: 1_742_000_000 picoseconds.
+		Weight::from_parts(1_748_000_000, 70116)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: XSTPool EnabledSynthetics (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
@@ -245,6 +261,204 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(17_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: Assets AssetOwners (r:1 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:0 w:1)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	fn set_cdp_collateral_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1230`
+		//  Estimated: `4935`
+		// Minimum execution time: 121_000_000 picoseconds.
+		Weight::from_parts(124_000_000, 4935)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	fn deposit_cdp_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2145`
+		//  Estimated: `16854`
+		// Minimum execution time: 412_000_000 picoseconds.
+		Weight::from_parts(418_000_000, 16854)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_cdp_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `42781`
+		// Minimum execution time: 721_000_000 picoseconds.
+		Weight::from_parts(729_000_000, 42781)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn mint_cdp_synthetic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3638`
+		//  Estimated: `47126`
+		// Minimum execution time: 768_000_000 picoseconds.
+		Weight::from_parts(775_000_000, 47126)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	fn repay_cdp_synthetic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2206`
+		//  Estimated: `14612`
+		// Minimum execution time: 398_000_000 picoseconds.
+		Weight::from_parts(403_000_000, 14612)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpBadDebt (r:1 w:1)
+	/// Proof Skipped: XSTPool CdpBadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:2 w:2)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn liquidate_cdp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4390`
+		//  Estimated: `76480`
+		// Minimum execution time: 1_689_000_000 picoseconds.
+		Weight::from_parts(1_702_000_000, 76480)
+			.saturating_add(T::DbWeight::get().reads(25_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuardStates (r:0 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuardStates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:0 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	fn set_volatility_guard() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `3093`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(155_000_000, 3093)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool VolatilityGuardStates (r:1 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuardStates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:1 w:0)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	fn resume_synthetic_trading() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `21437`
+		// Minimum execution time: 298_000_000 picoseconds.
+		Weight::from_parts(303_000_000, 21437)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -302,16 +516,20 @@ impl WeightInfo for () {
 	}
 	/// Storage: XSTPool EnabledSynthetics (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
-	/// Storage: XSTPool EnabledSymbols (r:0 w:1)
+	/// Storage: XSTPool EnabledSymbols (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSymbols (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:0 w:1)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LeverageRebalances (r:0 w:1)
+	/// Proof Skipped: XSTPool LeverageRebalances (max_values: None, max_size: None, mode: Measured)
 	fn remove_synthetic_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `309`
-		//  Estimated: `3093`
-		// Minimum execution time: 211_000_000 picoseconds.
-		Weight::from_parts(213_000_000, 3093)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Estimated: `5877`
+		// Minimum execution time: 231_000_000 picoseconds.
+		Weight::from_parts(234_000_000, 5877)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Assets AssetOwners (r:2 w:1)
 	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
@@ -335,14 +553,18 @@ impl WeightInfo for () {
 	/// Proof Skipped: Assets AssetInfos (max_values: None, max_size: None, mode: Measured)
 	/// Storage: XSTPool EnabledSynthetics (r:0 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:1 w:1)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool LeverageRebalances (r:1 w:1)
+	/// Proof Skipped: XSTPool LeverageRebalances (max_values: None, max_size: None, mode: Measured)
 	fn register_synthetic_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3175`
-		//  Estimated: `64548`
-		// Minimum execution time: 1_701_000_000 picoseconds.
-		Weight::from_parts(1_706_000_000, 64548)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+		//  Estimated: `70116`
+		// Minimum execution time: 1_742_000_000 picoseconds.
+		Weight::from_parts(1_748_000_000, 70116)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: XSTPool EnabledSynthetics (r:1 w:1)
 	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
@@ -420,4 +642,202 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(17_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: Assets AssetOwners (r:1 w:0)
+	/// Proof Skipped: Assets AssetOwners (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:0 w:1)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	fn set_cdp_collateral_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1230`
+		//  Estimated: `4935`
+		// Minimum execution time: 121_000_000 picoseconds.
+		Weight::from_parts(124_000_000, 4935)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	fn deposit_cdp_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2145`
+		//  Estimated: `16854`
+		// Minimum execution time: 412_000_000 picoseconds.
+		Weight::from_parts(418_000_000, 16854)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_cdp_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `42781`
+		// Minimum execution time: 721_000_000 picoseconds.
+		Weight::from_parts(729_000_000, 42781)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn mint_cdp_synthetic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3638`
+		//  Estimated: `47126`
+		// Minimum execution time: 768_000_000 picoseconds.
+		Weight::from_parts(775_000_000, 47126)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:1 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	fn repay_cdp_synthetic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2206`
+		//  Estimated: `14612`
+		// Minimum execution time: 398_000_000 picoseconds.
+		Weight::from_parts(403_000_000, 14612)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: XSTPool Cdps (r:1 w:1)
+	/// Proof Skipped: XSTPool Cdps (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:2 w:0)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpBadDebt (r:1 w:1)
+	/// Proof Skipped: XSTPool CdpBadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool CdpCollateralRatios (r:1 w:0)
+	/// Proof Skipped: XSTPool CdpCollateralRatios (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PriceTools PriceInfos (r:2 w:0)
+	/// Proof Skipped: PriceTools PriceInfos (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Permissions Permissions (r:2 w:0)
+	/// Proof Skipped: Permissions Permissions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:5 w:5)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:2 w:2)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn liquidate_cdp() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4390`
+		//  Estimated: `76480`
+		// Minimum execution time: 1_689_000_000 picoseconds.
+		Weight::from_parts(1_702_000_000, 76480)
+			.saturating_add(RocksDbWeight::get().reads(25_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuardStates (r:0 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuardStates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool VolatilityGuards (r:0 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuards (max_values: None, max_size: None, mode: Measured)
+	fn set_volatility_guard() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `3093`
+		// Minimum execution time: 152_000_000 picoseconds.
+		Weight::from_parts(155_000_000, 3093)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: XSTPool VolatilityGuardStates (r:1 w:1)
+	/// Proof Skipped: XSTPool VolatilityGuardStates (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool ReferenceAssetId (r:1 w:0)
+	/// Proof Skipped: XSTPool ReferenceAssetId (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XSTPool EnabledSynthetics (r:1 w:0)
+	/// Proof Skipped: XSTPool EnabledSynthetics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XSTPool PriceTransformations (r:1 w:0)
+	/// Proof Skipped: XSTPool PriceTransformations (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolPolicies (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolPolicies (max_values: None, max_size: None, mode: Measured)
+	/// Storage: OracleProxy EnabledOracles (r:1 w:0)
+	/// Proof Skipped: OracleProxy EnabledOracles (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: OracleProxy SymbolProviders (r:1 w:0)
+	/// Proof Skipped: OracleProxy SymbolProviders (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Band SymbolRates (r:1 w:0)
+	/// Proof Skipped: Band SymbolRates (max_values: None, max_size: None, mode: Measured)
+	fn resume_synthetic_trading() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1893`
+		//  Estimated: `21437`
+		// Minimum execution time: 298_000_000 picoseconds.
+		Weight::from_parts(303_000_000, 21437)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -31,7 +31,7 @@
 use crate::cli::prelude::*;
 use crate::substrate::AccountId;
 use bridge_types::H160;
-use substrate_gen::BridgeSignatureVersion;
+use substrate_gen::{BridgeSignatureVersion, GasStrategy, NetworkSettings};
 
 #[derive(Args, Clone, Debug)]
 pub struct Command {
@@ -46,6 +46,15 @@ pub struct Command {
     /// Bridge contract address
     #[clap(short, long)]
     contract: H160,
+    /// EVM chain id of the bridge network
+    #[clap(long)]
+    chain_id: u64,
+    /// Number of sidechain blocks to wait before handling a transaction
+    #[clap(long, default_value = "30")]
+    confirmations: u64,
+    /// Use EIP-1559 transactions instead of legacy ones
+    #[clap(long)]
+    eip1559: bool,
 }
 
 impl Command {
@@ -56,6 +65,15 @@ impl Command {
             self.contract,
             self.peers.clone(),
             BridgeSignatureVersion::V2,
+            NetworkSettings {
+                chain_id: self.chain_id,
+                confirmations: self.confirmations,
+                gas_strategy: if self.eip1559 {
+                    GasStrategy::Eip1559
+                } else {
+                    GasStrategy::Legacy
+                },
+            },
         ))
         .await?;
         Ok(())
@@ -31,7 +31,7 @@
 use crate::cli::prelude::*;
 use bridge_types::H256;
 use futures::StreamExt;
-use substrate_gen::SignatureParams;
+use substrate_gen::{GasStrategy, SignatureParams};
 
 #[derive(Args, Clone, Debug)]
 pub struct Command {
@@ -88,6 +88,29 @@ impl Command {
                 (),
             )
             .await?;
+        let settings = sub
+            .storage_fetch(
+                &runtime::storage()
+                    .eth_bridge()
+                    .sidechain_network_settings(&self.network),
+                (),
+            )
+            .await?;
+        // Networks without settings are operated with Ethereum mainnet ones.
+        let (gas_strategy, confirmations) = match settings {
+            Some(settings) => {
+                let chain_id = eth.inner().get_chainid().await?.as_u64();
+                if chain_id != settings.chain_id {
+                    return Err(anyhow::anyhow!(
+                        "Ethereum node serves chain {}, expected {}",
+                        chain_id,
+                        settings.chain_id
+                    ));
+                }
+                (settings.gas_strategy, settings.confirmations)
+            }
+            None => (GasStrategy::Legacy, 30),
+        };
         let contract = ethereum_gen::Bridge::new(contract_address, eth.inner());
         let request = sub
             .storage_fetch(
@@ -179,14 +202,17 @@ impl Command {
             }
             _ => return Ok(()),
         };
-        let call = call.legacy();
+        let call = match gas_strategy {
+            GasStrategy::Legacy => call.legacy(),
+            GasStrategy::Eip1559 => call,
+        };
         info!("Static call");
         call.call().await?;
         eth.save_gas_price(&call, "").await?;
         info!("Send");
         let pending = call.send().await?;
         info!("Wait for confirmations: {:?}", pending);
-        let res = pending.confirmations(30).await?;
+        let res = pending.confirmations(confirmations as usize).await?;
         info!("Result: {:?}", res);
         if let (Some(kind), Some(tx)) = (kind, res) {
             sub.submit_extrinsic(&runtime::tx().eth_bridge().request_from_sidechain(
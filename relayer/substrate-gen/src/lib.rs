@@ -64,6 +64,23 @@ pub enum BridgeSignatureVersion {
     V2,
 }
 
+#[derive(
+    Clone, Copy, Encode, Decode, PartialEq, Eq, Debug, scale_info::TypeInfo, Serialize, Deserialize,
+)]
+pub enum GasStrategy {
+    Legacy,
+    Eip1559,
+}
+
+#[derive(
+    Clone, Copy, Encode, Decode, PartialEq, Eq, Debug, scale_info::TypeInfo, Serialize, Deserialize,
+)]
+pub struct NetworkSettings {
+    pub chain_id: u64,
+    pub confirmations: u64,
+    pub gas_strategy: GasStrategy,
+}
+
 pub type MaxU32 = sp_runtime::traits::ConstU32<{ core::u32::MAX }>;
 pub type UnboundedBridgeMessage = bridge_types::substrate::BridgeMessage<MaxU32>;
 pub type UnboundedGenericCommitment = bridge_types::GenericCommitment<MaxU32, MaxU32>;
@@ -77,6 +94,10 @@ pub mod runtime {
     use crate::AssetKind;
     #[subxt(substitute_type = "eth_bridge::BridgeSignatureVersion")]
     use crate::BridgeSignatureVersion;
+    #[subxt(substitute_type = "eth_bridge::GasStrategy")]
+    use crate::GasStrategy;
+    #[subxt(substitute_type = "eth_bridge::NetworkSettings")]
+    use crate::NetworkSettings;
     #[subxt(substitute_type = "eth_bridge::offchain::SignatureParams")]
     use crate::SignatureParams;
     #[subxt(substitute_type = "bridge_types::substrate::BridgeMessage")]
@@ -172,9 +193,18 @@ pub mod runtime {
 pub use config::*;
 pub mod config {
     use std::fmt::Debug;
-    use subxt::{tx::PolkadotExtrinsicParams, Config};
+    use subxt::{tx::BaseExtrinsicParams, Config};
+
+    pub type SoraExtrinsicParams = BaseExtrinsicParams<DefaultConfig, SoraTip>;
 
-    pub type SoraExtrinsicParams = PolkadotExtrinsicParams<DefaultConfig>;
+    /// Extra data of `ChargeFeeInAsset` and `ChargeTransactionPayment` extensions, which follow
+    /// each other in the runtime. Fee is always paid in XOR.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Encode)]
+    pub struct SoraTip {
+        fee_asset_id: Option<[u8; 32]>,
+        #[codec(compact)]
+        tip: u128,
+    }
 
     #[derive(Clone, Debug, Default, Eq, PartialEq)]
     pub struct DefaultConfig;
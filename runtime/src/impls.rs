@@ -407,6 +407,32 @@ impl Contains<crate::RuntimeCall> for EVMBridgeCallFilter {
     }
 }
 
+/// Calls which can be dispatched by approved Ceres and Hermes governance proposals.
+pub struct GovernanceProposalCallFilter;
+
+impl frame_support::traits::Contains<crate::RuntimeCall> for GovernanceProposalCallFilter {
+    fn contains(call: &crate::RuntimeCall) -> bool {
+        match call {
+            crate::RuntimeCall::DemeterFarmingPlatform(
+                demeter_farming_platform::Call::change_pool_multiplier { .. }
+                | demeter_farming_platform::Call::change_pool_deposit_fee { .. },
+            ) => true,
+            crate::RuntimeCall::DemeterVaults(
+                demeter_vaults::Call::change_performance_fee { .. }
+                | demeter_vaults::Call::change_vault_status { .. },
+            ) => true,
+            crate::RuntimeCall::CeresLaunchpad(
+                ceres_launchpad::Call::change_fee_percent_for_raised_funds { .. }
+                | ceres_launchpad::Call::change_ceres_burn_fee { .. }
+                | ceres_launchpad::Call::change_ceres_contribution_fee { .. },
+            ) => true,
+            #[cfg(feature = "runtime-benchmarks")]
+            crate::RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
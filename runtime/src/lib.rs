@@ -52,7 +52,7 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
-use crate::impls::PreimageWeightInfo;
+use crate::impls::{GovernanceProposalCallFilter, PreimageWeightInfo};
 #[cfg(feature = "ready-to-test")]
 use crate::impls::{
     DispatchableSubstrateBridgeCall, EVMBridgeCallFilter, SubstrateBridgeCallFilter,
@@ -119,6 +119,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use static_assertions::assert_eq_size;
 use traits::parameter_type_with_key;
+use xor_fee::extension::ChargeFeeInAsset;
 
 // A few exports that help ease life for downstream crates.
 pub use common::prelude::{
@@ -152,9 +153,7 @@ pub use sp_runtime::BuildStorage;
 
 use eth_bridge::offchain::SignatureParams;
 use eth_bridge::requests::{AssetKind, OffchainRequest, OutgoingRequestEncoded, RequestStatus};
-use impls::{
-    CollectiveWeightInfo, DemocracyWeightInfo, NegativeImbalanceOf, OnUnbalancedDemocracySlash,
-};
+use impls::{CollectiveWeightInfo, DemocracyWeightInfo, OnUnbalancedDemocracySlash};
 
 use frame_support::traits::{Everything, ExistenceRequirement, Get, PrivilegeCmp, WithdrawReasons};
 #[cfg(all(feature = "private-net", feature = "wip"))] // order-book
@@ -260,7 +259,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_version: 60,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 60,
+    transaction_version: 62,
     state_version: 0,
 };
 
@@ -987,6 +986,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetFee;
     type OnPoolCreated = (PswapDistribution, Farming);
     type OnPoolReservesChanged = PriceTools;
+    type OnSwapFeeCharged = Referrals;
     type WeightInfo = pool_xyk::weights::SubstrateWeight<Runtime>;
     type XSTMarketInfo = XSTPool;
     type GetTradingPairRestrictedFlag = GetTradingPairRestrictedFlag;
@@ -1025,6 +1025,17 @@ parameter_types! {
                 .expect("Failed to get ordinary account id for technical account id.");
         account_id
     };
+    pub ReferralsRewardsAcc: AccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            b"referrals".to_vec(),
+            b"rewards".to_vec(),
+        );
+        let account_id =
+            technical::Pallet::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+                .expect("Failed to get ordinary account id for technical account id.");
+        account_id
+    };
+    pub const MaxReferralTiers: u32 = 10;
 }
 
 impl liquidity_proxy::Config for Runtime {
@@ -1159,6 +1170,7 @@ where
             frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
             frame_system::CheckNonce::<Runtime>::from(index),
             frame_system::CheckWeight::<Runtime>::new(),
+            ChargeFeeInAsset::<Runtime>::new(),
             ChargeTransactionPayment::<Runtime>::new(),
         );
         #[cfg_attr(not(feature = "std"), allow(unused_variables))]
@@ -1190,7 +1202,10 @@ where
 }
 
 impl referrals::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type ReservesAcc = ReferralsReservesAcc;
+    type RewardsAcc = ReferralsRewardsAcc;
+    type MaxReferralTiers = MaxReferralTiers;
     type WeightInfo = referrals::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1238,6 +1253,7 @@ impl<T> xor_fee::ApplyCustomFees<RuntimeCall> for xor_fee::Pallet<T> {
             | RuntimeCall::LiquidityProxy(..)
             | RuntimeCall::MulticollateralBondingCurvePool(..)
             | RuntimeCall::PoolXYK(..)
+            | RuntimeCall::PriceFeed(..)
             | RuntimeCall::Rewards(..)
             | RuntimeCall::Staking(pallet_staking::Call::payout_stakers { .. })
             | RuntimeCall::TradingPair(..)
@@ -1355,7 +1371,7 @@ impl xor_fee::WithdrawFee<Runtime> for WithdrawFee {
         who: &AccountId,
         call: &RuntimeCall,
         fee: Balance,
-    ) -> Result<(AccountId, Option<NegativeImbalanceOf<Runtime>>), DispatchError> {
+    ) -> Result<xor_fee::LiquidityInfo<Runtime>, DispatchError> {
         match call {
             RuntimeCall::Referrals(referrals::Call::set_referrer { referrer })
                 if Referrals::can_set_referrer(who) =>
@@ -1369,17 +1385,35 @@ impl xor_fee::WithdrawFee<Runtime> for WithdrawFee {
                         WithdrawReasons::TRANSACTION_PAYMENT,
                         ExistenceRequirement::KeepAlive,
                     )?),
-                ))
+                )
+                    .into())
             }
-            _ => Ok((
-                who.clone(),
-                Some(Balances::withdraw(
-                    who,
-                    fee,
-                    WithdrawReasons::TRANSACTION_PAYMENT,
-                    ExistenceRequirement::KeepAlive,
-                )?),
-            )),
+            _ => {
+                if let Some((sponsor, imbalance)) = XorFee::withdraw_sponsored_fee(who, call, fee) {
+                    return Ok(xor_fee::LiquidityInfo::Sponsored(sponsor, imbalance));
+                }
+                Ok((
+                    who.clone(),
+                    Some(Balances::withdraw(
+                        who,
+                        fee,
+                        WithdrawReasons::TRANSACTION_PAYMENT,
+                        ExistenceRequirement::KeepAlive,
+                    )?),
+                )
+                    .into())
+            }
+        }
+    }
+
+    fn is_fee_paid_by_user(who: &AccountId, call: &RuntimeCall, fee: Balance) -> bool {
+        match call {
+            RuntimeCall::Referrals(referrals::Call::set_referrer { .. })
+                if Referrals::can_set_referrer(who) =>
+            {
+                false
+            }
+            _ => XorFee::fee_sponsor(who, call, fee).is_none(),
         }
     }
 }
@@ -1404,10 +1438,12 @@ impl xor_fee::Config for Runtime {
     type OnValBurned = ValBurnedAggregator<Staking>;
     type CustomFees = XorFee;
     type GetTechnicalAccountId = GetXorFeeAccountId;
+    type GetSponsorsAccountId = GetXorFeeSponsorsAccountId;
     type SessionManager = Staking;
     type WeightInfo = xor_fee::weights::SubstrateWeight<Runtime>;
     type WithdrawFee = WithdrawFee;
     type BuyBackHandler = liquidity_proxy::LiquidityProxyBuyBackHandler<Runtime, GetBuyBackDexId>;
+    type PriceTools = PriceTools;
 }
 
 pub struct ConstantFeeMultiplier;
@@ -1634,6 +1670,17 @@ parameter_types! {
         technical::Pallet::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
             .expect("Failed to get ordinary account id for technical account id.")
     };
+    pub GetXorFeeSponsorsTechAccountId: TechAccountId = {
+        TechAccountId::from_generic_pair(
+            xor_fee::TECH_ACCOUNT_PREFIX.to_vec(),
+            xor_fee::TECH_ACCOUNT_SPONSORS.to_vec(),
+        )
+    };
+    pub GetXorFeeSponsorsAccountId: AccountId = {
+        let tech_account_id = GetXorFeeSponsorsTechAccountId::get();
+        technical::Pallet::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+            .expect("Failed to get ordinary account id for technical account id.")
+    };
     pub GetXSTPoolPermissionedTechAccountId: TechAccountId = {
         let tech_account_id = TechAccountId::from_generic_pair(
             xst::TECH_ACCOUNT_PREFIX.to_vec(),
@@ -1648,6 +1695,20 @@ parameter_types! {
                 .expect("Failed to get ordinary account id for technical account id.");
         account_id
     };
+    pub GetXSTCdpCollateralTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            xst::TECH_ACCOUNT_PREFIX.to_vec(),
+            xst::TECH_ACCOUNT_CDP_COLLATERAL.to_vec(),
+        );
+        tech_account_id
+    };
+    pub GetXSTCdpCollateralAccountId: AccountId = {
+        let tech_account_id = GetXSTCdpCollateralTechAccountId::get();
+        let account_id =
+            technical::Pallet::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+                .expect("Failed to get ordinary account id for technical account id.");
+        account_id
+    };
     pub GetTrustlessBridgeTechAccountId: TechAccountId = {
         let tech_account_id = TechAccountId::from_generic_pair(
             bridge_types::types::TECH_ACCOUNT_PREFIX.to_vec(),
@@ -1833,12 +1894,15 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
 parameter_types! {
     pub const GetXstPoolConversionAssetId: AssetId = GetXstAssetId::get();
     pub const GetSyntheticBaseBuySellLimit: Balance = Balance::MAX;
+    pub const GetLeverageRebalancePeriod: BlockNumber = 1 * DAYS;
+    pub const GetCdpLiquidationPenalty: Percent = Percent::from_percent(10);
 }
 
 impl xst::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type GetSyntheticBaseAssetId = GetXstPoolConversionAssetId;
     type GetXSTPoolPermissionedTechAccountId = GetXSTPoolPermissionedTechAccountId;
+    type GetCdpCollateralTechAccountId = GetXSTCdpCollateralTechAccountId;
     type EnsureDEXManager = DEXManager;
     type PriceToolsPallet = PriceTools;
     type WeightInfo = xst::weights::SubstrateWeight<Runtime>;
@@ -1846,6 +1910,10 @@ impl xst::Config for Runtime {
     type Symbol = <Runtime as band::Config>::Symbol;
     type TradingPairSourceManager = TradingPair;
     type GetSyntheticBaseBuySellLimit = GetSyntheticBaseBuySellLimit;
+    type LeverageRebalancePeriod = GetLeverageRebalancePeriod;
+    type LiquidityProxy = LiquidityProxy;
+    type CdpLiquidationPenalty = GetCdpLiquidationPenalty;
+    type Time = Timestamp;
 }
 
 parameter_types! {
@@ -1882,6 +1950,18 @@ impl vested_rewards::Config for Runtime {
     type WeightInfo = vested_rewards::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const MaxVestingSchedules: u32 = 64;
+    pub const MinVestedTransfer: Balance = balance!(1);
+}
+
+impl vesting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type MinVestedTransfer = MinVestedTransfer;
+    type WeightInfo = vesting::weights::SubstrateWeight<Runtime>;
+}
+
 impl price_tools::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type LiquidityProxy = LiquidityProxy;
@@ -1954,10 +2034,12 @@ parameter_types! {
     pub const CeresAssetId: AssetId = common::AssetId32::from_bytes
         (hex!("008bcfd2387d3fc453333557eecb0efe59fcba128769b2feefdd306e98e66440"));
     pub const MaximumCeresInStakingPool: Balance = balance!(14400);
+    pub const MaxCeresStakingRewardPeriods: u32 = 16;
 }
 
 impl ceres_launchpad::Config for Runtime {
     const MILLISECONDS_PER_DAY: Moment = 86_400_000;
+    type GovernanceOrigin = GovernanceProposalOrigin;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ceres_launchpad::weights::SubstrateWeight<Runtime>;
 }
@@ -1968,6 +2050,7 @@ impl ceres_staking::Config for Runtime {
     type CeresPerDay = CeresPerDay;
     type CeresAssetId = CeresAssetId;
     type MaximumCeresInStakingPool = MaximumCeresInStakingPool;
+    type MaxRewardPeriods = MaxCeresStakingRewardPeriods;
     type WeightInfo = ceres_staking::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1986,9 +2069,30 @@ impl ceres_token_locker::Config for Runtime {
     type WeightInfo = ceres_token_locker::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const GovernanceProposalTimelock: Moment = 2 * 86_400_000;
+    pub CeresGovernanceProposalQuorum: Perbill = Perbill::from_percent(5);
+    pub const CeresGovernanceProposalDeposit: Balance = balance!(100);
+    pub const HermesGovernanceProposalQuorum: Balance = balance!(1000000);
+    pub GovernanceProposalThreshold: Perbill = Perbill::from_percent(60);
+}
+
+type GovernanceProposalOrigin = EitherOf<
+    ceres_governance_platform::EnsureProposal,
+    hermes_governance_platform::EnsureProposal,
+>;
+
 impl ceres_governance_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type CeresAssetId = CeresAssetId;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type ProposalFilter = GovernanceProposalCallFilter;
+    type ProposalTimelock = GovernanceProposalTimelock;
+    type ProposalQuorum = CeresGovernanceProposalQuorum;
+    type ProposalDeposit = CeresGovernanceProposalDeposit;
+    type ProposalThreshold = GovernanceProposalThreshold;
+    const MIN_DURATION_OF_PROPOSAL: Moment = 14_400_000;
     type WeightInfo = ceres_governance_platform::weights::SubstrateWeight<Runtime>;
 }
 
@@ -2000,20 +2104,39 @@ impl demeter_farming_platform::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DemeterAssetId = DemeterAssetId;
     const BLOCKS_PER_HOUR_AND_A_HALF: BlockNumber = 3 * HOURS / 2;
+    type GovernanceOrigin = GovernanceProposalOrigin;
     type WeightInfo = demeter_farming_platform::weights::SubstrateWeight<Runtime>;
 }
 
+impl demeter_vaults::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type LiquidityProxy = LiquidityProxy;
+    type PriceTools = PriceTools;
+    const MAX_SLIPPAGE: Perbill = Perbill::from_percent(2);
+    const BLOCKS_PER_COMPOUND: BlockNumber = 6 * HOURS;
+    const MAX_VAULTS_PER_BLOCK: u32 = 5;
+    type GovernanceOrigin = GovernanceProposalOrigin;
+    type WeightInfo = demeter_vaults::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const OracleProxyMaxRateHistory: u32 = 360;
+}
+
 impl oracle_proxy::Config for Runtime {
     type Symbol = Symbol;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = oracle_proxy::weights::SubstrateWeight<Runtime>;
     type BandChainOracle = band::Pallet<Runtime>;
+    type PriceFeedOracle = price_feed::Pallet<Runtime>;
+    type MaxRateHistory = OracleProxyMaxRateHistory;
 }
 
 parameter_types! {
     pub const GetBandRateStalePeriod: Moment = 60*5*1000; // 5 minutes
     pub const GetBandRateStaleBlockPeriod: u32 = 600; // 1 hour in blocks
     pub const BandMaxRelaySymbols: u32 = 100;
+    pub const BandMaxDeviatingRounds: u32 = 3;
 }
 
 impl band::Config for Runtime {
@@ -2026,6 +2149,25 @@ impl band::Config for Runtime {
     type GetBandRateStaleBlockPeriod = GetBandRateStaleBlockPeriod;
     type OnSymbolDisabledHook = xst::Pallet<Runtime>;
     type MaxRelaySymbols = BandMaxRelaySymbols;
+    type MaxDeviatingRounds = BandMaxDeviatingRounds;
+}
+
+parameter_types! {
+    pub const GetPriceFeedRateStalePeriod: Moment = 60*5*1000; // 5 minutes
+    pub const PriceFeedMaxSubmitSymbols: u32 = 100;
+    pub const PriceFeedUpdatePeriod: BlockNumber = 1 * MINUTES;
+}
+
+impl price_feed::Config for Runtime {
+    type Symbol = Symbol;
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = price_feed::crypto::AuthorityId;
+    type OnNewSymbolsRelayedHook = oracle_proxy::Pallet<Runtime>;
+    type Time = Timestamp;
+    type GetRateStalePeriod = GetPriceFeedRateStalePeriod;
+    type MaxSubmitSymbols = PriceFeedMaxSubmitSymbols;
+    type UpdatePeriod = PriceFeedUpdatePeriod;
+    type WeightInfo = price_feed::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -2039,12 +2181,19 @@ parameter_types! {
 impl hermes_governance_platform::Config for Runtime {
     const MIN_DURATION_OF_POLL: Moment = 14_400_000;
     const MAX_DURATION_OF_POLL: Moment = 604_800_000;
+    const CONVICTION_LOCK_PERIOD: Moment = 86_400_000;
     type StringLimit = StringLimit;
     type OptionsLimit = OptionsLimit;
     type RuntimeEvent = RuntimeEvent;
     type HermesAssetId = HermesAssetId;
     type TitleLimit = TitleLimit;
     type DescriptionLimit = DescriptionLimit;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type ProposalFilter = GovernanceProposalCallFilter;
+    type ProposalTimelock = GovernanceProposalTimelock;
+    type ProposalQuorum = HermesGovernanceProposalQuorum;
+    type ProposalThreshold = GovernanceProposalThreshold;
     type WeightInfo = hermes_governance_platform::weights::SubstrateWeight<Runtime>;
 }
 
@@ -2217,6 +2366,7 @@ impl eth_app::Config for Runtime {
     type BalancePrecisionConverter = impls::BalancePrecisionConverter;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = BridgeProxy;
+    type OutflowLimiter = BridgeProxy;
     type WeightInfo = ();
 }
 
@@ -2235,6 +2385,7 @@ impl erc20_app::Config for Runtime {
     type BalancePrecisionConverter = impls::BalancePrecisionConverter;
     type AssetIdConverter = sp_runtime::traits::ConvertInto;
     type BridgeAssetLocker = BridgeProxy;
+    type OutflowLimiter = BridgeProxy;
     type WeightInfo = ();
 }
 
@@ -2410,7 +2561,7 @@ construct_runtime! {
         RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage} = 4,
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 5,
         Permissions: permissions::{Pallet, Call, Storage, Config<T>, Event<T>} = 6,
-        Referrals: referrals::{Pallet, Call, Storage} = 7,
+        Referrals: referrals::{Pallet, Call, Storage, Event<T>} = 7,
         Rewards: rewards::{Pallet, Call, Config<T>, Storage, Event<T>} = 8,
         XorFee: xor_fee::{Pallet, Call, Storage, Event<T>} = 9,
         BridgeMultisig: bridge_multisig::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
@@ -2455,7 +2606,7 @@ construct_runtime! {
         CeresStaking: ceres_staking::{Pallet, Call, Storage, Event<T>} = 45,
         CeresLiquidityLocker: ceres_liquidity_locker::{Pallet, Call, Storage, Event<T>} = 46,
         CeresTokenLocker: ceres_token_locker::{Pallet, Call, Storage, Event<T>} = 47,
-        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Event<T>} = 48,
+        CeresGovernancePlatform: ceres_governance_platform::{Pallet, Call, Storage, Origin, Event<T>} = 48,
         CeresLaunchpad: ceres_launchpad::{Pallet, Call, Storage, Event<T>} = 49,
         DemeterFarmingPlatform: demeter_farming_platform::{Pallet, Call, Storage, Event<T>} = 50,
         // Provides a semi-sorted list of nominators for staking.
@@ -2463,8 +2614,11 @@ construct_runtime! {
         ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 52,
         Band: band::{Pallet, Call, Storage, Event<T>} = 53,
         OracleProxy: oracle_proxy::{Pallet, Call, Storage, Event<T>} = 54,
-        HermesGovernancePlatform: hermes_governance_platform::{Pallet, Call, Storage, Event<T>} = 55,
+        HermesGovernancePlatform: hermes_governance_platform::{Pallet, Call, Storage, Origin, Event<T>} = 55,
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 56,
+        Vesting: vesting::{Pallet, Call, Storage, Event<T>} = 58,
+        DemeterVaults: demeter_vaults::{Pallet, Call, Storage, Event<T>} = 59,
+        PriceFeed: price_feed::{Pallet, Call, Storage, Event<T>} = 60,
 
         #[cfg(feature = "wip")] // order-book
         OrderBook: order_book::{Pallet, Call, Storage, Event<T>} = 57,
@@ -2480,7 +2634,7 @@ construct_runtime! {
         LeafProvider: leaf_provider::{Pallet, Storage, Event<T>} = 99,
         // TODO: rename to BridgeProxy
         #[cfg(feature = "ready-to-test")] // Bridges
-        BridgeProxy: bridge_proxy::{Pallet, Call, Storage, Event} = 103,
+        BridgeProxy: bridge_proxy::{Pallet, Call, Storage, Event<T>} = 103,
 
         // Trustless EVM bridge
         #[cfg(feature = "ready-to-test")] // EVM bridge
@@ -2559,6 +2713,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
+    ChargeFeeInAsset<Runtime>,
     ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -2579,6 +2734,22 @@ pub type Executive = frame_executive::Executive<
 #[cfg(feature = "ready-to-test")] // Bridges
 pub type MmrHashing = <Runtime as pallet_mmr::Config>::Hashing;
 
+fn collateral_reserves_info_to_api(
+    asset_id: AssetId,
+    info: multicollateral_bonding_curve_pool::CollateralReservesInfo,
+) -> multicollateral_bonding_curve_pool_runtime_api::CollateralReservesInfo<AssetId, Balance> {
+    multicollateral_bonding_curve_pool_runtime_api::CollateralReservesInfo {
+        asset_id,
+        reserves: info.reserves,
+        reference_value: info.reference_value,
+        ideal_reference_value: info.ideal_reference_value,
+        collateralized_fraction: info
+            .collateralized_fraction
+            .and_then(|fraction| fraction.into_bits().try_into().ok()),
+        sell_penalty: info.sell_penalty.and_then(|penalty| penalty.into_bits().try_into().ok()),
+    }
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -2988,6 +3159,37 @@ impl_runtime_apis! {
         }
     }
 
+    impl multicollateral_bonding_curve_pool_runtime_api::MulticollateralBondingCurvePoolApi<
+        Block,
+        AssetId,
+        Balance,
+    > for Runtime {
+        fn collateral_reserves(
+            collateral_asset_id: AssetId,
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::CollateralReservesInfo<AssetId, Balance>> {
+            MulticollateralBondingCurvePool::collateral_reserves_info(&collateral_asset_id)
+                .ok()
+                .map(|info| collateral_reserves_info_to_api(collateral_asset_id, info))
+        }
+
+        fn proof_of_reserves() -> Option<multicollateral_bonding_curve_pool_runtime_api::ReservesReport<AssetId, Balance>> {
+            let collaterals: Vec<_> = MulticollateralBondingCurvePool::collateral_reserves_report()
+                .ok()?
+                .into_iter()
+                .map(|(collateral_asset_id, info)| collateral_reserves_info_to_api(collateral_asset_id, info))
+                .collect();
+            // unknown if any of the collaterals can't be valued, so that the total is never under-reported
+            let total_reference_value = collaterals.iter().try_fold(0, |total: Balance, info| {
+                info.reference_value.map(|value| total.saturating_add(value))
+            });
+            Some(multicollateral_bonding_curve_pool_runtime_api::ReservesReport {
+                reference_asset_id: MulticollateralBondingCurvePool::reference_asset_id(),
+                total_reference_value,
+                collaterals,
+            })
+        }
+    }
+
     impl oracle_proxy_runtime_api::OracleProxyAPI<
         Block,
         Symbol,
@@ -3011,6 +3213,27 @@ impl_runtime_apis! {
                 OracleProxy as common::DataFeed<Symbol, common::Rate, ResolveTime>
             >::list_enabled_symbols()
         }
+
+        fn rate_at(
+            symbol: Symbol,
+            timestamp: ResolveTime,
+        ) -> Option<oracle_proxy_runtime_api::RateInfo> {
+            OracleProxy::rate_at(&symbol, timestamp).map(|rate| oracle_proxy_runtime_api::RateInfo {
+                value: rate.value,
+                last_updated: rate.timestamp,
+            })
+        }
+
+        fn twap(
+            symbol: Symbol,
+            from: ResolveTime,
+            to: ResolveTime,
+        ) -> Option<oracle_proxy_runtime_api::RateInfo> {
+            OracleProxy::twap(&symbol, from, to).map(|rate| oracle_proxy_runtime_api::RateInfo {
+                value: rate.value,
+                last_updated: rate.timestamp,
+            })
+        }
     }
 
     impl pswap_distribution_runtime_api::PswapDistributionAPI<
@@ -3267,9 +3490,12 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, ceres_governance_platform, CeresGovernancePlatform);
             list_benchmark!(list, extra, ceres_launchpad, CeresLaunchpad);
             list_benchmark!(list, extra, demeter_farming_platform, DemeterFarmingPlatformBench::<Runtime>);
+            list_benchmark!(list, extra, demeter_vaults, DemeterVaults);
             list_benchmark!(list, extra, band, Band);
             list_benchmark!(list, extra, xst, XSTPoolBench::<Runtime>);
             list_benchmark!(list, extra, oracle_proxy, OracleProxy);
+            list_benchmark!(list, extra, price_feed, PriceFeed);
+            list_benchmark!(list, extra, vesting, Vesting);
 
             #[cfg(feature = "wip")] // order-book
             list_benchmark!(list, extra, order_book, OrderBook);
@@ -3353,10 +3579,13 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, ceres_governance_platform, CeresGovernancePlatform);
             add_benchmark!(params, batches, ceres_launchpad, CeresLaunchpad);
             add_benchmark!(params, batches, demeter_farming_platform, DemeterFarmingPlatformBench::<Runtime>);
+            add_benchmark!(params, batches, demeter_vaults, DemeterVaults);
             add_benchmark!(params, batches, band, Band);
             add_benchmark!(params, batches, xst, XSTPoolBench::<Runtime>);
             add_benchmark!(params, batches, hermes_governance_platform, HermesGovernancePlatform);
             add_benchmark!(params, batches, oracle_proxy, OracleProxy);
+            add_benchmark!(params, batches, price_feed, PriceFeed);
+            add_benchmark!(params, batches, vesting, Vesting);
 
             #[cfg(feature = "wip")] // order-book
             add_benchmark!(params, batches, order_book, OrderBook);
@@ -3401,6 +3630,70 @@ impl_runtime_apis! {
         }
     }
 
+    impl vesting_runtime_api::VestingApi<Block, AccountId, AssetId, Balance> for Runtime {
+        fn vesting_balances(account_id: AccountId) -> Vec<vesting_runtime_api::VestingBalanceInfo<AssetId, Balance>> {
+            Vesting::vesting_balances(&account_id)
+                .into_iter()
+                .map(|(asset_id, vested, claimable, unvested)| vesting_runtime_api::VestingBalanceInfo {
+                    asset_id,
+                    vested,
+                    claimable,
+                    unvested,
+                })
+                .collect()
+        }
+    }
+
+    impl ceres_liquidity_locker_runtime_api::CeresLiquidityLockerApi<Block, AccountId, AssetId, Balance> for Runtime {
+        fn locked_liquidity(asset_a: AssetId, asset_b: AssetId) -> ceres_liquidity_locker_runtime_api::LockedLiquidityInfo<Balance> {
+            let (locked_pool_tokens, total_pool_tokens) = CeresLiquidityLocker::locked_liquidity(asset_a, asset_b);
+            ceres_liquidity_locker_runtime_api::LockedLiquidityInfo::<Balance> {
+                locked_pool_tokens,
+                total_pool_tokens,
+            }
+        }
+
+        fn locked_liquidity_of_account(account_id: AccountId, asset_a: AssetId, asset_b: AssetId) -> ceres_liquidity_locker_runtime_api::LockedLiquidityInfo<Balance> {
+            let (locked_pool_tokens, total_pool_tokens) = CeresLiquidityLocker::locked_liquidity_of_account(&account_id, asset_a, asset_b);
+            ceres_liquidity_locker_runtime_api::LockedLiquidityInfo::<Balance> {
+                locked_pool_tokens,
+                total_pool_tokens,
+            }
+        }
+    }
+
+    impl ceres_staking_runtime_api::CeresStakingApi<Block, AccountId, Balance> for Runtime {
+        fn pending_rewards(account_id: AccountId) -> ceres_staking_runtime_api::BalanceInfo<Balance> {
+            ceres_staking_runtime_api::BalanceInfo::<Balance> {
+                balance: CeresStaking::pending_rewards(&account_id),
+            }
+        }
+    }
+
+    impl demeter_vaults_runtime_api::DemeterVaultsApi<Block, AccountId, Balance> for Runtime {
+        fn share_info(vault_id: u32, account_id: AccountId) -> demeter_vaults_runtime_api::ShareInfo<Balance> {
+            let (shares, total_shares, balance) = DemeterVaults::share_info(vault_id, &account_id);
+            demeter_vaults_runtime_api::ShareInfo::<Balance> {
+                shares,
+                total_shares,
+                balance,
+            }
+        }
+    }
+
+    impl referrals_runtime_api::ReferralsApi<Block, AccountId, Balance> for Runtime {
+        fn referrals_info(referrer: AccountId) -> Vec<referrals_runtime_api::ReferralInfo<AccountId, Balance>> {
+            Referrals::referral_stats(&referrer)
+                .into_iter()
+                .map(|(referral, volume, earned)| referrals_runtime_api::ReferralInfo {
+                    referral,
+                    volume,
+                    earned,
+                })
+                .collect()
+        }
+    }
+
     impl farming_runtime_api::FarmingApi<Block, AssetId> for Runtime {
         fn reward_doubling_assets() -> Vec<AssetId> {
             Farming::reward_doubling_assets()
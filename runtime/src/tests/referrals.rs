@@ -30,11 +30,13 @@
 
 use common::mock::{alice, bob, charlie};
 use common::prelude::constants::SMALL_FEE;
-use common::{AssetInfoProvider, XOR};
-use frame_support::{assert_err, assert_ok};
+use common::{balance, AssetInfoProvider, OnSwapFeeCharged, PSWAP, XOR};
+use frame_support::{assert_err, assert_noop, assert_ok};
 use framenode_chain_spec::ext;
+use referrals::ReferralTier;
+use sp_runtime::Permill;
 
-use crate::{Assets, Currencies, Referrals, Runtime, RuntimeOrigin};
+use crate::{Assets, Currencies, Referrals, ReferralsRewardsAcc, Runtime, RuntimeOrigin};
 
 type E = referrals::Error<Runtime>;
 
@@ -140,3 +142,158 @@ fn withdraw() {
         assert_ok!(Referrals::withdraw_fee(&alice(), SMALL_FEE));
     })
 }
+
+fn set_default_tiers() {
+    assert_ok!(Referrals::set_referral_tiers(
+        RuntimeOrigin::root(),
+        vec![
+            ReferralTier {
+                min_volume: balance!(0),
+                reward_share: Permill::from_percent(10),
+            },
+            ReferralTier {
+                min_volume: balance!(1000),
+                reward_share: Permill::from_percent(20),
+            },
+        ]
+    ));
+}
+
+#[test]
+fn set_referral_tiers_invalid() {
+    ext().execute_with(|| {
+        let tier = ReferralTier {
+            min_volume: balance!(100),
+            reward_share: Permill::from_percent(10),
+        };
+
+        assert_noop!(
+            Referrals::set_referral_tiers(RuntimeOrigin::signed(alice()), vec![tier.clone()]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Referrals::set_referral_tiers(RuntimeOrigin::root(), vec![tier.clone(), tier]),
+            E::InvalidReferralTiers
+        );
+    })
+}
+
+#[test]
+fn reward_share_by_tier() {
+    ext().execute_with(|| {
+        assert_eq!(Referrals::reward_share(balance!(5000)), Permill::zero());
+
+        set_default_tiers();
+
+        assert_eq!(
+            Referrals::reward_share(balance!(0)),
+            Permill::from_percent(10)
+        );
+        assert_eq!(
+            Referrals::reward_share(balance!(999)),
+            Permill::from_percent(10)
+        );
+        assert_eq!(
+            Referrals::reward_share(balance!(1000)),
+            Permill::from_percent(20)
+        );
+    })
+}
+
+#[test]
+fn swap_fee_rewards_referrer() {
+    ext().execute_with(|| {
+        set_default_tiers();
+        assert_ok!(Referrals::set_referrer_to(&alice(), bob()));
+        assert_ok!(Currencies::update_balance(
+            RuntimeOrigin::root(),
+            charlie(),
+            XOR.into(),
+            balance!(10) as i128
+        ));
+
+        assert_ok!(Referrals::on_swap_fee_charged(
+            &alice(),
+            &charlie(),
+            &XOR.into(),
+            balance!(1),
+            balance!(500)
+        ));
+        assert_eq!(Referrals::referrer_rewards(&bob()), balance!(0.1));
+
+        // Referrer reaches the second tier
+        assert_ok!(Referrals::on_swap_fee_charged(
+            &alice(),
+            &charlie(),
+            &XOR.into(),
+            balance!(1),
+            balance!(500)
+        ));
+        assert_eq!(Referrals::referrer_rewards(&bob()), balance!(0.3));
+        assert_eq!(Referrals::referral_volume(&alice()), balance!(1000));
+        assert_eq!(
+            Referrals::referral_stats(&bob()),
+            vec![(alice(), balance!(1000), balance!(0.3))]
+        );
+
+        // Fees in other assets are ignored
+        assert_ok!(Referrals::on_swap_fee_charged(
+            &alice(),
+            &charlie(),
+            &PSWAP.into(),
+            balance!(1),
+            balance!(500)
+        ));
+        assert_eq!(Referrals::referral_volume(&alice()), balance!(1000));
+
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &charlie()),
+            Ok(balance!(9.7))
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &ReferralsRewardsAcc::get()),
+            Ok(balance!(0.3))
+        );
+    })
+}
+
+#[test]
+fn claim_referral_rewards() {
+    ext().execute_with(|| {
+        set_default_tiers();
+        assert_ok!(Referrals::set_referrer_to(&alice(), bob()));
+        assert_ok!(Currencies::update_balance(
+            RuntimeOrigin::root(),
+            charlie(),
+            XOR.into(),
+            balance!(10) as i128
+        ));
+
+        assert_noop!(
+            Referrals::claim_referral_rewards(RuntimeOrigin::signed(bob())),
+            E::NoReferralRewards
+        );
+
+        assert_ok!(Referrals::on_swap_fee_charged(
+            &alice(),
+            &charlie(),
+            &XOR.into(),
+            balance!(1),
+            balance!(100)
+        ));
+
+        let balance_before = Assets::free_balance(&XOR.into(), &bob()).unwrap();
+        assert_ok!(Referrals::claim_referral_rewards(RuntimeOrigin::signed(
+            bob()
+        )));
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &bob()),
+            Ok(balance_before + balance!(0.1))
+        );
+        assert_eq!(Referrals::referrer_rewards(&bob()), 0);
+        assert_eq!(
+            Referrals::referral_earnings(&bob(), &alice()),
+            balance!(0.1)
+        );
+    })
+}
@@ -41,6 +41,7 @@ use common::{balance, fixed_wrapper, AssetInfoProvider, FilterMode, VAL, XOR};
 use frame_support::assert_ok;
 use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
 use frame_support::pallet_prelude::{InvalidTransaction, Pays};
+use frame_support::storage::{with_transaction, TransactionOutcome};
 use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_support::unsigned::TransactionValidityError;
 use frame_support::weights::WeightToFee as WeightToFeeTrait;
@@ -51,9 +52,10 @@ use pallet_balances::NegativeImbalance;
 use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use referrals::ReferrerBalances;
 use sp_runtime::traits::SignedExtension;
-use sp_runtime::{AccountId32, FixedPointNumber, FixedU128};
+use sp_runtime::{AccountId32, DispatchError, FixedPointNumber, FixedU128, Percent};
 use traits::MultiCurrency;
-use xor_fee::{LiquidityInfo, XorToVal};
+use xor_fee::extension::ChargeFeeInAsset;
+use xor_fee::{LiquidityInfo, SponsoredCall, XorToVal};
 
 type BlockWeights = <Runtime as frame_system::Config>::BlockWeights;
 type LengthToFee = <Runtime as pallet_transaction_payment::Config>::LengthToFee;
@@ -828,6 +830,82 @@ fn withdraw_fee_set_referrer() {
     });
 }
 
+#[test]
+fn withdraw_fee_sponsored() {
+    ext().execute_with(|| {
+        increase_balance(bob(), XOR.into(), balance!(1000));
+
+        assert_ok!(XorFee::set_sponsor_rules(
+            RuntimeOrigin::signed(bob()),
+            vec![SponsoredCall {
+                pallet_name: b"Assets".to_vec().try_into().unwrap(),
+                call_name: Some(b"transfer".to_vec().try_into().unwrap()),
+            }],
+            balance!(1),
+            balance!(1)
+        ));
+        assert_ok!(XorFee::fund_sponsor_budget(
+            RuntimeOrigin::signed(bob()),
+            balance!(10)
+        ));
+        assert_ok!(XorFee::approve_sponsored_user(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            true
+        ));
+        assert_ok!(XorFee::set_fee_sponsor(
+            RuntimeOrigin::signed(alice()),
+            Some(bob())
+        ));
+
+        let dispatch_info = info_from_weight(Weight::from_parts(100_000_000, 0));
+        let call = RuntimeCall::Assets(assets::Call::transfer {
+            asset_id: XOR.into(),
+            to: charlie(),
+            amount: balance!(1),
+        });
+        let initial_balance = Assets::free_balance(&XOR.into(), &alice()).unwrap();
+
+        let result = XorFee::withdraw_fee(&alice(), &call, &dispatch_info, 1337, 0);
+        assert_eq!(
+            result,
+            Ok(LiquidityInfo::Sponsored(
+                bob(),
+                NegativeImbalance::new(SMALL_FEE)
+            ))
+        );
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &alice()),
+            Ok(initial_balance)
+        );
+        assert_eq!(
+            XorFee::sponsors(bob()).unwrap().budget,
+            balance!(10) - SMALL_FEE
+        );
+        assert_eq!(XorFee::sponsored_fees(bob(), alice()), SMALL_FEE);
+
+        // Fee of the call which turns out to be free is returned to the sponsor budget
+        let post_info = PostDispatchInfo {
+            actual_weight: None,
+            pays_fee: Pays::No,
+        };
+        assert_ok!(XorFee::correct_and_deposit_fee(
+            &alice(),
+            &dispatch_info,
+            &post_info,
+            0,
+            0,
+            result.unwrap()
+        ));
+        assert_eq!(XorFee::sponsors(bob()).unwrap().budget, balance!(10));
+        assert_eq!(XorFee::sponsored_fees(bob(), alice()), 0);
+        assert_eq!(
+            Assets::free_balance(&XOR.into(), &alice()),
+            Ok(initial_balance)
+        );
+    });
+}
+
 #[test]
 fn withdraw_fee_set_referrer_already() {
     ext().execute_with(|| {
@@ -878,3 +956,126 @@ fn withdraw_fee_set_referrer_already2() {
         assert_eq!(ReferrerBalances::<Runtime>::get(&bob()), Some(SMALL_FEE));
     });
 }
+
+#[test]
+fn fee_payment_in_whitelisted_asset() {
+    ext().execute_with(|| {
+        set_weight_to_fee_multiplier(1);
+        increase_balance(alice(), VAL.into(), balance!(1000));
+
+        increase_balance(bob(), XOR.into(), balance!(1000));
+        increase_balance(bob(), VAL.into(), balance!(1000));
+
+        ensure_pool_initialized(XOR.into(), VAL.into());
+        PoolXYK::deposit_liquidity(
+            RuntimeOrigin::signed(bob()),
+            0,
+            XOR.into(),
+            VAL.into(),
+            balance!(500),
+            balance!(500),
+            balance!(450),
+            balance!(450),
+        )
+        .unwrap();
+
+        fill_spot_price();
+
+        assert_ok!(XorFee::whitelist_fee_asset(
+            RuntimeOrigin::root(),
+            VAL.into(),
+            Percent::from_percent(10)
+        ));
+
+        let call = RuntimeCall::Assets(assets::Call::transfer {
+            asset_id: VAL.into(),
+            to: bob(),
+            amount: TRANSFER_AMOUNT,
+        });
+        let len = 10;
+        let dispatch_info = info_from_weight(MOCK_WEIGHT);
+        let fee = pallet_transaction_payment::Pallet::<Runtime>::compute_fee(
+            len as u32,
+            &dispatch_info,
+            0,
+        );
+        let fee_in_val = XorFee::fee_amount_in_asset(&VAL.into(), fee).unwrap();
+        let xor_balance = Balances::free_balance(alice());
+        let val_balance = Assets::free_balance(&VAL.into(), &alice()).unwrap();
+
+        // Validation only quotes the exchange of the fee asset
+        with_transaction(|| {
+            assert_ok!(
+                ChargeFeeInAsset::<Runtime>::from(Some(VAL.into())).validate(
+                    &alice(),
+                    &call,
+                    &dispatch_info,
+                    len
+                )
+            );
+            assert_ok!(
+                ChargeTransactionPayment::<Runtime>::from(0u128.into()).validate(
+                    &alice(),
+                    &call,
+                    &dispatch_info,
+                    len
+                )
+            );
+            assert_eq!(Assets::free_balance(&VAL.into(), &alice()), Ok(val_balance));
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(()))
+        })
+        .unwrap();
+
+        assert_ok!(
+            ChargeFeeInAsset::<Runtime>::from(Some(VAL.into())).pre_dispatch(
+                &alice(),
+                &call,
+                &dispatch_info,
+                len
+            )
+        );
+        let pre = ChargeTransactionPayment::<Runtime>::from(0u128.into())
+            .pre_dispatch(&alice(), &call, &dispatch_info, len)
+            .unwrap();
+        assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+            Some(pre),
+            &dispatch_info,
+            &default_post_info(),
+            len,
+            &Ok(())
+        )
+        .is_ok());
+
+        assert_eq!(
+            Assets::free_balance(&VAL.into(), &alice()),
+            Ok(val_balance - fee_in_val)
+        );
+        // Price margin is left to the user in XOR
+        assert!(Balances::free_balance(alice()) >= xor_balance);
+    });
+}
+
+#[test]
+fn fee_payment_in_not_whitelisted_asset_fails() {
+    ext().execute_with(|| {
+        increase_balance(alice(), VAL.into(), balance!(1000));
+
+        let call = RuntimeCall::Assets(assets::Call::transfer {
+            asset_id: VAL.into(),
+            to: bob(),
+            amount: TRANSFER_AMOUNT,
+        });
+
+        assert_eq!(
+            ChargeFeeInAsset::<Runtime>::from(Some(VAL.into())).validate(
+                &alice(),
+                &call,
+                &info_from_weight(MOCK_WEIGHT),
+                10
+            ),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Payment
+            ))
+        );
+    });
+}